@@ -74,7 +74,7 @@ pub mod get_info_command {
         let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
         let alias = get_str_param("alias", params).map_err(error_err!())?;
 
-        let res = match CheqdKeys::get_info(wallet_handle, alias) {
+        let res = match CheqdKeys::get_info(wallet_handle, alias, get_cheqd_connected_pool(ctx).as_deref()) {
             Ok(resp) => {
                 println_succ!("Get follow info \"{}\" ", resp);
                 Ok(())
@@ -104,7 +104,7 @@ pub mod get_list_keys_command {
 
         let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
 
-        let res = match CheqdKeys::get_list_keys(wallet_handle) {
+        let res = match CheqdKeys::get_list_keys(wallet_handle, get_cheqd_connected_pool(ctx).as_deref()) {
             Ok(resp) => {
                 let resp: Vec<serde_json::Value> = serde_json::from_str(&resp)
                     .map_err(|_| println_err!("{}", format!("Wrong data has been received: {}", resp)))?;
@@ -200,7 +200,7 @@ pub mod tests {
 
     pub fn get_key(ctx: &CommandContext) -> serde_json::Value {
         let wallet_handle = ensure_opened_wallet_handle(ctx).unwrap();
-        let key = CheqdKeys::get_info(wallet_handle, KEY_ALIAS_WITH_BALANCE).unwrap();
+        let key = CheqdKeys::get_info(wallet_handle, KEY_ALIAS_WITH_BALANCE, get_cheqd_connected_pool(ctx).as_deref()).unwrap();
         serde_json::from_str(&key).unwrap()
     }
 }
\ No newline at end of file
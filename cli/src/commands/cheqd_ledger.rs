@@ -79,7 +79,7 @@ pub mod create_nym_command {
         let pool_alias = ensure_cheqd_connected_pool(ctx)?;
         let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
         let timeout_height = get_timeout_height(&pool_alias)?;
-        let key_info = CheqdKeys::get_info(wallet_handle, key_alias)
+        let key_info = CheqdKeys::get_info(wallet_handle, key_alias, Some(pool_alias.as_str()))
             .map_err(|err| handle_indy_error(err, None, None, None))?;
 
         let key_info_json: Value = serde_json::from_str(&key_info)
@@ -181,7 +181,7 @@ pub mod bank_send_command {
         let memo = get_opt_str_param("memo", params).map_err(error_err!())?.unwrap_or("");
         let pool_alias = ensure_cheqd_connected_pool(ctx)?;
 
-        let request = CheqdLedger::build_msg_send(from, to, amount, denom)
+        let request = CheqdLedger::build_msg_send(&pool_alias, from, to, amount, denom)
             .map_err(|err| handle_indy_error(err, None, None, None))?;
 
         let response = build_and_sign_and_broadcast_tx(ctx, &pool_alias, &request, key_alias, denom, max_gas, max_coin, memo)?;
@@ -201,7 +201,7 @@ pub mod get_balance_command {
     command!(CommandMetadata::build("get-balance", "Get balance from Ledger.")
                 .add_required_param("address", "Account identifier.")
                 .add_required_param("denom", "Account balance denom")
-                .add_example("cheqd-ledger get-balance address=cosmos1mhl8w0xvdl3r6xf67utnqna77q0vjqgzenk7yv")
+                .add_example("cheqd-ledger get-balance address=cheqd1mhl8w0xvdl3r6xf67utnqna77q0vjqgzh3670a")
                 .finalize()
     );
 
@@ -211,7 +211,7 @@ pub mod get_balance_command {
         let denom = get_str_param("denom", params).map_err(error_err!())?;
         let pool_alias = ensure_cheqd_connected_pool(ctx)?;
 
-        let query = CheqdLedger::build_query_balance(address, denom)
+        let query = CheqdLedger::build_query_balance(&pool_alias, address, denom)
             .map_err(|err| handle_indy_error(err, None, None, None))?;
         let response = CheqdPool::abci_query(&pool_alias, &query)
             .map_err(|err| handle_indy_error(err, None, None, None))?;
@@ -288,7 +288,7 @@ pub fn build_and_sign_and_broadcast_tx(ctx: &CommandContext,
     let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
     let timeout_height = get_timeout_height(pool_alias)?;
 
-    let key_info = CheqdKeys::get_info(wallet_handle, key_alias)
+    let key_info = CheqdKeys::get_info(wallet_handle, key_alias, Some(pool_alias))
         .map_err(|err| handle_indy_error(err, None, None, None))?;
     let key_info_json: Value = serde_json::from_str(&key_info)
         .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
@@ -19,7 +19,15 @@ pub mod add_command {
                 .add_required_param("alias", "Alias for pool.")
                 .add_required_param("rpc_address", "RPC address of pool. Nodes need of RPC pool`s address for connection.")
                 .add_required_param("chain_id", "It marks unique id of network where pool will be created.")
+                .add_optional_param("account_prefix", "Bech32 prefix of network accounts. \"cheqd\" is used by default.")
+                .add_optional_param("trust_height", "Height of the header trusted by the light client. Query proofs are verified against the network only if it is set.")
+                .add_optional_param("trust_hash", "Hex encoded hash of the header trusted by the light client. Required if trust_height is set.")
+                .add_optional_param("backup_rpc_addresses", "Comma separated RPC addresses used if the node at rpc_address doesn't respond.")
+                .add_optional_param("timeout", "Timeout of a single request to a node in seconds. 20 is used by default.")
                 .add_example("cheqd-pool add alias=my_pool rpc_address=http://127.0.0.1:26657 chain_id=cheqdnode")
+                .add_example("cheqd-pool add alias=my_pool rpc_address=http://127.0.0.1:26657 chain_id=cheqdnode account_prefix=cheqd")
+                .add_example("cheqd-pool add alias=my_pool rpc_address=http://127.0.0.1:26657 chain_id=cheqdnode trust_height=1 trust_hash=6B68DCE8C5E48A1E4A1C1A4DBA4E7C8B2A2D4D25A1AA9D1B3AC2C2C5C2E1A1F0")
+                .add_example("cheqd-pool add alias=my_pool rpc_address=http://127.0.0.1:26657 chain_id=cheqdnode backup_rpc_addresses=http://127.0.0.2:26657,http://127.0.0.3:26657 timeout=5")
                 .finalize()
     );
 
@@ -28,8 +36,32 @@ pub mod add_command {
         let alias = get_str_param("alias", params).map_err(error_err!())?;
         let rpc_address = get_str_param("rpc_address", params).map_err(error_err!())?;
         let chain_id = get_str_param("chain_id", params).map_err(error_err!())?;
+        let account_prefix = get_opt_str_param("account_prefix", params).map_err(error_err!())?;
+        let trust_height = get_opt_number_param::<u64>("trust_height", params).map_err(error_err!())?;
+        let trust_hash = get_opt_str_param("trust_hash", params).map_err(error_err!())?;
+        let backup_rpc_addresses = get_opt_str_array_param("backup_rpc_addresses", params).map_err(error_err!())?;
+        let timeout = get_opt_number_param::<u64>("timeout", params).map_err(error_err!())?;
+
+        let trust_options = match (trust_height, trust_hash) {
+            (Some(height), Some(hash)) => Some(json!({ "height": height, "hash": hash }).to_string()),
+            (None, None) => None,
+            _ => {
+                println_err!("Both \"trust_height\" and \"trust_hash\" must be specified");
+                return Err(());
+            }
+        };
+
+        let connection_options = if backup_rpc_addresses.is_some() || timeout.is_some() {
+            let mut connection_options = json!({ "backup_rpc_addresses": backup_rpc_addresses.unwrap_or_default() });
+            if let Some(timeout) = timeout {
+                connection_options["timeout"] = json!(timeout);
+            }
+            Some(connection_options.to_string())
+        } else {
+            None
+        };
 
-        let res = match CheqdPoolLibindy::add(alias, rpc_address, chain_id) {
+        let res = match CheqdPoolLibindy::add(alias, rpc_address, chain_id, account_prefix, trust_options.as_deref(), connection_options.as_deref()) {
             Ok(pool) => {
                 println_succ!("Pool \"{}\" has been created \"{}\"", alias, pool);
                 Ok(())
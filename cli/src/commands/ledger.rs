@@ -2042,6 +2042,96 @@ pub mod get_acceptance_mechanisms_command {
     }
 }
 
+pub mod ledgers_freeze_command {
+    use super::*;
+
+    command!(CommandMetadata::build("ledgers-freeze", r#"Freeze ledgers so they can't be written to and are excluded from consensus and catch-up"#)
+                .add_required_param("ledgers_ids","The list of ledgers IDs to freeze")
+                .add_optional_param("sign","Sign the request (True by default)")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger ledgers-freeze ledgers_ids=1,2,3")
+                .add_example("ledger ledgers-freeze ledgers_ids=909 send=false")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let ledgers_ids = get_str_array_param("ledgers_ids", params).map_err(error_err!())?
+            .into_iter()
+            .map(|id| id.parse::<u64>()
+                .map_err(|_| println_err!("Invalid ledger id: {}. Ledger id must be a non-negative number.", id)))
+            .collect::<Result<Vec<u64>, ()>>()?;
+
+        let request = Ledger::build_ledgers_freeze_request(&submitter_did, &json!(ledgers_ids).to_string())
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (_, response): (String, Response<serde_json::Value>) =
+            send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
+
+        let res = handle_transaction_response(response)
+            .map(|result| print_transaction_response(result,
+                                                     "LedgersFreeze request has been sent to Ledger.",
+                                                     None,
+                                                     &[("ledgers_ids", "Ledgers IDs")],
+                                                     true));
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_frozen_ledgers_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-frozen-ledgers", r#"Get a list of frozen ledgers"#)
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger get-frozen-ledgers")
+                .add_example("ledger get-frozen-ledgers send=false")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let request = Ledger::build_get_frozen_ledgers_request(&submitter_did)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (response_json, response) = send_read_request!(&ctx, params, &request, Some(&submitter_did));
+
+        handle_transaction_response(response)?;
+
+        let frozen_ledgers = Ledger::parse_get_frozen_ledgers_response(&response_json)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let frozen_ledgers = serde_json::from_str::<BTreeMap<u64, serde_json::Value>>(&frozen_ledgers)
+            .map_err(|err| println_err!("Wrong data has been received: {:?}", err))?
+            .into_iter()
+            .map(|(ledger_id, frozen_ledger)|
+                json!({
+                    "ledger_id": ledger_id,
+                    "ledger": frozen_ledger["ledger"],
+                    "state": frozen_ledger["state"],
+                    "seq_no": frozen_ledger["seq_no"],
+                }))
+            .collect::<Vec<serde_json::Value>>();
+
+        print_list_table(&frozen_ledgers,
+                         &[("ledger_id", "Ledger ID"),
+                             ("ledger", "Ledger root hash"),
+                             ("state", "State root hash"),
+                             ("seq_no", "Last Sequence Number")],
+                         "There are no frozen ledgers");
+
+        trace!("execute <<");
+        Ok(())
+    }
+}
+
 pub fn set_author_agreement(ctx: &CommandContext, request: &mut String) -> Result<(), ()> {
     if let Some((text, version, acc_mech_type, time_of_acceptance)) = get_transaction_author_info(&ctx) {
         if acc_mech_type.is_empty() {
@@ -2251,6 +2341,9 @@ fn get_txn_title(role: &serde_json::Value) -> serde_json::Value {
         Some("5") => "TXN_AUTHR_AGRMT_AML",
         Some("6") => "GET_TXN_AUTHR_AGRMT",
         Some("7") => "GET_TXN_AUTHR_AGRMT_AML",
+        Some("8") => "DISABLE_ALL_TXN_AUTHR_AGRMTS",
+        Some("9") => "LEDGERS_FREEZE",
+        Some("10") => "GET_FROZEN_LEDGERS",
         Some("100") => "ATTRIB",
         Some("101") => "SCHEMA",
         Some("104") => "GET_ATTR",
@@ -3772,6 +3865,52 @@ pub mod tests {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        pub fn ledgers_freeze_works_without_sending() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "909,910".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let transaction = get_transaction(&ctx).unwrap();
+            let transaction: serde_json::Value = serde_json::from_str(&transaction).unwrap();
+            assert_eq!(json!([909, 910]), transaction["operation"]["ledgers_ids"]);
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn ledgers_freeze_works_for_invalid_ledger_id() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "domain".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn get_frozen_ledgers_works() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = get_frozen_ledgers_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
     mod pool_restart {
         use super::*;
 
@@ -11,15 +11,15 @@ impl CheqdKeys {
     }
 
     pub fn add_from_mnemonic(wallet_handle: WalletHandle, alias: &str, mnemonic: &str) -> Result<String, IndyError> {
-        cheqd_keys::add_from_mnemonic(wallet_handle, alias, mnemonic).wait()
+        cheqd_keys::add_from_mnemonic(wallet_handle, alias, mnemonic, None).wait()
     }
 
-    pub fn get_info(wallet_handle: WalletHandle, alias: &str) -> Result<String, IndyError> {
-        cheqd_keys::get_info(wallet_handle, alias).wait()
+    pub fn get_info(wallet_handle: WalletHandle, alias: &str, pool_alias: Option<&str>) -> Result<String, IndyError> {
+        cheqd_keys::get_info(wallet_handle, alias, pool_alias).wait()
     }
 
-    pub fn get_list_keys(wallet_handle: WalletHandle) -> Result<String, IndyError> {
-        cheqd_keys::get_list_keys(wallet_handle).wait()
+    pub fn get_list_keys(wallet_handle: WalletHandle, pool_alias: Option<&str>) -> Result<String, IndyError> {
+        cheqd_keys::get_list_keys(wallet_handle, pool_alias).wait()
     }
 
     pub fn sign(wallet_handle: WalletHandle, alias: &str, tx: &[u8]) -> Result<Vec<u8>, IndyError> {
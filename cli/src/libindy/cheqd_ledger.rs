@@ -38,20 +38,22 @@ impl CheqdLedger {
         cheqd_ledger::cheqd::parse_msg_create_nym_resp(commit_resp).wait()
     }
 
-    pub fn build_msg_send(from: &str,
+    pub fn build_msg_send(pool_alias: &str,
+                          from: &str,
                           to: &str,
                           amount: &str,
                           denom: &str) -> Result<Vec<u8>, IndyError> {
-        cheqd_ledger::bank::build_msg_send(from, to, amount, denom).wait()
+        cheqd_ledger::bank::build_msg_send(pool_alias, from, to, amount, denom).wait()
     }
 
     pub fn parse_msg_send_resp(resp: &str) -> Result<String, IndyError> {
         cheqd_ledger::bank::parse_msg_send_resp(resp).wait()
     }
 
-    pub fn build_query_balance(address: &str,
+    pub fn build_query_balance(pool_alias: &str,
+                               address: &str,
                                denom: &str) -> Result<String, IndyError> {
-        cheqd_ledger::bank::build_query_balance(address, denom).wait()
+        cheqd_ledger::bank::build_query_balance(pool_alias, address, denom).wait()
     }
 
     pub fn parse_query_balance_resp(resp: &str) -> Result<String, IndyError> {
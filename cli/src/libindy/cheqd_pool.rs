@@ -5,8 +5,8 @@ use indy::cheqd_pool;
 pub struct CheqdPool {}
 
 impl CheqdPool {
-    pub fn add(alias: &str, rpc_address: &str, chain_id: &str) -> Result<String, IndyError> {
-        cheqd_pool::add(alias, rpc_address, chain_id).wait()
+    pub fn add(alias: &str, rpc_address: &str, chain_id: &str, account_prefix: Option<&str>, trust_options: Option<&str>, connection_options: Option<&str>) -> Result<String, IndyError> {
+        cheqd_pool::add(alias, rpc_address, chain_id, account_prefix, trust_options, connection_options).wait()
     }
 
     pub fn get_config(alias: &str) -> Result<String, IndyError> {
@@ -59,6 +59,18 @@ impl Ledger {
         ledger::build_get_validator_info_request(submitter_did).wait()
     }
 
+    pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Result<String, IndyError> {
+        ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+    }
+
+    pub fn build_get_frozen_ledgers_request(submitter_did: &str) -> Result<String, IndyError> {
+        ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+    }
+
+    pub fn parse_get_frozen_ledgers_response(response: &str) -> Result<String, IndyError> {
+        ledger::parse_get_frozen_ledgers_response(response).wait()
+    }
+
     pub fn build_get_cred_def_request(submitter_did: Option<&str>, id: &str) -> Result<String, IndyError> {
         ledger::build_get_cred_def_request(submitter_did, id).wait()
     }
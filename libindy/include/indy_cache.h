@@ -123,6 +123,230 @@ extern "C" {
                                                   void          (*cb)(indy_handle_t command_handle_,
                                                                       indy_error_t  err)
                                                  );
+
+    /// Gets NYM data for specified DID.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// did: target DID.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    ///
+    /// #Returns
+    /// NYM data json:
+    /// {
+    ///     did: DID as base58-encoded string for 16 or 32 bit DID value.
+    ///     verkey: verification key as base58-encoded string.
+    ///     role: Role associated number
+    /// }
+    extern indy_error_t indy_get_nym(indy_handle_t command_handle,
+                                     indy_handle_t pool_handle,
+                                     indy_handle_t wallet_handle,
+                                     const char *  submitter_did,
+                                     const char *  did,
+                                     const char *  options_json,
+                                     void          (*cb)(indy_handle_t command_handle_,
+                                                         indy_error_t  err,
+                                                         const char*   nym_json)
+                                    );
+
+    /// Gets attribute data for specified DID.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// Note: one of the fields `hash`, `raw`, `enc` must be specified.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// raw: (Optional) Requested attribute name.
+    /// hash: (Optional) Requested attribute hash.
+    /// enc: (Optional) Requested attribute encrypted value.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    ///
+    /// #Returns
+    /// Attribute data as it is stored on the ledger.
+    extern indy_error_t indy_get_attrib(indy_handle_t command_handle,
+                                        indy_handle_t pool_handle,
+                                        indy_handle_t wallet_handle,
+                                        const char *  submitter_did,
+                                        const char *  target_did,
+                                        const char *  raw,
+                                        const char *  hash,
+                                        const char *  enc,
+                                        const char *  options_json,
+                                        void          (*cb)(indy_handle_t command_handle_,
+                                                            indy_error_t  err,
+                                                            const char*   attrib_data)
+                                       );
+
+    /// Gets revocation registry definition json data for specified revocation registry definition id.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// id: identifier of revocation registry definition.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    ///
+    /// #Returns
+    /// Revocation Registry Definition json.
+    extern indy_error_t indy_get_revoc_reg_def(indy_handle_t command_handle,
+                                               indy_handle_t pool_handle,
+                                               indy_handle_t wallet_handle,
+                                               const char *  submitter_did,
+                                               const char *  id,
+                                               const char *  options_json,
+                                               void          (*cb)(indy_handle_t command_handle_,
+                                                                   indy_error_t  err,
+                                                                   const char*   revoc_reg_def_json)
+                                              );
+
+    /// Gets revocation registry delta for specified revocation registry definition id and time interval.
+    /// If the whole interval is present inside of cache, cached data is returned.
+    /// If cached delta ends before `to`, only the missing part of the interval is fetched
+    /// from the ledger and merged into the cached delta.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// id: identifier of revocation registry definition.
+    /// from: Requested time represented as a total number of seconds from Unix Epoch (-1 means from the registry creation)
+    /// to: Requested time represented as a total number of seconds from Unix Epoch
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    ///
+    /// #Returns
+    /// Revocation Registry Delta json and timestamp of its accumulator.
+    extern indy_error_t indy_get_revoc_reg_delta(indy_handle_t command_handle,
+                                                 indy_handle_t pool_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  submitter_did,
+                                                 const char *  id,
+                                                 long long     from,
+                                                 long long     to,
+                                                 const char *  options_json,
+                                                 void          (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   revoc_reg_delta_json,
+                                                                     unsigned long long timestamp)
+                                                );
+
+    /// Purge NYM cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_nym_cache(indy_handle_t command_handle,
+                                                indy_handle_t wallet_handle,
+                                                const char *  options_json,
+                                                void          (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err)
+                                            );
+
+    /// Purge attribute cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_attrib_cache(indy_handle_t command_handle,
+                                                indy_handle_t wallet_handle,
+                                                const char *  options_json,
+                                                void          (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err)
+                                               );
+
+    /// Purge revocation registry definition cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_revoc_reg_def_cache(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  options_json,
+                                                       void          (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err)
+                                                      );
+
+    /// Purge revocation registry delta cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_revoc_reg_delta_cache(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  options_json,
+                                                         void          (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err)
+                                                        );
 #ifdef __cplusplus
 }
 #endif
@@ -26,6 +26,7 @@ extern "C" {
     ///               currently only 'ed25519' value is supported for this field)
     ///     "cid": bool, (optional; if not set then false is used;)
     ///     "method_name": string, method name to create fully qualified did (Example:  `did:method_name:NcYxiDXkpYi6ov5FcYDi1e`).
+    ///                    did:indy method must include ledger namespace with optional sub-namespace: `indy:sovrin:staging`.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -457,6 +458,37 @@ extern "C" {
                                                                  const char *const full_qualified_did)
                                         );
 
+    /// Resolves W3C DID Document for the DID written on Indy ledger.
+    /// Verification method is built from NYM transaction and service from ATTRIB `endpoint`.
+    /// Supported DID methods are `sov` and `indy`, unqualified DIDs are treated as `did:sov`.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// pool_handle: Pool handle (created by open_pool).
+    /// did: DID to resolve.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - resolution_result: DID resolution result json
+    ///   {"didDocument": {..}, "didResolutionMetadata": {..}, "didDocumentMetadata": {..}}
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    /// Crypto*
+    extern indy_error_t indy_resolve_did_document(indy_handle_t     command_handle,
+                                                  indy_handle_t     pool_handle,
+                                                  const char *const did,
+
+                                                  void              (*cb)(indy_handle_t     command_handle,
+                                                                          indy_error_t      err,
+                                                                          const char *const resolution_result)
+                                                 );
+
 #ifdef __cplusplus
 }
 #endif
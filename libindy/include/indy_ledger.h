@@ -68,6 +68,121 @@ extern "C" {
                                                                  const char*   request_result_json)
                                            );
 
+    /// Publishes a batch of read requests to validator pool with a single pool command.
+    ///
+    /// Every request is processed as if it was sent by indy_submit_request (including consensus
+    /// and state proof verification), but all of them share pool connections.
+    /// Failure of one request doesn't affect the others.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// requests_json: array of read requests data json. Only GET_* requests and requests
+    ///                with a registered state proof parser are accepted.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Array of results in the same order as requests:
+    /// [
+    ///     {"response": string - request result json} or
+    ///     {"error": {"code": <int> - error code, "message": string - error description}},
+    ///     ...
+    /// ]
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_requests_batch(indy_handle_t command_handle,
+                                                   indy_handle_t pool_handle,
+                                                   const char *  requests_json,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   results_json)
+                                                  );
+
+    /// Replicates DOMAIN or CONFIG ledger of the pool into a local append-only store.
+    ///
+    /// Transactions are fetched with GET_TXN requests starting right after the last stored one,
+    /// so replication can be interrupted and resumed later. A transaction is stored only if the
+    /// replica extended with it is consistent (checked with a merkle tree consistency proof)
+    /// with the multi-signed ledger root returned by the pool.
+    ///
+    /// The replica is kept in the indy home directory and isn't removed with the pool config.
+    /// Concurrent calls for the same pool and ledger are performed one after another.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// ledger_type: type of the ledger to replicate: "DOMAIN" or "CONFIG".
+    /// options_json: (Optional) replication options:
+    ///     {
+    ///         "batchSize": (Optional) <int> - count of GET_TXN requests sent at once (100 by default),
+    ///         "maxTxns": (Optional) <int> - max count of transactions fetched by this call (all by default),
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Replication status json:
+    /// {
+    ///     "ledgerType": string - replicated ledger,
+    ///     "fromSeqNo": <int> - sequence number replication has been started from,
+    ///     "seqNo": <int> - sequence number of the last stored transaction,
+    ///     "rootHash": string - base58 merkle root of the replica (null if it's empty),
+    ///     "ledgerSize": <int> - ledger size confirmed by the pool (null if no transactions were fetched),
+    ///     "signedRootHash": string - multi-signed base58 merkle root of the ledger of "ledgerSize",
+    ///     "complete": bool - whether the replica has caught up with the ledger
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_replicate_ledger(indy_handle_t command_handle,
+                                              indy_handle_t pool_handle,
+                                              const char *  ledger_type,
+                                              const char *  options_json,
+
+                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                   indy_error_t  err,
+                                                                   const char*   status_json)
+                                             );
+
+    /// Reads transactions from the local replica of DOMAIN or CONFIG ledger (see indy_replicate_ledger).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_name: name of the pool ledger configuration the replica has been made for.
+    /// ledger_type: type of the replicated ledger: "DOMAIN" or "CONFIG".
+    /// filter_json: (Optional) transactions filter:
+    ///     {
+    ///         "from": (Optional) <int> - first sequence number (1 by default),
+    ///         "to": (Optional) <int> - last sequence number (last stored by default),
+    ///         "authors": (Optional) [string] - return only transactions authored or endorsed by these DIDs,
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// {
+    ///     "seqNo": <int> - sequence number of the last stored transaction,
+    ///     "rootHash": string - base58 merkle root of the replica (null if it's empty),
+    ///     "txns": [{"txn": {..}, "txnMetadata": {..}, "ver": string, "reqSignature": {..}}, ...]
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_get_ledger_replica_txns(indy_handle_t command_handle,
+                                                     const char *  pool_name,
+                                                     const char *  ledger_type,
+                                                     const char *  filter_json,
+
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   txns_json)
+                                                    );
+
     /// Send action to particular nodes of validator pool.
     ///
     /// The list of requests can be send:
@@ -952,6 +1067,32 @@ extern "C" {
                                                                );
 
 
+    /// Register callbacks for parsing state proofs of plugged transaction type.
+    ///
+    /// Reads of transactions of the registered type are sent to a single node
+    /// and accepted if the reply contains valid state proof and multi-signature.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// txn_type: type of transaction to apply `parser` callback.
+    /// parser: required callback to parse reply for state proof.
+    ///     reply_from_node: string representation of node's reply ("as is")
+    ///     parsed_sp: out param to return serialized as string JSON with array of ParsedSP
+    /// free: required callback to deallocate memory allocated by `parser`.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_register_transaction_parser_for_sp(indy_handle_t command_handle,
+                                                                const char *  txn_type,
+                                                                indy_error_t (*parser)(const char *  reply_from_node,
+                                                                                       const char ** parsed_sp),
+                                                                indy_error_t (*free)(const char * data),
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err)
+                                                               );
+
     /// Parse transaction response to fetch metadata.
     /// The important use case for this method is validation of Node's response freshens.
     ///
@@ -1326,6 +1467,65 @@ extern "C" {
                                                                                                     const char*   request_with_meta_json)
                                                                                );
 
+    /// Stores transaction author agreement acceptance for the pool in the wallet.
+    ///
+    /// indy_sign_and_submit_request appends the stored acceptance to DOMAIN write requests
+    /// sent to the pool with this name if the ledger requires the transaction author agreement
+    /// and "taa_acceptance" isn't set in the pool open config.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// pool_name: name of the pool ledger configuration.
+    /// acceptance_json: transaction author agreement acceptance json
+    ///     {
+    ///         "text": string (optional), "version": string (optional) - accepted TAA (passed together),
+    ///         "taa_digest": string (optional) - digest of the accepted TAA (required if text and version are omitted),
+    ///         "mechanism": string - acceptance mechanism from the active AML,
+    ///         "time": int - UTC timestamp of the acceptance. Note that the time portion will be discarded to avoid a privacy risk.
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_store_txn_author_agreement_acceptance(indy_handle_t command_handle,
+                                                                   indy_handle_t wallet_handle,
+                                                                   const char *  pool_name,
+                                                                   const char *  acceptance_json,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err)
+                                                                   );
+
+    /// Returns transaction author agreement and acceptance mechanisms active on the ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Active transaction author agreement json or null if the ledger doesn't require it:
+    /// {
+    ///     "text": string, "version": string, "digest": string, "ratificationTs": int (optional),
+    ///     "aml": object (optional), "amlVersion": string (optional), "amlContext": string (optional)
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_get_pool_txn_author_agreement(indy_handle_t command_handle,
+                                                           indy_handle_t pool_handle,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   taa_json)
+                                                           );
+
     /// Append Endorser to an existing request.
     ///
     /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1354,6 +1554,199 @@ extern "C" {
                                                                           const char*   out_request_json)
                                                      );
 
+    /// Builds a transaction bundle to collect signatures of the transaction author, the Endorser
+    /// and other signers required by the ledger auth rules before submission.
+    ///
+    /// The bundle can be passed between parties as JSON. Each of them signs it with indy_sign_transaction_bundle.
+    /// The required number of signatures is derived from the auth rule constraints fetched from the ledger
+    /// by GET_AUTH_RULE request.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: request to collect signatures for.
+    ///     Signatures already present in the request are moved into the bundle.
+    /// options_json: (optional) bundle options:
+    /// {
+    ///     "signers": [string] - (optional) DIDs which must sign the request
+    ///                           in addition to the transaction author and the Endorser,
+    ///     "expiresAt": int - (optional) timestamp after which the bundle can't be signed and submitted,
+    ///     "authAction": string - (optional) auth rule action of the request: "ADD" (default) or "EDIT",
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction bundle json:
+    /// {
+    ///     "ver": "1",
+    ///     "request": object - request without signatures,
+    ///     "requiredSigners": [string] - DIDs which must sign the request,
+    ///     "requiredSignatureCount": int - total number of signatures required by the ledger,
+    ///     "constraint": object (optional) - auth rule constraint of the request,
+    ///     "signatures": {did: signature} - collected signatures,
+    ///     "expiresAt": int (optional) - expiration timestamp,
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_build_transaction_bundle(indy_handle_t command_handle,
+                                                      indy_handle_t pool_handle,
+                                                      const char *  request_json,
+                                                      const char *  options_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   bundle_json)
+                                                      );
+
+    /// Adds the signature of the signer to the transaction bundle.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// bundle_json: transaction bundle (see indy_build_transaction_bundle).
+    /// signer_did: DID of the signer stored in the wallet.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction bundle json with the signature added.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_sign_transaction_bundle(indy_handle_t command_handle,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *  bundle_json,
+                                                     const char *  signer_did,
+
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   bundle_json)
+                                                     );
+
+    /// Returns which signatures are still missing in the transaction bundle.
+    ///
+    /// Signatures of the bundle are verified against its request first. Whether signature keys
+    /// belong to the signers on the ledger is checked only on submit (see indy_submit_transaction_bundle).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// bundle_json: transaction bundle (see indy_build_transaction_bundle).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction bundle status json:
+    /// {
+    ///     "missingSigners": [string] - required signers which haven't signed the request yet,
+    ///     "missingSignatureCount": int - number of signatures still required by the ledger,
+    ///     "expired": bool - whether the bundle has expired,
+    ///     "ready": bool - whether the bundle can be submitted,
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_get_transaction_bundle_status(indy_handle_t command_handle,
+                                                           const char *  bundle_json,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   status_json)
+                                                           );
+
+    /// Submits the request of the transaction bundle with all collected signatures to validator pool.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// bundle_json: transaction bundle (see indy_build_transaction_bundle).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    ///
+    /// CommonInvalidState is returned if the bundle has expired or is missing signatures.
+    extern indy_error_t indy_submit_transaction_bundle(indy_handle_t command_handle,
+                                                       indy_handle_t pool_handle,
+                                                       const char *  bundle_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   request_result_json)
+                                                       );
+
+    /// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    /// ledgers_ids: list of ledgers IDs for freezing ledgers (json format).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_ledgers_freeze_request(indy_handle_t command_handle,
+                                                          const char *  submitter_did,
+                                                          const char *  ledgers_ids,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   request_json)
+                                                          );
+
+    /// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: DID of the read request sender.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_get_frozen_ledgers_request(indy_handle_t command_handle,
+                                                              const char *  submitter_did,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   request_json)
+                                                              );
+
+    /// Parse a GET_FROZEN_LEDGERS response to get list of frozen ledgers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_frozen_ledgers_response: response on GET_FROZEN_LEDGERS request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Frozen ledgers json:
+    /// {
+    ///     <ledger_id>: {"ledger": string, "state": string, "seq_no": u64},
+    ///     ...
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_parse_get_frozen_ledgers_response(indy_handle_t command_handle,
+                                                               const char *  get_frozen_ledgers_response,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   frozen_ledgers_json)
+                                                               );
+
 #ifdef __cplusplus
 }
 #endif
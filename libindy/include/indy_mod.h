@@ -132,6 +132,9 @@ typedef enum
     // Item not found on ledger.
     LedgerNotFound = 309,
 
+    // Ledger response proof doesn't match the state verified by the light client.
+    LedgerInvalidProofError = 310,
+
     // Revocation registry is full and creation of new registry is necessary
     AnoncredsRevocationRegistryFullError = 400,
 
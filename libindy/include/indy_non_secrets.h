@@ -176,6 +176,35 @@ extern "C" {
                                                                     const char*   record_json)
                                               );
 
+    /// Apply several non-secret record changes atomically.
+    ///
+    /// Either all operations are stored or, if any of them fails, none of them is.
+    /// Operations are applied in order, so a later operation can refer to a record added
+    /// by an earlier one.
+    ///
+    /// Note that plugged wallet storages don't support atomic batches.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// operations_json: list of operations as json:
+    ///   [
+    ///     {"op": "add", "type": <str>, "id": <str>, "value": <str>, "tags": (optional) {<tag_name>: <str>, ...}},
+    ///     {"op": "update", "type": <str>, "id": <str>, "value": <str>},
+    ///     {"op": "addTags", "type": <str>, "id": <str>, "tags": {<tag_name>: <str>, ...}},
+    ///     {"op": "updateTags", "type": <str>, "id": <str>, "tags": {<tag_name>: <str>, ...}},
+    ///     {"op": "deleteTags", "type": <str>, "id": <str>, "tagNames": [<str>, ...]},
+    ///     {"op": "delete", "type": <str>, "id": <str>},
+    ///   ]
+    ///   Tags follow the same rules as in indy_add_wallet_record.
+
+    extern indy_error_t indy_wallet_batch(indy_handle_t  command_handle,
+                                          indy_handle_t  wallet_handle,
+                                          const char*    operations_json,
+                                          void           (*fn)(indy_handle_t command_handle_,
+                                                               indy_error_t  err)
+                                         );
+
     /// Search for wallet records.
     ///
     /// Note instead of immediately returning of fetched records
@@ -200,6 +229,17 @@ extern "C" {
     ///    retrieveType: (optional, false by default) Retrieve record type,
     ///    retrieveValue: (optional, true by default) Retrieve record value,
     ///    retrieveTags: (optional, false by default) Retrieve record tags,
+    ///    offset: (optional) Count of matched records to skip,
+    ///    limit: (optional) Max count of records to return,
+    ///    sortBy: (optional) Sort records by the value of plaintext tag:
+    ///      {
+    ///        tagName: "~tagName", // name of plaintext tag
+    ///        order: (optional, "asc" by default) "asc" or "desc",
+    ///      }
+    ///      Records without the tag go after all others. Order without sortBy is storage specific but stable.
+    ///    cursor: (optional) Cursor returned by indy_fetch_wallet_search_next_records
+    ///      to continue search after the last fetched record. Records added or deleted between pages
+    ///      don't shift the next page. Must be used with the same type, query and sortBy. Can't be combined with offset.
     ///  }
     /// #Returns
     /// search_handle: Wallet search handle that can be used later
@@ -418,6 +418,87 @@ extern "C" {
                                                                          const char *const key)
                                                 );
 
+    /// Rotates wallet data keys (keys used for encryption of record types, names, values and tags).
+    /// Fresh keys are generated and all wallet records are re-encrypted in atomic batches.
+    /// Searchable tags stay searchable as their HMACs are recalculated with the new keys.
+    /// Wallet master key (passphrase) is not changed. Use "rekey" credential of indy_open_wallet for it.
+    ///
+    /// The new keys are persisted before the first batch is applied, so the rotation
+    /// interrupted by a failure or process termination is resumed by the next call of this
+    /// function or automatically on the next indy_open_wallet.
+    /// Other wallet operations wait until the rotation is completed.
+    ///
+    /// Records are read and re-encrypted page by page, so the whole wallet isn't loaded into memory.
+    ///
+    /// Note: Supported only for wallet storages with atomic batches support
+    /// (default SQLite, MySQL and PostgreSQL).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// credentials: Wallet credentials json. Opened wallet doesn't keep the master key,
+    ///              so it is derived again to protect the new data keys.
+    /// {
+    ///   "key": string, Key or passphrase the wallet was opened with.
+    ///   "key_derivation_method": optional<string> Algorithm used for wallet key derivation:
+    ///                             ARGON2I_MOD (used by default), ARGON2I_INT or RAW.
+    /// }
+    /// config: (optional) rotation configuration json.
+    /// {
+    ///   "batch_size": optional<int>, Count of records re-encrypted in one atomic batch. Defaults to 100.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// progress_json: Final rotation progress json
+    /// {
+    ///   "total": int, Count of wallet records
+    ///   "processed": int, Count of processed records
+    ///   "completed": bool, Whether rotation is completed
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_rotate_wallet_data_keys(indy_handle_t     command_handle,
+                                                     indy_handle_t     wallet_handle,
+                                                     const char *const credentials,
+                                                     const char *const config,
+
+                                                     void              (*cb)(indy_handle_t     command_handle,
+                                                                             indy_error_t      err,
+                                                                             const char *const progress_json)
+                                                    );
+
+    /// Returns progress of the wallet data keys rotation started by indy_rotate_wallet_data_keys
+    /// or resumed on wallet opening.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// progress_json: Rotation progress json or "null" if rotation wasn't started for the opened wallet
+    /// {
+    ///   "total": int, Count of wallet records
+    ///   "processed": int, Count of processed records
+    ///   "completed": bool, Whether rotation is completed
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_get_wallet_data_keys_rotation_progress(indy_handle_t command_handle,
+                                                                    indy_handle_t wallet_handle,
+
+                                                                    void          (*cb)(indy_handle_t     command_handle,
+                                                                                        indy_error_t      err,
+                                                                                        const char *const progress_json)
+                                                                   );
+
 #ifdef __cplusplus
 }
 #endif
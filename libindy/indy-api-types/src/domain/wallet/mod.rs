@@ -67,6 +67,26 @@ pub struct ExportConfig {
     pub key_derivation_method: KeyDerivationMethod
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KeyRotationConfig {
+    #[serde(default = "default_key_rotation_batch_size")]
+    pub batch_size: usize,
+}
+
+pub const DEFAULT_KEY_ROTATION_BATCH_SIZE: usize = 100;
+
+fn default_key_rotation_batch_size() -> usize {
+    DEFAULT_KEY_ROTATION_BATCH_SIZE
+}
+
+impl Default for KeyRotationConfig {
+    fn default() -> Self {
+        KeyRotationConfig {
+            batch_size: DEFAULT_KEY_ROTATION_BATCH_SIZE,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct KeyConfig {
     pub seed: Option<String>
@@ -87,6 +107,76 @@ pub struct Record {
 
 pub type Tags = HashMap<String, String>;
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum WalletOperation {
+    Add {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        value: String,
+        #[serde(default)]
+        tags: Tags,
+    },
+    Update {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        value: String,
+    },
+    AddTags {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        tags: Tags,
+    },
+    UpdateTags {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        tags: Tags,
+    },
+    DeleteTags {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+        #[serde(rename = "tagNames")]
+        tag_names: Vec<String>,
+    },
+    Delete {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WalletOperations(pub Vec<WalletOperation>);
+
+impl WalletOperation {
+    pub fn type_(&self) -> &str {
+        match *self {
+            WalletOperation::Add { ref type_, .. }
+            | WalletOperation::Update { ref type_, .. }
+            | WalletOperation::AddTags { ref type_, .. }
+            | WalletOperation::UpdateTags { ref type_, .. }
+            | WalletOperation::DeleteTags { ref type_, .. }
+            | WalletOperation::Delete { ref type_, .. } => type_,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        match *self {
+            WalletOperation::Add { ref id, .. }
+            | WalletOperation::Update { ref id, .. }
+            | WalletOperation::AddTags { ref id, .. }
+            | WalletOperation::UpdateTags { ref id, .. }
+            | WalletOperation::DeleteTags { ref id, .. }
+            | WalletOperation::Delete { ref id, .. } => id,
+        }
+    }
+}
+
 impl Validatable for Config {
     fn validate(&self) -> Result<(), String> {
         if self.id.is_empty() {
@@ -96,3 +186,33 @@ impl Validatable for Config {
     }
 }
 
+impl Validatable for WalletOperation {
+    fn validate(&self) -> Result<(), String> {
+        if self.type_().is_empty() {
+            return Err("Wallet operation record type is empty".to_string());
+        }
+        if self.id().is_empty() {
+            return Err("Wallet operation record id is empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Validatable for KeyRotationConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.batch_size == 0 {
+            return Err("Key rotation batch size must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Validatable for WalletOperations {
+    fn validate(&self) -> Result<(), String> {
+        if self.0.is_empty() {
+            return Err("Empty list of wallet operations has been passed".to_string());
+        }
+
+        self.0.iter().try_for_each(Validatable::validate)
+    }
+}
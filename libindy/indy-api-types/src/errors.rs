@@ -60,6 +60,8 @@ pub enum IndyErrorKind {
     InvalidTransaction,
     #[fail(display = "Item not found on ledger")]
     LedgerItemNotFound,
+    #[fail(display = "Ledger response proof is invalid")]
+    InvalidLedgerProof,
     // Pool errors
     #[fail(display = "Pool not created")]
     PoolNotCreated,
@@ -364,6 +366,11 @@ impl From<sqlx::Error> for IndyError {
                         IndyErrorKind::WalletItemAlreadyExists,
                         "Wallet item already exists",
                     ),
+                    // Unique violation - postgres (23505)
+                    "23505" => err.to_indy(
+                        IndyErrorKind::WalletItemAlreadyExists,
+                        "Wallet item already exists",
+                    ),
                     _ => err.to_indy(IndyErrorKind::InvalidState, "Unexpected database error"),
                 },
                 None => err.to_indy(IndyErrorKind::InvalidState, "Unexpected database error"),
@@ -472,6 +479,7 @@ impl From<IndyErrorKind> for ErrorCode {
             IndyErrorKind::NoConsensus => ErrorCode::LedgerNoConsensusError,
             IndyErrorKind::InvalidTransaction => ErrorCode::LedgerInvalidTransaction,
             IndyErrorKind::LedgerItemNotFound => ErrorCode::LedgerNotFound,
+            IndyErrorKind::InvalidLedgerProof => ErrorCode::LedgerInvalidProofError,
             IndyErrorKind::PoolNotCreated => ErrorCode::PoolLedgerNotCreatedError,
             IndyErrorKind::InvalidPoolHandle => ErrorCode::PoolLedgerInvalidPoolHandle,
             IndyErrorKind::PoolTerminated => ErrorCode::PoolLedgerTerminated,
@@ -573,6 +581,7 @@ impl From<ErrorCode> for IndyErrorKind {
             ErrorCode::LedgerNoConsensusError => IndyErrorKind::NoConsensus,
             ErrorCode::LedgerInvalidTransaction => IndyErrorKind::InvalidTransaction,
             ErrorCode::LedgerNotFound => IndyErrorKind::LedgerItemNotFound,
+            ErrorCode::LedgerInvalidProofError => IndyErrorKind::InvalidLedgerProof,
             ErrorCode::PoolLedgerNotCreatedError => IndyErrorKind::PoolNotCreated,
             ErrorCode::PoolLedgerInvalidPoolHandle => IndyErrorKind::InvalidPoolHandle,
             ErrorCode::PoolLedgerTerminated => IndyErrorKind::PoolTerminated,
@@ -51,6 +51,18 @@ pub fn pool_path(pool_name: &str) -> PathBuf {
     path
 }
 
+pub fn ledger_replica_home_path() -> PathBuf {
+    let mut path = indy_home_path();
+    path.push("ledger_replica");
+    path
+}
+
+pub fn ledger_replica_path(pool_name: &str) -> PathBuf {
+    let mut path = ledger_replica_home_path();
+    path.push(pool_name);
+    path
+}
+
 pub fn cheqd_pool_home_path() -> PathBuf {
     let mut path = indy_home_path();
     path.push("cheqd_pool");
@@ -130,6 +142,17 @@ mod tests {
         assert!(path.to_string_lossy().contains("pool"));
     }
 
+    #[test]
+    fn ledger_replica_path_works() {
+        let path = ledger_replica_path("pool1");
+
+        assert!(path.is_absolute());
+        assert!(path.has_root());
+        assert!(path.to_string_lossy().contains(".indy_client"));
+        assert!(path.to_string_lossy().contains("ledger_replica"));
+        assert!(path.to_string_lossy().contains("pool1"));
+    }
+
     #[test]
     fn pool_path_works() {
         let path = pool_path("pool1");
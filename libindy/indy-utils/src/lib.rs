@@ -26,7 +26,7 @@ pub mod wql;
 
 pub(crate) use indy_api_types::ErrorCode;
 
-use indy_api_types::{CommandHandle, PoolHandle, WalletHandle, SearchHandle};
+use indy_api_types::{CommandHandle, PoolHandle, WalletHandle, SearchHandle, VdrHandle};
 
 pub fn next_wallet_handle() -> WalletHandle { WalletHandle(sequence::get_next_id()) }
 
@@ -42,3 +42,7 @@ pub fn next_search_handle() -> SearchHandle {
     SearchHandle(sequence::get_next_id())
 }
 
+pub fn next_vdr_handle() -> VdrHandle {
+    sequence::get_next_id()
+}
+
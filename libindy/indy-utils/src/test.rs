@@ -37,9 +37,14 @@ pub fn cleanup_cheqd_pool(name: &str) {
     cleanup_files(&environment::cheqd_pool_home_path(), name);
 }
 
+pub fn cleanup_ledger_replica(name: &str) {
+    cleanup_files(&environment::ledger_replica_home_path(), name);
+}
+
 pub fn cleanup_storage(name: &str) {
     cleanup_wallet(name);
     cleanup_pool(name);
+    cleanup_ledger_replica(name);
     cleanup_indy_home(name);
     cleanup_temp(name);
     cleanup_cheqd_pool(name);
@@ -8,4 +8,5 @@ pub trait Cache {
     fn peek(&self, key: &WalletCacheKey) -> Option<&WalletCacheValue>;
     fn len(&self) -> usize;
     fn cap(&self) -> usize;
+    fn clear(&mut self);
 }
\ No newline at end of file
@@ -42,5 +42,9 @@ impl Cache for LruCache {
     fn cap(&self) -> usize {
         self.inner.cap()
     }
+
+    fn clear(&mut self) {
+        self.inner.clear()
+    }
 }
 
@@ -220,6 +220,12 @@ impl WalletCache {
             }
         }
     }
+
+    pub async fn clear(&self) {
+        if let Some(protected_cache) = &self.cache {
+            protected_cache.lock().await.clear();
+        }
+    }
 }
 
 #[derive(Default, Debug)]
@@ -730,6 +730,7 @@ mod tests {
             let metadata = Metadata::MetadataArgon(MetadataArgon {
                 master_key_salt: master_key_salt[..].to_vec(),
                 keys: keys.serialize_encrypted(&master_key).unwrap(),
+                pending_keys: None,
             });
 
             serde_json::to_vec(&metadata)
@@ -747,7 +748,13 @@ mod tests {
 
         let storage = storage_type.open_storage(id, None, None).await.unwrap();
 
-        Wallet::new(id.to_string(), storage, Arc::new(keys), WalletCache::new(None))
+        Wallet::new(
+            id.to_string(),
+            storage,
+            Arc::new(keys),
+            master_key,
+            WalletCache::new(None),
+        )
     }
 
     async fn _assert_is_empty(wallet: &Wallet) {
@@ -3,12 +3,26 @@ use std::sync::Arc;
 use indy_api_types::errors::IndyError;
 
 use super::{
-    encryption::decrypt_storage_record, storage::StorageIterator, wallet::Keys, WalletRecord,
+    encryption::decrypt_storage_record,
+    storage::{StorageIterator, Tag},
+    wallet::Keys,
+    SearchCursor, SearchPosition, WalletRecord,
 };
 
 pub(super) struct WalletIterator {
     storage_iterator: Box<dyn StorageIterator>,
     keys: Arc<Keys>,
+    // Hash of the search the cursor is created for
+    search_hash: String,
+    // Position of the last fetched record in search results
+    position: Option<SearchPosition>,
+    // Count of records left in the requested page
+    remaining: Option<usize>,
+    // Encrypted name of the plaintext tag search results are sorted by
+    sort_tag_name: Option<Vec<u8>>,
+    // Tags are fetched for sorting even if they aren't requested
+    retrieve_tags: bool,
+    exhausted: bool,
 }
 
 impl WalletIterator {
@@ -16,18 +30,60 @@ impl WalletIterator {
         WalletIterator {
             storage_iterator: storage_iter,
             keys,
+            search_hash: String::new(),
+            position: None,
+            remaining: None,
+            sort_tag_name: None,
+            retrieve_tags: true,
+            exhausted: false,
         }
     }
 
+    pub fn with_page(
+        mut self,
+        search_hash: String,
+        after: Option<SearchPosition>,
+        limit: Option<usize>,
+        sort_tag_name: Option<Vec<u8>>,
+        retrieve_tags: bool,
+    ) -> Self {
+        self.search_hash = search_hash;
+        self.position = after;
+        self.remaining = limit;
+        self.sort_tag_name = sort_tag_name;
+        self.retrieve_tags = retrieve_tags;
+        self
+    }
+
     pub async fn next(&mut self) -> Result<Option<WalletRecord>, IndyError> {
         let next_storage_entity = self.storage_iterator.next().await?;
 
-        if let Some(next_storage_entity) = next_storage_entity {
+        if let Some(mut next_storage_entity) = next_storage_entity {
+            self.remaining = self.remaining.map(|remaining| remaining.saturating_sub(1));
+
+            let sort_value = self.sort_tag_name.as_ref().and_then(|sort_tag_name| {
+                next_storage_entity
+                    .tags
+                    .iter()
+                    .flatten()
+                    .find_map(|tag| match tag {
+                        Tag::PlainText(name, value) if name == sort_tag_name => Some(value.clone()),
+                        _ => None,
+                    })
+            });
+
+            self.position = Some(SearchPosition::new(&next_storage_entity.id, sort_value));
+
+            if !self.retrieve_tags {
+                next_storage_entity.tags = None;
+            }
+
             Ok(Some(decrypt_storage_record(
                 &next_storage_entity,
                 &self.keys,
             )?))
         } else {
+            self.exhausted = true;
             Ok(None)
         }
     }
@@ -35,4 +91,18 @@ impl WalletIterator {
     pub fn get_total_count(&self) -> Result<Option<usize>, IndyError> {
         Ok(self.storage_iterator.get_total_count()?)
     }
+
+    pub fn get_cursor(&self) -> Result<Option<String>, IndyError> {
+        // Page that was filled up completely can be followed by more records
+        if self.exhausted && self.remaining != Some(0) {
+            return Ok(None);
+        }
+
+        SearchCursor {
+            search: self.search_hash.clone(),
+            after: self.position.clone(),
+        }
+        .encode()
+        .map(Some)
+    }
 }
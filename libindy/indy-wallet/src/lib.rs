@@ -13,7 +13,10 @@ use std::{
 
 use futures::lock::Mutex;
 use indy_api_types::{
-    domain::wallet::{Config, Credentials, ExportConfig, Tags},
+    domain::wallet::{
+        Config, Credentials, ExportConfig, KeyRotationConfig, Tags, WalletOperation,
+        DEFAULT_KEY_ROTATION_BATCH_SIZE,
+    },
     errors::prelude::*,
     wallet::*,
     WalletHandle,
@@ -29,7 +32,8 @@ use serde_json::Value as SValue;
 use crate::{
     export_import::{export_continue, finish_import, preparse_file_to_import},
     storage::{
-        default::SQLiteStorageType, mysql::MySqlStorageType, WalletStorage, WalletStorageType,
+        default::SQLiteStorageType, mysql::MySqlStorageType, plugged::PluggedStorageType,
+        postgres::PostgresStorageType, WalletStorage, WalletStorageType,
     },
     wallet::{Keys, Wallet},
     cache::wallet_cache::{WalletCache, WalletCacheHitMetrics, WalletCacheHitData},
@@ -37,8 +41,6 @@ use crate::{
 pub use crate::encryption::KeyDerivationData;
 use indy_api_types::domain::wallet::CacheConfig;
 
-//use crate::storage::plugged::PluggedStorageType; FXIME:
-
 mod encryption;
 mod iterator;
 mod query_encryption;
@@ -87,6 +89,7 @@ impl WalletService {
             let mut map: HashMap<String, Box<dyn WalletStorageType>> = HashMap::new();
             map.insert("default".to_string(), Box::new(SQLiteStorageType::new()));
             map.insert("mysql".to_string(), Box::new(MySqlStorageType::new()));
+            map.insert("postgres".to_string(), Box::new(PostgresStorageType::new()));
             Mutex::new(map)
         };
 
@@ -100,57 +103,77 @@ impl WalletService {
         }
     }
 
-    pub fn register_wallet_storage(
+    pub async fn register_wallet_storage(
         &self,
         type_: &str,
-        _create: WalletCreate,
-        _open: WalletOpen,
-        _close: WalletClose,
-        _delete: WalletDelete,
-        _add_record: WalletAddRecord,
-        _update_record_value: WalletUpdateRecordValue,
-        _update_record_tags: WalletUpdateRecordTags,
-        _add_record_tags: WalletAddRecordTags,
-        _delete_record_tags: WalletDeleteRecordTags,
-        _delete_record: WalletDeleteRecord,
-        _get_record: WalletGetRecord,
-        _get_record_id: WalletGetRecordId,
-        _get_record_type: WalletGetRecordType,
-        _get_record_value: WalletGetRecordValue,
-        _get_record_tags: WalletGetRecordTags,
-        _free_record: WalletFreeRecord,
-        _get_storage_metadata: WalletGetStorageMetadata,
-        _set_storage_metadata: WalletSetStorageMetadata,
-        _free_storage_metadata: WalletFreeStorageMetadata,
-        _search_records: WalletSearchRecords,
-        _search_all_records: WalletSearchAllRecords,
-        _get_search_total_count: WalletGetSearchTotalCount,
-        _fetch_search_next_record: WalletFetchSearchNextRecord,
-        _free_search: WalletFreeSearch,
+        create: WalletCreate,
+        open: WalletOpen,
+        close: WalletClose,
+        delete: WalletDelete,
+        add_record: WalletAddRecord,
+        update_record_value: WalletUpdateRecordValue,
+        update_record_tags: WalletUpdateRecordTags,
+        add_record_tags: WalletAddRecordTags,
+        delete_record_tags: WalletDeleteRecordTags,
+        delete_record: WalletDeleteRecord,
+        get_record: WalletGetRecord,
+        get_record_id: WalletGetRecordId,
+        get_record_type: WalletGetRecordType,
+        get_record_value: WalletGetRecordValue,
+        get_record_tags: WalletGetRecordTags,
+        free_record: WalletFreeRecord,
+        get_storage_metadata: WalletGetStorageMetadata,
+        set_storage_metadata: WalletSetStorageMetadata,
+        free_storage_metadata: WalletFreeStorageMetadata,
+        search_records: WalletSearchRecords,
+        search_all_records: WalletSearchAllRecords,
+        get_search_total_count: WalletGetSearchTotalCount,
+        fetch_search_next_record: WalletFetchSearchNextRecord,
+        free_search: WalletFreeSearch,
     ) -> IndyResult<()> {
         trace!("register_wallet_storage >>> type_: {:?}", type_);
-        Ok(()) // FIXME: !!!
 
-        //         let mut storage_types = self.storage_types.lock().await;
+        let mut storage_types = self.storage_types.lock().await;
 
-        //         if storage_types.contains_key(type_) {
-        //             return Err(err_msg(IndyErrorKind::WalletStorageTypeAlreadyRegistered, format!("Wallet storage is already registered for type: {}", type_)));
-        //         }
+        if storage_types.contains_key(type_) {
+            return Err(err_msg(
+                IndyErrorKind::WalletStorageTypeAlreadyRegistered,
+                format!("Wallet storage is already registered for type: {}", type_),
+            ));
+        }
 
-        //         storage_types.insert(type_.to_string(),
-        //                              Box::new(
-        //                                  PluggedStorageType::new(create, open, close, delete,
-        //                                                          add_record, update_record_value,
-        //                                                          update_record_tags, add_record_tags, delete_record_tags,
-        //                                                          delete_record, get_record, get_record_id,
-        //                                                          get_record_type, get_record_value, get_record_tags, free_record,
-        //                                                          get_storage_metadata, set_storage_metadata, free_storage_metadata,
-        //                                                          search_records, search_all_records,
-        //                                                          get_search_total_count,
-        //                                                          fetch_search_next_record, free_search)));
+        storage_types.insert(
+            type_.to_string(),
+            Box::new(PluggedStorageType::new(
+                create,
+                open,
+                close,
+                delete,
+                add_record,
+                update_record_value,
+                update_record_tags,
+                add_record_tags,
+                delete_record_tags,
+                delete_record,
+                get_record,
+                get_record_id,
+                get_record_type,
+                get_record_value,
+                get_record_tags,
+                free_record,
+                get_storage_metadata,
+                set_storage_metadata,
+                free_storage_metadata,
+                search_records,
+                search_all_records,
+                get_search_total_count,
+                fetch_search_next_record,
+                free_search,
+            )),
+        );
 
-        //         trace!("register_wallet_storage <<<");
-        //         Ok(())
+        trace!("register_wallet_storage <<<");
+        Ok(())
     }
 
     pub async fn create_wallet(
@@ -181,7 +204,7 @@ impl WalletService {
             WalletService::_get_config_and_cred_for_storage(config, credentials, &storage_types)?;
 
         let keys = Keys::new();
-        let metadata = self._prepare_metadata(master_key, key_data, &keys)?;
+        let metadata = self._prepare_metadata(master_key, key_data, &keys, None)?;
 
         storage_type
             .create_storage(
@@ -317,12 +340,17 @@ impl WalletService {
 
         let (master_key, rekey) = master_key;
         let keys = self._restore_keys(&metadata, &master_key)?;
+        let pending_keys = self._restore_pending_keys(&metadata, &master_key)?;
 
         // Rotate master key
-        if let (Some(rekey), Some(rekey_data)) = (rekey, rekey_data) {
-            let metadata = self._prepare_metadata(rekey, &rekey_data, &keys)?;
+        let master_key = if let (Some(rekey), Some(rekey_data)) = (rekey, rekey_data) {
+            let metadata =
+                self._prepare_metadata(rekey, &rekey_data, &keys, pending_keys.as_ref())?;
             storage.set_storage_metadata(&metadata).await?;
-        }
+            rekey
+        } else {
+            master_key
+        };
 
         let wallet = Wallet::new(
             id.clone(),
@@ -331,6 +359,11 @@ impl WalletService {
             WalletCache::new(cache_config)
         );
 
+        // Finish keys rotation that was interrupted before the wallet was closed
+        if pending_keys.is_some() {
+            wallet.rotate_keys(master_key, DEFAULT_KEY_ROTATION_BATCH_SIZE).await?;
+        }
+
         {
             let mut wallets = self.wallets.lock().await;
             wallets.insert(wallet_handle, Arc::new(wallet));
@@ -543,6 +576,60 @@ impl WalletService {
             .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name))
     }
 
+    /// Applies all operations atomically: either every record change is stored or,
+    /// if any of them fails, the wallet is left untouched.
+    pub async fn transaction(
+        &self,
+        wallet_handle: WalletHandle,
+        operations: &[WalletOperation],
+    ) -> IndyResult<()> {
+        let wallet = self.get_wallet(wallet_handle).await?;
+        wallet.batch(operations).await
+    }
+
+    /// Returns data to derive the master key that protects new wallet data keys.
+    /// Opened wallet doesn't keep the master key, so credentials are required for rotation.
+    pub async fn rotate_keys_prepare(
+        &self,
+        wallet_handle: WalletHandle,
+        credentials: &Credentials,
+    ) -> IndyResult<KeyDerivationData> {
+        trace!(
+            "rotate_keys_prepare >>> wallet_handle: {:?}, credentials: {:?}",
+            wallet_handle,
+            secret!(credentials)
+        );
+
+        let wallet = self.get_wallet(wallet_handle).await?;
+        let metadata = wallet.get_metadata().await?;
+
+        KeyDerivationData::from_passphrase_and_metadata(
+            &credentials.key,
+            &metadata,
+            &credentials.key_derivation_method,
+        )
+    }
+
+    /// Replaces wallet data keys (record, tag and HMAC keys) with fresh ones and
+    /// re-encrypts all records. Master key stays the same.
+    pub async fn rotate_keys_continue(
+        &self,
+        wallet_handle: WalletHandle,
+        config: &KeyRotationConfig,
+        master_key: &MasterKey,
+    ) -> IndyResult<KeyRotationProgress> {
+        let wallet = self.get_wallet(wallet_handle).await?;
+        wallet.rotate_keys(master_key, config.batch_size).await
+    }
+
+    pub async fn get_key_rotation_progress(
+        &self,
+        wallet_handle: WalletHandle,
+    ) -> IndyResult<Option<KeyRotationProgress>> {
+        let wallet = self.get_wallet(wallet_handle).await?;
+        Ok(wallet.get_rotation_progress().await)
+    }
+
     pub async fn delete_indy_record<T>(
         &self,
         wallet_handle: WalletHandle,
@@ -967,17 +1054,25 @@ impl WalletService {
         master_key: &chacha20poly1305_ietf::Key,
         key_data: &KeyDerivationData,
         keys: &Keys,
+        pending_keys: Option<&Keys>,
     ) -> IndyResult<Vec<u8>> {
         let encrypted_keys = keys.serialize_encrypted(master_key)?;
 
+        let encrypted_pending_keys = match pending_keys {
+            Some(pending_keys) => Some(pending_keys.serialize_encrypted(master_key)?),
+            None => None,
+        };
+
         let metadata = match key_data {
             KeyDerivationData::Raw(_) => Metadata::MetadataRaw(MetadataRaw {
                 keys: encrypted_keys,
+                pending_keys: encrypted_pending_keys,
             }),
             KeyDerivationData::Argon2iInt(_, salt) | KeyDerivationData::Argon2iMod(_, salt) => {
                 Metadata::MetadataArgon(MetadataArgon {
                     keys: encrypted_keys,
                     master_key_salt: salt[..].to_vec(),
+                    pending_keys: encrypted_pending_keys,
                 })
             }
         };
@@ -1003,6 +1098,17 @@ impl WalletService {
         Ok(res)
     }
 
+    fn _restore_pending_keys(
+        &self,
+        metadata: &Metadata,
+        master_key: &MasterKey,
+    ) -> IndyResult<Option<Keys>> {
+        match metadata.get_pending_keys() {
+            Some(pending_keys) => Ok(Some(Keys::deserialize_encrypted(pending_keys, master_key)?)),
+            None => Ok(None),
+        }
+    }
+
     pub const PREFIX: &'static str = "Indy";
 
     pub fn add_prefix(&self, type_: &str) -> String {
@@ -1037,17 +1143,61 @@ impl Metadata {
             Metadata::MetadataRaw(ref metadata) => &metadata.keys,
         }
     }
+
+    pub fn set_keys(&mut self, keys: Vec<u8>) {
+        match *self {
+            Metadata::MetadataArgon(ref mut metadata) => metadata.keys = keys,
+            Metadata::MetadataRaw(ref mut metadata) => metadata.keys = keys,
+        }
+    }
+
+    pub fn get_pending_keys(&self) -> Option<&Vec<u8>> {
+        match *self {
+            Metadata::MetadataArgon(ref metadata) => metadata.pending_keys.as_ref(),
+            Metadata::MetadataRaw(ref metadata) => metadata.pending_keys.as_ref(),
+        }
+    }
+
+    pub fn set_pending_keys(&mut self, pending_keys: Option<Vec<u8>>) {
+        match *self {
+            Metadata::MetadataArgon(ref mut metadata) => metadata.pending_keys = pending_keys,
+            Metadata::MetadataRaw(ref mut metadata) => metadata.pending_keys = pending_keys,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MetadataArgon {
     pub keys: Vec<u8>,
     pub master_key_salt: Vec<u8>,
+    // Keys of the data keys rotation that is not completed yet
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_keys: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MetadataRaw {
     pub keys: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_keys: Option<Vec<u8>>,
+}
+
+/// Progress of the wallet data keys rotation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KeyRotationProgress {
+    pub total: usize,
+    pub processed: usize,
+    pub completed: bool,
+}
+
+impl KeyRotationProgress {
+    pub fn new(total: usize) -> Self {
+        KeyRotationProgress {
+            total,
+            processed: 0,
+            completed: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1178,9 +1328,15 @@ impl WalletSearch {
     pub async fn fetch_next_record(&mut self) -> IndyResult<Option<WalletRecord>> {
         self.iter.next().await
     }
+
+    /// Returns cursor to continue search after the last fetched record
+    /// or None if all matched records are already fetched.
+    pub fn get_cursor(&self) -> IndyResult<Option<String>> {
+        self.iter.get_cursor()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchOptions {
     #[serde(default = "default_true")]
@@ -1193,6 +1349,17 @@ pub struct SearchOptions {
     retrieve_value: bool,
     #[serde(default = "default_false")]
     retrieve_tags: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    offset: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sort_by: Option<SearchSort>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    // Set from the cursor only, storages return records that go after this position
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    after: Option<SearchPosition>,
 }
 
 impl SearchOptions {
@@ -1203,10 +1370,46 @@ impl SearchOptions {
             retrieve_type: true,
             retrieve_value: true,
             retrieve_tags: false,
+            ..SearchOptions::default()
         };
 
         serde_json::to_string(&options).unwrap()
     }
+
+    // Replaces continuation cursor with the position it points to.
+    // The cursor must be created for the same search, so `search_hash` of the current one is required.
+    fn resolve_cursor(&mut self, search_hash: &str) -> IndyResult<()> {
+        self.after = None;
+
+        if let Some(cursor) = self.cursor.take() {
+            if self.offset.is_some() {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    "Search options can't contain both offset and cursor",
+                ));
+            }
+
+            let cursor = SearchCursor::decode(&cursor)?;
+
+            if cursor.search != search_hash {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    "Search cursor is created for another type, query or sorting",
+                ));
+            }
+
+            self.after = cursor.after;
+        }
+
+        Ok(())
+    }
+
+    fn is_paged(&self) -> bool {
+        self.offset.is_some()
+            || self.limit.is_some()
+            || self.sort_by.is_some()
+            || self.after.is_some()
+    }
 }
 
 impl Default for SearchOptions {
@@ -1217,8 +1420,121 @@ impl Default for SearchOptions {
             retrieve_type: false,
             retrieve_value: true,
             retrieve_tags: false,
+            offset: None,
+            limit: None,
+            sort_by: None,
+            cursor: None,
+            after: None,
+        }
+    }
+}
+
+/// Sorting of search results by the value of plaintext tag.
+///
+/// Records without the tag always go after the records that have it.
+/// Before passing to the storage `tag_name` is replaced with the base64 encoded encrypted name.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSort {
+    tag_name: String,
+    #[serde(default)]
+    order: SortOrder,
+}
+
+impl SearchSort {
+    fn encrypted_tag_name(&self) -> IndyResult<Vec<u8>> {
+        indy_utils::crypto::base64::decode(&self.tag_name)
+    }
+
+    fn order_sql(&self) -> &'static str {
+        match self.order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> SortOrder {
+        SortOrder::Asc
+    }
+}
+
+/// Position of the record in search results ordered by the sort tag value and the encrypted record name.
+///
+/// Records are compared by their values instead of the index in results,
+/// so the position stays valid when records are added or deleted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPosition {
+    // Base64 encoded encrypted name of the record, it's unique within the record type
+    name: String,
+    // Value of the sort tag, absent if results aren't sorted or the record has no sort tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sort_value: Option<String>,
+}
+
+impl SearchPosition {
+    fn new(encrypted_name: &[u8], sort_value: Option<String>) -> SearchPosition {
+        SearchPosition {
+            name: indy_utils::crypto::base64::encode(encrypted_name),
+            sort_value,
         }
     }
+
+    fn encrypted_name(&self) -> IndyResult<Vec<u8>> {
+        indy_utils::crypto::base64::decode(&self.name)
+    }
+}
+
+// Continuation cursor is opaque for the callers. It points to the last fetched record
+// and is bound to the search it was created for by the hash of the type, query and sorting.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct SearchCursor {
+    search: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    after: Option<SearchPosition>,
+}
+
+impl SearchCursor {
+    fn search_hash(type_: &str, query: &str, sort_by: Option<&SearchSort>) -> IndyResult<String> {
+        // Query is re-serialized to not depend on its formatting
+        let query: SValue = serde_json::from_str(query)
+            .to_indy(IndyErrorKind::WalletQueryError, "Query is malformed json")?;
+
+        let search = serde_json::to_vec(&json!([type_, query, sort_by]))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize search")?;
+
+        let hash = indy_utils::crypto::hash::hash(&search)?;
+
+        Ok(indy_utils::crypto::base64::encode_urlsafe(&hash)
+            .trim_end_matches('=')
+            .to_string())
+    }
+
+    fn encode(&self) -> IndyResult<String> {
+        let cursor = serde_json::to_vec(self)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize search cursor")?;
+
+        Ok(indy_utils::crypto::base64::encode_urlsafe(&cursor)
+            .trim_end_matches('=')
+            .to_string())
+    }
+
+    fn decode(cursor: &str) -> IndyResult<SearchCursor> {
+        let cursor = indy_utils::crypto::base64::decode_urlsafe(cursor)
+            .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Invalid search cursor"))?;
+
+        serde_json::from_slice(&cursor)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid search cursor")
+    }
 }
 
 fn short_type_name<T>() -> &'static str {
@@ -1363,6 +1679,22 @@ mod tests {
             self.delete_wallet_continue(config, credentials, &metadata, &master_key)
                 .await
         }
+
+        async fn rotate_keys(
+            &self,
+            wallet_handle: WalletHandle,
+            credentials: &Credentials,
+            config: &KeyRotationConfig,
+        ) -> IndyResult<KeyRotationProgress> {
+            let key_derivation_data = self
+                .rotate_keys_prepare(wallet_handle, credentials)
+                .await?;
+
+            let master_key = key_derivation_data.calc_master_key()?;
+
+            self.rotate_keys_continue(wallet_handle, config, &master_key)
+                .await
+        }
     }
 
     #[test]
@@ -3934,6 +4266,474 @@ mod tests {
         test::cleanup_wallet("wallet_service_search_records_works");
     }
 
+    #[async_std::test]
+    async fn wallet_service_search_records_works_for_sort_and_cursor() {
+        test::cleanup_wallet("wallet_service_search_records_works_for_sort_and_cursor");
+        {
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(
+                    &_config("wallet_service_search_records_works_for_sort_and_cursor"),
+                    &RAW_CREDENTIAL,
+                    (&RAW_KDD, &RAW_MASTER_KEY),
+                )
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(
+                    &_config("wallet_service_search_records_works_for_sort_and_cursor"),
+                    &RAW_CREDENTIAL,
+                )
+                .await
+                .unwrap();
+
+            for (id, order) in [("key1", "b"), ("key2", "c"), ("key3", "a")].iter() {
+                let mut tags = HashMap::new();
+                tags.insert("~order".to_string(), order.to_string());
+
+                wallet_service
+                    .add_record(wallet_handle, "type", id, "value", &tags)
+                    .await
+                    .unwrap();
+            }
+
+            let mut search = wallet_service
+                .search_records(
+                    wallet_handle,
+                    "type",
+                    "{}",
+                    r#"{"limit": 2, "sortBy": {"tagName": "~order", "order": "desc"}}"#,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!("key2", search.fetch_next_record().await.unwrap().unwrap().get_id());
+            assert_eq!("key1", search.fetch_next_record().await.unwrap().unwrap().get_id());
+            assert!(search.fetch_next_record().await.unwrap().is_none());
+
+            let cursor = search.get_cursor().unwrap().unwrap();
+
+            let mut search = wallet_service
+                .search_records(
+                    wallet_handle,
+                    "type",
+                    "{}",
+                    &json!({
+                        "limit": 2,
+                        "sortBy": {"tagName": "~order", "order": "desc"},
+                        "cursor": cursor,
+                    })
+                    .to_string(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!("key3", search.fetch_next_record().await.unwrap().unwrap().get_id());
+            assert!(search.fetch_next_record().await.unwrap().is_none());
+            assert!(search.get_cursor().unwrap().is_none());
+        }
+
+        test::cleanup_wallet("wallet_service_search_records_works_for_sort_and_cursor");
+    }
+
+    #[async_std::test]
+    async fn wallet_service_transaction_works() {
+        test::cleanup_wallet("wallet_service_transaction_works");
+        {
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(
+                    &_config("wallet_service_transaction_works"),
+                    &RAW_CREDENTIAL,
+                    (&RAW_KDD, &RAW_MASTER_KEY),
+                )
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(&_config_cached("wallet_service_transaction_works"), &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            wallet_service
+                .add_record(wallet_handle, "type", "key1", "value1", &HashMap::new())
+                .await
+                .unwrap();
+
+            // warm up the cache
+            wallet_service
+                .get_record(wallet_handle, "type", "key1", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            let operations: Vec<WalletOperation> = serde_json::from_value(json!([
+                {"op": "update", "type": "type", "id": "key1", "value": "value2"},
+                {"op": "addTags", "type": "type", "id": "key1", "tags": {"tag1": "tag_value1"}},
+                {"op": "add", "type": "type", "id": "key2", "value": "value3"},
+                {"op": "delete", "type": "type", "id": "key2"},
+            ]))
+            .unwrap();
+
+            wallet_service
+                .transaction(wallet_handle, &operations)
+                .await
+                .unwrap();
+
+            let record = wallet_service
+                .get_record(wallet_handle, "type", "key1", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            assert_eq!("value2", record.get_value().unwrap());
+            assert_eq!("tag_value1", record.get_tags().unwrap()["tag1"]);
+
+            let res = wallet_service
+                .get_record(wallet_handle, "type", "key2", &_fetch_options(false, true, true))
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        test::cleanup_wallet("wallet_service_transaction_works");
+    }
+
+    #[async_std::test]
+    async fn wallet_service_transaction_works_for_rollback() {
+        test::cleanup_wallet("wallet_service_transaction_works_for_rollback");
+        {
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(
+                    &_config("wallet_service_transaction_works_for_rollback"),
+                    &RAW_CREDENTIAL,
+                    (&RAW_KDD, &RAW_MASTER_KEY),
+                )
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(
+                    &_config_cached("wallet_service_transaction_works_for_rollback"),
+                    &RAW_CREDENTIAL,
+                )
+                .await
+                .unwrap();
+
+            wallet_service
+                .add_record(wallet_handle, "type", "key1", "value1", &HashMap::new())
+                .await
+                .unwrap();
+
+            wallet_service
+                .get_record(wallet_handle, "type", "key1", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            let operations: Vec<WalletOperation> = serde_json::from_value(json!([
+                {"op": "update", "type": "type", "id": "key1", "value": "value2"},
+                {"op": "add", "type": "type", "id": "key2", "value": "value3"},
+                {"op": "delete", "type": "type", "id": "unknown_key"},
+            ]))
+            .unwrap();
+
+            let res = wallet_service.transaction(wallet_handle, &operations).await;
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            let record = wallet_service
+                .get_record(wallet_handle, "type", "key1", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            assert_eq!("value1", record.get_value().unwrap());
+
+            let res = wallet_service
+                .get_record(wallet_handle, "type", "key2", &_fetch_options(false, true, true))
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        test::cleanup_wallet("wallet_service_transaction_works_for_rollback");
+    }
+
+    #[async_std::test]
+    async fn wallet_service_rotate_keys_works() {
+        test::cleanup_wallet("wallet_service_rotate_keys_works");
+        {
+            let config = _config_cached("wallet_service_rotate_keys_works");
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(&config, &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY))
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            for i in 0..5 {
+                let tags = serde_json::from_value(json!({"tag1": i.to_string(), "~tag2": "value"}))
+                    .unwrap();
+
+                wallet_service
+                    .add_record(wallet_handle, "type", &format!("key{}", i), "value", &tags)
+                    .await
+                    .unwrap();
+            }
+
+            // warm up the cache
+            wallet_service
+                .get_record(wallet_handle, "type", "key1", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            let (_, metadata, _) = wallet_service
+                ._open_storage_and_fetch_metadata(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            let progress = wallet_service
+                .rotate_keys(wallet_handle, &RAW_CREDENTIAL, &KeyRotationConfig { batch_size: 2 })
+                .await
+                .unwrap();
+
+            assert_eq!(
+                KeyRotationProgress {
+                    total: 5,
+                    processed: 5,
+                    completed: true
+                },
+                progress
+            );
+
+            assert_eq!(
+                Some(progress),
+                wallet_service
+                    .get_key_rotation_progress(wallet_handle)
+                    .await
+                    .unwrap()
+            );
+
+            let (_, rotated_metadata, _) = wallet_service
+                ._open_storage_and_fetch_metadata(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            assert_ne!(metadata.get_keys(), rotated_metadata.get_keys());
+            assert!(rotated_metadata.get_pending_keys().is_none());
+
+            let record = wallet_service
+                .get_record(wallet_handle, "type", "key1", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            assert_eq!("value", record.get_value().unwrap());
+            assert_eq!("1", record.get_tags().unwrap()["tag1"]);
+
+            let mut search = wallet_service
+                .search_records(
+                    wallet_handle,
+                    "type",
+                    r#"{"tag1": "3", "~tag2": "value"}"#,
+                    &_fetch_options(false, true, true),
+                )
+                .await
+                .unwrap();
+
+            let record = search.fetch_next_record().await.unwrap().unwrap();
+            assert_eq!("key3", record.get_id());
+            assert!(search.fetch_next_record().await.unwrap().is_none());
+
+            wallet_service.close_wallet(wallet_handle).await.unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            let record = wallet_service
+                .get_record(wallet_handle, "type", "key4", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            assert_eq!("value", record.get_value().unwrap());
+            assert_eq!("4", record.get_tags().unwrap()["tag1"]);
+        }
+
+        test::cleanup_wallet("wallet_service_rotate_keys_works");
+    }
+
+    #[async_std::test]
+    async fn wallet_service_rotate_keys_works_for_invalid_credentials() {
+        test::cleanup_wallet("wallet_service_rotate_keys_works_for_invalid_credentials");
+        {
+            let config = _config("wallet_service_rotate_keys_works_for_invalid_credentials");
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(&config, &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY))
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            let res = wallet_service
+                .rotate_keys(
+                    wallet_handle,
+                    &ARGON_INT_CREDENTIAL,
+                    &KeyRotationConfig { batch_size: 2 },
+                )
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletAccessFailed, res);
+
+            wallet_service.close_wallet(wallet_handle).await.unwrap();
+        }
+
+        test::cleanup_wallet("wallet_service_rotate_keys_works_for_invalid_credentials");
+    }
+
+    #[async_std::test]
+    async fn wallet_service_open_wallet_resumes_keys_rotation() {
+        test::cleanup_wallet("wallet_service_open_wallet_resumes_keys_rotation");
+        {
+            let config = _config("wallet_service_open_wallet_resumes_keys_rotation");
+            let wallet_service = WalletService::new();
+
+            wallet_service
+                .create_wallet(&config, &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY))
+                .await
+                .unwrap();
+
+            let wallet_handle = wallet_service
+                .open_wallet(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            let tags = serde_json::from_value(json!({"tag1": "value1"})).unwrap();
+
+            wallet_service
+                .add_record(wallet_handle, "type", "key1", "value1", &tags)
+                .await
+                .unwrap();
+
+            wallet_service.close_wallet(wallet_handle).await.unwrap();
+
+            // Simulate rotation interrupted right after new keys were stored
+            {
+                let (mut storage, mut metadata, _) = wallet_service
+                    ._open_storage_and_fetch_metadata(&config, &RAW_CREDENTIAL)
+                    .await
+                    .unwrap();
+
+                let pending_keys = Keys::new().serialize_encrypted(&RAW_MASTER_KEY).unwrap();
+                metadata.set_pending_keys(Some(pending_keys));
+
+                storage
+                    .set_storage_metadata(&serde_json::to_vec(&metadata).unwrap())
+                    .await
+                    .unwrap();
+
+                storage.close().unwrap();
+            }
+
+            let wallet_handle = wallet_service
+                .open_wallet(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            let progress = wallet_service
+                .get_key_rotation_progress(wallet_handle)
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert!(progress.completed);
+
+            let record = wallet_service
+                .get_record(wallet_handle, "type", "key1", &_fetch_options(false, true, true))
+                .await
+                .unwrap();
+
+            assert_eq!("value1", record.get_value().unwrap());
+            assert_eq!("value1", record.get_tags().unwrap()["tag1"]);
+
+            let (_, metadata, _) = wallet_service
+                ._open_storage_and_fetch_metadata(&config, &RAW_CREDENTIAL)
+                .await
+                .unwrap();
+
+            assert!(metadata.get_pending_keys().is_none());
+        }
+
+        test::cleanup_wallet("wallet_service_open_wallet_resumes_keys_rotation");
+    }
+
+    #[test]
+    fn search_cursor_works() {
+        let cursor = SearchCursor {
+            search: "search".to_string(),
+            after: Some(SearchPosition::new(&[1, 2, 3], Some("value".to_string()))),
+        };
+
+        assert_eq!(cursor, SearchCursor::decode(&cursor.encode().unwrap()).unwrap());
+
+        let res = SearchCursor::decode("invalid cursor");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn search_cursor_search_hash_works() {
+        let sort_by: SearchSort = serde_json::from_str(r#"{"tagName": "~tag"}"#).unwrap();
+
+        let hash = SearchCursor::search_hash("type", r#"{"~tag": "value"}"#, Some(&sort_by)).unwrap();
+
+        assert_eq!(
+            hash,
+            SearchCursor::search_hash("type", r#"{ "~tag" : "value" }"#, Some(&sort_by)).unwrap()
+        );
+        assert_ne!(
+            hash,
+            SearchCursor::search_hash("type", r#"{"~tag": "other"}"#, Some(&sort_by)).unwrap()
+        );
+        assert_ne!(
+            hash,
+            SearchCursor::search_hash("other", r#"{"~tag": "value"}"#, Some(&sort_by)).unwrap()
+        );
+        assert_ne!(
+            hash,
+            SearchCursor::search_hash("type", r#"{"~tag": "value"}"#, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn search_options_resolve_cursor_works_for_another_search() {
+        let cursor = SearchCursor {
+            search: "search".to_string(),
+            after: None,
+        };
+
+        let mut options = SearchOptions {
+            cursor: Some(cursor.encode().unwrap()),
+            ..SearchOptions::default()
+        };
+
+        let res = options.clone().resolve_cursor("another search");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+        options.resolve_cursor("search").unwrap();
+        assert_eq!(None, options.cursor);
+    }
+
     #[async_std::test]
     #[ignore]
     async fn wallet_service_search_records_works_for_cached_wallet() {
@@ -3,7 +3,7 @@ use indy_api_types::errors::prelude::*;
 use super::encryption::encrypt_as_searchable;
 use super::language::{Operator, TagName, TargetValue};
 use super::wallet::Keys;
-use indy_utils::wql::Query;
+use indy_utils::{crypto::base64, wql::Query};
 
 // Performs encryption of WQL query
 // WQL query is provided as top-level Operator
@@ -81,6 +81,23 @@ fn transform_list_operators(operators: Vec<Query>, keys: &Keys) -> IndyResult<Ve
     Ok(transformed)
 }
 
+// Encrypts name of the tag used to sort search results.
+// Only plaintext tags can be used for sorting as values of encrypted tags have no meaningful order.
+// Result is base64 encoded to be passed to the storage in search options.
+pub(super) fn encrypt_sort_tag_name(name: &str, keys: &Keys) -> IndyResult<String> {
+    match TagName::from(name.to_string())? {
+        TagName::PlainTagName(ref name) => {
+            let encrypted_name =
+                encrypt_as_searchable(&name[..], &keys.tag_name_key, &keys.tags_hmac_key);
+            Ok(base64::encode(&encrypted_name))
+        }
+        TagName::EncryptedTagName(_) => Err(err_msg(
+            IndyErrorKind::WalletQueryError,
+            "Search results can be sorted only by plaintext tag",
+        )),
+    }
+}
+
 // Encrypts a single tag name, tag value pair.
 // If the tag name is EncryptedTagName enum variant, encrypts both the tag name and the tag value
 // If the tag name is PlainTagName enum variant, encrypts only the tag name
@@ -8,16 +8,19 @@ use indy_utils::environment;
 use serde::Deserialize;
 use sqlx::{
     sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions},
-    ConnectOptions, Done, SqlitePool,
+    ConnectOptions, Done, Sqlite, SqlitePool, Transaction,
 };
 
 use async_trait::async_trait;
 
 use crate::{
     language,
-    storage::{StorageIterator, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType},
+    storage::{
+        StorageIterator, StorageOperation, StorageRecord, Tag, TagName, WalletStorage,
+        WalletStorageType,
+    },
     wallet::EncryptedValue,
-    RecordOptions, SearchOptions,
+    RecordOptions, SearchOptions, SearchSort,
 };
 
 mod query;
@@ -87,140 +90,21 @@ impl SQLiteStorageType {
     }
 }
 
-#[async_trait]
-impl WalletStorage for SQLiteStorage {
-    ///
-    /// Tries to fetch values and/or tags from the storage.
-    /// Returns Result with StorageEntity object which holds requested data in case of success or
-    /// Result with IndyError in case of failure.
-    ///
-    ///
-    /// # Arguments
-    ///
-    ///  * `type_` - type_ of the item in storage
-    ///  * `id` - id of the item in storage
-    ///  * `options` - JSon containing what needs to be fetched.
-    ///  Example: {"retrieveValue": true, "retrieveTags": true}
-    ///
-    /// # Returns
-    ///
-    /// Result that can be either:
-    ///
-    ///  * `StorageEntity` - Contains name, optional value and optional tags
-    ///  * `IndyError`
-    ///
-    /// # Errors
-    ///
-    /// Any of the following `IndyError` type_ of errors can be throw by this method:
-    ///
-    ///  * `IndyError::Closed` - Storage is closed
-    ///  * `IndyError::ItemNotFound` - Item is not found in database
-    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
-    ///
-    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
-        let options: RecordOptions = serde_json::from_str(options).to_indy(
-            IndyErrorKind::InvalidStructure,
-            "RecordOptions is malformed json",
-        )?;
-
-        let mut conn = self.pool.acquire().await?;
-
-        let (item_id, value, key): (i64, Vec<u8>, Vec<u8>) =
-            sqlx::query_as("SELECT id, value, key FROM items where type = ?1 AND name = ?2")
-                .bind(type_)
-                .bind(id)
-                .fetch_one(&mut conn)
-                .await?;
-
-        let value = if options.retrieve_value {
-            Some(EncryptedValue::new(value, key))
-        } else {
-            None
-        };
-
-        let type_ = if options.retrieve_type {
-            Some(type_.to_vec())
-        } else {
-            None
-        };
-
-        let tags = if options.retrieve_tags {
-            let mut tags = Vec::new();
-
-            tags.extend(
-                sqlx::query_as::<_, (Vec<u8>, String)>(
-                    "SELECT name, value from tags_plaintext where item_id = ?",
-                )
-                .bind(item_id)
-                .fetch_all(&mut conn)
-                .await?
-                .drain(..)
-                .map(|r| Tag::PlainText(r.0, r.1)),
-            );
-
-            tags.extend(
-                sqlx::query_as::<_, (Vec<u8>, Vec<u8>)>(
-                    "SELECT name, value from tags_encrypted where item_id = ?",
-                )
-                .bind(item_id)
-                .fetch_all(&mut conn)
-                .await?
-                .drain(..)
-                .map(|r| Tag::Encrypted(r.0, r.1)),
-            );
-
-            Some(tags)
-        } else {
-            None
-        };
-
-        Ok(StorageRecord::new(id.to_vec(), value, type_, tags))
-    }
-
-    ///
-    /// inserts value and tags into storage.
-    /// Returns Result with () on success or
-    /// Result with IndyError in case of failure.
-    ///
-    ///
-    /// # Arguments
-    ///
-    ///  * `type_` - type of the item in storage
-    ///  * `id` - id of the item in storage
-    ///  * `value` - value of the item in storage
-    ///  * `value_key` - key used to encrypt the value
-    ///  * `tags` - tags assigned to the value
-    ///
-    /// # Returns
-    ///
-    /// Result that can be either:
-    ///
-    ///  * `()`
-    ///  * `IndyError`
-    ///
-    /// # Errors
-    ///
-    /// Any of the following `IndyError` class of errors can be throw by this method:
-    ///
-    ///  * `IndyError::Closed` - Storage is closed
-    ///  * `IndyError::ItemAlreadyExists` - Item is already present in database
-    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
-    ///
-    async fn add(
+impl SQLiteStorage {
+    async fn _add(
         &self,
+        tx: &mut Transaction<'_, Sqlite>,
         type_: &[u8],
         id: &[u8],
         value: &EncryptedValue,
         tags: &[Tag],
     ) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
         let id = sqlx::query("INSERT INTO items (type, name, value, key) VALUES (?1, ?2, ?3, ?4)")
             .bind(type_)
             .bind(id)
             .bind(&value.data)
             .bind(&value.key)
-            .execute(&mut tx)
+            .execute(&mut *tx)
             .await?
             .last_insert_rowid();
 
@@ -233,7 +117,7 @@ impl WalletStorage for SQLiteStorage {
                     .bind(id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
                 Tag::PlainText(ref tag_name, ref tag_data) => {
@@ -243,34 +127,34 @@ impl WalletStorage for SQLiteStorage {
                     .bind(id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
             };
         }
 
-        tx.commit().await?;
         Ok(())
     }
 
-    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _update(
+        &self,
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+    ) -> IndyResult<()> {
         let row_updated =
             sqlx::query("UPDATE items SET value = ?1, key = ?2 WHERE type = ?3 AND name = ?4")
                 .bind(&value.data)
                 .bind(&value.key)
                 .bind(&type_)
                 .bind(&id)
-                .execute(&mut tx)
+                .execute(&mut *tx)
                 .await?
                 .rows_affected();
 
         match row_updated {
-            1 => {
-                tx.commit().await?;
-                Ok(())
-            }
+            1 => Ok(()),
             0 => Err(err_msg(
                 IndyErrorKind::WalletItemNotFound,
                 "Item to update not found",
@@ -282,14 +166,18 @@ impl WalletStorage for SQLiteStorage {
         }
     }
 
-    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _add_tags(
+        &self,
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
         let (item_id,): (i64,) =
             sqlx::query_as("SELECT id FROM items WHERE type = ?1 AND name = ?2")
                 .bind(type_)
                 .bind(id)
-                .fetch_one(&mut tx)
+                .fetch_one(&mut *tx)
                 .await?;
 
         for tag in tags {
@@ -301,7 +189,7 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
                 Tag::PlainText(ref tag_name, ref tag_data) => {
@@ -311,34 +199,37 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
             };
         }
 
-        tx.commit().await?;
         Ok(())
     }
 
-    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _update_tags(
+        &self,
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
         let (item_id,): (i64,) =
             sqlx::query_as("SELECT id FROM items WHERE type = ?1 AND name = ?2")
                 .bind(type_)
                 .bind(&id)
-                .fetch_one(&mut tx)
+                .fetch_one(&mut *tx)
                 .await?;
 
         sqlx::query("DELETE FROM tags_encrypted WHERE item_id = ?1")
             .bind(item_id)
-            .execute(&mut tx)
+            .execute(&mut *tx)
             .await?;
 
         sqlx::query("DELETE FROM tags_plaintext WHERE item_id = ?1")
             .bind(item_id)
-            .execute(&mut tx)
+            .execute(&mut *tx)
             .await?;
 
         for tag in tags {
@@ -350,7 +241,7 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
                 Tag::PlainText(ref tag_name, ref tag_data) => {
@@ -360,25 +251,27 @@ impl WalletStorage for SQLiteStorage {
                     .bind(item_id)
                     .bind(tag_name)
                     .bind(tag_data)
-                    .execute(&mut tx)
+                    .execute(&mut *tx)
                     .await?
                 }
             };
         }
 
-        tx.commit().await?;
-
         Ok(())
     }
 
-    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
-        let mut tx = self.pool.begin().await?;
-
+    async fn _delete_tags(
+        &self,
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+        tag_names: &[TagName],
+    ) -> IndyResult<()> {
         let (item_id,): (i64,) =
             sqlx::query_as("SELECT id FROM items WHERE type = ?1 AND name = ?2")
                 .bind(type_)
                 .bind(id)
-                .fetch_one(&mut tx)
+                .fetch_one(&mut *tx)
                 .await?;
 
         for tag_name in tag_names {
@@ -387,19 +280,205 @@ impl WalletStorage for SQLiteStorage {
                     sqlx::query("DELETE FROM tags_encrypted WHERE item_id = ?1 AND name = ?2")
                         .bind(item_id)
                         .bind(tag_name)
-                        .execute(&mut tx)
+                        .execute(&mut *tx)
                         .await?
                 }
                 TagName::OfPlain(ref tag_name) => {
                     sqlx::query("DELETE FROM tags_plaintext WHERE item_id = ?1 AND name = ?2")
                         .bind(item_id)
                         .bind(tag_name)
-                        .execute(&mut tx)
+                        .execute(&mut *tx)
                         .await?
                 }
             };
         }
 
+        Ok(())
+    }
+
+    async fn _delete(
+        &self,
+        tx: &mut Transaction<'_, Sqlite>,
+        type_: &[u8],
+        id: &[u8],
+    ) -> IndyResult<()> {
+        let rows_affected = sqlx::query("DELETE FROM items where type = ?1 AND name = ?2")
+            .bind(type_)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        match rows_affected {
+            1 => Ok(()),
+            0 => Err(err_msg(
+                IndyErrorKind::WalletItemNotFound,
+                "Item to delete not found",
+            )),
+            _ => Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "More than one row deleted. Seems wallet structure is inconsistent",
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl WalletStorage for SQLiteStorage {
+    ///
+    /// Tries to fetch values and/or tags from the storage.
+    /// Returns Result with StorageEntity object which holds requested data in case of success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type_ of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `options` - JSon containing what needs to be fetched.
+    ///  Example: {"retrieveValue": true, "retrieveTags": true}
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `StorageEntity` - Contains name, optional value and optional tags
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemNotFound` - Item is not found in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
+        let options: RecordOptions = serde_json::from_str(options).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "RecordOptions is malformed json",
+        )?;
+
+        let mut conn = self.pool.acquire().await?;
+
+        let (item_id, value, key): (i64, Vec<u8>, Vec<u8>) =
+            sqlx::query_as("SELECT id, value, key FROM items where type = ?1 AND name = ?2")
+                .bind(type_)
+                .bind(id)
+                .fetch_one(&mut conn)
+                .await?;
+
+        let value = if options.retrieve_value {
+            Some(EncryptedValue::new(value, key))
+        } else {
+            None
+        };
+
+        let type_ = if options.retrieve_type {
+            Some(type_.to_vec())
+        } else {
+            None
+        };
+
+        let tags = if options.retrieve_tags {
+            let mut tags = Vec::new();
+
+            tags.extend(
+                sqlx::query_as::<_, (Vec<u8>, String)>(
+                    "SELECT name, value from tags_plaintext where item_id = ?",
+                )
+                .bind(item_id)
+                .fetch_all(&mut conn)
+                .await?
+                .drain(..)
+                .map(|r| Tag::PlainText(r.0, r.1)),
+            );
+
+            tags.extend(
+                sqlx::query_as::<_, (Vec<u8>, Vec<u8>)>(
+                    "SELECT name, value from tags_encrypted where item_id = ?",
+                )
+                .bind(item_id)
+                .fetch_all(&mut conn)
+                .await?
+                .drain(..)
+                .map(|r| Tag::Encrypted(r.0, r.1)),
+            );
+
+            Some(tags)
+        } else {
+            None
+        };
+
+        Ok(StorageRecord::new(id.to_vec(), value, type_, tags))
+    }
+
+    ///
+    /// inserts value and tags into storage.
+    /// Returns Result with () on success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `value` - value of the item in storage
+    ///  * `value_key` - key used to encrypt the value
+    ///  * `tags` - tags assigned to the value
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` class of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemAlreadyExists` - Item is already present in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn add(
+        &self,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+        tags: &[Tag],
+    ) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        self._add(&mut tx, type_, id, value, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        self._update(&mut tx, type_, id, value).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        self._add_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        self._update_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
+        self._delete_tags(&mut tx, type_, id, tag_names).await?;
         tx.commit().await?;
         Ok(())
     }
@@ -432,28 +511,50 @@ impl WalletStorage for SQLiteStorage {
     ///
     async fn delete(&self, type_: &[u8], id: &[u8]) -> IndyResult<()> {
         let mut tx = self.pool.begin().await?;
+        self._delete(&mut tx, type_, id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
 
-        let rows_affected = sqlx::query("DELETE FROM items where type = ?1 AND name = ?2")
-            .bind(type_)
-            .bind(id)
-            .execute(&mut tx)
-            .await?
-            .rows_affected();
+    async fn batch(&self, operations: &[StorageOperation]) -> IndyResult<()> {
+        let mut tx = self.pool.begin().await?;
 
-        match rows_affected {
-            1 => {
-                tx.commit().await?;
-                Ok(())
+        for operation in operations {
+            match *operation {
+                StorageOperation::Add {
+                    ref type_,
+                    ref id,
+                    ref value,
+                    ref tags,
+                } => self._add(&mut tx, type_, id, value, tags).await?,
+                StorageOperation::Update {
+                    ref type_,
+                    ref id,
+                    ref value,
+                } => self._update(&mut tx, type_, id, value).await?,
+                StorageOperation::AddTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => self._add_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::UpdateTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => self._update_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::DeleteTags {
+                    ref type_,
+                    ref id,
+                    ref tag_names,
+                } => self._delete_tags(&mut tx, type_, id, tag_names).await?,
+                StorageOperation::Delete { ref type_, ref id } => {
+                    self._delete(&mut tx, type_, id).await?
+                }
             }
-            0 => Err(err_msg(
-                IndyErrorKind::WalletItemNotFound,
-                "Item to delete not found",
-            )),
-            _ => Err(err_msg(
-                IndyErrorKind::InvalidState,
-                "More than one row deleted. Seems wallet structure is inconsistent",
-            )),
         }
+
+        tx.commit().await?;
+        Ok(())
     }
 
     async fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
@@ -532,6 +633,85 @@ impl WalletStorage for SQLiteStorage {
         )?))
     }
 
+    async fn count_all(&self) -> IndyResult<usize> {
+        let mut conn = self.pool.acquire().await?;
+
+        let (count,): (i64,) = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM items")
+            .fetch_one(&mut conn)
+            .await?;
+
+        Ok(count as usize)
+    }
+
+    async fn get_all_page(
+        &self,
+        after: Option<i64>,
+        limit: usize,
+    ) -> IndyResult<Vec<(i64, StorageRecord)>> {
+        let mut conn = self.pool.acquire().await?;
+        let after = after.unwrap_or(i64::MIN);
+        let limit = limit as i64;
+
+        let items = sqlx::query_as::<_, (i64, Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>)>(
+            "SELECT id, name, value, key, type FROM items WHERE id > ?1 ORDER BY id LIMIT ?2",
+        )
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut conn)
+        .await?;
+
+        let mut tags: Vec<(i64, Tag)> = Vec::new();
+
+        tags.extend(
+            sqlx::query_as::<_, (i64, Vec<u8>, String)>(
+                "SELECT item_id, name, value FROM tags_plaintext \
+                WHERE item_id IN (SELECT id FROM items WHERE id > ?1 ORDER BY id LIMIT ?2)",
+            )
+            .bind(after)
+            .bind(limit)
+            .fetch_all(&mut conn)
+            .await?
+            .drain(..)
+            .map(|r| (r.0, Tag::PlainText(r.1, r.2))),
+        );
+
+        tags.extend(
+            sqlx::query_as::<_, (i64, Vec<u8>, Vec<u8>)>(
+                "SELECT item_id, name, value FROM tags_encrypted \
+                WHERE item_id IN (SELECT id FROM items WHERE id > ?1 ORDER BY id LIMIT ?2)",
+            )
+            .bind(after)
+            .bind(limit)
+            .fetch_all(&mut conn)
+            .await?
+            .drain(..)
+            .map(|r| (r.0, Tag::Encrypted(r.1, r.2))),
+        );
+
+        let mut mtags = HashMap::new();
+
+        for (k, v) in tags {
+            mtags.entry(k).or_insert_with(Vec::new).push(v)
+        }
+
+        let records = items
+            .into_iter()
+            .map(|r| {
+                (
+                    r.0,
+                    StorageRecord::new(
+                        r.1,
+                        Some(EncryptedValue::new(r.2, r.3)),
+                        Some(r.4),
+                        mtags.remove(&r.0).or_else(|| Some(Vec::new())),
+                    ),
+                )
+            })
+            .collect();
+
+        Ok(records)
+    }
+
     async fn search(
         &self,
         type_: &[u8],
@@ -550,7 +730,14 @@ impl WalletStorage for SQLiteStorage {
         let mut conn = self.pool.acquire().await?;
 
         let records = if options.retrieve_records {
-            let (query, args) = query::wql_to_sql(type_, query, None)?;
+            let sort_tag_name = options
+                .sort_by
+                .as_ref()
+                .map(SearchSort::encrypted_tag_name)
+                .transpose()?;
+
+            let (query, args) =
+                query::wql_to_sql(type_, query, &options, sort_tag_name.as_deref())?;
 
             // "SELECT i.id, i.name, i.value, i.key, i.type FROM items as i WHERE i.type = ?"
 
@@ -561,6 +748,8 @@ impl WalletStorage for SQLiteStorage {
                 query = match arg {
                     query::ToSQL::ByteSlice(a) => query.bind(a),
                     query::ToSQL::CharSlice(a) => query.bind(a),
+                    query::ToSQL::Bytes(a) => query.bind(a.as_slice()),
+                    query::ToSQL::Chars(a) => query.bind(a.as_str()),
                 }
             }
 
@@ -666,6 +855,8 @@ impl WalletStorage for SQLiteStorage {
                 query = match arg {
                     query::ToSQL::ByteSlice(a) => query.bind(a),
                     query::ToSQL::CharSlice(a) => query.bind(a),
+                    query::ToSQL::Bytes(a) => query.bind(a.as_slice()),
+                    query::ToSQL::Chars(a) => query.bind(a.as_str()),
                 }
             }
 
@@ -1407,6 +1598,47 @@ mod tests {
         _cleanup("sqlite_storage_get_all_works");
     }
 
+    #[async_std::test]
+    async fn sqlite_storage_get_all_page_works() {
+        _cleanup("sqlite_storage_get_all_page_works");
+
+        {
+            let storage = _storage("sqlite_storage_get_all_page_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .add(&_type2(), &_id2(), &_value2(), &_tags())
+                .await
+                .unwrap();
+
+            assert_eq!(2, storage.count_all().await.unwrap());
+
+            let records = storage.get_all_page(None, 1).await.unwrap();
+            assert_eq!(1, records.len());
+
+            let (position, ref record) = records[0];
+            assert_eq!(record.type_.clone().unwrap(), _type1());
+            assert_eq!(record.value.clone().unwrap(), _value1());
+            assert_eq!(_sort(record.tags.clone().unwrap()), _sort(_tags()));
+
+            let records = storage.get_all_page(Some(position), 5).await.unwrap();
+            assert_eq!(1, records.len());
+
+            let (position, ref record) = records[0];
+            assert_eq!(record.type_.clone().unwrap(), _type2());
+            assert_eq!(_sort(record.tags.clone().unwrap()), _sort(_tags()));
+
+            let records = storage.get_all_page(Some(position), 5).await.unwrap();
+            assert!(records.is_empty());
+        }
+
+        _cleanup("sqlite_storage_get_all_page_works");
+    }
+
     #[async_std::test]
     async fn sqlite_storage_get_all_works_for_empty() {
         _cleanup("sqlite_storage_get_all_works_for_empty");
@@ -1873,6 +2105,127 @@ mod tests {
         _cleanup("sqlite_storage_delete_tags_works_for_non_existing_id");
     }
 
+    #[async_std::test]
+    async fn sqlite_storage_batch_works() {
+        _cleanup("sqlite_storage_batch_works");
+
+        {
+            let storage = _storage("sqlite_storage_batch_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .batch(&[
+                    StorageOperation::Update {
+                        type_: _type1(),
+                        id: _id1(),
+                        value: _value2(),
+                    },
+                    StorageOperation::UpdateTags {
+                        type_: _type1(),
+                        id: _id1(),
+                        tags: _new_tags(),
+                    },
+                    StorageOperation::Add {
+                        type_: _type1(),
+                        id: _id2(),
+                        value: _value1(),
+                        tags: _tags(),
+                    },
+                ])
+                .await
+                .unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id2(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value1());
+        }
+
+        _cleanup("sqlite_storage_batch_works");
+    }
+
+    #[async_std::test]
+    async fn sqlite_storage_batch_works_for_rollback() {
+        _cleanup("sqlite_storage_batch_works_for_rollback");
+
+        {
+            let storage = _storage("sqlite_storage_batch_works_for_rollback").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let res = storage
+                .batch(&[
+                    StorageOperation::Add {
+                        type_: _type1(),
+                        id: _id2(),
+                        value: _value2(),
+                        tags: _tags(),
+                    },
+                    StorageOperation::Delete {
+                        type_: _type1(),
+                        id: _id1(),
+                    },
+                    StorageOperation::Add {
+                        type_: _type1(),
+                        id: _id2(),
+                        value: _value2(),
+                        tags: _tags(),
+                    },
+                ])
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemAlreadyExists, res);
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value1());
+
+            let res = storage
+                .get(
+                    &_type1(),
+                    &_id2(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        _cleanup("sqlite_storage_batch_works_for_rollback");
+    }
+
     fn _cleanup(name: &str) {
         test::cleanup_storage(name)
     }
@@ -2,12 +2,17 @@ use std::convert::From;
 
 use indy_api_types::errors::prelude::*;
 
-use crate::language::{Operator, TagName, TargetValue};
+use crate::{
+    language::{Operator, TagName, TargetValue},
+    SearchOptions, SortOrder,
+};
 
 #[derive(Debug)]
 pub(crate) enum ToSQL<'a> {
     ByteSlice(&'a [u8]),
     CharSlice(&'a str),
+    Bytes(Vec<u8>),
+    Chars(String),
 }
 
 impl<'a> From<&'a Vec<u8>> for ToSQL<'a> {
@@ -37,10 +42,13 @@ impl<'a> From<&'a String> for ToSQL<'a> {
 // Translates Wallet Query Language to SQL
 // WQL input is provided as a reference to a top level Operator
 // Result is a tuple of query string and query arguments
+// Records are ordered by name or by the value of the plaintext tag and name if sorting is requested.
+// Name is unique within the type, so records after the cursor position are selected by these values.
 pub(crate) fn wql_to_sql<'a>(
     class: &'a [u8],
     op: &'a Operator,
-    _options: Option<&str>,
+    options: &SearchOptions,
+    sort_tag_name: Option<&'a [u8]>,
 ) -> Result<(String, Vec<ToSQL<'a>>), IndyError> {
     let mut arguments: Vec<ToSQL<'a>> = Vec::new();
     arguments.push(class.into());
@@ -49,15 +57,80 @@ pub(crate) fn wql_to_sql<'a>(
 
     const BASE: &str =
         "SELECT i.id, i.name, i.value, i.key, i.type FROM items as i WHERE i.type = ?";
+
+    let mut query_string = BASE.to_string();
+
     if !clause_string.is_empty() {
-        let mut query_string = String::with_capacity(BASE.len() + 5 + clause_string.len());
-        query_string.push_str(BASE);
         query_string.push_str(" AND ");
         query_string.push_str(&clause_string);
-        Ok((query_string, arguments))
-    } else {
-        Ok((BASE.to_string(), arguments))
     }
+
+    const SORT_VALUE: &str =
+        "(SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?)";
+
+    let sort = options.sort_by.as_ref().and(sort_tag_name);
+
+    if let Some(ref after) = options.after {
+        let after_name = after.encrypted_name()?;
+
+        match (sort, after.sort_value.as_ref()) {
+            (Some(sort_tag_name), Some(sort_value)) => {
+                let operator = match options.sort_by.as_ref().map(|sort_by| sort_by.order) {
+                    Some(SortOrder::Desc) => "<",
+                    _ => ">",
+                };
+
+                query_string.push_str(&format!(
+                    " AND ({} IS NULL OR {} {} ? OR ({} = ? AND i.name > ?))",
+                    SORT_VALUE, SORT_VALUE, operator, SORT_VALUE
+                ));
+
+                arguments.push(sort_tag_name.into());
+                arguments.push(sort_tag_name.into());
+                arguments.push(ToSQL::Chars(sort_value.to_string()));
+                arguments.push(sort_tag_name.into());
+                arguments.push(ToSQL::Chars(sort_value.to_string()));
+                arguments.push(ToSQL::Bytes(after_name));
+            }
+            // Position is among the records without the sort tag
+            (Some(sort_tag_name), None) => {
+                query_string.push_str(&format!(" AND {} IS NULL AND i.name > ?", SORT_VALUE));
+
+                arguments.push(sort_tag_name.into());
+                arguments.push(ToSQL::Bytes(after_name));
+            }
+            (None, _) => {
+                query_string.push_str(" AND i.name > ?");
+                arguments.push(ToSQL::Bytes(after_name));
+            }
+        }
+    }
+
+    match (options.sort_by.as_ref(), sort) {
+        (Some(sort_by), Some(sort_tag_name)) => {
+            query_string.push_str(&format!(
+                " ORDER BY {} IS NULL, {} {}, i.name",
+                SORT_VALUE,
+                SORT_VALUE,
+                sort_by.order_sql()
+            ));
+
+            arguments.push(sort_tag_name.into());
+            arguments.push(sort_tag_name.into());
+        }
+        _ => query_string.push_str(" ORDER BY i.name"),
+    }
+
+    match (options.limit, options.offset) {
+        (Some(limit), offset) => {
+            query_string.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset.unwrap_or(0)))
+        }
+        // SQLite requires LIMIT clause for OFFSET, negative value means no limit
+        (None, Some(offset)) => query_string.push_str(&format!(" LIMIT -1 OFFSET {}", offset)),
+        (None, None) => {}
+    }
+
+    Ok((query_string, arguments))
 }
 
 pub(crate) fn wql_to_sql_count<'a>(
@@ -426,6 +499,85 @@ mod tests {
 
         let query = Operator::Or(vec![condition_1, condition_2]);
         let class = vec![100, 100, 100];
-        let (_query, _arguments) = wql_to_sql(&class, &query, None).unwrap();
+        let options = SearchOptions::default();
+        let (_query, _arguments) = wql_to_sql(&class, &query, &options, None).unwrap();
+    }
+
+    #[test]
+    fn sort_and_page_works() {
+        let query = Operator::And(vec![]);
+        let class = vec![100, 100, 100];
+        let sort_tag_name = vec![1, 2, 3];
+
+        let options: SearchOptions = serde_json::from_str(
+            r#"{"offset": 10, "limit": 5, "sortBy": {"tagName": "AQID", "order": "desc"}}"#,
+        )
+        .unwrap();
+
+        let (query, arguments) =
+            wql_to_sql(&class, &query, &options, Some(&sort_tag_name)).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT i.id, i.name, i.value, i.key, i.type FROM items as i WHERE i.type = ? \
+            ORDER BY (SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?) IS NULL, \
+            (SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?) DESC, i.name \
+            LIMIT 5 OFFSET 10"
+        );
+
+        assert_eq!(arguments.len(), 3);
+    }
+
+    #[test]
+    fn sort_and_position_works() {
+        let query = Operator::And(vec![]);
+        let class = vec![100, 100, 100];
+        let sort_tag_name = vec![1, 2, 3];
+
+        let options: SearchOptions = serde_json::from_str(
+            r#"{"limit": 5, "sortBy": {"tagName": "AQID", "order": "desc"}, "after": {"name": "BAUG", "sortValue": "5"}}"#,
+        )
+        .unwrap();
+
+        let (query, arguments) =
+            wql_to_sql(&class, &query, &options, Some(&sort_tag_name)).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT i.id, i.name, i.value, i.key, i.type FROM items as i WHERE i.type = ? \
+            AND ((SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?) IS NULL \
+            OR (SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?) < ? \
+            OR ((SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?) = ? AND i.name > ?)) \
+            ORDER BY (SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?) IS NULL, \
+            (SELECT value FROM tags_plaintext WHERE item_id = i.id AND name = ?) DESC, i.name \
+            LIMIT 5 OFFSET 0"
+        );
+
+        assert_eq!(arguments.len(), 9);
+    }
+
+    #[test]
+    fn position_without_sort_works() {
+        let query = Operator::And(vec![]);
+        let class = vec![100, 100, 100];
+
+        let options: SearchOptions = serde_json::from_str(r#"{"after": {"name": "BAUG"}}"#).unwrap();
+
+        let (query, arguments) = wql_to_sql(&class, &query, &options, None).unwrap();
+
+        assert!(query.ends_with(" WHERE i.type = ? AND i.name > ? ORDER BY i.name"));
+        assert_eq!(arguments.len(), 2);
+    }
+
+    #[test]
+    fn offset_without_limit_works() {
+        let query = Operator::And(vec![]);
+        let class = vec![100, 100, 100];
+
+        let options: SearchOptions = serde_json::from_str(r#"{"offset": 10}"#).unwrap();
+
+        let (query, _arguments) = wql_to_sql(&class, &query, &options, None).unwrap();
+
+        assert!(query.ends_with(" ORDER BY i.name LIMIT -1 OFFSET 10"));
     }
 }
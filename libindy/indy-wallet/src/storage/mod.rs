@@ -5,7 +5,8 @@ use crate::{language, wallet::EncryptedValue};
 
 pub mod default;
 pub mod mysql;
-//pub mod plugged; FIXME:!!!
+pub mod plugged;
+pub mod postgres;
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Tag {
@@ -19,6 +20,65 @@ pub enum TagName {
     OfPlain(Vec<u8>),
 }
 
+/// Single write applied as a part of `WalletStorage::batch`.
+#[derive(Debug)]
+pub enum StorageOperation {
+    Add {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        value: EncryptedValue,
+        tags: Vec<Tag>,
+    },
+    Update {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        value: EncryptedValue,
+    },
+    AddTags {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        tags: Vec<Tag>,
+    },
+    UpdateTags {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        tags: Vec<Tag>,
+    },
+    DeleteTags {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+        tag_names: Vec<TagName>,
+    },
+    Delete {
+        type_: Vec<u8>,
+        id: Vec<u8>,
+    },
+}
+
+impl StorageOperation {
+    /// Encrypted type and id of the record the operation is applied to.
+    pub fn key(&self) -> (&[u8], &[u8]) {
+        match *self {
+            StorageOperation::Add {
+                ref type_, ref id, ..
+            }
+            | StorageOperation::Update {
+                ref type_, ref id, ..
+            }
+            | StorageOperation::AddTags {
+                ref type_, ref id, ..
+            }
+            | StorageOperation::UpdateTags {
+                ref type_, ref id, ..
+            }
+            | StorageOperation::DeleteTags {
+                ref type_, ref id, ..
+            }
+            | StorageOperation::Delete { ref type_, ref id } => (type_, id),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct StorageRecord {
     pub id: Vec<u8>,
@@ -75,10 +135,46 @@ pub trait WalletStorage: Send + Sync {
         tag_names: &[TagName],
     ) -> Result<(), IndyError>;
     async fn delete(&self, type_: &[u8], id: &[u8]) -> Result<(), IndyError>;
+    /// Applies all operations in a single storage transaction: either all of them are
+    /// persisted or none.
+    async fn batch(&self, operations: &[StorageOperation]) -> Result<(), IndyError>;
     async fn get_storage_metadata(&self) -> Result<Vec<u8>, IndyError>;
     async fn set_storage_metadata(&self, metadata: &[u8]) -> Result<(), IndyError>;
     async fn get_all(&self) -> Result<Box<dyn StorageIterator>, IndyError>;
 
+    async fn count_all(&self) -> Result<usize, IndyError> {
+        Ok(self.get_all().await?.get_total_count()?.unwrap_or(0))
+    }
+
+    /// Returns up to `limit` records that follow the storage specific position `after`
+    /// in the order records were added, together with their positions. Records added
+    /// later get greater positions, so all records can be walked without loading them at once.
+    ///
+    /// Default implementation uses indexes of records returned by `get_all`.
+    async fn get_all_page(
+        &self,
+        after: Option<i64>,
+        limit: usize,
+    ) -> Result<Vec<(i64, StorageRecord)>, IndyError> {
+        let mut iterator = self.get_all().await?;
+        let mut records = Vec::new();
+        let mut position = 0;
+
+        while let Some(record) = iterator.next().await? {
+            if records.len() == limit {
+                break;
+            }
+
+            if after.map(|after| position > after).unwrap_or(true) {
+                records.push((position, record));
+            }
+
+            position += 1;
+        }
+
+        Ok(records)
+    }
+
     // TODO:
     async fn search(
         &self,
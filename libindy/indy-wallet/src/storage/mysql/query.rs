@@ -4,7 +4,7 @@ use serde_json::Value;
 
 use crate::{
     language::{Operator, TagName, TargetValue},
-    SearchOptions,
+    SearchOptions, SortOrder,
 };
 
 pub fn wql_to_sql(
@@ -20,7 +20,7 @@ pub fn wql_to_sql(
         Err(err) => return Err(err),
     };
 
-    let query_string = format!(
+    let mut query_string = format!(
         "SELECT {}, name, {}, {} FROM items WHERE {} type = ? AND wallet_id = ?",
         if options.retrieve_type {
             "type"
@@ -47,6 +47,76 @@ pub fn wql_to_sql(
     arguments.push(base64::encode(type_).into());
     arguments.push(wallet_id.into());
 
+    // Tag name is re-encoded to be sure it is safe to embed it into the query
+    let tag_path = match options.sort_by {
+        Some(ref sort_by) => Some(format!(
+            r#"'$."~{}"'"#,
+            base64::encode(&sort_by.encrypted_tag_name()?)
+        )),
+        None => None,
+    };
+
+    // Name is unique within the type, so records after the cursor position are selected
+    // by the sort value and the name. Names are compared as binary strings to not depend on collation.
+    if let Some(ref after) = options.after {
+        let after_name = base64::encode(&after.encrypted_name()?);
+
+        match (tag_path.as_ref(), after.sort_value.as_ref()) {
+            (Some(tag_path), Some(sort_value)) => {
+                let operator = match options.sort_by.as_ref().map(|sort_by| sort_by.order) {
+                    Some(SortOrder::Desc) => "<",
+                    _ => ">",
+                };
+
+                query_string.push_str(&format!(
+                    " AND (JSON_EXTRACT(tags, {}) IS NULL OR JSON_UNQUOTE(JSON_EXTRACT(tags, {})) {} ? \
+                    OR (JSON_UNQUOTE(JSON_EXTRACT(tags, {})) = ? AND CAST(name AS BINARY) > ?))",
+                    tag_path, tag_path, operator, tag_path
+                ));
+
+                arguments.push(sort_value.to_string().into());
+                arguments.push(sort_value.to_string().into());
+                arguments.push(after_name.into());
+            }
+            // Position is among the records without the sort tag
+            (Some(tag_path), None) => {
+                query_string.push_str(&format!(
+                    " AND JSON_EXTRACT(tags, {}) IS NULL AND CAST(name AS BINARY) > ?",
+                    tag_path
+                ));
+
+                arguments.push(after_name.into());
+            }
+            (None, _) => {
+                query_string.push_str(" AND CAST(name AS BINARY) > ?");
+                arguments.push(after_name.into());
+            }
+        }
+    }
+
+    match (options.sort_by.as_ref(), tag_path.as_ref()) {
+        (Some(sort_by), Some(tag_path)) => {
+            query_string.push_str(&format!(
+                " ORDER BY JSON_EXTRACT(tags, {}) IS NULL, JSON_UNQUOTE(JSON_EXTRACT(tags, {})) {}, CAST(name AS BINARY)",
+                tag_path,
+                tag_path,
+                sort_by.order_sql()
+            ));
+        }
+        _ => query_string.push_str(" ORDER BY CAST(name AS BINARY)"),
+    }
+
+    match (options.limit, options.offset) {
+        (Some(limit), offset) => {
+            query_string.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset.unwrap_or(0)))
+        }
+        // MySQL requires LIMIT clause for OFFSET
+        (None, Some(offset)) => {
+            query_string.push_str(&format!(" LIMIT {} OFFSET {}", u64::MAX, offset))
+        }
+        (None, None) => {}
+    }
+
     Ok((query_string, arguments))
 }
 
@@ -352,4 +422,61 @@ mod tests {
 
         let (_query, _arguments) = wql_to_sql(1_i64, &class, &query, &options).unwrap();
     }
+
+    #[test]
+    fn sort_and_page_works() {
+        let query = Operator::And(vec![]);
+        let class = [100, 100, 100];
+
+        let options: SearchOptions = serde_json::from_str(
+            r#"{"offset": 10, "limit": 5, "sortBy": {"tagName": "AQID"}}"#,
+        )
+        .unwrap();
+
+        let (query, arguments) = wql_to_sql(1_i64, &class, &query, &options).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT NULL, name, value, NULL FROM items WHERE  type = ? AND wallet_id = ? \
+            ORDER BY JSON_EXTRACT(tags, '$.\"~AQID\"') IS NULL, \
+            JSON_UNQUOTE(JSON_EXTRACT(tags, '$.\"~AQID\"')) ASC, CAST(name AS BINARY) \
+            LIMIT 5 OFFSET 10"
+        );
+
+        assert_eq!(arguments.len(), 2);
+    }
+
+    #[test]
+    fn sort_and_position_works() {
+        let query = Operator::And(vec![]);
+        let class = [100, 100, 100];
+
+        let options: SearchOptions = serde_json::from_str(
+            r#"{"limit": 5, "sortBy": {"tagName": "AQID"}, "after": {"name": "BAUG", "sortValue": "5"}}"#,
+        )
+        .unwrap();
+
+        let (query, arguments) = wql_to_sql(1_i64, &class, &query, &options).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT NULL, name, value, NULL FROM items WHERE  type = ? AND wallet_id = ? \
+            AND (JSON_EXTRACT(tags, '$.\"~AQID\"') IS NULL OR JSON_UNQUOTE(JSON_EXTRACT(tags, '$.\"~AQID\"')) > ? \
+            OR (JSON_UNQUOTE(JSON_EXTRACT(tags, '$.\"~AQID\"')) = ? AND CAST(name AS BINARY) > ?)) \
+            ORDER BY JSON_EXTRACT(tags, '$.\"~AQID\"') IS NULL, \
+            JSON_UNQUOTE(JSON_EXTRACT(tags, '$.\"~AQID\"')) ASC, CAST(name AS BINARY) \
+            LIMIT 5 OFFSET 0"
+        );
+
+        assert_eq!(
+            arguments,
+            vec![
+                Value::from("ZGRk"),
+                Value::from(1_i64),
+                Value::from("5"),
+                Value::from("5"),
+                Value::from("BAUG"),
+            ]
+        );
+    }
 }
@@ -1,9 +1,12 @@
 use std::{slice, str};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use std::ffi::{CStr, CString};
 use std::ptr;
 
+use async_trait::async_trait;
 use libc::c_char;
+use serde::Deserialize;
 use serde_json;
 
 use indy_api_types::{ErrorCode, SearchHandle, INVALID_SEARCH_HANDLE};
@@ -12,8 +15,8 @@ use indy_api_types::errors::prelude::*;
 use crate::language;
 use indy_utils::crypto::base64;
 
-use super::{EncryptedValue, StorageIterator, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType};
-use super::super::{RecordOptions, SearchOptions};
+use super::{EncryptedValue, StorageIterator, StorageOperation, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType};
+use super::super::{RecordOptions, SearchOptions, SortOrder};
 
 #[derive(Debug, Deserialize)]
 pub struct PluggedWalletJSONValue {
@@ -87,8 +90,9 @@ impl PluggedStorageIterator {
     }
 }
 
+#[async_trait]
 impl StorageIterator for PluggedStorageIterator {
-    fn next(&mut self) -> IndyResult<Option<StorageRecord>> {
+    async fn next(&mut self) -> IndyResult<Option<StorageRecord>> {
         let mut record_handle = -1;
 
         let err = (self.fetch_search_next_record_handler)(self.storage_handle,
@@ -211,6 +215,116 @@ impl Drop for PluggedStorageIterator {
     }
 }
 
+// Plugins know nothing about paging and sorting of search results,
+// so they are applied to the records fetched from the plugin.
+struct PluggedPagedStorageIterator {
+    records: VecDeque<StorageRecord>,
+    total_count: Option<usize>,
+}
+
+impl PluggedPagedStorageIterator {
+    async fn new(mut iterator: PluggedStorageIterator, options: &SearchOptions) -> IndyResult<Self> {
+        let total_count = iterator.get_total_count()?;
+
+        let mut records = Vec::new();
+
+        while let Some(record) = iterator.next().await? {
+            records.push(record);
+        }
+
+        let tag_name = match options.sort_by {
+            Some(ref sort_by) => Some(sort_by.encrypted_tag_name()?),
+            None => None,
+        };
+
+        let desc = options
+            .sort_by
+            .as_ref()
+            .map(|sort_by| sort_by.order == SortOrder::Desc)
+            .unwrap_or(false);
+
+        // Name is unique within the type, so it makes the order of records stable
+        let compare = |a: &StorageRecord, b: &StorageRecord| {
+            let by_value = match tag_name {
+                Some(ref tag_name) => _compare_sort_values(
+                    _plain_tag_value(a, tag_name),
+                    _plain_tag_value(b, tag_name),
+                    desc,
+                ),
+                None => Ordering::Equal,
+            };
+
+            by_value.then_with(|| a.id.cmp(&b.id))
+        };
+
+        records.sort_by(|a, b| compare(a, b));
+
+        // Records after the cursor position are selected by the sort value and the name
+        if let Some(ref after) = options.after {
+            let after_name = after.encrypted_name()?;
+
+            records.retain(|record| {
+                let by_value = match tag_name {
+                    Some(ref tag_name) => _compare_sort_values(
+                        _plain_tag_value(record, tag_name),
+                        after.sort_value.as_deref(),
+                        desc,
+                    ),
+                    None => Ordering::Equal,
+                };
+
+                by_value.then_with(|| record.id.cmp(&after_name)) == Ordering::Greater
+            });
+        }
+
+        let records = records
+            .into_iter()
+            .skip(options.offset.unwrap_or(0))
+            .take(options.limit.unwrap_or(usize::MAX))
+            .map(|mut record| {
+                if !options.retrieve_tags {
+                    record.tags = None;
+                }
+                record
+            })
+            .collect();
+
+        Ok(Self {
+            records,
+            total_count,
+        })
+    }
+}
+
+#[async_trait]
+impl StorageIterator for PluggedPagedStorageIterator {
+    async fn next(&mut self) -> IndyResult<Option<StorageRecord>> {
+        Ok(self.records.pop_front())
+    }
+
+    fn get_total_count(&self) -> IndyResult<Option<usize>> {
+        Ok(self.total_count)
+    }
+}
+
+// Records without the sort tag go after all others
+fn _compare_sort_values(a: Option<&str>, b: Option<&str>, desc: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if desc => b.cmp(a),
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn _plain_tag_value<'a>(record: &'a StorageRecord, tag_name: &[u8]) -> Option<&'a str> {
+    record.tags.as_ref()?.iter().find_map(|tag| match *tag {
+        Tag::PlainText(ref name, ref value) if name.as_slice() == tag_name => Some(value.as_str()),
+        _ => None,
+    })
+}
+
 #[derive(PartialEq, Debug)]
 struct PluggedStorage {
     handle: i32,
@@ -340,8 +454,9 @@ fn _tags_names_to_json(tag_names: &[TagName]) -> IndyResult<String> {
         .to_indy(IndyErrorKind::InvalidState, "Unable to serialize tag names as json")
 }
 
+#[async_trait]
 impl WalletStorage for PluggedStorage {
-    fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
+    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
         let type_cstr = CString::new(base64::encode(type_))?;
         let id_cstr = CString::new(base64::encode(id))?;
         let options_cstr = CString::new(options)?;
@@ -412,7 +527,7 @@ impl WalletStorage for PluggedStorage {
         Ok(result)
     }
 
-    fn add(&self, type_: &[u8], id: &[u8], value: &EncryptedValue, tags: &[Tag]) -> IndyResult<()> {
+    async fn add(&self, type_: &[u8], id: &[u8], value: &EncryptedValue, tags: &[Tag]) -> IndyResult<()> {
         let type_ = CString::new(base64::encode(type_))?;
         let id = CString::new(base64::encode(id))?;
         let joined_value = value.to_bytes();
@@ -434,7 +549,7 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
-    fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
         let type_ = CString::new(base64::encode(type_))?;
         let id = CString::new(base64::encode(id))?;
         let tags = CString::new(_tags_to_json(&tags)?)?;
@@ -451,7 +566,7 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
-    fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
         let type_ = CString::new(base64::encode(type_))?;
         let id = CString::new(base64::encode(id))?;
         let tags = CString::new(_tags_to_json(&tags)?)?;
@@ -468,7 +583,7 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
-    fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
+    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
         let type_ = CString::new(base64::encode(type_))?;
         let id = CString::new(base64::encode(id))?;
         let tag_names = CString::new(_tags_names_to_json(tag_names)?)?;
@@ -485,7 +600,7 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
-    fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
+    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
         let type_ = CString::new(base64::encode(type_))?;
         let id = CString::new(base64::encode(id))?;
         let joined_value = value.to_bytes();
@@ -503,7 +618,7 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
-    fn delete(&self, type_: &[u8], id: &[u8]) -> IndyResult<()> {
+    async fn delete(&self, type_: &[u8], id: &[u8]) -> IndyResult<()> {
         let type_ = CString::new(base64::encode(type_))?;
         let id = CString::new(base64::encode(id))?;
 
@@ -518,7 +633,14 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
-    fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
+    async fn batch(&self, _operations: &[StorageOperation]) -> IndyResult<()> {
+        // Plugged storage interface has no transaction callbacks, so applying operations
+        // one by one would silently break atomicity.
+        Err(err_msg(IndyErrorKind::WalletStorageError,
+                    "Atomic batches are not supported by plugged wallet storage"))
+    }
+
+    async fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
         let mut metadata_ptr: *const c_char = ptr::null_mut();
         let mut metadata_handle = -1;
 
@@ -541,7 +663,7 @@ impl WalletStorage for PluggedStorage {
         Ok(metadata)
     }
 
-    fn set_storage_metadata(&self, metadata: &[u8]) -> IndyResult<()> {
+    async fn set_storage_metadata(&self, metadata: &[u8]) -> IndyResult<()> {
         let metadata = CString::new(base64::encode(metadata))?;
 
         let err = (self.set_storage_metadata_handler)(self.handle, metadata.as_ptr());
@@ -553,7 +675,7 @@ impl WalletStorage for PluggedStorage {
         Ok(())
     }
 
-    fn get_all(&self) -> IndyResult<Box<dyn StorageIterator>> {
+    async fn get_all(&self) -> IndyResult<Box<dyn StorageIterator>> {
         let mut search_handle: SearchHandle = INVALID_SEARCH_HANDLE;
 
         let err = (self.search_all_records_handler)(self.handle, &mut search_handle.0);
@@ -572,19 +694,40 @@ impl WalletStorage for PluggedStorage {
                     retrieve_type: true,
                     retrieve_value: true,
                     retrieve_tags: true,
+                    ..SearchOptions::default()
                 },
             )
         ))
     }
 
-    fn search(&self, type_: &[u8], query: &language::Operator, options: Option<&str>) -> IndyResult<Box<dyn StorageIterator>> {
+    async fn search(&self, type_: &[u8], query: &language::Operator, options: Option<&str>) -> IndyResult<Box<dyn StorageIterator>> {
         let type_ = CString::new(base64::encode(type_))?;
         let query = CString::new(query.to_string())?;
-        let options_cstr = CString::new(options.unwrap_or("{}"))?;
 
         let options: SearchOptions = serde_json::from_str(options.unwrap_or("{}"))
             .to_indy(IndyErrorKind::InvalidStructure, "Search options is malformed json")?;
 
+        let paged = options.retrieve_records && options.is_paged();
+
+        let plugin_options = if paged {
+            SearchOptions {
+                retrieve_tags: options.retrieve_tags || options.sort_by.is_some(),
+                offset: None,
+                limit: None,
+                sort_by: None,
+                cursor: None,
+                after: None,
+                ..options.clone()
+            }
+        } else {
+            options.clone()
+        };
+
+        let options_cstr = CString::new(
+            serde_json::to_string(&plugin_options)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize search options")?
+        )?;
+
         let mut search_handle: SearchHandle = INVALID_SEARCH_HANDLE;
 
         let err = (self.search_records_handler)(self.handle,
@@ -597,13 +740,13 @@ impl WalletStorage for PluggedStorage {
             return Err(err.into());
         }
 
-        Ok(Box::new(
-            PluggedStorageIterator::new(
-                &self,
-                search_handle,
-                options,
-            )
-        ))
+        let iterator = PluggedStorageIterator::new(&self, search_handle, plugin_options);
+
+        if paged {
+            Ok(Box::new(PluggedPagedStorageIterator::new(iterator, &options).await?))
+        } else {
+            Ok(Box::new(iterator))
+        }
     }
 
     fn close(&mut self) -> IndyResult<()> {
@@ -712,8 +855,9 @@ impl PluggedStorageType {
     }
 }
 
+#[async_trait]
 impl WalletStorageType for PluggedStorageType {
-    fn create_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>, metadata: &[u8]) -> IndyResult<()> {
+    async fn create_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>, metadata: &[u8]) -> IndyResult<()> {
         let name = CString::new(id)?;
         let metadata = CString::new(base64::encode(metadata))?;
 
@@ -737,7 +881,7 @@ impl WalletStorageType for PluggedStorageType {
         Ok(())
     }
 
-    fn open_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> IndyResult<Box<dyn WalletStorage>> {
+    async fn open_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> IndyResult<Box<dyn WalletStorage>> {
         let mut handle: i32 = -1;
         let id = CString::new(id)?;
 
@@ -784,7 +928,7 @@ impl WalletStorageType for PluggedStorageType {
                 self.close_handler)))
     }
 
-    fn delete_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> IndyResult<()> {
+    async fn delete_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> IndyResult<()> {
         let id = CString::new(id)?;
 
         let config = config
@@ -815,6 +959,7 @@ mod tests {
     use std::sync::RwLock;
 
     use indy_api_types::ErrorCode;
+    use lazy_static::lazy_static;
 
     use super::*;
 
@@ -1375,8 +1520,8 @@ mod tests {
     }
 
 
-    #[test]
-    fn plugged_storage_type_create_works() {
+    #[async_std::test]
+    async fn plugged_storage_type_create_works() {
         DEBUG_VEC.write().unwrap().clear();
 
         let storage_type = _create_storage_type();
@@ -1384,7 +1529,7 @@ mod tests {
         let credentials = "credentials";
         let metadata = vec![1, 2, 3];
 
-        storage_type.create_storage(storage_name, None, Some(credentials), &metadata).unwrap();
+        storage_type.create_storage(storage_name, None, Some(credentials), &metadata).await.unwrap();
 
         let expected_call = Call::CreateHandler(
             Some(storage_name.to_owned()),
@@ -1399,15 +1544,15 @@ mod tests {
         assert_eq!(&expected_call, debug.get(0).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_type_open_close_works() {
+    #[async_std::test]
+    async fn plugged_storage_type_open_close_works() {
         DEBUG_VEC.write().unwrap().clear();
 
         let storage_type = _create_storage_type();
         let storage_name = "wallet1";
         let credentials = "credentials";
 
-        let mut storage = storage_type.open_storage(storage_name, None, Some(credentials)).unwrap();
+        let mut storage = storage_type.open_storage(storage_name, None, Some(credentials)).await.unwrap();
         storage.close().unwrap();
 
         let expected_open_call = Call::OpenHandler(
@@ -1427,15 +1572,15 @@ mod tests {
         assert_eq!(&expected_close_call, debug.get(1).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_type_delete_works() {
+    #[async_std::test]
+    async fn plugged_storage_type_delete_works() {
         DEBUG_VEC.write().unwrap().clear();
 
         let storage_type = _create_storage_type();
         let storage_name = "wallet1";
         let credentials = "credentials";
 
-        storage_type.delete_storage(storage_name, None, Some(credentials)).unwrap();
+        storage_type.delete_storage(storage_name, None, Some(credentials)).await.unwrap();
 
         let expected_call = Call::DeleteHandler(
             Some(storage_name.to_owned()),
@@ -1449,7 +1594,7 @@ mod tests {
         assert_eq!(&expected_call, debug.get(0).unwrap());
     }
 
-    fn _open_storage() -> Box<dyn WalletStorage> {
+    async fn _open_storage() -> Box<dyn WalletStorage> {
         // save the current index inside of DEBUG_VEC.
         let open_index = DEBUG_VEC.read().unwrap().len();
 
@@ -1457,7 +1602,7 @@ mod tests {
         let storage_name = "wallet1";
         let credentials = "credentials";
 
-        let storage = storage_type.open_storage(storage_name, None, Some(credentials)).unwrap();
+        let storage = storage_type.open_storage(storage_name, None, Some(credentials)).await.unwrap();
 
         let expected_call = Call::OpenHandler(
             Some(storage_name.to_owned()),
@@ -1495,11 +1640,11 @@ mod tests {
         serde_json::to_string(&map).unwrap()
     }
 
-    #[test]
-    fn plugged_storage_add_works() {
+    #[async_std::test]
+    async fn plugged_storage_add_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1511,7 +1656,7 @@ mod tests {
         tags.push(Tag::PlainText(_random_vector(32), _random_string(64)));
         tags.push(Tag::Encrypted(_random_vector(32), _random_vector(64)));
 
-        storage.add(&type_, &id, &value, &tags).unwrap();
+        storage.add(&type_, &id, &value, &tags).await.unwrap();
 
         let expected_call = Call::AddRecordHandler(
             RETURN_STORAGE_HANDLE,
@@ -1527,11 +1672,11 @@ mod tests {
         assert_eq!(&expected_call, debug.get(0).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_update_record_value_works() {
+    #[async_std::test]
+    async fn plugged_storage_update_record_value_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1539,7 +1684,7 @@ mod tests {
         let id = _random_vector(32);
         let value = EncryptedValue { data: _random_vector(256), key: _random_vector(44) };
 
-        storage.update(&type_, &id, &value).unwrap();
+        storage.update(&type_, &id, &value).await.unwrap();
 
         let expected_call = Call::UpdateRecordValueHandler(
             RETURN_STORAGE_HANDLE,
@@ -1554,11 +1699,11 @@ mod tests {
         assert_eq!(&expected_call, debug.get(0).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_update_record_tags_works() {
+    #[async_std::test]
+    async fn plugged_storage_update_record_tags_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1570,7 +1715,7 @@ mod tests {
         tags.push(Tag::Encrypted(_random_vector(32), _random_vector(64)));
 
 
-        storage.update_tags(&type_, &id, &tags).unwrap();
+        storage.update_tags(&type_, &id, &tags).await.unwrap();
 
         let expected_call = Call::UpdateRecordTagsHandler(
             RETURN_STORAGE_HANDLE,
@@ -1585,11 +1730,11 @@ mod tests {
         assert_eq!(&expected_call, debug.get(0).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_add_record_tags_works() {
+    #[async_std::test]
+    async fn plugged_storage_add_record_tags_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1601,7 +1746,7 @@ mod tests {
         tags.push(Tag::Encrypted(_random_vector(32), _random_vector(64)));
 
 
-        storage.add_tags(&type_, &id, &tags).unwrap();
+        storage.add_tags(&type_, &id, &tags).await.unwrap();
 
         let expected_call = Call::AddRecordTagsHandler(
             RETURN_STORAGE_HANDLE,
@@ -1616,11 +1761,11 @@ mod tests {
         assert_eq!(&expected_call, debug.get(0).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_get_record_type_value_tags_works() {
+    #[async_std::test]
+    async fn plugged_storage_get_record_type_value_tags_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1628,7 +1773,7 @@ mod tests {
         let id = _random_vector(32);
         let options = _fetch_options(true, true, true);
 
-        let storage_entity = storage.get(&type_, &id, &options).unwrap();
+        let storage_entity = storage.get(&type_, &id, &options).await.unwrap();
 
         let expected_storage_entity = StorageRecord {
             type_: Some(type_.clone()),
@@ -1668,11 +1813,11 @@ mod tests {
         assert_eq!(&expected_free_record_call, debug.get(3).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_get_record_value_tags_works() {
+    #[async_std::test]
+    async fn plugged_storage_get_record_value_tags_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1680,7 +1825,7 @@ mod tests {
         let id = _random_vector(32);
         let options = _fetch_options(true, true, false);
 
-        let storage_entity = storage.get(&type_, &id, &options).unwrap();
+        let storage_entity = storage.get(&type_, &id, &options).await.unwrap();
 
         let expected_storage_entity = StorageRecord {
             type_: None,
@@ -1719,11 +1864,11 @@ mod tests {
         assert_eq!(&expected_free_record_call, debug.get(3).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_get_record_value_works() {
+    #[async_std::test]
+    async fn plugged_storage_get_record_value_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1731,7 +1876,7 @@ mod tests {
         let id = _random_vector(32);
         let options = _fetch_options(true, false, false);
 
-        let storage_entity = storage.get(&type_, &id, &options).unwrap();
+        let storage_entity = storage.get(&type_, &id, &options).await.unwrap();
 
         let expected_storage_entity = StorageRecord {
             type_: None,
@@ -1765,11 +1910,11 @@ mod tests {
         assert_eq!(&expected_free_record_call, debug.get(2).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_get_record_tags_works() {
+    #[async_std::test]
+    async fn plugged_storage_get_record_tags_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1777,7 +1922,7 @@ mod tests {
         let id = _random_vector(32);
         let options = _fetch_options(false, true, false);
 
-        let storage_entity = storage.get(&type_, &id, &options).unwrap();
+        let storage_entity = storage.get(&type_, &id, &options).await.unwrap();
 
         let expected_storage_entity = StorageRecord {
             type_: None,
@@ -1812,11 +1957,11 @@ mod tests {
         assert_eq!(&expected_free_record_call, debug.get(2).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_get_record_none_works() {
+    #[async_std::test]
+    async fn plugged_storage_get_record_none_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1824,7 +1969,7 @@ mod tests {
         let id = _random_vector(32);
         let options = _fetch_options(false, false, false);
 
-        let storage_entity = storage.get(&type_, &id, &options).unwrap();
+        let storage_entity = storage.get(&type_, &id, &options).await.unwrap();
 
         let expected_storage_entity = StorageRecord {
             type_: None,
@@ -1853,15 +1998,15 @@ mod tests {
         assert_eq!(&expected_free_record_call, debug.get(1).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_get_storage_metadata_works() {
+    #[async_std::test]
+    async fn plugged_storage_get_storage_metadata_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
-        let metadata = storage.get_storage_metadata().unwrap();
+        let metadata = storage.get_storage_metadata().await.unwrap();
 
         assert_eq!(RETURN_METADATA.read().unwrap().1, metadata);
 
@@ -1881,17 +2026,17 @@ mod tests {
         assert_eq!(&expected_free_call, debug.get(1).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_set_storage_metadata_works() {
+    #[async_std::test]
+    async fn plugged_storage_set_storage_metadata_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
         let metadata = _random_vector(512);
 
-        storage.set_storage_metadata(&metadata).unwrap();
+        storage.set_storage_metadata(&metadata).await.unwrap();
 
         let expected_call = Call::SetStorageMetadataHandler(
             RETURN_STORAGE_HANDLE,
@@ -1904,11 +2049,11 @@ mod tests {
         assert_eq!(&expected_call, debug.get(0).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_search_with_total_count_works() {
+    #[async_std::test]
+    async fn plugged_storage_search_with_total_count_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -1924,7 +2069,7 @@ mod tests {
         let options = _search_options(true, true, true, true, false);
 
         {
-            let mut storage_iterator = storage.search(&type_, &query, Some(&options)).unwrap();
+            let mut storage_iterator = storage.search(&type_, &query, Some(&options)).await.unwrap();
 
             // TODO: solve how to get &PluggedStorage from Box<dyn WalletStorage>
 
@@ -1944,7 +2089,7 @@ mod tests {
 
             assert_eq!(total_count, Some(RETURN_SEARCH_TOTAL_COUNT));
 
-            let storage_entity = storage_iterator.next().unwrap();
+            let storage_entity = storage_iterator.next().await.unwrap();
 
             let expected_storage_entity = StorageRecord {
                 type_: None,
@@ -2004,11 +2149,11 @@ mod tests {
         assert_eq!(&expected_free_search_call, debug.get(7).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_search_without_total_count_works() {
+    #[async_std::test]
+    async fn plugged_storage_search_without_total_count_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
@@ -2024,7 +2169,7 @@ mod tests {
         let options = _search_options(true, false, true, true, false);
 
         {
-            let mut storage_iterator = storage.search(&type_, &query, Some(&options)).unwrap();
+            let mut storage_iterator = storage.search(&type_, &query, Some(&options)).await.unwrap();
 
             // TODO: solve how to get &PluggedStorage from Box<dyn WalletStorage>
 
@@ -2044,7 +2189,7 @@ mod tests {
 
             assert_eq!(total_count, None);
 
-            let storage_entity = storage_iterator.next().unwrap();
+            let storage_entity = storage_iterator.next().await.unwrap();
 
             let expected_storage_entity = StorageRecord {
                 type_: None,
@@ -2100,16 +2245,16 @@ mod tests {
         assert_eq!(&expected_free_search_call, debug.get(6).unwrap());
     }
 
-    #[test]
-    fn plugged_storage_get_all_works() {
+    #[async_std::test]
+    async fn plugged_storage_get_all_works() {
         DEBUG_VEC.write().unwrap().clear();
 
-        let storage = _open_storage();
+        let storage = _open_storage().await;
 
         DEBUG_VEC.write().unwrap().clear();
 
         {
-            let mut storage_iterator = storage.get_all().unwrap();
+            let mut storage_iterator = storage.get_all().await.unwrap();
 
             // TODO: solve how to get &PluggedStorage from Box<dyn WalletStorage>
 
@@ -2125,7 +2270,7 @@ mod tests {
 
             //        assert_eq!(*storage_iterator as PluggedStorageIterator, expected_storage_iterator);
 
-            let storage_entity = storage_iterator.next().unwrap();
+            let storage_entity = storage_iterator.next().await.unwrap();
 
             let expected_storage_entity = StorageRecord {
                 type_: Some(RETURN_TYPE.read().unwrap().1.clone()),
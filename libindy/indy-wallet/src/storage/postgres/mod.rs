@@ -0,0 +1,2030 @@
+use std::collections::{HashMap, VecDeque};
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use indy_api_types::errors::prelude::*;
+use log::LevelFilter;
+use query::{wql_to_sql, wql_to_sql_count, Argument};
+use serde::Deserialize;
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions},
+    ConnectOptions, Done, PgPool, Postgres, Transaction,
+};
+
+use crate::{
+    language,
+    storage::{
+        StorageIterator, StorageOperation, StorageRecord, Tag, TagName, WalletStorage,
+        WalletStorageType,
+    },
+    wallet::EncryptedValue,
+    RecordOptions, SearchOptions,
+};
+
+mod query;
+
+// Schema of `MultiWalletSingleTable` scheme of postgres storage plugin: all wallets are stored
+// in the same tables and are distinguished by `wallet_id` column.
+// Note: wallet id length is limited by 64 characters as in the plugin.
+const CREATE_SCHEMA: [&str; 11] = [
+    "CREATE TABLE IF NOT EXISTS metadata (
+        wallet_id VARCHAR(64) NOT NULL,
+        value BYTEA NOT NULL,
+        PRIMARY KEY(wallet_id)
+    )",
+    "CREATE UNIQUE INDEX IF NOT EXISTS ux_metadata_values ON metadata(wallet_id, value)",
+    "CREATE TABLE IF NOT EXISTS items(
+        wallet_id VARCHAR(64) NOT NULL,
+        id BIGSERIAL NOT NULL,
+        type BYTEA NOT NULL,
+        name BYTEA NOT NULL,
+        value BYTEA NOT NULL,
+        key BYTEA NOT NULL,
+        PRIMARY KEY(wallet_id, id)
+    )",
+    "CREATE UNIQUE INDEX IF NOT EXISTS ux_items_type_name ON items(wallet_id, type, name)",
+    "CREATE TABLE IF NOT EXISTS tags_encrypted(
+        wallet_id VARCHAR(64) NOT NULL,
+        name BYTEA NOT NULL,
+        value BYTEA NOT NULL,
+        item_id BIGINT NOT NULL,
+        PRIMARY KEY(wallet_id, name, item_id),
+        FOREIGN KEY(wallet_id, item_id)
+            REFERENCES items(wallet_id, id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+    )",
+    "CREATE INDEX IF NOT EXISTS ix_tags_encrypted_name ON tags_encrypted(wallet_id, name)",
+    "CREATE INDEX IF NOT EXISTS ix_tags_encrypted_wallet_id_item_id ON tags_encrypted(wallet_id, item_id)",
+    "CREATE TABLE IF NOT EXISTS tags_plaintext(
+        wallet_id VARCHAR(64) NOT NULL,
+        name BYTEA NOT NULL,
+        value TEXT NOT NULL,
+        item_id BIGINT NOT NULL,
+        PRIMARY KEY(wallet_id, name, item_id),
+        FOREIGN KEY(wallet_id, item_id)
+            REFERENCES items(wallet_id, id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+    )",
+    "CREATE INDEX IF NOT EXISTS ix_tags_plaintext_name ON tags_plaintext(wallet_id, name)",
+    "CREATE INDEX IF NOT EXISTS ix_tags_plaintext_value ON tags_plaintext(wallet_id, value)",
+    "CREATE INDEX IF NOT EXISTS ix_tags_plaintext_wallet_id_item_id ON tags_plaintext(wallet_id, item_id)",
+];
+
+const DELETE_WALLET: [&str; 3] = [
+    "DELETE FROM tags_plaintext WHERE wallet_id = $1",
+    "DELETE FROM tags_encrypted WHERE wallet_id = $1",
+    "DELETE FROM items WHERE wallet_id = $1",
+];
+
+const MAX_WALLET_ID_LEN: usize = 64;
+
+struct PostgresStorageIterator {
+    records: Option<VecDeque<StorageRecord>>,
+    total_count: Option<usize>,
+}
+
+impl PostgresStorageIterator {
+    fn new(
+        records: Option<VecDeque<StorageRecord>>,
+        total_count: Option<usize>,
+    ) -> PostgresStorageIterator {
+        PostgresStorageIterator {
+            records,
+            total_count,
+        }
+    }
+}
+
+#[async_trait]
+impl StorageIterator for PostgresStorageIterator {
+    async fn next(&mut self) -> IndyResult<Option<StorageRecord>> {
+        Ok(self
+            .records
+            .as_mut()
+            .and_then(|records| records.pop_front()))
+    }
+
+    fn get_total_count(&self) -> IndyResult<Option<usize>> {
+        Ok(self.total_count.to_owned())
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+enum WalletScheme {
+    MultiWalletSingleTable,
+}
+
+impl Default for WalletScheme {
+    fn default() -> Self {
+        WalletScheme::MultiWalletSingleTable
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Config {
+    pub read_host: String,
+    pub write_host: String,
+    pub port: u16,
+    pub db_name: String,
+    #[serde(default = "default_connection_limit")]
+    pub connection_limit: u32,
+    #[serde(default)]
+    #[allow(dead_code)]
+    pub wallet_scheme: WalletScheme,
+}
+
+fn default_connection_limit() -> u32 {
+    100
+}
+
+#[derive(Deserialize)]
+pub struct Credentials {
+    pub user: String,
+    pub pass: String,
+}
+
+#[derive(Debug)]
+struct PostgresStorage {
+    wallet_id: String,
+    read_pool: PgPool,
+    write_pool: PgPool,
+}
+
+pub struct PostgresStorageType {
+    connections: Mutex<HashMap<String, PgPool>>,
+}
+
+impl PostgresStorageType {
+    pub fn new() -> PostgresStorageType {
+        PostgresStorageType {
+            connections: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn _connect(
+        &self,
+        read_only: bool,
+        config: Option<&str>,
+        credentials: Option<&str>,
+    ) -> IndyResult<PgPool> {
+        let config = config
+            .map(serde_json::from_str::<Config>)
+            .transpose()
+            .to_indy(IndyErrorKind::InvalidStructure, "Malformed config json")?
+            .ok_or(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Absent config json",
+            ))?;
+
+        let credentials = credentials
+            .map(serde_json::from_str::<Credentials>)
+            .transpose()
+            .to_indy(
+                IndyErrorKind::InvalidStructure,
+                "Malformed credentials json",
+            )?
+            .ok_or(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Absent credentials json",
+            ))?;
+
+        let host_addr = if read_only {
+            &config.read_host
+        } else {
+            &config.write_host
+        };
+
+        let connection_string = format!(
+            "{}:{}@{}:{}/{}",
+            credentials.user, credentials.pass, host_addr, config.port, config.db_name
+        );
+
+        let mut connref = self.connections.lock().await;
+
+        if let Some(connection) = connref.get(&connection_string) {
+            return Ok(connection.clone());
+        }
+
+        let mut pg_connect_options = PgConnectOptions::new()
+            .host(host_addr)
+            .port(config.port)
+            .database(&config.db_name)
+            .username(&credentials.user)
+            .password(&credentials.pass);
+        pg_connect_options.log_statements(LevelFilter::Debug);
+
+        let connection = PgPoolOptions::default()
+            .max_connections(config.connection_limit)
+            .test_before_acquire(false)
+            .connect_with(pg_connect_options)
+            .await?;
+
+        // Tables are shared by all wallets, so they are created once per database
+        // on the first write connection.
+        if !read_only {
+            for sql in CREATE_SCHEMA.iter() {
+                sqlx::query(sql).execute(&connection).await?;
+            }
+        }
+
+        connref.insert(connection_string, connection.clone());
+        Ok(connection)
+    }
+}
+
+impl PostgresStorage {
+    async fn _add(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+        tags: &[Tag],
+    ) -> IndyResult<()> {
+        let (item_id,) = sqlx::query_as::<_, (i64,)>(
+            r#"
+            INSERT INTO items (wallet_id, type, name, value, key)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+        )
+        .bind(&self.wallet_id)
+        .bind(type_)
+        .bind(id)
+        .bind(&value.data)
+        .bind(&value.key)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        _insert_tags(tx, &self.wallet_id, item_id, tags).await?;
+        Ok(())
+    }
+
+    async fn _update(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+    ) -> IndyResult<()> {
+        let row_updated = sqlx::query(
+            r#"
+            UPDATE items
+            SET value = $1, key = $2
+            WHERE wallet_id = $3
+                AND type = $4
+                AND name = $5
+            "#,
+        )
+        .bind(&value.data)
+        .bind(&value.key)
+        .bind(&self.wallet_id)
+        .bind(type_)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        match row_updated {
+            1 => Ok(()),
+            0 => Err(err_msg(
+                IndyErrorKind::WalletItemNotFound,
+                "Item to update not found",
+            )),
+            _ => Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "More than one row update. Seems wallet structure is inconsistent",
+            )),
+        }
+    }
+
+    async fn _add_tags(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
+        let item_id = _get_item_id(tx, &self.wallet_id, type_, id).await?;
+        _insert_tags(tx, &self.wallet_id, item_id, tags).await?;
+        Ok(())
+    }
+
+    async fn _update_tags(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        type_: &[u8],
+        id: &[u8],
+        tags: &[Tag],
+    ) -> IndyResult<()> {
+        let item_id = _get_item_id(tx, &self.wallet_id, type_, id).await?;
+
+        for table in ["tags_encrypted", "tags_plaintext"].iter() {
+            sqlx::query(&format!(
+                r#"
+                DELETE FROM {}
+                WHERE wallet_id = $1
+                    AND item_id = $2
+                "#,
+                table
+            ))
+            .bind(&self.wallet_id)
+            .bind(item_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        _insert_tags(tx, &self.wallet_id, item_id, tags).await?;
+        Ok(())
+    }
+
+    async fn _delete_tags(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        type_: &[u8],
+        id: &[u8],
+        tag_names: &[TagName],
+    ) -> IndyResult<()> {
+        let item_id = _get_item_id(tx, &self.wallet_id, type_, id).await?;
+
+        for tag_name in tag_names {
+            let (table, name) = match *tag_name {
+                TagName::OfEncrypted(ref name) => ("tags_encrypted", name),
+                TagName::OfPlain(ref name) => ("tags_plaintext", name),
+            };
+
+            sqlx::query(&format!(
+                r#"
+                DELETE FROM {}
+                WHERE wallet_id = $1
+                    AND item_id = $2
+                    AND name = $3
+                "#,
+                table
+            ))
+            .bind(&self.wallet_id)
+            .bind(item_id)
+            .bind(name)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn _delete(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        type_: &[u8],
+        id: &[u8],
+    ) -> IndyResult<()> {
+        // Tags are removed by cascade
+        let rows_affected = sqlx::query(
+            r#"
+            DELETE FROM items
+            WHERE wallet_id = $1
+                AND type = $2
+                AND name = $3
+            "#,
+        )
+        .bind(&self.wallet_id)
+        .bind(type_)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        match rows_affected {
+            1 => Ok(()),
+            0 => Err(err_msg(
+                IndyErrorKind::WalletItemNotFound,
+                "Item to delete not found",
+            )),
+            _ => Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "More than one row deleted. Seems wallet structure is inconsistent",
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl WalletStorage for PostgresStorage {
+    ///
+    /// Tries to fetch values and/or tags from the storage.
+    /// Returns Result with StorageEntity object which holds requested data in case of success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type_ of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `options` - JSon containing what needs to be fetched.
+    ///  Example: {"retrieveValue": true, "retrieveTags": true}
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `StorageEntity` - Contains name, optional value and optional tags
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemNotFound` - Item is not found in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn get(&self, type_: &[u8], id: &[u8], options: &str) -> IndyResult<StorageRecord> {
+        let options: RecordOptions = serde_json::from_str(options).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "RecordOptions is malformed json",
+        )?;
+
+        let (item_id, value, key): (i64, Option<Vec<u8>>, Option<Vec<u8>>) =
+            sqlx::query_as(&format!(
+                r#"
+                SELECT id, {}
+                FROM items
+                WHERE
+                    wallet_id = $1
+                        AND type = $2
+                        AND name = $3
+                "#,
+                if options.retrieve_value {
+                    "value, key"
+                } else {
+                    "NULL::BYTEA, NULL::BYTEA"
+                },
+            ))
+            .bind(&self.wallet_id)
+            .bind(type_)
+            .bind(id)
+            .fetch_one(&self.read_pool)
+            .await?;
+
+        let value = _value_from_parts(value, key);
+
+        let type_ = if options.retrieve_type {
+            Some(type_.to_vec())
+        } else {
+            None
+        };
+
+        let tags = if options.retrieve_tags {
+            let mut tags = _get_tags(&self.read_pool, &self.wallet_id, &[item_id]).await?;
+            Some(tags.remove(&item_id).unwrap_or_default())
+        } else {
+            None
+        };
+
+        Ok(StorageRecord::new(id.to_vec(), value, type_, tags))
+    }
+
+    ///
+    /// inserts value and tags into storage.
+    /// Returns Result with () on success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `value` - value of the item in storage
+    ///  * `value_key` - key used to encrypt the value
+    ///  * `tags` - tags assigned to the value
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` class of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemAlreadyExists` - Item is already present in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn add(
+        &self,
+        type_: &[u8],
+        id: &[u8],
+        value: &EncryptedValue,
+        tags: &[Tag],
+    ) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._add(&mut tx, type_, id, value, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update(&self, type_: &[u8], id: &[u8], value: &EncryptedValue) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._update(&mut tx, type_, id, value).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn add_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._add_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._update_tags(&mut tx, type_, id, tags).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._delete_tags(&mut tx, type_, id, tag_names).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    ///
+    /// deletes value and tags into storage.
+    /// Returns Result with () on success or
+    /// Result with IndyError in case of failure.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type of the item in storage
+    ///  * `id` - id of the item in storage
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemNotFound` - Item is not found in database
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn delete(&self, type_: &[u8], id: &[u8]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+        self._delete(&mut tx, type_, id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn batch(&self, operations: &[StorageOperation]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+
+        for operation in operations {
+            match *operation {
+                StorageOperation::Add {
+                    ref type_,
+                    ref id,
+                    ref value,
+                    ref tags,
+                } => self._add(&mut tx, type_, id, value, tags).await?,
+                StorageOperation::Update {
+                    ref type_,
+                    ref id,
+                    ref value,
+                } => self._update(&mut tx, type_, id, value).await?,
+                StorageOperation::AddTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => self._add_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::UpdateTags {
+                    ref type_,
+                    ref id,
+                    ref tags,
+                } => self._update_tags(&mut tx, type_, id, tags).await?,
+                StorageOperation::DeleteTags {
+                    ref type_,
+                    ref id,
+                    ref tag_names,
+                } => self._delete_tags(&mut tx, type_, id, tag_names).await?,
+                StorageOperation::Delete { ref type_, ref id } => {
+                    self._delete(&mut tx, type_, id).await?
+                }
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
+        let (metadata,) = sqlx::query_as::<_, (Vec<u8>,)>(
+            r#"
+            SELECT value
+            FROM metadata
+            WHERE wallet_id = $1
+            "#,
+        )
+        .bind(&self.wallet_id)
+        .fetch_one(&self.read_pool)
+        .await?;
+
+        Ok(metadata)
+    }
+
+    async fn set_storage_metadata(&self, metadata: &[u8]) -> IndyResult<()> {
+        let mut tx = self.write_pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE metadata
+            SET value = $1
+            WHERE wallet_id = $2
+            "#,
+        )
+        .bind(metadata)
+        .bind(&self.wallet_id)
+        .execute(&mut tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_all(&self) -> IndyResult<Box<dyn StorageIterator>> {
+        let items = sqlx::query_as::<_, (i64, Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>)>(
+            r#"
+            SELECT id, type, name, value, key
+            FROM items
+            WHERE wallet_id = $1
+            ORDER BY id
+            "#,
+        )
+        .bind(&self.wallet_id)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        let item_ids: Vec<i64> = items.iter().map(|item| item.0).collect();
+        let mut tags = _get_tags(&self.read_pool, &self.wallet_id, &item_ids).await?;
+
+        let records: VecDeque<_> = items
+            .into_iter()
+            .map(|(item_id, type_, id, value, key)| {
+                StorageRecord::new(
+                    id,
+                    Some(EncryptedValue::new(value, key)),
+                    Some(type_),
+                    Some(tags.remove(&item_id).unwrap_or_default()),
+                )
+            })
+            .collect();
+
+        let total_len = records.len();
+
+        Ok(Box::new(PostgresStorageIterator::new(
+            Some(records),
+            Some(total_len),
+        )))
+    }
+
+    async fn count_all(&self) -> IndyResult<usize> {
+        let (count,) = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM items WHERE wallet_id = $1")
+            .bind(&self.wallet_id)
+            .fetch_one(&self.read_pool)
+            .await?;
+
+        Ok(count as usize)
+    }
+
+    async fn get_all_page(
+        &self,
+        after: Option<i64>,
+        limit: usize,
+    ) -> IndyResult<Vec<(i64, StorageRecord)>> {
+        let items = sqlx::query_as::<_, (i64, Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>)>(
+            r#"
+            SELECT id, type, name, value, key
+            FROM items
+            WHERE wallet_id = $1 AND id > $2
+            ORDER BY id
+            LIMIT $3
+            "#,
+        )
+        .bind(&self.wallet_id)
+        .bind(after.unwrap_or(i64::MIN))
+        .bind(limit as i64)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        let item_ids: Vec<i64> = items.iter().map(|item| item.0).collect();
+        let mut tags = _get_tags(&self.read_pool, &self.wallet_id, &item_ids).await?;
+
+        let records = items
+            .into_iter()
+            .map(|(item_id, type_, id, value, key)| {
+                (
+                    item_id,
+                    StorageRecord::new(
+                        id,
+                        Some(EncryptedValue::new(value, key)),
+                        Some(type_),
+                        Some(tags.remove(&item_id).unwrap_or_default()),
+                    ),
+                )
+            })
+            .collect();
+
+        Ok(records)
+    }
+
+    async fn search(
+        &self,
+        type_: &[u8],
+        query: &language::Operator,
+        options: Option<&str>,
+    ) -> IndyResult<Box<dyn StorageIterator>> {
+        let options = if let Some(options) = options {
+            serde_json::from_str(options).to_indy(
+                IndyErrorKind::InvalidStructure,
+                "Search options is malformed json",
+            )?
+        } else {
+            SearchOptions::default()
+        };
+
+        let total_count = if options.retrieve_total_count {
+            let (query, args) = wql_to_sql_count(&self.wallet_id, type_, query)?;
+            let mut query = sqlx::query_as::<sqlx::Postgres, (i64,)>(&query);
+
+            for arg in args.iter() {
+                query = match arg {
+                    Argument::Text(value) => query.bind(value.as_str()),
+                    Argument::Bytes(value) => query.bind(value.as_slice()),
+                }
+            }
+
+            let (total_count,) = query.fetch_one(&self.read_pool).await?;
+            Some(total_count as usize)
+        } else {
+            None
+        };
+
+        let records = if options.retrieve_records {
+            let (query, args) = wql_to_sql(&self.wallet_id, type_, query, &options)?;
+
+            let mut query = sqlx::query_as::<
+                sqlx::Postgres,
+                (i64, Vec<u8>, Option<Vec<u8>>, Option<Vec<u8>>),
+            >(&query);
+
+            for arg in args.iter() {
+                query = match arg {
+                    Argument::Text(value) => query.bind(value.as_str()),
+                    Argument::Bytes(value) => query.bind(value.as_slice()),
+                }
+            }
+
+            let items = query.fetch_all(&self.read_pool).await?;
+
+            let mut tags = if options.retrieve_tags {
+                let item_ids: Vec<i64> = items.iter().map(|item| item.0).collect();
+                Some(_get_tags(&self.read_pool, &self.wallet_id, &item_ids).await?)
+            } else {
+                None
+            };
+
+            let records: VecDeque<_> = items
+                .into_iter()
+                .map(|(item_id, id, value, key)| {
+                    StorageRecord::new(
+                        id,
+                        _value_from_parts(value, key),
+                        if options.retrieve_type {
+                            Some(type_.to_vec())
+                        } else {
+                            None
+                        },
+                        tags.as_mut()
+                            .map(|tags| tags.remove(&item_id).unwrap_or_default()),
+                    )
+                })
+                .collect();
+
+            Some(records)
+        } else {
+            None
+        };
+
+        Ok(Box::new(PostgresStorageIterator::new(records, total_count)))
+    }
+
+    fn close(&mut self) -> IndyResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WalletStorageType for PostgresStorageType {
+    ///
+    /// Deletes the wallet with the provided id from the shared tables of the Postgres database
+    /// specified in the config.
+    ///
+    /// # Arguments
+    ///
+    ///  * `id` - id of the wallet
+    ///  * `storage_config` - config containing the location of Postgres DB
+    ///  * `storage_credentials` - DB credentials
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `IndyError::NotFound` - Wallet with the provided id not found
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn delete_storage(
+        &self,
+        id: &str,
+        config: Option<&str>,
+        credentials: Option<&str>,
+    ) -> IndyResult<()> {
+        let mut tx = self
+            ._connect(false, config, credentials)
+            .await?
+            .begin()
+            .await?;
+
+        for sql in DELETE_WALLET.iter() {
+            sqlx::query(sql).bind(id).execute(&mut tx).await?;
+        }
+
+        let rows_affected = sqlx::query(
+            r#"
+            DELETE FROM metadata
+            WHERE wallet_id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&mut tx)
+        .await?
+        .rows_affected();
+
+        match rows_affected {
+            1 => {
+                tx.commit().await?;
+                Ok(())
+            }
+            0 => Err(err_msg(
+                IndyErrorKind::WalletNotFound,
+                "Item to delete not found",
+            )),
+            _ => Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "More than one row deleted. Seems wallet structure is inconsistent",
+            )),
+        }
+    }
+
+    ///
+    /// Registers the wallet with the provided id in the shared tables of the Postgres database
+    /// specified in the config and stores the encryption keys needed for encryption and
+    /// decryption of data. Tables are created on the first connection if they don't exist.
+    ///
+    /// # Arguments
+    ///
+    ///  * `id` - id of the wallet
+    ///  * `config` - config containing the location of Postgres DB
+    ///  * `credentials` - DB credentials
+    ///  * `metadata` - encryption keys that need to be stored in the newly created wallet
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `AlreadyExists` - Wallet with a given id already exists in the database
+    ///  * `IOError("IO error during storage operation:...")` - Connection to the DB failed
+    ///
+    async fn create_storage(
+        &self,
+        id: &str,
+        config: Option<&str>,
+        credentials: Option<&str>,
+        metadata: &[u8],
+    ) -> IndyResult<()> {
+        if id.len() > MAX_WALLET_ID_LEN {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!(
+                    "Wallet id is too long for postgres storage. Max length: {}",
+                    MAX_WALLET_ID_LEN
+                ),
+            ));
+        }
+
+        let mut tx = self
+            ._connect(false, config, credentials)
+            .await?
+            .begin()
+            .await?;
+
+        let res = sqlx::query(
+            r#"
+            INSERT INTO metadata (wallet_id, value)
+            VALUES ($1, $2)
+            "#,
+        )
+        .bind(id)
+        .bind(metadata)
+        .execute(&mut tx)
+        .await;
+
+        match res {
+            // Unique violation (23505)
+            Err(sqlx::Error::Database(e)) if e.code().is_some() && e.code().unwrap() == "23505" => {
+                return Err(err_msg(
+                    IndyErrorKind::WalletAlreadyExists,
+                    "Wallet already exists",
+                ))
+            }
+            e => e?,
+        };
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    ///
+    /// Establishes connections to the Postgres DB specified in the config and checks
+    /// that the wallet with the provided id exists. In case of success returns a Storage object
+    /// embedding separate read and write connection pools.
+    ///
+    ///
+    /// # Arguments
+    ///
+    ///  * `id` - id of the wallet
+    ///  * `config` - config containing the location of Postgres DB
+    ///  * `credentials` - DB credentials
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `Box<Storage>` - `PostgresStorage` bound to the wallet
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` type_ of errors can be throw by this method:
+    ///
+    ///  * `IndyError::NotFound` - Wallet with the provided id not found
+    ///  * `IOError("IO error during storage operation:...")` - Failed connection or SQL query
+    ///
+    async fn open_storage(
+        &self,
+        id: &str,
+        config: Option<&str>,
+        credentials: Option<&str>,
+    ) -> IndyResult<Box<dyn WalletStorage>> {
+        let read_pool = self._connect(true, config, credentials).await?;
+        let write_pool = self._connect(false, config, credentials).await?;
+
+        let res = sqlx::query_as::<_, (String,)>(
+            r#"
+            SELECT wallet_id FROM metadata
+            WHERE wallet_id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_one(&read_pool)
+        .await;
+
+        let (wallet_id,) = match res {
+            Err(sqlx::Error::RowNotFound) => {
+                return Err(err_msg(IndyErrorKind::WalletNotFound, "Wallet not found"));
+            }
+            e => e?,
+        };
+
+        Ok(Box::new(PostgresStorage {
+            wallet_id,
+            read_pool,
+            write_pool,
+        }))
+    }
+}
+
+async fn _get_item_id(
+    tx: &mut Transaction<'_, Postgres>,
+    wallet_id: &str,
+    type_: &[u8],
+    id: &[u8],
+) -> IndyResult<i64> {
+    let (item_id,) = sqlx::query_as::<_, (i64,)>(
+        r#"
+        SELECT id
+        FROM items
+        WHERE wallet_id = $1
+            AND type = $2
+            AND name = $3
+        FOR UPDATE
+        "#,
+    )
+    .bind(wallet_id)
+    .bind(type_)
+    .bind(id)
+    .fetch_one(tx)
+    .await?;
+
+    Ok(item_id)
+}
+
+// Adds tags to the item. Values of already existing tags are replaced.
+async fn _insert_tags(
+    tx: &mut Transaction<'_, Postgres>,
+    wallet_id: &str,
+    item_id: i64,
+    tags: &[Tag],
+) -> IndyResult<()> {
+    for tag in tags {
+        match *tag {
+            Tag::Encrypted(ref name, ref value) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO tags_encrypted (wallet_id, item_id, name, value)
+                    VALUES ($1, $2, $3, $4)
+                    ON CONFLICT (wallet_id, name, item_id) DO UPDATE SET value = excluded.value
+                    "#,
+                )
+                .bind(wallet_id)
+                .bind(item_id)
+                .bind(name)
+                .bind(value)
+                .execute(&mut *tx)
+                .await?;
+            }
+            Tag::PlainText(ref name, ref value) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO tags_plaintext (wallet_id, item_id, name, value)
+                    VALUES ($1, $2, $3, $4)
+                    ON CONFLICT (wallet_id, name, item_id) DO UPDATE SET value = excluded.value
+                    "#,
+                )
+                .bind(wallet_id)
+                .bind(item_id)
+                .bind(name)
+                .bind(value)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn _get_tags(
+    pool: &PgPool,
+    wallet_id: &str,
+    item_ids: &[i64],
+) -> IndyResult<HashMap<i64, Vec<Tag>>> {
+    let mut tags: HashMap<i64, Vec<Tag>> = HashMap::new();
+
+    if item_ids.is_empty() {
+        return Ok(tags);
+    }
+
+    let encrypted_tags = sqlx::query_as::<_, (i64, Vec<u8>, Vec<u8>)>(
+        r#"
+        SELECT item_id, name, value
+        FROM tags_encrypted
+        WHERE wallet_id = $1
+            AND item_id = ANY($2)
+        "#,
+    )
+    .bind(wallet_id)
+    .bind(item_ids.to_vec())
+    .fetch_all(pool)
+    .await?;
+
+    for (item_id, name, value) in encrypted_tags {
+        tags.entry(item_id)
+            .or_insert_with(Vec::new)
+            .push(Tag::Encrypted(name, value));
+    }
+
+    let plain_tags = sqlx::query_as::<_, (i64, Vec<u8>, String)>(
+        r#"
+        SELECT item_id, name, value
+        FROM tags_plaintext
+        WHERE wallet_id = $1
+            AND item_id = ANY($2)
+        "#,
+    )
+    .bind(wallet_id)
+    .bind(item_ids.to_vec())
+    .fetch_all(pool)
+    .await?;
+
+    for (item_id, name, value) in plain_tags {
+        tags.entry(item_id)
+            .or_insert_with(Vec::new)
+            .push(Tag::PlainText(name, value));
+    }
+
+    Ok(tags)
+}
+
+fn _value_from_parts(value: Option<Vec<u8>>, key: Option<Vec<u8>>) -> Option<EncryptedValue> {
+    match (value, key) {
+        (Some(value), Some(key)) => Some(EncryptedValue::new(value, key)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indy_utils::assert_kind;
+
+    use super::*;
+    use super::super::Tag;
+
+    // docker run --name indy-postgres -e POSTGRES_PASSWORD=pass@word1 -e POSTGRES_DB=indy -p 5432:5432 -d postgres:latest
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_type_create_works() {
+        _cleanup("postgres_storage_type_create_works").await;
+
+        let storage_type = PostgresStorageType::new();
+
+        storage_type
+            .create_storage(
+                "postgres_storage_type_create_works",
+                _config(),
+                _credentials(),
+                &_metadata(),
+            )
+            .await
+            .unwrap();
+
+        _cleanup("postgres_storage_type_create_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_type_create_works_for_twice() {
+        _cleanup("postgres_storage_type_create_works_for_twice").await;
+
+        let storage_type = PostgresStorageType::new();
+        storage_type
+            .create_storage(
+                "postgres_storage_type_create_works_for_twice",
+                _config(),
+                _credentials(),
+                &_metadata(),
+            )
+            .await
+            .unwrap();
+
+        let res = storage_type
+            .create_storage(
+                "postgres_storage_type_create_works_for_twice",
+                _config(),
+                _credentials(),
+                &_metadata(),
+            )
+            .await;
+
+        assert_kind!(IndyErrorKind::WalletAlreadyExists, res);
+
+        storage_type
+            .delete_storage(
+                "postgres_storage_type_create_works_for_twice",
+                _config(),
+                _credentials(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[async_std::test]
+    async fn postgres_storage_type_create_works_for_too_long_id() {
+        let storage_type = PostgresStorageType::new();
+
+        let res = storage_type
+            .create_storage(
+                &"a".repeat(MAX_WALLET_ID_LEN + 1),
+                _config(),
+                _credentials(),
+                &_metadata(),
+            )
+            .await;
+
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[async_std::test]
+    async fn postgres_storage_type_create_works_for_unknown_wallet_scheme() {
+        let storage_type = PostgresStorageType::new();
+
+        let config = r#"
+            {
+                "read_host": "127.0.0.1",
+                "write_host": "127.0.0.1",
+                "port": 5432,
+                "db_name": "indy",
+                "wallet_scheme": "DatabasePerWallet"
+            }
+            "#;
+
+        let res = storage_type
+            .create_storage(
+                "postgres_storage_type_create_works_for_unknown_wallet_scheme",
+                Some(config),
+                _credentials(),
+                &_metadata(),
+            )
+            .await;
+
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_get_storage_metadata_works() {
+        _cleanup("postgres_storage_get_storage_metadata_works").await;
+
+        {
+            let storage = _storage("postgres_storage_get_storage_metadata_works").await;
+            let metadata = storage.get_storage_metadata().await.unwrap();
+
+            assert_eq!(metadata, _metadata());
+        }
+
+        _cleanup("postgres_storage_get_storage_metadata_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_set_storage_metadata_works() {
+        _cleanup("postgres_storage_set_storage_metadata_works").await;
+
+        {
+            let storage = _storage("postgres_storage_set_storage_metadata_works").await;
+            let metadata = vec![1, 2, 3];
+
+            storage.set_storage_metadata(&metadata).await.unwrap();
+
+            assert_eq!(storage.get_storage_metadata().await.unwrap(), metadata);
+        }
+
+        _cleanup("postgres_storage_set_storage_metadata_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_type_delete_works() {
+        _cleanup("postgres_storage_type_delete_works").await;
+
+        let storage_type = PostgresStorageType::new();
+        storage_type
+            .create_storage(
+                "postgres_storage_type_delete_works",
+                _config(),
+                _credentials(),
+                &_metadata(),
+            )
+            .await
+            .unwrap();
+
+        storage_type
+            .delete_storage(
+                "postgres_storage_type_delete_works",
+                _config(),
+                _credentials(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_type_delete_works_for_non_existing() {
+        _cleanup("postgres_storage_type_delete_works_for_non_existing").await;
+
+        let storage_type = PostgresStorageType::new();
+
+        let res = storage_type
+            .delete_storage("unknown", _config(), _credentials())
+            .await;
+
+        assert_kind!(IndyErrorKind::WalletNotFound, res);
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_type_open_works() {
+        _cleanup("postgres_storage_type_open_works").await;
+        _storage("postgres_storage_type_open_works").await;
+        _cleanup("postgres_storage_type_open_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_type_open_works_for_not_created() {
+        let storage_type = PostgresStorageType::new();
+
+        let res = storage_type
+            .open_storage("unknown", _config(), _credentials())
+            .await;
+
+        assert_kind!(IndyErrorKind::WalletNotFound, res);
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_set_get_works() {
+        _cleanup("postgres_storage_set_get_works").await;
+
+        {
+            let storage = _storage("postgres_storage_set_get_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+        }
+
+        _cleanup("postgres_storage_set_get_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_set_get_works_for_twice() {
+        _cleanup("postgres_storage_set_get_works_for_twice").await;
+
+        {
+            let storage = _storage("postgres_storage_set_get_works_for_twice").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let res = storage.add(&_type1(), &_id1(), &_value2(), &_tags()).await;
+            assert_kind!(IndyErrorKind::WalletItemAlreadyExists, res);
+        }
+
+        _cleanup("postgres_storage_set_get_works_for_twice").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_get_works_for_wrong_key() {
+        _cleanup("postgres_storage_get_works_for_wrong_key").await;
+
+        {
+            let storage = _storage("postgres_storage_get_works_for_wrong_key").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let res = storage
+                .get(
+                    &_type1(),
+                    &_id2(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        _cleanup("postgres_storage_get_works_for_wrong_key").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_delete_works() {
+        _cleanup("postgres_storage_delete_works").await;
+
+        {
+            let storage = _storage("postgres_storage_delete_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage.delete(&_type1(), &_id1()).await.unwrap();
+
+            let res = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await;
+
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        _cleanup("postgres_storage_delete_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_delete_works_for_non_existing() {
+        _cleanup("postgres_storage_delete_works_for_non_existing").await;
+
+        {
+            let storage = _storage("postgres_storage_delete_works_for_non_existing").await;
+
+            let res = storage.delete(&_type1(), &_id1()).await;
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        _cleanup("postgres_storage_delete_works_for_non_existing").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_get_all_works() {
+        _cleanup("postgres_storage_get_all_works").await;
+
+        {
+            let storage = _storage("postgres_storage_get_all_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .add(&_type2(), &_id2(), &_value2(), &_tags())
+                .await
+                .unwrap();
+
+            let mut storage_iterator = storage.get_all().await.unwrap();
+
+            let record = storage_iterator.next().await.unwrap().unwrap();
+            assert_eq!(record.type_.unwrap(), _type1());
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+
+            let record = storage_iterator.next().await.unwrap().unwrap();
+            assert_eq!(record.type_.unwrap(), _type2());
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+
+            let record = storage_iterator.next().await.unwrap();
+            assert!(record.is_none());
+        }
+
+        _cleanup("postgres_storage_get_all_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_get_all_works_for_empty() {
+        _cleanup("postgres_storage_get_all_works_for_empty").await;
+
+        {
+            let storage = _storage("postgres_storage_get_all_works_for_empty").await;
+            let mut storage_iterator = storage.get_all().await.unwrap();
+
+            let record = storage_iterator.next().await.unwrap();
+            assert!(record.is_none());
+        }
+
+        _cleanup("postgres_storage_get_all_works_for_empty").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_update_works() {
+        _cleanup("postgres_storage_update_works").await;
+
+        {
+            let storage = _storage("postgres_storage_update_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .update(&_type1(), &_id1(), &_value2())
+                .await
+                .unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value2());
+        }
+
+        _cleanup("postgres_storage_update_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_update_works_for_non_existing_id() {
+        _cleanup("postgres_storage_update_works_for_non_existing_id").await;
+
+        {
+            let storage = _storage("postgres_storage_update_works_for_non_existing_id").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let res = storage.update(&_type1(), &_id2(), &_value2()).await;
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        _cleanup("postgres_storage_update_works_for_non_existing_id").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_add_tags_works() {
+        _cleanup("postgres_storage_add_tags_works").await;
+
+        {
+            let storage = _storage("postgres_storage_add_tags_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .add_tags(&_type1(), &_id1(), &_new_tags())
+                .await
+                .unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            let expected_tags = {
+                let mut tags = _tags();
+                tags.extend(_new_tags());
+                _sort(tags)
+            };
+
+            assert_eq!(_sort(record.tags.unwrap()), expected_tags);
+        }
+
+        _cleanup("postgres_storage_add_tags_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_add_tags_works_for_non_existing_id() {
+        _cleanup("postgres_storage_add_tags_works_for_non_existing_id").await;
+
+        {
+            let storage = _storage("postgres_storage_add_tags_works_for_non_existing_id").await;
+
+            let res = storage.add_tags(&_type1(), &_id1(), &_new_tags()).await;
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+
+        _cleanup("postgres_storage_add_tags_works_for_non_existing_id").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_add_tags_works_for_already_existing() {
+        _cleanup("postgres_storage_add_tags_works_for_already_existing").await;
+
+        {
+            let storage = _storage("postgres_storage_add_tags_works_for_already_existing").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let updated_tags = vec![
+                Tag::Encrypted(vec![1, 5, 8], vec![7, 7, 7]),
+                Tag::PlainText(vec![1, 5, 8, 1], "Updated value".to_string()),
+            ];
+
+            storage
+                .add_tags(&_type1(), &_id1(), &updated_tags)
+                .await
+                .unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(_sort(record.tags.unwrap()), _sort(updated_tags));
+        }
+
+        _cleanup("postgres_storage_add_tags_works_for_already_existing").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_update_tags_works() {
+        _cleanup("postgres_storage_update_tags_works").await;
+
+        {
+            let storage = _storage("postgres_storage_update_tags_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .update_tags(&_type1(), &_id1(), &_new_tags())
+                .await
+                .unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+        }
+
+        _cleanup("postgres_storage_update_tags_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_delete_tags_works() {
+        _cleanup("postgres_storage_delete_tags_works").await;
+
+        {
+            let storage = _storage("postgres_storage_delete_tags_works").await;
+
+            let tag_name1 = vec![0, 0, 0];
+            let tag_name2 = vec![1, 1, 1];
+            let tag_name3 = vec![2, 2, 2];
+            let tag1 = Tag::Encrypted(tag_name1.clone(), vec![0, 0, 0]);
+            let tag2 = Tag::PlainText(tag_name2.clone(), "tag_value_2".to_string());
+            let tag3 = Tag::Encrypted(tag_name3.clone(), vec![2, 2, 2]);
+            let tags = vec![tag1.clone(), tag2.clone(), tag3.clone()];
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &tags)
+                .await
+                .unwrap();
+
+            let tag_names = vec![
+                TagName::OfEncrypted(tag_name1.clone()),
+                TagName::OfPlain(tag_name2.clone()),
+            ];
+
+            storage
+                .delete_tags(&_type1(), &_id1(), &tag_names)
+                .await
+                .unwrap();
+
+            let record = storage
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.tags.unwrap(), vec![tag3]);
+        }
+
+        _cleanup("postgres_storage_delete_tags_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_search_works() {
+        _cleanup("postgres_storage_search_works").await;
+
+        {
+            let storage = _storage("postgres_storage_search_works").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .add(&_type1(), &_id2(), &_value2(), &_new_tags())
+                .await
+                .unwrap();
+
+            let query = language::Operator::Eq(
+                language::TagName::PlainTagName(vec![1, 5, 8, 1]),
+                language::TargetValue::Unencrypted("Plain value".to_string()),
+            );
+
+            let mut storage_iterator = storage
+                .search(
+                    &_type1(),
+                    &query,
+                    Some(r##"{"retrieveTotalCount": true, "retrieveType": true, "retrieveTags": true}"##),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(storage_iterator.get_total_count().unwrap(), Some(1));
+
+            let record = storage_iterator.next().await.unwrap().unwrap();
+            assert_eq!(record.id, _id1());
+            assert_eq!(record.type_.unwrap(), _type1());
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+
+            assert!(storage_iterator.next().await.unwrap().is_none());
+        }
+
+        _cleanup("postgres_storage_search_works").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_search_works_for_empty_query() {
+        _cleanup("postgres_storage_search_works_for_empty_query").await;
+
+        {
+            let storage = _storage("postgres_storage_search_works_for_empty_query").await;
+
+            storage
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .add(&_type1(), &_id2(), &_value2(), &_tags())
+                .await
+                .unwrap();
+
+            storage
+                .add(&_type2(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            let mut storage_iterator = storage
+                .search(&_type1(), &language::Operator::And(vec![]), None)
+                .await
+                .unwrap();
+
+            assert_eq!(storage_iterator.get_total_count().unwrap(), None);
+
+            let record = storage_iterator.next().await.unwrap().unwrap();
+            assert_eq!(record.id, _id1());
+            assert!(record.type_.is_none());
+            assert!(record.tags.is_none());
+
+            let record = storage_iterator.next().await.unwrap().unwrap();
+            assert_eq!(record.id, _id2());
+
+            assert!(storage_iterator.next().await.unwrap().is_none());
+        }
+
+        _cleanup("postgres_storage_search_works_for_empty_query").await;
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "postgres")]
+    async fn postgres_storage_works_for_multiple_wallets() {
+        _cleanup("postgres_storage_works_for_multiple_wallets_1").await;
+        _cleanup("postgres_storage_works_for_multiple_wallets_2").await;
+
+        {
+            let storage_1 = _storage("postgres_storage_works_for_multiple_wallets_1").await;
+            let storage_2 = _storage("postgres_storage_works_for_multiple_wallets_2").await;
+
+            storage_1
+                .add(&_type1(), &_id1(), &_value1(), &_tags())
+                .await
+                .unwrap();
+
+            storage_2
+                .add(&_type1(), &_id1(), &_value2(), &_new_tags())
+                .await
+                .unwrap();
+
+            let record = storage_1
+                .get(
+                    &_type1(),
+                    &_id1(),
+                    r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+
+            storage_2.delete(&_type1(), &_id1()).await.unwrap();
+
+            let mut storage_iterator = storage_1.get_all().await.unwrap();
+            assert!(storage_iterator.next().await.unwrap().is_some());
+            assert!(storage_iterator.next().await.unwrap().is_none());
+
+            let mut storage_iterator = storage_2.get_all().await.unwrap();
+            assert!(storage_iterator.next().await.unwrap().is_none());
+        }
+
+        _cleanup("postgres_storage_works_for_multiple_wallets_1").await;
+        _cleanup("postgres_storage_works_for_multiple_wallets_2").await;
+    }
+
+    fn _config() -> Option<&'static str> {
+        Some(
+            r#"
+            {
+                "read_host": "127.0.0.1",
+                "write_host": "127.0.0.1",
+                "port": 5432,
+                "db_name": "indy",
+                "wallet_scheme": "MultiWalletSingleTable"
+            }
+            "#,
+        )
+    }
+
+    fn _credentials() -> Option<&'static str> {
+        Some(
+            r#"
+            {
+                "user": "postgres",
+                "pass": "pass@word1"
+            }
+            "#,
+        )
+    }
+
+    #[allow(dead_code)]
+    async fn _cleanup(name: &str) {
+        PostgresStorageType::new()
+            .delete_storage(name, _config(), _credentials())
+            .await
+            .ok();
+    }
+
+    #[allow(dead_code)]
+    async fn _storage(name: &str) -> Box<dyn WalletStorage> {
+        let storage_type = PostgresStorageType::new();
+
+        storage_type
+            .create_storage(name, _config(), _credentials(), &_metadata())
+            .await
+            .unwrap();
+
+        storage_type
+            .open_storage(name, _config(), _credentials())
+            .await
+            .unwrap()
+    }
+
+    fn _metadata() -> Vec<u8> {
+        return vec![
+            1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5,
+            6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2,
+            3, 4, 5, 6, 7, 8,
+        ];
+    }
+
+    #[allow(dead_code)]
+    fn _type(i: u8) -> Vec<u8> {
+        vec![i, 1 + i, 2 + i]
+    }
+
+    #[allow(dead_code)]
+    fn _type1() -> Vec<u8> {
+        _type(1)
+    }
+
+    #[allow(dead_code)]
+    fn _type2() -> Vec<u8> {
+        _type(2)
+    }
+
+    #[allow(dead_code)]
+    fn _id(i: u8) -> Vec<u8> {
+        vec![3 + i, 4 + i, 5 + i]
+    }
+
+    #[allow(dead_code)]
+    fn _id1() -> Vec<u8> {
+        _id(1)
+    }
+
+    #[allow(dead_code)]
+    fn _id2() -> Vec<u8> {
+        _id(2)
+    }
+
+    #[allow(dead_code)]
+    fn _value(i: u8) -> EncryptedValue {
+        EncryptedValue {
+            data: vec![6 + i, 7 + i, 8 + i],
+            key: vec![9 + i, 10 + i, 11 + i, 9 + i, 10 + i, 11 + i],
+        }
+    }
+
+    #[allow(dead_code)]
+    fn _value1() -> EncryptedValue {
+        _value(1)
+    }
+
+    #[allow(dead_code)]
+    fn _value2() -> EncryptedValue {
+        _value(2)
+    }
+
+    #[allow(dead_code)]
+    fn _tags() -> Vec<Tag> {
+        let mut tags: Vec<Tag> = Vec::new();
+        tags.push(Tag::Encrypted(vec![1, 5, 8], vec![3, 5, 6]));
+        tags.push(Tag::PlainText(vec![1, 5, 8, 1], "Plain value".to_string()));
+        tags
+    }
+
+    #[allow(dead_code)]
+    fn _new_tags() -> Vec<Tag> {
+        vec![
+            Tag::Encrypted(vec![1, 1, 1], vec![2, 2, 2]),
+            Tag::PlainText(vec![1, 1, 1], String::from("tag_value_3")),
+        ]
+    }
+
+    #[allow(dead_code)]
+    fn _sort(mut v: Vec<Tag>) -> Vec<Tag> {
+        v.sort();
+        v
+    }
+}
@@ -0,0 +1,472 @@
+use indy_api_types::errors::prelude::*;
+
+use crate::{
+    language::{Operator, TagName, TargetValue},
+    SearchOptions, SortOrder,
+};
+
+// Arguments of the generated queries. The first two are always the wallet id and the record type.
+#[derive(Debug, PartialEq)]
+pub enum Argument {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+pub fn wql_to_sql(
+    wallet_id: &str,
+    type_: &[u8],
+    wql: &Operator,
+    options: &SearchOptions,
+) -> IndyResult<(String, Vec<Argument>)> {
+    let mut arguments = vec![
+        Argument::Text(wallet_id.to_string()),
+        Argument::Bytes(type_.to_vec()),
+    ];
+
+    let query_condition = operator_to_sql(wql, &mut arguments)?;
+
+    let mut query_string = format!(
+        "SELECT i.id, i.name, {}, {} FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2{}",
+        if options.retrieve_value {
+            "i.value"
+        } else {
+            "NULL::BYTEA"
+        },
+        if options.retrieve_value {
+            "i.key"
+        } else {
+            "NULL::BYTEA"
+        },
+        if !query_condition.is_empty() {
+            format!(" AND {}", query_condition)
+        } else {
+            "".to_string()
+        }
+    );
+
+    let sort_value = match options.sort_by {
+        Some(ref sort_by) => {
+            let name = push_argument(
+                &mut arguments,
+                Argument::Bytes(sort_by.encrypted_tag_name()?),
+            );
+
+            Some(format!(
+                "(SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = {})",
+                name
+            ))
+        }
+        None => None,
+    };
+
+    // Name is unique within the type, so records after the cursor position are selected
+    // by the sort value and the name
+    if let Some(ref after) = options.after {
+        let after_name = after.encrypted_name()?;
+
+        match (sort_value.as_ref(), after.sort_value.as_ref()) {
+            (Some(sort_value), Some(after_value)) => {
+                let operator = match options.sort_by.as_ref().map(|sort_by| sort_by.order) {
+                    Some(SortOrder::Desc) => "<",
+                    _ => ">",
+                };
+
+                let value = push_argument(&mut arguments, Argument::Text(after_value.to_string()));
+                let name = push_argument(&mut arguments, Argument::Bytes(after_name));
+
+                query_string.push_str(&format!(
+                    " AND ({} IS NULL OR {} {} {} OR ({} = {} AND i.name > {}))",
+                    sort_value, sort_value, operator, value, sort_value, value, name
+                ));
+            }
+            // Position is among the records without the sort tag
+            (Some(sort_value), None) => {
+                let name = push_argument(&mut arguments, Argument::Bytes(after_name));
+
+                query_string.push_str(&format!(
+                    " AND {} IS NULL AND i.name > {}",
+                    sort_value, name
+                ));
+            }
+            (None, _) => {
+                let name = push_argument(&mut arguments, Argument::Bytes(after_name));
+                query_string.push_str(&format!(" AND i.name > {}", name));
+            }
+        }
+    }
+
+    // Name is unique within the type, so it makes the order of records stable
+    match (options.sort_by.as_ref(), sort_value.as_ref()) {
+        (Some(sort_by), Some(sort_value)) => {
+            query_string.push_str(&format!(
+                " ORDER BY {} IS NULL, {} {}, i.name",
+                sort_value,
+                sort_value,
+                sort_by.order_sql()
+            ));
+        }
+        _ => query_string.push_str(" ORDER BY i.name"),
+    }
+
+    if let Some(limit) = options.limit {
+        query_string.push_str(&format!(" LIMIT {}", limit));
+    }
+
+    if let Some(offset) = options.offset {
+        query_string.push_str(&format!(" OFFSET {}", offset));
+    }
+
+    Ok((query_string, arguments))
+}
+
+pub fn wql_to_sql_count(
+    wallet_id: &str,
+    type_: &[u8],
+    wql: &Operator,
+) -> IndyResult<(String, Vec<Argument>)> {
+    let mut arguments = vec![
+        Argument::Text(wallet_id.to_string()),
+        Argument::Bytes(type_.to_vec()),
+    ];
+
+    let query_condition = operator_to_sql(wql, &mut arguments)?;
+
+    let query_string = format!(
+        "SELECT COUNT(*) FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2{}",
+        if !query_condition.is_empty() {
+            format!(" AND {}", query_condition)
+        } else {
+            "".to_string()
+        }
+    );
+
+    Ok((query_string, arguments))
+}
+
+fn operator_to_sql(op: &Operator, arguments: &mut Vec<Argument>) -> IndyResult<String> {
+    match *op {
+        Operator::Eq(ref tag_name, ref target_value) => {
+            tag_to_sql(tag_name, "=", target_value, arguments)
+        }
+        Operator::Neq(ref tag_name, ref target_value) => {
+            tag_to_sql(tag_name, "!=", target_value, arguments)
+        }
+        Operator::Gt(ref tag_name, ref target_value) => {
+            plain_tag_to_sql(tag_name, ">", target_value, arguments, "$gt")
+        }
+        Operator::Gte(ref tag_name, ref target_value) => {
+            plain_tag_to_sql(tag_name, ">=", target_value, arguments, "$gte")
+        }
+        Operator::Lt(ref tag_name, ref target_value) => {
+            plain_tag_to_sql(tag_name, "<", target_value, arguments, "$lt")
+        }
+        Operator::Lte(ref tag_name, ref target_value) => {
+            plain_tag_to_sql(tag_name, "<=", target_value, arguments, "$lte")
+        }
+        Operator::Like(ref tag_name, ref target_value) => {
+            plain_tag_to_sql(tag_name, "LIKE", target_value, arguments, "$like")
+        }
+        Operator::In(ref tag_name, ref target_values) => {
+            in_to_sql(tag_name, target_values, arguments)
+        }
+        Operator::And(ref suboperators) => and_to_sql(suboperators, arguments),
+        Operator::Or(ref suboperators) => or_to_sql(suboperators, arguments),
+        Operator::Not(ref suboperator) => not_to_sql(suboperator, arguments),
+    }
+}
+
+fn tag_to_sql(
+    tag_name: &TagName,
+    op: &str,
+    tag_value: &TargetValue,
+    arguments: &mut Vec<Argument>,
+) -> IndyResult<String> {
+    match (tag_name, tag_value) {
+        (&TagName::PlainTagName(ref name), &TargetValue::Unencrypted(ref value)) => {
+            let name = push_argument(arguments, Argument::Bytes(name.clone()));
+            let value = push_argument(arguments, Argument::Text(value.clone()));
+
+            Ok(format!(
+                "(i.id IN (SELECT item_id FROM tags_plaintext WHERE wallet_id = $1 AND name = {} AND value {} {}))",
+                name, op, value
+            ))
+        }
+        (&TagName::EncryptedTagName(ref name), &TargetValue::Encrypted(ref value)) => {
+            let name = push_argument(arguments, Argument::Bytes(name.clone()));
+            let value = push_argument(arguments, Argument::Bytes(value.clone()));
+
+            Ok(format!(
+                "(i.id IN (SELECT item_id FROM tags_encrypted WHERE wallet_id = $1 AND name = {} AND value {} {}))",
+                name, op, value
+            ))
+        }
+        _ => Err(err_msg(
+            IndyErrorKind::WalletQueryError,
+            "Invalid combination of tag name and value for equality operator",
+        )),
+    }
+}
+
+fn plain_tag_to_sql(
+    tag_name: &TagName,
+    op: &str,
+    tag_value: &TargetValue,
+    arguments: &mut Vec<Argument>,
+    wql_op: &str,
+) -> IndyResult<String> {
+    match (tag_name, tag_value) {
+        (&TagName::PlainTagName(_), &TargetValue::Unencrypted(_)) => {
+            tag_to_sql(tag_name, op, tag_value, arguments)
+        }
+        _ => Err(err_msg(
+            IndyErrorKind::WalletQueryError,
+            format!(
+                "Invalid combination of tag name and value for {} operator",
+                wql_op
+            ),
+        )),
+    }
+}
+
+fn in_to_sql(
+    tag_name: &TagName,
+    tag_values: &[TargetValue],
+    arguments: &mut Vec<Argument>,
+) -> IndyResult<String> {
+    let (table, name) = match *tag_name {
+        TagName::PlainTagName(ref name) => ("tags_plaintext", name),
+        TagName::EncryptedTagName(ref name) => ("tags_encrypted", name),
+    };
+
+    let name = push_argument(arguments, Argument::Bytes(name.clone()));
+
+    let mut values = Vec::with_capacity(tag_values.len());
+
+    for tag_value in tag_values {
+        let value = match (tag_name, tag_value) {
+            (&TagName::PlainTagName(_), &TargetValue::Unencrypted(ref value)) => {
+                Argument::Text(value.clone())
+            }
+            (&TagName::EncryptedTagName(_), &TargetValue::Encrypted(ref value)) => {
+                Argument::Bytes(value.clone())
+            }
+            _ => {
+                return Err(err_msg(
+                    IndyErrorKind::WalletQueryError,
+                    "Invalid combination of tag name and value for $in operator",
+                ))
+            }
+        };
+
+        values.push(push_argument(arguments, value));
+    }
+
+    Ok(format!(
+        "(i.id IN (SELECT item_id FROM {} WHERE wallet_id = $1 AND name = {} AND value IN ({})))",
+        table,
+        name,
+        values.join(",")
+    ))
+}
+
+fn and_to_sql(suboperators: &[Operator], arguments: &mut Vec<Argument>) -> IndyResult<String> {
+    join_operators(suboperators, " AND ", arguments)
+}
+
+fn or_to_sql(suboperators: &[Operator], arguments: &mut Vec<Argument>) -> IndyResult<String> {
+    join_operators(suboperators, " OR ", arguments)
+}
+
+fn not_to_sql(suboperator: &Operator, arguments: &mut Vec<Argument>) -> IndyResult<String> {
+    let suboperator_string = operator_to_sql(suboperator, arguments)?;
+    Ok("NOT (".to_string() + &suboperator_string + ")")
+}
+
+fn join_operators(
+    operators: &[Operator],
+    join_str: &str,
+    arguments: &mut Vec<Argument>,
+) -> IndyResult<String> {
+    let mut s = String::new();
+
+    if !operators.is_empty() {
+        s.push('(');
+        for (index, operator) in operators.iter().enumerate() {
+            let operator_string = operator_to_sql(operator, arguments)?;
+
+            s.push_str(&operator_string);
+
+            if index < operators.len() - 1 {
+                s.push_str(join_str);
+            }
+        }
+
+        s.push(')');
+    }
+
+    Ok(s)
+}
+
+fn push_argument(arguments: &mut Vec<Argument>, argument: Argument) -> String {
+    arguments.push(argument);
+    format!("${}", arguments.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_and() {
+        let condition_1 = Operator::And(vec![
+            Operator::Eq(
+                TagName::EncryptedTagName(vec![1, 2, 3]),
+                TargetValue::Encrypted(vec![4, 5, 6]),
+            ),
+            Operator::Eq(
+                TagName::PlainTagName(vec![7, 8, 9]),
+                TargetValue::Unencrypted("spam".to_string()),
+            ),
+        ]);
+
+        let condition_2 = Operator::And(vec![
+            Operator::Eq(
+                TagName::EncryptedTagName(vec![10, 11, 12]),
+                TargetValue::Encrypted(vec![13, 14, 15]),
+            ),
+            Operator::Not(Box::new(Operator::Eq(
+                TagName::PlainTagName(vec![16, 17, 18]),
+                TargetValue::Unencrypted("eggs".to_string()),
+            ))),
+        ]);
+
+        let query = Operator::Or(vec![condition_1, condition_2]);
+        let class = [100, 100, 100];
+        let options = SearchOptions::default();
+
+        let (query, arguments) = wql_to_sql("wallet1", &class, &query, &options).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT i.id, i.name, i.value, i.key FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2 AND \
+            (((i.id IN (SELECT item_id FROM tags_encrypted WHERE wallet_id = $1 AND name = $3 AND value = $4)) AND \
+            (i.id IN (SELECT item_id FROM tags_plaintext WHERE wallet_id = $1 AND name = $5 AND value = $6))) OR \
+            ((i.id IN (SELECT item_id FROM tags_encrypted WHERE wallet_id = $1 AND name = $7 AND value = $8)) AND \
+            NOT ((i.id IN (SELECT item_id FROM tags_plaintext WHERE wallet_id = $1 AND name = $9 AND value = $10))))) \
+            ORDER BY i.name"
+        );
+
+        assert_eq!(arguments.len(), 10);
+        assert_eq!(arguments[0], Argument::Text("wallet1".to_string()));
+        assert_eq!(arguments[1], Argument::Bytes(class.to_vec()));
+        assert_eq!(arguments[5], Argument::Text("spam".to_string()));
+    }
+
+    #[test]
+    fn in_works() {
+        let query = Operator::In(
+            TagName::EncryptedTagName(vec![1, 2, 3]),
+            vec![TargetValue::Encrypted(vec![4, 5, 6])],
+        );
+
+        let (query, arguments) = wql_to_sql_count("wallet1", &[100], &query).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT COUNT(*) FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2 AND \
+            (i.id IN (SELECT item_id FROM tags_encrypted WHERE wallet_id = $1 AND name = $3 AND value IN ($4)))"
+        );
+
+        assert_eq!(arguments.len(), 4);
+    }
+
+    #[test]
+    fn empty_query_works() {
+        let options = SearchOptions::default();
+
+        let (query, arguments) =
+            wql_to_sql("wallet1", &[100], &Operator::And(vec![]), &options).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT i.id, i.name, i.value, i.key FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2 ORDER BY i.name"
+        );
+
+        assert_eq!(arguments.len(), 2);
+    }
+
+    #[test]
+    fn sort_and_page_works() {
+        let options: SearchOptions = serde_json::from_str(
+            r#"{"offset": 10, "limit": 5, "sortBy": {"tagName": "AQID", "order": "desc"}}"#,
+        )
+        .unwrap();
+
+        let (query, arguments) =
+            wql_to_sql("wallet1", &[100], &Operator::And(vec![]), &options).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT i.id, i.name, i.value, i.key FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2 \
+            ORDER BY (SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = $3) IS NULL, \
+            (SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = $3) DESC, i.name \
+            LIMIT 5 OFFSET 10"
+        );
+
+        assert_eq!(arguments.len(), 3);
+        assert_eq!(arguments[2], Argument::Bytes(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn sort_and_position_works() {
+        let options: SearchOptions = serde_json::from_str(
+            r#"{"limit": 5, "sortBy": {"tagName": "AQID", "order": "desc"}, "after": {"name": "BAUG", "sortValue": "5"}}"#,
+        )
+        .unwrap();
+
+        let (query, arguments) =
+            wql_to_sql("wallet1", &[100], &Operator::And(vec![]), &options).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT i.id, i.name, i.value, i.key FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2 \
+            AND ((SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = $3) IS NULL OR \
+            (SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = $3) < $4 OR \
+            ((SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = $3) = $4 AND i.name > $5)) \
+            ORDER BY (SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = $3) IS NULL, \
+            (SELECT value FROM tags_plaintext WHERE wallet_id = $1 AND item_id = i.id AND name = $3) DESC, i.name \
+            LIMIT 5"
+        );
+
+        assert_eq!(arguments.len(), 5);
+        assert_eq!(arguments[3], Argument::Text("5".to_string()));
+        assert_eq!(arguments[4], Argument::Bytes(vec![4, 5, 6]));
+    }
+
+    #[test]
+    fn position_without_sort_works() {
+        let options: SearchOptions = serde_json::from_str(r#"{"after": {"name": "BAUG"}}"#).unwrap();
+
+        let (query, arguments) =
+            wql_to_sql("wallet1", &[100], &Operator::And(vec![]), &options).unwrap();
+
+        assert_eq!(
+            query,
+            "SELECT i.id, i.name, i.value, i.key FROM items AS i WHERE i.wallet_id = $1 AND i.type = $2 \
+            AND i.name > $3 ORDER BY i.name"
+        );
+
+        assert_eq!(arguments[2], Argument::Bytes(vec![4, 5, 6]));
+    }
+
+    #[test]
+    fn gt_for_encrypted_tag_fails() {
+        let query = Operator::Gt(
+            TagName::EncryptedTagName(vec![1, 2, 3]),
+            TargetValue::Encrypted(vec![4, 5, 6]),
+        );
+
+        let res = wql_to_sql_count("wallet1", &[100], &query);
+        assert!(res.is_err());
+    }
+}
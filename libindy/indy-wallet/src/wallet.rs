@@ -1,6 +1,6 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{cmp, collections::HashMap, sync::Arc};
 
-use indy_api_types::errors::prelude::*;
+use indy_api_types::{domain::wallet::WalletOperation, errors::prelude::*};
 
 use indy_utils::{
     crypto::{chacha20poly1305_ietf, hmacsha256},
@@ -9,11 +9,13 @@ use indy_utils::{
 
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
+use log::info;
 
-use crate::{encryption::*, iterator::WalletIterator, query_encryption::encrypt_query, storage, WalletRecord, cache::wallet_cache::WalletCache, RecordOptions};
+use crate::{encryption::*, iterator::WalletIterator, query_encryption::{encrypt_query, encrypt_sort_tag_name}, storage, WalletRecord, cache::wallet_cache::WalletCache, KeyRotationProgress, Metadata, RecordOptions, SearchCursor, SearchOptions, SearchSort};
 use crate::storage::StorageRecord;
 use crate::cache::wallet_cache::WalletCacheHitMetrics;
-use futures::future::join;
+use futures::{future::join, lock::Mutex};
+use async_std::sync::{RwLock, RwLockReadGuard};
 
 #[derive(Serialize, Deserialize)]
 pub(super) struct Keys {
@@ -135,8 +137,9 @@ impl EncryptedValue {
 pub(super) struct Wallet {
     id: String,
     storage: Box<dyn storage::WalletStorage>,
-    keys: Arc<Keys>,
+    keys: RwLock<Arc<Keys>>,
     cache: WalletCache,
+    rotation_progress: Mutex<Option<KeyRotationProgress>>,
 }
 
 impl Wallet {
@@ -146,7 +149,13 @@ impl Wallet {
         keys: Arc<Keys>,
         cache: WalletCache,
     ) -> Wallet {
-        Wallet { id, storage, keys, cache }
+        Wallet {
+            id,
+            storage,
+            keys: RwLock::new(keys),
+            cache,
+            rotation_progress: Mutex::new(None),
+        }
     }
 
     pub async fn add(
@@ -156,25 +165,27 @@ impl Wallet {
         value: &str,
         tags: &HashMap<String, String>,
     ) -> IndyResult<()> {
+        let keys = self.keys().await?;
+
         let etype = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
         let ename = encrypt_as_searchable(
             name.as_bytes(),
-            &self.keys.name_key,
-            &self.keys.item_hmac_key,
+            &keys.name_key,
+            &keys.item_hmac_key,
         );
 
-        let evalue = EncryptedValue::encrypt(value, &self.keys.value_key);
+        let evalue = EncryptedValue::encrypt(value, &keys.value_key);
 
         let etags = encrypt_tags(
             tags,
-            &self.keys.tag_name_key,
-            &self.keys.tag_value_key,
-            &self.keys.tags_hmac_key,
+            &keys.tag_name_key,
+            &keys.tag_value_key,
+            &keys.tags_hmac_key,
         );
 
         self.storage.add(&etype, &ename, &evalue, &etags).await?;
@@ -189,23 +200,25 @@ impl Wallet {
         name: &str,
         tags: &HashMap<String, String>,
     ) -> IndyResult<()> {
+        let keys = self.keys().await?;
+
         let encrypted_type = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
         let encrypted_name = encrypt_as_searchable(
             name.as_bytes(),
-            &self.keys.name_key,
-            &self.keys.item_hmac_key,
+            &keys.name_key,
+            &keys.item_hmac_key,
         );
 
         let encrypted_tags = encrypt_tags(
             tags,
-            &self.keys.tag_name_key,
-            &self.keys.tag_value_key,
-            &self.keys.tags_hmac_key,
+            &keys.tag_name_key,
+            &keys.tag_value_key,
+            &keys.tags_hmac_key,
         );
 
         self.storage
@@ -224,23 +237,25 @@ impl Wallet {
         name: &str,
         tags: &HashMap<String, String>,
     ) -> IndyResult<()> {
+        let keys = self.keys().await?;
+
         let encrypted_type = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
         let encrypted_name = encrypt_as_searchable(
             name.as_bytes(),
-            &self.keys.name_key,
-            &self.keys.item_hmac_key,
+            &keys.name_key,
+            &keys.item_hmac_key,
         );
 
         let encrypted_tags = encrypt_tags(
             tags,
-            &self.keys.tag_name_key,
-            &self.keys.tag_value_key,
-            &self.keys.tags_hmac_key,
+            &keys.tag_name_key,
+            &keys.tag_value_key,
+            &keys.tags_hmac_key,
         );
 
         self.storage
@@ -254,20 +269,22 @@ impl Wallet {
     }
 
     pub async fn delete_tags(&self, type_: &str, name: &str, tag_names: &[&str]) -> IndyResult<()> {
+        let keys = self.keys().await?;
+
         let encrypted_type = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
         let encrypted_name = encrypt_as_searchable(
             name.as_bytes(),
-            &self.keys.name_key,
-            &self.keys.item_hmac_key,
+            &keys.name_key,
+            &keys.item_hmac_key,
         );
 
         let encrypted_tag_names =
-            encrypt_tag_names(tag_names, &self.keys.tag_name_key, &self.keys.tags_hmac_key);
+            encrypt_tag_names(tag_names, &keys.tag_name_key, &keys.tags_hmac_key);
 
         self.storage
             .delete_tags(&encrypted_type, &encrypted_name, &encrypted_tag_names[..])
@@ -280,19 +297,21 @@ impl Wallet {
     }
 
     pub async fn update(&self, type_: &str, name: &str, new_value: &str) -> IndyResult<()> {
+        let keys = self.keys().await?;
+
         let encrypted_type = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
         let encrypted_name = encrypt_as_searchable(
             name.as_bytes(),
-            &self.keys.name_key,
-            &self.keys.item_hmac_key,
+            &keys.name_key,
+            &keys.item_hmac_key,
         );
 
-        let encrypted_value = EncryptedValue::encrypt(new_value, &self.keys.value_key);
+        let encrypted_value = EncryptedValue::encrypt(new_value, &keys.value_key);
 
         self.storage
             .update(&encrypted_type, &encrypted_name, &encrypted_value)
@@ -305,16 +324,18 @@ impl Wallet {
     }
 
     pub async fn get(&self, type_: &str, name: &str, options: &str, cache_hit_metrics: &WalletCacheHitMetrics) -> IndyResult<WalletRecord> {
+        let keys = self.keys().await?;
+
         let etype = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
         let ename = encrypt_as_searchable(
             name.as_bytes(),
-            &self.keys.name_key,
-            &self.keys.item_hmac_key,
+            &keys.name_key,
+            &keys.item_hmac_key,
         );
 
         let result = if self.cache.is_type_cacheable(type_) {
@@ -356,13 +377,13 @@ impl Wallet {
 
         let value = match result.value {
             None => None,
-            Some(encrypted_value) => Some(encrypted_value.decrypt(&self.keys.value_key)?),
+            Some(encrypted_value) => Some(encrypted_value.decrypt(&keys.value_key)?),
         };
 
         let tags = decrypt_tags(
             &result.tags,
-            &self.keys.tag_name_key,
-            &self.keys.tag_value_key,
+            &keys.tag_name_key,
+            &keys.tag_value_key,
         )?;
 
         Ok(WalletRecord::new(
@@ -374,16 +395,18 @@ impl Wallet {
     }
 
     pub async fn delete(&self, type_: &str, name: &str) -> IndyResult<()> {
+        let keys = self.keys().await?;
+
         let etype = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
         let ename = encrypt_as_searchable(
             name.as_bytes(),
-            &self.keys.name_key,
-            &self.keys.item_hmac_key,
+            &keys.name_key,
+            &keys.item_hmac_key,
         );
 
         self.storage.delete(&etype, &ename).await?;
@@ -392,31 +415,202 @@ impl Wallet {
         Ok(())
     }
 
+    pub async fn batch(&self, operations: &[WalletOperation]) -> IndyResult<()> {
+        let keys = self.keys().await?;
+
+        let eoperations = operations
+            .iter()
+            .map(|operation| Self::_encrypt_operation(operation, &keys))
+            .collect::<Vec<_>>();
+
+        if let Err(err) = self.storage.batch(&eoperations).await {
+            // Nothing was committed, but drop touched records from the cache anyway
+            // so a failed batch can never leave stale entries behind.
+            for (operation, eoperation) in operations.iter().zip(eoperations.iter()) {
+                let (etype, ename) = eoperation.key();
+                self.cache.delete(operation.type_(), etype, ename).await;
+            }
+
+            return Err(err);
+        }
+
+        for (operation, eoperation) in operations.iter().zip(eoperations.iter()) {
+            let type_ = operation.type_();
+
+            match *eoperation {
+                storage::StorageOperation::Add {
+                    type_: ref etype,
+                    ref id,
+                    ref value,
+                    ref tags,
+                } => self.cache.add(type_, etype, id, value, tags).await,
+                storage::StorageOperation::Update {
+                    type_: ref etype,
+                    ref id,
+                    ref value,
+                } => self.cache.update(type_, etype, id, value).await,
+                storage::StorageOperation::AddTags {
+                    type_: ref etype,
+                    ref id,
+                    ref tags,
+                } => self.cache.add_tags(type_, etype, id, tags).await,
+                storage::StorageOperation::UpdateTags {
+                    type_: ref etype,
+                    ref id,
+                    ref tags,
+                } => self.cache.update_tags(type_, etype, id, tags).await,
+                storage::StorageOperation::DeleteTags {
+                    type_: ref etype,
+                    ref id,
+                    ref tag_names,
+                } => self.cache.delete_tags(type_, etype, id, tag_names).await,
+                storage::StorageOperation::Delete {
+                    type_: ref etype,
+                    ref id,
+                } => self.cache.delete(type_, etype, id).await,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn _encrypt_operation(operation: &WalletOperation, keys: &Keys) -> storage::StorageOperation {
+        let type_ = encrypt_as_searchable(
+            operation.type_().as_bytes(),
+            &keys.type_key,
+            &keys.item_hmac_key,
+        );
+
+        let id = encrypt_as_searchable(
+            operation.id().as_bytes(),
+            &keys.name_key,
+            &keys.item_hmac_key,
+        );
+
+        match *operation {
+            WalletOperation::Add {
+                ref value,
+                ref tags,
+                ..
+            } => storage::StorageOperation::Add {
+                type_,
+                id,
+                value: EncryptedValue::encrypt(value, &keys.value_key),
+                tags: encrypt_tags(
+                    tags,
+                    &keys.tag_name_key,
+                    &keys.tag_value_key,
+                    &keys.tags_hmac_key,
+                ),
+            },
+            WalletOperation::Update { ref value, .. } => storage::StorageOperation::Update {
+                type_,
+                id,
+                value: EncryptedValue::encrypt(value, &keys.value_key),
+            },
+            WalletOperation::AddTags { ref tags, .. } => storage::StorageOperation::AddTags {
+                type_,
+                id,
+                tags: encrypt_tags(
+                    tags,
+                    &keys.tag_name_key,
+                    &keys.tag_value_key,
+                    &keys.tags_hmac_key,
+                ),
+            },
+            WalletOperation::UpdateTags { ref tags, .. } => storage::StorageOperation::UpdateTags {
+                type_,
+                id,
+                tags: encrypt_tags(
+                    tags,
+                    &keys.tag_name_key,
+                    &keys.tag_value_key,
+                    &keys.tags_hmac_key,
+                ),
+            },
+            WalletOperation::DeleteTags { ref tag_names, .. } => {
+                let tag_names = tag_names.iter().map(String::as_str).collect::<Vec<_>>();
+
+                storage::StorageOperation::DeleteTags {
+                    type_,
+                    id,
+                    tag_names: encrypt_tag_names(
+                        &tag_names,
+                        &keys.tag_name_key,
+                        &keys.tags_hmac_key,
+                    ),
+                }
+            }
+            WalletOperation::Delete { .. } => storage::StorageOperation::Delete { type_, id },
+        }
+    }
+
     pub async fn search<'a>(
         &'a self,
         type_: &str,
         query: &str,
         options: Option<&str>,
     ) -> IndyResult<WalletIterator> {
+        let keys = self.keys().await?;
+
         let parsed_query: Query = ::serde_json::from_str::<Query>(query)
             .map_err(|err| IndyError::from_msg(IndyErrorKind::WalletQueryError, err))?
             .optimise()
             .unwrap_or_default();
 
-        let encrypted_query = encrypt_query(parsed_query, &self.keys)?;
+        let encrypted_query = encrypt_query(parsed_query, &keys)?;
 
         let encrypted_type_ = encrypt_as_searchable(
             type_.as_bytes(),
-            &self.keys.type_key,
-            &self.keys.item_hmac_key,
+            &keys.type_key,
+            &keys.item_hmac_key,
         );
 
+        let mut options: SearchOptions = match options {
+            Some(options) => serde_json::from_str(options).to_indy(
+                IndyErrorKind::InvalidStructure,
+                "Search options is malformed json",
+            )?,
+            None => SearchOptions::default(),
+        };
+
+        let search_hash = SearchCursor::search_hash(type_, query, options.sort_by.as_ref())?;
+
+        options.resolve_cursor(&search_hash)?;
+
+        if let Some(ref mut sort_by) = options.sort_by {
+            sort_by.tag_name = encrypt_sort_tag_name(&sort_by.tag_name, &keys)?;
+        }
+
+        let sort_tag_name = options
+            .sort_by
+            .as_ref()
+            .map(SearchSort::encrypted_tag_name)
+            .transpose()?;
+
+        // Value of the sort tag of the last fetched record is required for the cursor
+        let storage_options = SearchOptions {
+            retrieve_tags: options.retrieve_tags || sort_tag_name.is_some(),
+            ..options.clone()
+        };
+
+        let storage_options = serde_json::to_string(&storage_options).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize search options",
+        )?;
+
         let storage_iterator = self
             .storage
-            .search(&encrypted_type_, &encrypted_query, options)
+            .search(&encrypted_type_, &encrypted_query, Some(&storage_options))
             .await?;
 
-        let wallet_iterator = WalletIterator::new(storage_iterator, Arc::clone(&self.keys));
+        let wallet_iterator = WalletIterator::new(storage_iterator, Arc::clone(&keys)).with_page(
+            search_hash,
+            options.after,
+            options.limit,
+            sort_tag_name,
+            options.retrieve_tags,
+        );
 
         Ok(wallet_iterator)
     }
@@ -426,13 +620,158 @@ impl Wallet {
     }
 
     pub async fn get_all(&self) -> IndyResult<WalletIterator> {
+        let keys = self.keys().await?;
+
         let all_items = self.storage.get_all().await?;
-        Ok(WalletIterator::new(all_items, self.keys.clone()))
+        Ok(WalletIterator::new(all_items, Arc::clone(&keys)))
     }
 
     pub fn get_id<'a>(&'a self) -> &'a str {
         &self.id
     }
+
+    /// Replaces all record, tag and HMAC keys with fresh ones and re-encrypts every record.
+    ///
+    /// Master key isn't kept by the wallet, so it is passed by the caller to store the keys.
+    /// Records are moved in atomic batches of `batch_size` and read page by page. New keys
+    /// are stored in metadata as pending before the first batch, so an interrupted rotation
+    /// can be resumed: records that already decrypt with the new keys are skipped.
+    pub async fn rotate_keys(
+        &self,
+        master_key: &chacha20poly1305_ietf::Key,
+        batch_size: usize,
+    ) -> IndyResult<KeyRotationProgress> {
+        // Other operations wait until all records are re-encrypted
+        let mut keys = self.keys.write().await;
+
+        // Fail early for storages that can't apply atomic batches
+        self.storage.batch(&[]).await?;
+
+        let mut metadata = self.get_metadata().await?;
+
+        Keys::deserialize_encrypted(metadata.get_keys(), master_key).map_err(|err| {
+            err.map(
+                IndyErrorKind::WalletAccessFailed,
+                "Invalid master key provided",
+            )
+        })?;
+
+        let new_keys = match metadata.get_pending_keys() {
+            Some(pending_keys) => Keys::deserialize_encrypted(pending_keys, master_key)?,
+            None => {
+                let new_keys = Keys::new();
+                metadata.set_pending_keys(Some(new_keys.serialize_encrypted(master_key)?));
+                self._set_metadata(&metadata).await?;
+                new_keys
+            }
+        };
+
+        let mut progress = KeyRotationProgress::new(self.storage.count_all().await?);
+        *self.rotation_progress.lock().await = Some(progress.clone());
+
+        let mut position = None;
+
+        // Re-encrypted records are added after all existing ones,
+        // so rotation is done when existing ones are processed
+        while progress.processed < progress.total {
+            let records = self.storage.get_all_page(position, batch_size).await?;
+
+            if records.is_empty() {
+                break;
+            }
+
+            let mut operations = Vec::with_capacity(records.len() * 2);
+
+            for (_, record) in records.iter() {
+                let decrypted = match decrypt_storage_record(record, &keys) {
+                    Ok(decrypted) => decrypted,
+                    Err(_) => {
+                        // Already re-encrypted by the rotation attempt that was interrupted
+                        decrypt_storage_record(record, &new_keys)?;
+                        continue;
+                    }
+                };
+
+                let type_ = record.type_.clone().ok_or_else(|| {
+                    err_msg(IndyErrorKind::InvalidState, "Record type not returned")
+                })?;
+
+                operations.push(storage::StorageOperation::Delete {
+                    type_,
+                    id: record.id.clone(),
+                });
+
+                operations.push(Self::_encrypt_operation(
+                    &WalletOperation::Add {
+                        type_: decrypted.get_type().unwrap_or_default().to_string(),
+                        id: decrypted.get_id().to_string(),
+                        value: decrypted.get_value().unwrap_or_default().to_string(),
+                        tags: decrypted.get_tags().cloned().unwrap_or_default(),
+                    },
+                    &new_keys,
+                ));
+            }
+
+            if !operations.is_empty() {
+                self.storage.batch(&operations).await?;
+            }
+
+            position = records.last().map(|(position, _)| *position);
+
+            progress.processed = cmp::min(progress.processed + records.len(), progress.total);
+            *self.rotation_progress.lock().await = Some(progress.clone());
+
+            info!(
+                "Wallet {} keys rotation: {} of {} records processed",
+                self.id, progress.processed, progress.total
+            );
+        }
+
+        metadata.set_keys(new_keys.serialize_encrypted(master_key)?);
+        metadata.set_pending_keys(None);
+        self._set_metadata(&metadata).await?;
+
+        // Cached entries are keyed by values encrypted with the old keys
+        self.cache.clear().await;
+        *keys = Arc::new(new_keys);
+
+        progress.completed = true;
+        *self.rotation_progress.lock().await = Some(progress.clone());
+
+        Ok(progress)
+    }
+
+    pub async fn get_metadata(&self) -> IndyResult<Metadata> {
+        serde_json::from_slice(&self.storage.get_storage_metadata().await?)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize metadata")
+    }
+
+    pub async fn get_rotation_progress(&self) -> Option<KeyRotationProgress> {
+        self.rotation_progress.lock().await.clone()
+    }
+
+    async fn keys(&self) -> IndyResult<RwLockReadGuard<'_, Arc<Keys>>> {
+        let keys = self.keys.read().await;
+
+        // Records are partially re-encrypted until the interrupted rotation is resumed
+        if let Some(KeyRotationProgress { completed: false, .. }) = *self.rotation_progress.lock().await {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Wallet keys rotation is not completed. Resume it before using the wallet",
+            ));
+        }
+
+        Ok(keys)
+    }
+
+    async fn _set_metadata(&self, metadata: &Metadata) -> IndyResult<()> {
+        let metadata = serde_json::to_vec(metadata).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize wallet metadata",
+        )?;
+
+        self.storage.set_storage_metadata(&metadata).await
+    }
 }
 
 impl Drop for Wallet {
@@ -3614,6 +3953,177 @@ mod tests {
         test::cleanup_wallet("wallet_search_works_for_nested_empty");
     }
 
+    #[async_std::test]
+    async fn wallet_rotate_keys_works() {
+        test::cleanup_wallet("wallet_rotate_keys_works");
+
+        {
+            let mut wallet = _wallet("wallet_rotate_keys_works").await;
+            let metrics = WalletCacheHitMetrics::new();
+
+            wallet
+                .add(_type1(), _id1(), _value1(), &_tags())
+                .await
+                .unwrap();
+
+            wallet
+                .add(_type1(), _id2(), _value2(), &_tags())
+                .await
+                .unwrap();
+
+            let progress = wallet.rotate_keys(&_master_key(), 1).await.unwrap();
+
+            assert_eq!(
+                KeyRotationProgress {
+                    total: 2,
+                    processed: 2,
+                    completed: true
+                },
+                progress
+            );
+
+            let record = wallet
+                .get(_type1(), _id1(), &_fetch_options(true, true, true), &metrics)
+                .await
+                .unwrap();
+
+            assert_eq!(record.get_value().unwrap(), _value1());
+            assert_eq!(record.get_tags().unwrap(), &_tags());
+
+            let mut iterator = wallet
+                .search(
+                    _type1(),
+                    &jsonstr!({"tag1": "tag_value_1"}),
+                    Some(&_search_options(true, false, false, false, false)),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(_fetch_all(&mut iterator).await.len(), 2);
+
+            wallet.close().unwrap();
+        }
+
+        test::cleanup_wallet("wallet_rotate_keys_works");
+    }
+
+    #[async_std::test]
+    async fn wallet_rotate_keys_works_for_interrupted_rotation() {
+        test::cleanup_wallet("wallet_rotate_keys_works_for_interrupted_rotation");
+
+        {
+            let mut wallet = _wallet("wallet_rotate_keys_works_for_interrupted_rotation").await;
+            let metrics = WalletCacheHitMetrics::new();
+
+            wallet
+                .add(_type1(), _id1(), _value1(), &_tags())
+                .await
+                .unwrap();
+
+            wallet
+                .add(_type1(), _id2(), _value2(), &_tags())
+                .await
+                .unwrap();
+
+            // Store pending keys and re-encrypt the first record as the interrupted rotation would
+            let new_keys = Keys::new();
+
+            let mut metadata: Metadata =
+                serde_json::from_slice(&wallet.storage.get_storage_metadata().await.unwrap())
+                    .unwrap();
+
+            metadata.set_pending_keys(Some(new_keys.serialize_encrypted(&_master_key()).unwrap()));
+            wallet._set_metadata(&metadata).await.unwrap();
+
+            let old_keys = wallet.keys().await.unwrap().clone();
+
+            let operations = vec![
+                Wallet::_encrypt_operation(
+                    &WalletOperation::Delete {
+                        type_: _type1().to_string(),
+                        id: _id1().to_string(),
+                    },
+                    &old_keys,
+                ),
+                Wallet::_encrypt_operation(
+                    &WalletOperation::Add {
+                        type_: _type1().to_string(),
+                        id: _id1().to_string(),
+                        value: _value1().to_string(),
+                        tags: _tags(),
+                    },
+                    &new_keys,
+                ),
+            ];
+
+            wallet.storage.batch(&operations).await.unwrap();
+
+            let progress = wallet.rotate_keys(&_master_key(), 10).await.unwrap();
+            assert!(progress.completed);
+
+            for (id, value) in &[(_id1(), _value1()), (_id2(), _value2())] {
+                let record = wallet
+                    .get(_type1(), id, &_fetch_options(true, true, true), &metrics)
+                    .await
+                    .unwrap();
+
+                assert_eq!(record.get_value().unwrap(), *value);
+                assert_eq!(record.get_tags().unwrap(), &_tags());
+            }
+
+            wallet.close().unwrap();
+        }
+
+        test::cleanup_wallet("wallet_rotate_keys_works_for_interrupted_rotation");
+    }
+
+    #[async_std::test]
+    #[cfg(feature = "mysql")]
+    async fn wallet_rotate_keys_works_for_mysql() {
+        _mysql_cleanup_wallet("wallet_rotate_keys_works_for_mysql").await;
+
+        {
+            let mut wallet = _mysql_wallet("wallet_rotate_keys_works_for_mysql").await;
+            let metrics = WalletCacheHitMetrics::new();
+
+            wallet
+                .add(_type1(), _id1(), _value1(), &_tags())
+                .await
+                .unwrap();
+
+            wallet
+                .add(_type1(), _id2(), _value2(), &_tags())
+                .await
+                .unwrap();
+
+            let progress = wallet.rotate_keys(&_master_key(), 1).await.unwrap();
+            assert!(progress.completed);
+
+            let record = wallet
+                .get(_type1(), _id2(), &_fetch_options(true, true, true), &metrics)
+                .await
+                .unwrap();
+
+            assert_eq!(record.get_value().unwrap(), _value2());
+            assert_eq!(record.get_tags().unwrap(), &_tags());
+
+            let mut iterator = wallet
+                .search(
+                    _type1(),
+                    &jsonstr!({"tag1": "tag_value_1"}),
+                    Some(&_search_options(true, false, false, false, false)),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(_fetch_all(&mut iterator).await.len(), 2);
+
+            wallet.close().unwrap();
+        }
+
+        _mysql_cleanup_wallet("wallet_rotate_keys_works_for_mysql").await;
+    }
+
     fn _type1() -> &'static str {
         "type1"
     }
@@ -3666,6 +4176,7 @@ mod tests {
             let metadata = Metadata::MetadataArgon(MetadataArgon {
                 master_key_salt: master_key_salt[..].to_vec(),
                 keys: keys.serialize_encrypted(&master_key).unwrap(),
+                pending_keys: None,
             });
 
             serde_json::to_vec(&metadata).unwrap()
@@ -3678,7 +4189,12 @@ mod tests {
 
         let storage = storage_type.open_storage(name, None, None).await.unwrap();
 
-        Wallet::new(name.to_string(), storage, Arc::new(keys), WalletCache::new(None))
+        Wallet::new(
+            name.to_string(),
+            storage,
+            Arc::new(keys),
+            WalletCache::new(None),
+        )
     }
 
     async fn _mysql_wallet(name: &str) -> Wallet {
@@ -3693,6 +4209,7 @@ mod tests {
             let metadata = Metadata::MetadataArgon(MetadataArgon {
                 master_key_salt: master_key_salt[..].to_vec(),
                 keys: keys.serialize_encrypted(&master_key).unwrap(),
+                pending_keys: None,
             });
 
             serde_json::to_vec(&metadata).unwrap()
@@ -3708,7 +4225,12 @@ mod tests {
             .await
             .unwrap();
 
-        Wallet::new(name.to_string(), storage, Arc::new(keys), WalletCache::new(None))
+        Wallet::new(
+            name.to_string(),
+            storage,
+            Arc::new(keys),
+            WalletCache::new(None),
+        )
     }
 
     async fn _exists_wallet(name: &str) -> Wallet {
@@ -3723,7 +4245,12 @@ mod tests {
         let master_key = _master_key();
         let keys = Keys::deserialize_encrypted(&metadata.keys, &master_key).unwrap();
 
-        Wallet::new(name.to_string(), storage, Arc::new(keys), WalletCache::new(None))
+        Wallet::new(
+            name.to_string(),
+            storage,
+            Arc::new(keys),
+            WalletCache::new(None),
+        )
     }
 
     fn _master_key() -> chacha20poly1305_ietf::Key {
@@ -7,7 +7,10 @@ use libc::c_char;
 
 use crate::{
     domain::{
-        anoncreds::{credential_definition::CredentialDefinitionId, schema::SchemaId},
+        anoncreds::{
+            credential_definition::CredentialDefinitionId,
+            revocation_registry_definition::RevocationRegistryId, schema::SchemaId,
+        },
         cache::{GetCacheOptions, PurgeOptions},
         crypto::did::DidValue,
     },
@@ -287,3 +290,635 @@ pub extern "C" fn indy_purge_schema_cache(
     debug!("indy_purge_schema_cache < {:?}", res);
     res
 }
+
+/// Gets NYM data for specified DID.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// did: target DID.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// NYM data json:
+/// {
+///     did: DID as base58-encoded string for 16 or 32 bit DID value.
+///     verkey: verification key as base58-encoded string.
+///     role: Role associated number
+/// }
+#[no_mangle]
+pub extern "C" fn indy_get_nym(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    submitter_did: *const c_char,
+    did: *const c_char,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, nym_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_nym > pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            did {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, did, options_json
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam5, DidValue);
+
+    check_useful_json!(
+        options_json,
+        ErrorCode::CommonInvalidParam6,
+        GetCacheOptions
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    debug!(
+        "indy_get_nym ? pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            did {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, did, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .get_nym(pool_handle, wallet_handle, submitter_did, did, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, nym) = prepare_result!(res, String::new());
+        debug!("indy_get_nym ? err {:?} nym {:?}", err, nym);
+
+        let nym = ctypes::string_to_cstring(nym);
+        cb(command_handle, err, nym.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandGetNym, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_nym < {:?}", res);
+    res
+}
+
+/// Gets attribute data for specified DID.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// Note: one of the fields `hash`, `raw`, `enc` must be specified.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// raw: (Optional) Requested attribute name.
+/// hash: (Optional) Requested attribute hash.
+/// enc: (Optional) Requested attribute encrypted value.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Attribute data as it is stored on the ledger.
+#[no_mangle]
+pub extern "C" fn indy_get_attrib(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    submitter_did: *const c_char,
+    target_did: *const c_char,
+    raw: *const c_char,
+    hash: *const c_char,
+    enc: *const c_char,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, attrib_data: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_attrib > pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            target_did {:?} raw {:?} hash {:?} enc {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, target_did, raw, hash, enc, options_json
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam5, DidValue);
+    check_useful_opt_c_str!(raw, ErrorCode::CommonInvalidParam6);
+    check_useful_opt_c_str!(hash, ErrorCode::CommonInvalidParam7);
+    check_useful_opt_c_str!(enc, ErrorCode::CommonInvalidParam8);
+
+    check_useful_json!(
+        options_json,
+        ErrorCode::CommonInvalidParam9,
+        GetCacheOptions
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam10);
+
+    debug!(
+        "indy_get_attrib ? pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            target_did {:?} raw {:?} hash {:?} enc {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, target_did, raw, hash, enc, options_json
+    );
+
+    if raw.is_none() && hash.is_none() && enc.is_none() {
+        return IndyError::from_msg(
+            IndyErrorKind::InvalidStructure,
+            "Either raw or hash or enc must be specified",
+        )
+        .into();
+    }
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .get_attrib(
+                pool_handle,
+                wallet_handle,
+                submitter_did,
+                target_did,
+                raw,
+                hash,
+                enc,
+                options_json,
+            )
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, attrib) = prepare_result!(res, String::new());
+        debug!("indy_get_attrib ? err {:?} attrib {:?}", err, attrib);
+
+        let attrib = ctypes::string_to_cstring(attrib);
+        cb(command_handle, err, attrib.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandGetAttrib, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_attrib < {:?}", res);
+    res
+}
+
+/// Gets revocation registry definition json data for specified revocation registry definition id.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// id: identifier of revocation registry definition.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation Registry Definition json.
+#[no_mangle]
+pub extern "C" fn indy_get_revoc_reg_def(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    submitter_did: *const c_char,
+    id: *const c_char,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            revoc_reg_def_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_revoc_reg_def > pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            id {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, id, options_json
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+
+    check_useful_json!(
+        options_json,
+        ErrorCode::CommonInvalidParam6,
+        GetCacheOptions
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    debug!(
+        "indy_get_revoc_reg_def ? pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            id {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, id, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .get_revoc_reg_def(pool_handle, wallet_handle, submitter_did, id, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, revoc_reg_def) = prepare_result!(res, String::new());
+        debug!(
+            "indy_get_revoc_reg_def ? err {:?} revoc_reg_def {:?}",
+            err, revoc_reg_def
+        );
+
+        let revoc_reg_def = ctypes::string_to_cstring(revoc_reg_def);
+        cb(command_handle, err, revoc_reg_def.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandGetRevocRegDef, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_revoc_reg_def < {:?}", res);
+    res
+}
+
+/// Gets revocation registry delta for specified revocation registry definition id and time interval.
+/// If the whole interval is present inside of cache, cached data is returned.
+/// If cached delta ends before `to`, only the missing part of the interval is fetched
+/// from the ledger and merged into the cached delta.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// id: identifier of revocation registry definition.
+/// from: Requested time represented as a total number of seconds from Unix Epoch (-1 means from the registry creation)
+/// to: Requested time represented as a total number of seconds from Unix Epoch
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation Registry Delta json and timestamp of its accumulator.
+#[no_mangle]
+pub extern "C" fn indy_get_revoc_reg_delta(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    submitter_did: *const c_char,
+    id: *const c_char,
+    from: i64,
+    to: i64,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            revoc_reg_delta_json: *const c_char,
+            timestamp: u64,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_revoc_reg_delta > pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            id {:?} from {:?} to {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, id, from, to, options_json
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+
+    check_useful_json!(
+        options_json,
+        ErrorCode::CommonInvalidParam8,
+        GetCacheOptions
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let from = if from != -1 { Some(from) } else { None };
+
+    debug!(
+        "indy_get_revoc_reg_delta ? pool_handle {:?} \
+            wallet_handle {:?} submitter_did {:?} \
+            id {:?} from {:?} to {:?} options_json {:?}",
+        pool_handle, wallet_handle, submitter_did, id, from, to, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .get_revoc_reg_delta(
+                pool_handle,
+                wallet_handle,
+                submitter_did,
+                id,
+                from,
+                to,
+                options_json,
+            )
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, (revoc_reg_delta, timestamp)) = prepare_result!(res, String::new(), 0);
+        debug!(
+            "indy_get_revoc_reg_delta ? err {:?} revoc_reg_delta {:?} timestamp {:?}",
+            err, revoc_reg_delta, timestamp
+        );
+
+        let revoc_reg_delta = ctypes::string_to_cstring(revoc_reg_delta);
+        cb(command_handle, err, revoc_reg_delta.as_ptr(), timestamp)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandGetRevocRegDelta, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_revoc_reg_delta < {:?}", res);
+    res
+}
+
+/// Purge NYM cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_nym_cache(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    options_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_purge_nym_cache > wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_purge_nym_cache ? wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .purge_nym_cache(wallet_handle, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_purge_nym_cache ? err {:?}", err);
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandPurgeNymCache, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_purge_nym_cache < {:?}", res);
+    res
+}
+
+/// Purge attribute cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_attrib_cache(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    options_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_purge_attrib_cache > wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_purge_attrib_cache ? wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .purge_attrib_cache(wallet_handle, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_purge_attrib_cache ? err {:?}", err);
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandPurgeAttribCache, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_purge_attrib_cache < {:?}", res);
+    res
+}
+
+/// Purge revocation registry definition cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_revoc_reg_def_cache(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    options_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_purge_revoc_reg_def_cache > wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_purge_revoc_reg_def_cache ? wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .purge_revoc_reg_def_cache(wallet_handle, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_purge_revoc_reg_def_cache ? err {:?}", err);
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandPurgeRevocRegDefCache, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_purge_revoc_reg_def_cache < {:?}", res);
+    res
+}
+
+/// Purge revocation registry delta cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_revoc_reg_delta_cache(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    options_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_purge_revoc_reg_delta_cache > wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_purge_revoc_reg_delta_cache ? wallet_handle {:?} \
+            options_json {:?}",
+        wallet_handle, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cache_controller
+            .purge_revoc_reg_delta_cache(wallet_handle, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_purge_revoc_reg_delta_cache ? err {:?}", err);
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::CacheCommandPurgeRevocRegDeltaCache, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_purge_revoc_reg_delta_cache < {:?}", res);
+    res
+}
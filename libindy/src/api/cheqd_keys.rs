@@ -3,6 +3,7 @@ use indy_utils::ctypes;
 use libc::c_char;
 
 use crate::Locator;
+use crate::domain::cheqd_keys::{MnemonicOptions, MultisigMember};
 use crate::services::CommandMetric;
 
 /// Creates keys (signing and encryption keys) for a new account.
@@ -65,13 +66,19 @@ pub extern "C" fn indy_cheqd_keys_add_random(
 }
 
 /// Creates keys (signing and encryption keys) for a new account.
+/// By default keys are derived from BIP-39 mnemonic by BIP-32/44 path `m/44'/118'/0'/0/0`
+/// the same way as Keplr and `cheqd-noded keys add --recover` do.
 /// #Params
 /// alias: alias for a new keys
 /// mnemonic: for generating keys
-/// Example:
+/// options: (optional) derivation options json
 /// {
-///     "alias": string
-///     "mnemonic": string
+///     "derivation_method": string, (optional) one of:
+///         "BIP44" - (default) BIP-39 seed derived by BIP-32/44 HD path
+///         "LEGACY_SHA3" - SHA3-256 of the mnemonic seeding random generator,
+///                         used for keys created before BIP44 support
+///     "hd_path": string, (optional) BIP-32 derivation path, "m/44'/118'/0'/0/0" by default
+///     "passphrase": string, (optional) BIP-39 passphrase, empty by default
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -91,22 +98,24 @@ pub extern "C" fn indy_cheqd_keys_add_from_mnemonic(
     wallet_handle: WalletHandle,
     alias: *const c_char,
     mnemonic: *const c_char,
+    options: *const c_char,
     cb: Option<
         extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, key_info: *const c_char),
     >,
 ) -> ErrorCode {
     debug!(
-        "indy_cheqd_keys_add_from_mnemonic > wallet_handle {:?} alias {:?}, mnemonic {:?} ",
-        wallet_handle, alias, mnemonic
+        "indy_cheqd_keys_add_from_mnemonic > wallet_handle {:?} alias {:?}, mnemonic {:?} options {:?}",
+        wallet_handle, alias, mnemonic, options
     );
 
     check_useful_c_str!(alias, ErrorCode::CommonInvalidParam1);
     check_useful_c_str!(mnemonic, ErrorCode::CommonInvalidParam2);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(options, ErrorCode::CommonInvalidParam3, MnemonicOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     debug!(
-        "indy_cheqd_keys_add_from_mnemonic > alias {:?}, mnemonic {:?} ",
-        alias, mnemonic
+        "indy_cheqd_keys_add_from_mnemonic > alias {:?}, mnemonic {:?} options {:?}",
+        alias, mnemonic, options
     );
 
     let locator = Locator::instance();
@@ -114,7 +123,7 @@ pub extern "C" fn indy_cheqd_keys_add_from_mnemonic(
     let action = async move {
         let res = locator
             .cheqd_keys_controller
-            .add_from_mnemonic(wallet_handle, &alias, &mnemonic)
+            .add_from_mnemonic(wallet_handle, &alias, &mnemonic, options)
             .await;
         res
     };
@@ -146,6 +155,8 @@ pub extern "C" fn indy_cheqd_keys_add_from_mnemonic(
 /// {
 ///     "alias": string
 /// }
+/// pool_alias: (optional) alias of cheqd pool which bech32 account prefix must be used for the address.
+///     Default "cheqd" prefix is used if not specified.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -163,21 +174,29 @@ pub extern "C" fn indy_cheqd_keys_get_info(
     command_handle: CommandHandle,
     wallet_handle: WalletHandle,
     alias: *const c_char,
+    pool_alias: *const c_char,
     cb: Option<
         extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, key_info: *const c_char),
     >,
 ) -> ErrorCode {
-    debug!("indy_cheqd_keys_key_info > wallet_handle {:?} alias {:?} ", wallet_handle, alias);
+    debug!(
+        "indy_cheqd_keys_key_info > wallet_handle {:?} alias {:?} pool_alias {:?}",
+        wallet_handle, alias, pool_alias
+    );
 
-    check_useful_c_str!(alias, ErrorCode::CommonInvalidParam1);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(alias, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(pool_alias, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
-    debug!("indy_cheqd_keys_key_info > alias {:?} ", alias);
+    debug!("indy_cheqd_keys_key_info > alias {:?} pool_alias {:?}", alias, pool_alias);
 
     let locator = Locator::instance();
 
     let action = async move {
-        let res = locator.cheqd_keys_controller.get_info(wallet_handle, &alias).await;
+        let res = locator
+            .cheqd_keys_controller
+            .get_info(wallet_handle, &alias, pool_alias.as_deref())
+            .await;
         res
     };
 
@@ -202,6 +221,8 @@ pub extern "C" fn indy_cheqd_keys_get_info(
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// wallet_handle: specific wallet
+/// pool_alias: (optional) alias of cheqd pool which bech32 account prefix must be used for addresses.
+///     Default "cheqd" prefix is used if not specified.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -216,20 +237,22 @@ pub extern "C" fn indy_cheqd_keys_get_info(
 pub extern "C" fn indy_cheqd_keys_get_list_keys(
     command_handle: CommandHandle,
     wallet_handle: WalletHandle,
+    pool_alias: *const c_char,
     cb: Option<
         extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, key_info: *const c_char),
     >,
 ) -> ErrorCode {
     debug!(
-        "indy_cheqd_keys_get_list_keys > wallet_handle {:?}",
-        wallet_handle
+        "indy_cheqd_keys_get_list_keys > wallet_handle {:?} pool_alias {:?}",
+        wallet_handle, pool_alias
     );
 
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(pool_alias, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     debug!(
-        "indy_cheqd_keys_get_list_keys ? wallet_handle {:?}",
-        wallet_handle
+        "indy_cheqd_keys_get_list_keys ? wallet_handle {:?} pool_alias {:?}",
+        wallet_handle, pool_alias
     );
 
     let locator = Locator::instance();
@@ -237,7 +260,7 @@ pub extern "C" fn indy_cheqd_keys_get_list_keys(
     let action = async move {
         let res = locator
             .cheqd_keys_controller
-            .get_list_keys(wallet_handle)
+            .get_list_keys(wallet_handle, pool_alias.as_deref())
             .await;
         res
     };
@@ -328,3 +351,207 @@ pub extern "C" fn indy_cheqd_keys_sign(
     debug!("indy_cheqd_keys_sign < {:?}", res);
     res
 }
+
+/// Generates new BIP-39 mnemonic phrase (English word list).
+/// The phrase can be used later for creating keys by `indy_cheqd_keys_add_from_mnemonic`
+/// and for recovering the account in other Cosmos wallets.
+/// #Params
+/// words_count: number of words in the phrase: 12, 15, 18, 21 or 24
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   mnemonic: generated mnemonic phrase
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_keys_generate_mnemonic(
+    command_handle: CommandHandle,
+    words_count: u32,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, mnemonic: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!("indy_cheqd_keys_generate_mnemonic > words_count {:?}", words_count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator.cheqd_keys_controller.generate_mnemonic(words_count);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_keys_generate_mnemonic ? err {:?}", err);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdKeysGenerateMnemonic, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_keys_generate_mnemonic < {:?}", res);
+    res
+}
+
+/// Get info about multisig threshold account (Cosmos `LegacyAminoPubKey`) of several members.
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet containing member keys referenced by alias
+/// threshold: number of members which signatures are required to authorize a transaction
+/// members: json array of multisig members in the order of the multisig key
+/// [
+///     {"alias": string} - key stored in the wallet, or
+///     {"pub_key": string} - base58-encoded public key of a foreign member
+/// ]
+/// pool_alias: (optional) alias of cheqd pool which bech32 account prefix must be used for the address.
+///     Default "cheqd" prefix is used if not specified.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   multisig_info: json
+///   {
+///       "account_id": string - address of the multisig account,
+///       "threshold": u32,
+///       "pub_keys": [string] - base58-encoded public keys of members
+///   }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_keys_get_multisig_info(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    threshold: u32,
+    members: *const c_char,
+    pool_alias: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, multisig_info: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_keys_get_multisig_info > wallet_handle {:?} threshold {:?} members {:?} pool_alias {:?}",
+        wallet_handle, threshold, members, pool_alias
+    );
+
+    check_useful_json!(members, ErrorCode::CommonInvalidParam4, Vec<MultisigMember>);
+    check_useful_opt_c_str!(pool_alias, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!(
+        "indy_cheqd_keys_get_multisig_info ? wallet_handle {:?} threshold {:?} members {:?} pool_alias {:?}",
+        wallet_handle, threshold, members, pool_alias
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_keys_controller
+            .get_multisig_info(wallet_handle, threshold, members, pool_alias.as_deref())
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_keys_get_multisig_info ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdKeysGetMultisigInfo, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_keys_get_multisig_info < {:?}", res);
+    res
+}
+
+/// Sign transaction of multisig account by one of its members.
+/// Signatures of all members are combined later by `indy_cheqd_ledger_auth_combine_multisig_signatures`.
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet containing the member key
+/// alias: alias of the member key
+/// tx_raw: SignDoc built by `indy_cheqd_ledger_auth_build_multisig_tx`
+/// tx_len: length of SignDoc
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   signature: base58-encoded signature of the member
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_keys_sign_multisig_partial(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    alias: *const c_char,
+    tx_raw: *const u8,
+    tx_len: u32,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, signature: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_keys_sign_multisig_partial > wallet_handle {:?} alias {:?} tx_raw {:?} tx_len {:?}",
+        wallet_handle, alias, tx_raw, tx_len
+    );
+
+    check_useful_c_str!(alias, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(
+        tx_raw,
+        tx_len,
+        ErrorCode::CommonInvalidParam4,
+        ErrorCode::CommonInvalidParam5
+    );
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    debug!("indy_cheqd_keys_sign_multisig_partial > alias {:?} ", alias);
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_keys_controller
+            .sign_multisig_partial(wallet_handle, &alias, &tx_raw)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_keys_sign_multisig_partial ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdKeysSignMultisigPartial, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_keys_sign_multisig_partial < {:?}", res);
+    res
+}
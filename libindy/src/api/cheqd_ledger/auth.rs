@@ -1,5 +1,9 @@
-use indy_api_types::{errors::prelude::*, CommandHandle, ErrorCode};
+use indy_api_types::{errors::prelude::*, validation::Validatable, CommandHandle, ErrorCode};
 
+use std::collections::HashMap;
+
+use crate::domain::cheqd_keys::MultisigKeyInfo;
+use crate::domain::cheqd_ledger::tx::FeeOptions;
 use crate::services::CommandMetric;
 use crate::Locator;
 use indy_utils::ctypes;
@@ -131,6 +135,347 @@ pub extern "C" fn indy_cheqd_ledger_auth_build_tx(
     res
 }
 
+/// Build txn before sending. Gas limit and fee of the txn are estimated
+/// by simulating its execution on the ledger, so the caller doesn't have to guess them.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: string alias of a pool
+/// sender_public_key: public key of sender
+/// msg_raw: message in raw format,
+/// msg_len: length of message,
+/// account_number: number of accounts,
+/// sequence_number: how many txns are already written,
+/// coin_denom: which kind of coins user is going to pay fee in,
+/// timeout_height: block height until which the transaction is valid,
+/// memo: a note or comment to send with the transaction,
+/// fee_options: (optional) options for the fee estimation
+/// {
+///     "gas_price": float, (optional) price of gas unit in coin_denom. 0.025 by default
+///     "gas_adjustment": float, (optional) multiplier applied to the simulated gas. 1.3 by default
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_auth_build_tx_with_fee_estimation(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    sender_public_key: *const c_char,
+    msg_raw: *const u8,
+    msg_len: u32,
+    account_number: u64,
+    sequence_number: u64,
+    coin_denom: *const c_char,
+    timeout_height: u64,
+    memo: *const c_char,
+    fee_options: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            tx_raw: *const u8,
+            tx_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_auth_build_tx_with_fee_estimation > pool_alias {:?} sender_public_key {:?} \
+        msg_raw {:?} msg_len {:?} account_number {:?} sequence_number {:?} coin_denom {:?} \
+        timeout_height {:?} memo {:?} fee_options {:?}",
+        pool_alias,
+        sender_public_key,
+        msg_raw,
+        msg_len,
+        account_number,
+        sequence_number,
+        coin_denom,
+        timeout_height,
+        memo,
+        fee_options
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(sender_public_key, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(
+        msg_raw,
+        msg_len,
+        ErrorCode::CommonInvalidParam4,
+        ErrorCode::CommonInvalidParam5
+    );
+    check_useful_c_str!(coin_denom, ErrorCode::CommonInvalidParam8);
+    check_useful_c_str!(memo, ErrorCode::CommonInvalidParam10);
+    check_useful_opt_validatable_json!(fee_options, ErrorCode::CommonInvalidParam11, FeeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam12);
+
+    debug!(
+        "indy_cheqd_ledger_auth_build_tx_with_fee_estimation > pool_alias {:?} sender_public_key {:?} \
+        msg_raw {:?} account_number {:?} sequence_number {:?} coin_denom {:?} \
+        timeout_height {:?} memo {:?} fee_options {:?}",
+        pool_alias,
+        sender_public_key,
+        msg_raw,
+        account_number,
+        sequence_number,
+        coin_denom,
+        timeout_height,
+        memo,
+        fee_options
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .auth_build_tx_with_fee_estimation(
+                &pool_alias,
+                &sender_public_key,
+                &msg_raw,
+                account_number,
+                sequence_number,
+                &coin_denom,
+                timeout_height,
+                &memo,
+                fee_options,
+            )
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, tx) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_auth_build_tx_with_fee_estimation ? err {:?} tx {:?}", err, tx);
+
+        let (tx_raw, tx_len) = ctypes::vec_to_pointer(&tx);
+        cb(command_handle, err, tx_raw, tx_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildTxWithFeeEstimation,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_auth_build_tx_with_fee_estimation < {:?}", res);
+    res
+}
+
+
+/// Build txn of multisig account before collecting signatures of its members.
+/// Members which are going to sign the txn are fixed at this point.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: string alias of a pool
+/// multisig_info: info of multisig account returned by `indy_cheqd_keys_get_multisig_info`
+/// signers: json array with base58-encoded public keys of members which are going to sign the txn.
+///     Number of signers must satisfy the threshold of the account.
+/// msg_raw: message in raw format,
+/// msg_len: length of message,
+/// account_number: number of accounts,
+/// sequence_number: how many txns are already written,
+/// max_gas: how much gas user is ready to pay.,
+/// max_coin_amount: how many coins user can pay,
+/// max_coin_denom: which kink of coins user is ready to pay,
+/// timeout_height: block height until which the transaction is valid,
+/// memo: a note or comment to send with the transaction,
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// SignDoc to be signed by every signer with `indy_cheqd_keys_sign_multisig_partial`.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_auth_build_multisig_tx(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    multisig_info: *const c_char,
+    signers: *const c_char,
+    msg_raw: *const u8,
+    msg_len: u32,
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: *const c_char,
+    timeout_height: u64,
+    memo: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            tx_raw: *const u8,
+            tx_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_auth_build_multisig_tx > pool_alias {:?} multisig_info {:?} signers {:?} \
+        msg_raw {:?} msg_len {:?} account_number {:?} sequence_number {:?} max_gas {:?} \
+        max_coin_amount {:?} max_coin_denom {:?} timeout_height {:?} memo {:?}",
+        pool_alias,
+        multisig_info,
+        signers,
+        msg_raw,
+        msg_len,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_json!(multisig_info, ErrorCode::CommonInvalidParam3, MultisigKeyInfo);
+    check_useful_json!(signers, ErrorCode::CommonInvalidParam4, Vec<String>);
+    check_useful_c_byte_array!(
+        msg_raw,
+        msg_len,
+        ErrorCode::CommonInvalidParam5,
+        ErrorCode::CommonInvalidParam6
+    );
+    check_useful_c_str!(max_coin_denom, ErrorCode::CommonInvalidParam11);
+    check_useful_c_str!(memo, ErrorCode::CommonInvalidParam13);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam14);
+
+    debug!(
+        "indy_cheqd_ledger_auth_build_multisig_tx > pool_alias {:?} multisig_info {:?} signers {:?} \
+        msg_raw {:?} account_number {:?} sequence_number {:?} max_gas {:?} \
+        max_coin_amount {:?} max_coin_denom {:?} timeout_height {:?} memo {:?}",
+        pool_alias,
+        multisig_info,
+        signers,
+        msg_raw,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .auth_build_multisig_tx(
+                &pool_alias,
+                multisig_info,
+                signers,
+                &msg_raw,
+                account_number,
+                sequence_number,
+                max_gas,
+                max_coin_amount,
+                &max_coin_denom,
+                timeout_height,
+                &memo,
+            )
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, tx) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_auth_build_multisig_tx ? err {:?} tx {:?}", err, tx);
+
+        let (tx_raw, tx_len) = ctypes::vec_to_pointer(&tx);
+        cb(command_handle, err, tx_raw, tx_len)
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdLedgerCommandBuildMultisigTx, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_auth_build_multisig_tx < {:?}", res);
+    res
+}
+
+/// Combine signatures of multisig account members into txn ready for broadcasting.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// tx_raw: SignDoc built by `indy_cheqd_ledger_auth_build_multisig_tx`
+/// tx_len: length of SignDoc
+/// signatures: json object with signatures returned by `indy_cheqd_keys_sign_multisig_partial`
+/// {
+///     <base58-encoded public key of member>: <base58-encoded signature>
+/// }
+/// Signature of every member specified as signer while building the txn is required.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signed txn in raw format
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_auth_combine_multisig_signatures(
+    command_handle: CommandHandle,
+    tx_raw: *const u8,
+    tx_len: u32,
+    signatures: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            signed_raw: *const u8,
+            signed_len: u32,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_auth_combine_multisig_signatures > tx_raw {:?} tx_len {:?} signatures {:?}",
+        tx_raw, tx_len, signatures
+    );
+
+    check_useful_c_byte_array!(
+        tx_raw,
+        tx_len,
+        ErrorCode::CommonInvalidParam2,
+        ErrorCode::CommonInvalidParam3
+    );
+    check_useful_json!(signatures, ErrorCode::CommonInvalidParam4, HashMap<String, String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_cheqd_ledger_auth_combine_multisig_signatures > tx_raw {:?} signatures {:?}",
+        tx_raw, signatures
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .auth_combine_multisig_signatures(&tx_raw, signatures);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, signed) = prepare_result!(res, Vec::new());
+        debug!("indy_cheqd_ledger_auth_combine_multisig_signatures ? err {:?} signed {:?}", err, signed);
+
+        let (signed_raw, signed_len) = ctypes::vec_to_pointer(&signed);
+        cb(command_handle, err, signed_raw, signed_len)
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandCombineMultisigSignatures,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_auth_combine_multisig_signatures < {:?}", res);
+    res
+}
 
 /// Build query for getting info about account.
 ///
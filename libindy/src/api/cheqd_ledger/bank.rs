@@ -9,6 +9,7 @@ use crate::services::CommandMetric;
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
+/// pool_alias: alias of cheqd pool. Addresses must have the bech32 account prefix of this pool.
 /// from: address of sender coins
 /// to: address of getter coins
 /// amount: Amount of coins for sending
@@ -20,6 +21,7 @@ use crate::services::CommandMetric;
 #[no_mangle]
 pub extern "C" fn indy_cheqd_ledger_bank_build_msg_send(
     command_handle: CommandHandle,
+    pool_alias: *const c_char,
     from: *const c_char,
     to: *const c_char,
     amount: *const c_char,
@@ -34,19 +36,20 @@ pub extern "C" fn indy_cheqd_ledger_bank_build_msg_send(
     >,
 ) -> ErrorCode {
     debug!(
-        "indy_cheqd_ledger_bank_build_msg_send > from {:?} to {:?} amount {:?} denom {:?}",
-        from, to, amount, denom
+        "indy_cheqd_ledger_bank_build_msg_send > pool_alias {:?} from {:?} to {:?} amount {:?} denom {:?}",
+        pool_alias, from, to, amount, denom
     );
 
-    check_useful_c_str!(from, ErrorCode::CommonInvalidParam2);
-    check_useful_c_str!(to, ErrorCode::CommonInvalidParam3);
-    check_useful_c_str!(amount, ErrorCode::CommonInvalidParam4);
-    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam5);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(from, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(to, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(amount, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
 
     debug!(
-        "indy_cheqd_ledger_bank_build_msg_send > did {:?} creator {:?} verkey {:?} alias {:?}",
-        from, to, amount, denom
+        "indy_cheqd_ledger_bank_build_msg_send > pool_alias {:?} from {:?} to {:?} amount {:?} denom {:?}",
+        pool_alias, from, to, amount, denom
     );
 
     let locator = Locator::instance();
@@ -54,7 +57,8 @@ pub extern "C" fn indy_cheqd_ledger_bank_build_msg_send(
     let action = async move {
         let res = locator
             .cheqd_ledger_controller
-            .bank_build_msg_send(&from, &to, &amount, &denom);
+            .bank_build_msg_send(&pool_alias, &from, &to, &amount, &denom)
+            .await;
         res
     };
 
@@ -143,6 +147,7 @@ pub extern "C" fn indy_cheqd_ledger_bank_parse_msg_send_resp(
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
+/// pool_alias: alias of cheqd pool. Address must have the bech32 account prefix of this pool.
 /// address: address of account which need to get.
 /// denom: currency of balance for getting.
 /// cb: Callback that takes command result as parameter.
@@ -152,6 +157,7 @@ pub extern "C" fn indy_cheqd_ledger_bank_parse_msg_send_resp(
 #[no_mangle]
 pub extern "C" fn indy_cheqd_ledger_bank_build_query_balance(
     command_handle: CommandHandle,
+    pool_alias: *const c_char,
     address: *const c_char,
     denom: *const c_char,
     cb: Option<
@@ -159,16 +165,17 @@ pub extern "C" fn indy_cheqd_ledger_bank_build_query_balance(
     >,
 ) -> ErrorCode {
     debug!(
-        "indy_cheqd_ledger_bank_build_query_balance > address {:?} denom {:?}",
-        address, denom
+        "indy_cheqd_ledger_bank_build_query_balance > pool_alias {:?} address {:?} denom {:?}",
+        pool_alias, address, denom
     );
-    check_useful_c_str!(address, ErrorCode::CommonInvalidParam2);
-    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam3);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(address, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(denom, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     debug!(
-        "indy_cheqd_ledger_bank_build_query_balance > address {:?} denom {:?}",
-        address, denom
+        "indy_cheqd_ledger_bank_build_query_balance > pool_alias {:?} address {:?} denom {:?}",
+        pool_alias, address, denom
     );
 
     let locator = Locator::instance();
@@ -176,7 +183,8 @@ pub extern "C" fn indy_cheqd_ledger_bank_build_query_balance(
     let action = async move {
         let res = locator
             .cheqd_ledger_controller
-            .bank_build_query_balance(address, denom);
+            .bank_build_query_balance(&pool_alias, address, denom)
+            .await;
         res
     };
 
@@ -631,3 +631,81 @@ pub extern "C" fn indy_cheqd_ledger_cheqd_parse_query_all_nym_resp(
     debug!("indy_cheqd_ledger_cheqd_parse_query_all_nym_resp < {:?}", res);
     res
 }
+
+/// Resolves W3C DID Document for the DID written on Cheqd ledger.
+/// Request for getting NYM is built, sent to the pool and its state proof is verified.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: alias of the Cheqd pool to query.
+/// did: fully qualified DID in form `did:cheqd:<network>:<nym id>`.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// DID resolution result json
+/// {
+///     "didDocument": {
+///         "@context": [string],
+///         "id": string,
+///         "verificationMethod": [{"id": string, "type": "Ed25519VerificationKey2018", "controller": string, "publicKeyBase58": string}],
+///         "authentication": [string],
+///         "assertionMethod": [string],
+///     },
+///     "didResolutionMetadata": {"contentType": "application/did+ld+json"},
+///     "didDocumentMetadata": {"alias": string, "role": string, "creator": string}, (alias and role are optional)
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_cheqd_resolve_did_document(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    did: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resolution_result: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_cheqd_resolve_did_document > pool_alias {:?} did {:?}",
+        pool_alias, did
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_cheqd_resolve_did_document > pool_alias {:?} did {:?}",
+        pool_alias, did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .cheqd_resolve_did_document(&pool_alias, &did)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, resolution_result) = prepare_result!(res, String::new());
+        debug!(
+            "indy_cheqd_ledger_cheqd_resolve_did_document: resolution_result: {:?}",
+            resolution_result
+        );
+        let resolution_result = ctypes::string_to_cstring(resolution_result);
+        cb(command_handle, err, resolution_result.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandResolveDidDocument,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_cheqd_resolve_did_document < {:?}", res);
+    res
+}
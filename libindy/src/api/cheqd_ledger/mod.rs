@@ -1,3 +1,4 @@
 pub mod auth;
 pub mod cheqd;
 pub mod bank;
+pub mod tx;
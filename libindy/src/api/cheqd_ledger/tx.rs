@@ -0,0 +1,203 @@
+use indy_api_types::{errors::prelude::*, validation::Validatable, CommandHandle, ErrorCode};
+use indy_utils::ctypes;
+use libc::c_char;
+
+use crate::domain::cheqd_ledger::tx::FeeOptions;
+use crate::services::CommandMetric;
+use crate::Locator;
+
+/// Build query for simulating execution of the txn.
+/// Result of the simulation contains amount of gas used by the txn.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// tx_raw: txn built by `indy_cheqd_ledger_auth_build_tx` in raw format. It doesn't need to be signed.
+/// tx_len: length of txn,
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_tx_build_query_simulate(
+    command_handle: CommandHandle,
+    tx_raw: *const u8,
+    tx_len: u32,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, query: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_tx_build_query_simulate > tx_raw {:?} tx_len {:?}",
+        tx_raw, tx_len
+    );
+
+    check_useful_c_byte_array!(
+        tx_raw,
+        tx_len,
+        ErrorCode::CommonInvalidParam2,
+        ErrorCode::CommonInvalidParam3
+    );
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!("indy_cheqd_ledger_tx_build_query_simulate > tx_raw {:?}", tx_raw);
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .tx_build_query_simulate(&tx_raw);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, query) = prepare_result!(res, String::new());
+        debug!(
+            "indy_cheqd_ledger_tx_build_query_simulate: query: {:?}",
+            query
+        );
+
+        let query = ctypes::string_to_cstring(query);
+        cb(command_handle, err, query.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandBuildQuerySimulate,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_tx_build_query_simulate < {:?}", res);
+    res
+}
+
+/// Parse response for simulating execution of the txn.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// query_resp: response of the simulation query.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+/// {
+///     "gas_info": {
+///         "gas_wanted": u64,
+///         "gas_used": u64
+///     }
+/// }
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_tx_parse_query_simulate_resp(
+    command_handle: CommandHandle,
+    query_resp: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, resp: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_tx_parse_query_simulate_resp > query_resp {:?}",
+        query_resp
+    );
+
+    check_useful_c_str!(query_resp, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_cheqd_ledger_tx_parse_query_simulate_resp > query_resp {:?}",
+        query_resp
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .tx_parse_query_simulate_resp(&query_resp);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, resp) = prepare_result!(res, String::new());
+        debug!(
+            "indy_cheqd_ledger_tx_parse_query_simulate_resp: resp: {:?}",
+            resp
+        );
+
+        let resp = ctypes::string_to_cstring(resp);
+        cb(command_handle, err, resp.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandParseQuerySimulateResp,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_tx_parse_query_simulate_resp < {:?}", res);
+    res
+}
+
+/// Suggest gas limit and fee for the txn from the amount of gas used in its simulation.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// gas_used: amount of gas used in the simulation of the txn.
+/// fee_options: (optional) options for the fee estimation
+/// {
+///     "gas_price": float, (optional) price of gas unit. 0.025 by default
+///     "gas_adjustment": float, (optional) multiplier applied to the simulated gas. 1.3 by default
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Success or error message.
+/// {
+///     "gas_used": u64,
+///     "gas_limit": u64, - value for `max_gas` of `indy_cheqd_ledger_auth_build_tx`
+///     "amount": u64, - value for `max_coin_amount` of `indy_cheqd_ledger_auth_build_tx`
+/// }
+#[no_mangle]
+pub extern "C" fn indy_cheqd_ledger_tx_estimate_fee(
+    command_handle: CommandHandle,
+    gas_used: u64,
+    fee_options: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, fee: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_ledger_tx_estimate_fee > gas_used {:?} fee_options {:?}",
+        gas_used, fee_options
+    );
+
+    check_useful_opt_validatable_json!(fee_options, ErrorCode::CommonInvalidParam3, FeeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_ledger_tx_estimate_fee > gas_used {:?} fee_options {:?}",
+        gas_used, fee_options
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_ledger_controller
+            .tx_estimate_fee(gas_used, fee_options);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, fee) = prepare_result!(res, String::new());
+        debug!("indy_cheqd_ledger_tx_estimate_fee: fee: {:?}", fee);
+
+        let fee = ctypes::string_to_cstring(fee);
+        cb(command_handle, err, fee.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::CheqdLedgerCommandEstimateFee,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_ledger_tx_estimate_fee < {:?}", res);
+    res
+}
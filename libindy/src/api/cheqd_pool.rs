@@ -1,5 +1,6 @@
-use indy_api_types::{errors::prelude::*, CommandHandle, ErrorCode};
+use indy_api_types::{errors::prelude::*, validation::Validatable, CommandHandle, ErrorCode};
 
+use crate::domain::cheqd_pool::{ConnectionOptions, TrustOptions};
 use crate::services::CommandMetric;
 use crate::Locator;
 use indy_utils::ctypes;
@@ -11,6 +12,26 @@ use libc::c_char;
 /// alias: name of a pool
 /// rpc_address: address for making remote calls
 /// chain_id: name of network
+/// account_prefix: (optional) bech32 human-readable prefix of network accounts ("cheqd" by default)
+/// trust_options: (optional) header trusted by the light client verifying query proofs.
+///     If not set, proofs are checked only for consistency with the app hash returned by the node.
+///     {
+///         "height": <u64> - height of the trusted header,
+///         "hash": <string> - hex encoded hash of the trusted header,
+///         "trusting_period": Optional<u64> - period in seconds validators can be trusted for (2 weeks by default),
+///         "clock_drift": Optional<u64> - allowed clock drift in seconds (5 by default),
+///         "trust_level": Optional<{"numerator": u64, "denominator": u64}> - voting power
+///             of trusted validators needed to skip headers during bisection (1/3 by default),
+///     }
+/// connection_options: (optional) how requests are sent to the nodes of the pool.
+///     {
+///         "backup_rpc_addresses": Optional<[string]> - addresses used if `rpc_address` doesn't respond,
+///         "timeout": Optional<u64> - timeout of a single request in seconds (20 by default),
+///         "max_retries": Optional<u32> - how many times all nodes are retried after connection errors (3 by default),
+///         "retry_backoff": Optional<u64> - delay in milliseconds before the first retry,
+///             it is doubled for each next one (500 by default),
+///     }
+///     The node that answered the last request is asked first until it fails.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -27,23 +48,29 @@ pub extern "C" fn indy_cheqd_pool_add(
     alias: *const c_char,
     rpc_address: *const c_char,
     chain_id: *const c_char,
+    account_prefix: *const c_char,
+    trust_options: *const c_char,
+    connection_options: *const c_char,
     cb: Option<
         extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, pool_info: *const c_char),
     >,
 ) -> ErrorCode {
     debug!(
-        "indy_cheqd_pool_add > alias {:?} rpc_address {:?} chain_id {:?}",
-        alias, rpc_address, chain_id
+        "indy_cheqd_pool_add > alias {:?} rpc_address {:?} chain_id {:?} account_prefix {:?} trust_options {:?} connection_options {:?}",
+        alias, rpc_address, chain_id, account_prefix, trust_options, connection_options
     );
 
     check_useful_c_str!(alias, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(rpc_address, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(chain_id, ErrorCode::CommonInvalidParam4);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(account_prefix, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_validatable_json!(trust_options, ErrorCode::CommonInvalidParam6, TrustOptions);
+    check_useful_opt_validatable_json!(connection_options, ErrorCode::CommonInvalidParam7, ConnectionOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
 
     debug!(
-        "indy_cheqd_pool_add > alias {:?} rpc_address {:?} chain_id {:?}",
-        alias, rpc_address, chain_id
+        "indy_cheqd_pool_add > alias {:?} rpc_address {:?} chain_id {:?} account_prefix {:?} trust_options {:?} connection_options {:?}",
+        alias, rpc_address, chain_id, account_prefix, trust_options, connection_options
     );
 
     let locator = Locator::instance();
@@ -51,7 +78,7 @@ pub extern "C" fn indy_cheqd_pool_add(
     let action = async move {
         let res = locator
             .cheqd_pool_controller
-            .add(&alias, &rpc_address, &chain_id)
+            .add(&alias, &rpc_address, &chain_id, account_prefix.as_deref(), trust_options, connection_options)
             .await;
         res
     };
@@ -264,6 +291,235 @@ pub extern "C" fn indy_cheqd_pool_broadcast_tx_commit(
     res
 }
 
+/// Send broadcast transaction to the whole pool
+/// and wait only for the check of transaction by a node (CheckTx).
+/// Inclusion of transaction into a block can be checked by `indy_cheqd_pool_get_tx`.
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// signed_tx_raw: signed transaction in the raw format
+/// signed_tx_len: length of signed transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Structure TxSyncResponse with `hash` of transaction
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_broadcast_tx_sync(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    signed_tx_raw: *const u8,
+    signed_tx_len: u32,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            tx_sync_response: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_broadcast_tx_sync > pool_alias {:?} signed_tx_raw {:?} signed_tx_len {:?}",
+        pool_alias, signed_tx_raw, signed_tx_len
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_byte_array!(
+        signed_tx_raw,
+        signed_tx_len,
+        ErrorCode::CommonInvalidParam3,
+        ErrorCode::CommonInvalidParam4
+    );
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_cheqd_pool_broadcast_tx_sync > pool_alias {:?} signed_tx_raw {:?} signed_tx_len {:?}",
+        pool_alias, signed_tx_raw, signed_tx_len
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .broadcast_tx_sync(&pool_alias, &signed_tx_raw)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, tx_sync_response) = prepare_result!(res, String::new());
+        debug!(
+            "indy_cheqd_pool_broadcast_tx_sync ? err {:?} tx_sync_response {:?}",
+            err, tx_sync_response
+        );
+
+        let tx_sync_response = ctypes::string_to_cstring(tx_sync_response);
+        cb(command_handle, err, tx_sync_response.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandBroadcastTxSync, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_broadcast_tx_sync < {:?}", res);
+    res
+}
+
+/// Send broadcast transaction to the whole pool without waiting for any check of transaction.
+/// Inclusion of transaction into a block can be checked by `indy_cheqd_pool_get_tx`.
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// signed_tx_raw: signed transaction in the raw format
+/// signed_tx_len: length of signed transaction
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Structure TxAsyncResponse with `hash` of transaction
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_broadcast_tx_async(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    signed_tx_raw: *const u8,
+    signed_tx_len: u32,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            tx_async_response: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_broadcast_tx_async > pool_alias {:?} signed_tx_raw {:?} signed_tx_len {:?}",
+        pool_alias, signed_tx_raw, signed_tx_len
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_byte_array!(
+        signed_tx_raw,
+        signed_tx_len,
+        ErrorCode::CommonInvalidParam3,
+        ErrorCode::CommonInvalidParam4
+    );
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_cheqd_pool_broadcast_tx_async > pool_alias {:?} signed_tx_raw {:?} signed_tx_len {:?}",
+        pool_alias, signed_tx_raw, signed_tx_len
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .broadcast_tx_async(&pool_alias, &signed_tx_raw)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, tx_async_response) = prepare_result!(res, String::new());
+        debug!(
+            "indy_cheqd_pool_broadcast_tx_async ? err {:?} tx_async_response {:?}",
+            err, tx_async_response
+        );
+
+        let tx_async_response = ctypes::string_to_cstring(tx_async_response);
+        cb(command_handle, err, tx_async_response.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandBroadcastTxAsync, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_broadcast_tx_async < {:?}", res);
+    res
+}
+
+/// Get committed transaction by hash
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_alias: name of a pool
+/// tx_hash: hex encoded hash of transaction returned by broadcast functions
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///   Structure TxResponse. It can be passed to parse functions of messages
+///   like the response of `indy_cheqd_pool_broadcast_tx_commit`.
+///
+/// #Errors
+/// Common*
+/// LedgerNotFound - transaction isn't committed yet
+#[no_mangle]
+pub extern "C" fn indy_cheqd_pool_get_tx(
+    command_handle: CommandHandle,
+    pool_alias: *const c_char,
+    tx_hash: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, tx_response: *const c_char)>,
+) -> ErrorCode {
+    debug!(
+        "indy_cheqd_pool_get_tx > pool_alias {:?} tx_hash {:?}",
+        pool_alias, tx_hash
+    );
+
+    check_useful_c_str!(pool_alias, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(tx_hash, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_cheqd_pool_get_tx > pool_alias {:?} tx_hash {:?}",
+        pool_alias, tx_hash
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .cheqd_pool_controller
+            .get_tx(&pool_alias, &tx_hash)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, tx_response) = prepare_result!(res, String::new());
+        debug!(
+            "indy_cheqd_pool_get_tx ? err {:?} tx_response {:?}",
+            err, tx_response
+        );
+
+        let tx_response = ctypes::string_to_cstring(tx_response);
+        cb(command_handle, err, tx_response.as_ptr())
+    };
+
+    locator
+        .executor
+        .spawn_ok_instrumented(CommandMetric::CheqdPoolCommandGetTx, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_cheqd_pool_get_tx < {:?}", res);
+    res
+}
+
 /// Send general ABCI request
 /// #Params
 /// command_handle: command handle to map callback to caller context.
@@ -271,6 +527,9 @@ pub extern "C" fn indy_cheqd_pool_broadcast_tx_commit(
 /// req_json: string of ABCI query in json format
 /// cb: Callback that takes command result as parameter.
 ///
+/// If the pool has trust options and the query requests a proof, the proof is verified
+/// against the app hash of the header verified by the light client.
+///
 /// #Returns
 /// Error Code
 /// cb:
@@ -279,6 +538,7 @@ pub extern "C" fn indy_cheqd_pool_broadcast_tx_commit(
 ///
 /// #Errors
 /// Common*
+/// Ledger*
 #[no_mangle]
 pub extern "C" fn indy_cheqd_pool_abci_query(
     command_handle: CommandHandle,
@@ -42,6 +42,7 @@ use crate::services::CommandMetric;
 ///               currently only 'ed25519' value is supported for this field)
 ///     "cid": bool, (optional; if not set then false is used;)
 ///     "method_name": string, (optional) method name to create fully qualified did.
+///                    did:indy method must include ledger namespace with optional sub-namespace: `indy:sovrin:staging`.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -981,6 +982,7 @@ pub extern "C" fn indy_abbreviate_verkey(
 /// wallet_handle: Wallet handle (created by open_wallet).
 /// did: target DID stored in the wallet.
 /// method: method to apply to the DID.
+///         did:indy method must include ledger namespace with optional sub-namespace: `indy:sovrin:staging`.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -1044,3 +1046,87 @@ pub extern "C" fn indy_qualify_did(
     debug!("indy_qualify_did < {:?}", res);
     res
 }
+
+/// Resolves W3C DID Document for the DID written on Indy ledger.
+/// Verification method is built from NYM transaction and service from ATTRIB `endpoint`.
+/// Supported DID methods are `sov` and `indy`, unqualified DIDs are treated as `did:sov`.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// pool_handle: Pool handle (created by open_pool).
+/// did: DID to resolve.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - resolution_result: DID resolution result json
+/// {
+///     "didDocument": {
+///         "@context": [string],
+///         "id": string, // fully qualified DID
+///         "verificationMethod": [{"id": string, "type": "Ed25519VerificationKey2018", "controller": string, "publicKeyBase58": string}],
+///         "authentication": [string],
+///         "assertionMethod": [string],
+///         "service": [{"id": string, "type": "endpoint", "serviceEndpoint": string, "recipientKeys": [string]}], (optional)
+///     },
+///     "didResolutionMetadata": {"contentType": "application/did+ld+json"},
+///     "didDocumentMetadata": {"role": string}, (role is optional)
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern "C" fn indy_resolve_did_document(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    did: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            resolution_result: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_resolve_did_document > pool_handle {:?} did {:?}",
+        pool_handle, did
+    );
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_resolve_did_document ? pool_handle {:?} did {:?}",
+        pool_handle, did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .did_controller
+            .resolve_did_document(pool_handle, did)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_resolve_did_document ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::DidCommandResolveDidDocument, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_resolve_did_document < {:?}", res);
+    res
+}
@@ -5,6 +5,11 @@ use indy_api_types::{
 use indy_utils::ctypes;
 use libc::c_char;
 use serde_json;
+use std::{
+    ffi::{CStr, CString},
+    ptr,
+    sync::Arc,
+};
 
 use crate::{
     domain::{
@@ -17,9 +22,13 @@ use crate::{
         crypto::did::DidValue,
         ledger::{
             auth_rule::{AuthRules, Constraint},
-            author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData},
+            author_agreement::{
+                AcceptanceMechanisms, GetTxnAuthorAgreementData, TxnAuthorAgreementAcceptance,
+            },
+            bundle::{TransactionBundle, TransactionBundleOptions},
             node::NodeOperationData,
             pool::Schedule,
+            txn::{LedgerReplicaTxnsFilter, ReplicateLedgerOptions},
         },
     },
     Locator,
@@ -171,6 +180,250 @@ pub extern "C" fn indy_submit_request(
     res
 }
 
+/// Publishes a batch of read requests to validator pool with a single pool command.
+///
+/// Every request is processed as if it was sent by indy_submit_request (including consensus
+/// and state proof verification), but all of them share pool connections.
+/// Failure of one request doesn't affect the others.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// requests_json: array of read requests data json. Only GET_* requests and requests
+///                with a registered state proof parser are accepted.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Array of results in the same order as requests:
+/// [
+///     {"response": string - request result json} or
+///     {"error": {"code": <int> - error code, "message": string - error description}},
+///     ...
+/// ]
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_submit_requests_batch(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    requests_json: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            results_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_submit_requests_batch > pool_handle {:?} requests_json {:?}",
+        pool_handle, requests_json
+    );
+
+    check_useful_json!(requests_json, ErrorCode::CommonInvalidParam3, Vec<serde_json::Value>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_submit_requests_batch? pool_handle {:?} requests_json {:?}",
+        pool_handle, requests_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .submit_requests_batch(pool_handle, requests_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_submit_requests_batch ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandSubmitRequestsBatch, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_submit_requests_batch < {:?}", res);
+    res
+}
+
+/// Replicates DOMAIN or CONFIG ledger of the pool into a local append-only store.
+///
+/// Transactions are fetched with GET_TXN requests starting right after the last stored one,
+/// so replication can be interrupted and resumed later. A transaction is stored only if the
+/// replica extended with it is consistent (checked with a merkle tree consistency proof)
+/// with the multi-signed ledger root returned by the pool.
+///
+/// The replica is kept in the indy home directory and isn't removed with the pool config.
+/// Concurrent calls for the same pool and ledger are performed one after another.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// ledger_type: type of the ledger to replicate: "DOMAIN" or "CONFIG".
+/// options_json: (Optional) replication options:
+///     {
+///         "batchSize": (Optional) <int> - count of GET_TXN requests sent at once (100 by default),
+///         "maxTxns": (Optional) <int> - max count of transactions fetched by this call (all by default),
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Replication status json:
+/// {
+///     "ledgerType": string - replicated ledger,
+///     "fromSeqNo": <int> - sequence number replication has been started from,
+///     "seqNo": <int> - sequence number of the last stored transaction,
+///     "rootHash": string - base58 merkle root of the replica (null if it's empty),
+///     "ledgerSize": <int> - ledger size confirmed by the pool (null if no transactions were fetched),
+///     "signedRootHash": string - multi-signed base58 merkle root of the ledger of "ledgerSize",
+///     "complete": bool - whether the replica has caught up with the ledger
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_replicate_ledger(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    ledger_type: *const c_char,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            status_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_replicate_ledger > pool_handle {:?} ledger_type {:?} options_json {:?}",
+        pool_handle, ledger_type, options_json
+    );
+
+    check_useful_c_str!(ledger_type, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam4, ReplicateLedgerOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let options_json = options_json.unwrap_or_default();
+
+    debug!(
+        "indy_replicate_ledger ? pool_handle {:?} ledger_type {:?} options_json {:?}",
+        pool_handle, ledger_type, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .replicate_ledger(pool_handle, ledger_type, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_replicate_ledger ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandReplicateLedger, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_replicate_ledger < {:?}", res);
+    res
+}
+
+/// Reads transactions from the local replica of DOMAIN or CONFIG ledger (see indy_replicate_ledger).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_name: name of the pool ledger configuration the replica has been made for.
+/// ledger_type: type of the replicated ledger: "DOMAIN" or "CONFIG".
+/// filter_json: (Optional) transactions filter:
+///     {
+///         "from": (Optional) <int> - first sequence number (1 by default),
+///         "to": (Optional) <int> - last sequence number (last stored by default),
+///         "authors": (Optional) [string] - return only transactions authored or endorsed by these DIDs,
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// {
+///     "seqNo": <int> - sequence number of the last stored transaction,
+///     "rootHash": string - base58 merkle root of the replica (null if it's empty),
+///     "txns": [{"txn": {..}, "txnMetadata": {..}, "ver": string, "reqSignature": {..}}, ...]
+/// }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_get_ledger_replica_txns(
+    command_handle: CommandHandle,
+    pool_name: *const c_char,
+    ledger_type: *const c_char,
+    filter_json: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            txns_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_ledger_replica_txns > pool_name {:?} ledger_type {:?} filter_json {:?}",
+        pool_name, ledger_type, filter_json
+    );
+
+    check_useful_c_str!(pool_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(ledger_type, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(filter_json, ErrorCode::CommonInvalidParam4, LedgerReplicaTxnsFilter);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let filter_json = filter_json.unwrap_or_default();
+
+    debug!(
+        "indy_get_ledger_replica_txns ? pool_name {:?} ledger_type {:?} filter_json {:?}",
+        pool_name, ledger_type, filter_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .get_ledger_replica_txns(pool_name, ledger_type, filter_json);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_get_ledger_replica_txns ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandGetLedgerReplicaTxns, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_ledger_replica_txns < {:?}", res);
+    res
+}
+
 /// Send action to particular nodes of validator pool.
 ///
 /// The list of requests can be send:
@@ -2457,9 +2710,57 @@ pub type CustomTransactionParser =
 /// Callback type to deallocate result buffer `parsed_sp` from `CustomTransactionParser`
 pub type CustomFree = extern "C" fn(data: *const c_char) -> ErrorCode;
 
-// FIXME:
+/// Native counterpart of `CustomTransactionParser` and `CustomFree` callbacks
+/// for applications which link libindy as a Rust library.
+pub use crate::services::StateProofParser;
+
+/// Adapts `CustomTransactionParser` and `CustomFree` callbacks registered through C API
+/// to `StateProofParser`.
+struct CustomStateProofParser {
+    parser: CustomTransactionParser,
+    free: CustomFree,
+}
+
+impl StateProofParser for CustomStateProofParser {
+    fn parse(&self, reply_from_node: &str) -> IndyResult<String> {
+        let reply_from_node = CString::new(reply_from_node)
+            .to_indy(IndyErrorKind::InvalidStructure, "Node reply contains null byte")?;
+
+        let mut parsed_sp: *const c_char = ptr::null();
+        let err = (self.parser)(reply_from_node.as_ptr(), &mut parsed_sp);
+
+        if err != ErrorCode::Success {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Custom StateProof parser returned error {:?}", err),
+            ));
+        }
+
+        if parsed_sp.is_null() {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Custom StateProof parser returned null",
+            ));
+        }
+
+        let res = unsafe { CStr::from_ptr(parsed_sp) }
+            .to_str()
+            .map(String::from)
+            .to_indy(IndyErrorKind::InvalidState, "Custom StateProof parser returned invalid utf8 string");
+
+        let err = (self.free)(parsed_sp);
+        trace!("CustomStateProofParser::parse: free res {:?}", err);
+
+        res
+    }
+}
+
 /// Register callbacks (see type description for `CustomTransactionParser` and `CustomFree`
 ///
+/// Reads of transactions of the registered type are sent to a single node
+/// and accepted if the reply contains valid state proof and multi-signature
+/// (instead of waiting for f+1 equal replies).
+///
 /// # params
 /// command_handle: command handle to map callback to caller context.
 /// txn_type: type of transaction to apply `parse` callback.
@@ -2472,41 +2773,70 @@ pub type CustomFree = extern "C" fn(data: *const c_char) -> ErrorCode;
 ///
 /// # errors
 /// Common*
-// #[no_mangle]
-// pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHandle,
-//                                                       txn_type: *const c_char,
-//                                                       parser: Option<CustomTransactionParser>,
-//                                                       free: Option<CustomFree>,
-//                                                       cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
-//     debug!("indy_register_transaction_parser_for_sp > txn_type {:?} parser {:?} free {:?}",
-//            txn_type, parser, free);
-
-//     check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam2);
-//     check_useful_c_callback!(parser, ErrorCode::CommonInvalidParam3);
-//     check_useful_c_callback!(free, ErrorCode::CommonInvalidParam4);
-//     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
-
-//     debug!("indy_register_transaction_parser_for_sp: entities: txn_type {}, parser {:?} free {:?}",
-//            txn_type, parser, free);
-
-//     let res = CommandExecutor::instance()
-//         .send(Command::Ledger(LedgerCommand::RegisterSPParser(
-//             txn_type,
-//             parser,
-//             free,
-//             Box::new(move |res| {
-//                 let res = prepare_result!(res);
-//                 debug!("indy_register_transaction_parser_for_sp: res {:?}", res);
-//                 cb(command_handle, res)
-//             }),
-//         )));
-
-//     let res = prepare_result!(res);
-
-//     debug!("indy_register_transaction_parser_for_sp < {:?}", res);
-
-//     res
-// }
+#[no_mangle]
+pub extern "C" fn indy_register_transaction_parser_for_sp(
+    command_handle: CommandHandle,
+    txn_type: *const c_char,
+    parser: Option<CustomTransactionParser>,
+    free: Option<CustomFree>,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_register_transaction_parser_for_sp > txn_type {:?} parser {:?} free {:?}",
+        txn_type, parser, free
+    );
+
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(parser, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(free, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_register_transaction_parser_for_sp? txn_type {:?} parser {:?} free {:?}",
+        txn_type, parser, free
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let parser = Arc::new(CustomStateProofParser { parser, free });
+
+        let res = locator
+            .ledger_controller
+            .register_sp_parser(txn_type, parser);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_register_transaction_parser_for_sp ? err {:?}", err);
+
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandRegisterSPParser, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_register_transaction_parser_for_sp < {:?}", res);
+    res
+}
+
+/// Register native StateProof parser for transaction type (see `StateProofParser`).
+///
+/// Rust counterpart of `indy_register_transaction_parser_for_sp`.
+pub fn register_transaction_parser_for_sp(
+    txn_type: &str,
+    parser: Arc<dyn StateProofParser>,
+) -> IndyResult<()> {
+    debug!("register_transaction_parser_for_sp > txn_type {:?}", txn_type);
+
+    let res = Locator::instance()
+        .ledger_controller
+        .register_sp_parser(txn_type.to_string(), parser);
+
+    debug!("register_transaction_parser_for_sp < {:?}", res);
+    res
+}
 
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
@@ -3377,50 +3707,59 @@ pub extern "C" fn indy_append_txn_author_agreement_acceptance_to_request(
     res
 }
 
-/// Append Endorser to an existing request.
-///
-/// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
-/// But it is expecting that the transaction will be sent by the specified Endorser.
-///
-/// Note: Both Transaction Author and Endorser must sign output request after that.
+/// Stores transaction author agreement acceptance for the pool in the wallet.
 ///
-/// More about Transaction Endorser: https://github.com/hyperledger/indy-node/blob/master/design/transaction_endorser.md
-///                                  https://github.com/hyperledger/indy-sdk/blob/master/docs/configuration.md
+/// indy_sign_and_submit_request appends the stored acceptance to DOMAIN write requests
+/// sent to the pool with this name if the ledger requires the transaction author agreement
+/// and "taa_acceptance" isn't set in the pool open config.
+/// The acceptance is checked against the active agreement and acceptance mechanisms of the ledger
+/// fetched by the first such request and cached until the pool refresh (see indy_get_pool_txn_author_agreement).
 ///
 /// #Params
-/// request_json: original request
-/// endorser_did: DID of the Endorser that will submit the transaction.
-///               The Endorser's DID must be present on the ledger.
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// pool_name: name of the pool ledger configuration.
+/// acceptance_json: transaction author agreement acceptance json
+///     {
+///         "text": string (optional), "version": string (optional) - accepted TAA (passed together),
+///         "taa_digest": string (optional) - digest of the accepted TAA (required if text and version are omitted),
+///         "mechanism": string - acceptance mechanism from the active AML,
+///         "time": int - UTC timestamp of the acceptance. Note that the time portion will be discarded to avoid a privacy risk.
+///     }
 /// cb: Callback that takes command result as parameter.
-///     The command result is a request JSON with Endorser field appended.
+///
+/// #Returns
+/// Error code
 ///
 /// #Errors
 /// Common*
+/// Wallet*
 #[no_mangle]
-pub extern "C" fn indy_append_request_endorser(
+pub extern "C" fn indy_store_txn_author_agreement_acceptance(
     command_handle: CommandHandle,
-    request_json: *const c_char,
-    endorser_did: *const c_char,
-    cb: Option<
-        extern "C" fn(
-            command_handle_: CommandHandle,
-            err: ErrorCode,
-            out_request_json: *const c_char,
-        ),
-    >,
+    wallet_handle: WalletHandle,
+    pool_name: *const c_char,
+    acceptance_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
 ) -> ErrorCode {
     debug!(
-        "indy_append_request_endorser > request_json {:?} endorser_did {:?}",
-        request_json, endorser_did
+        "indy_store_txn_author_agreement_acceptance > wallet_handle {:?} \
+            pool_name {:?} acceptance_json {:?}",
+        wallet_handle, pool_name, acceptance_json
     );
 
-    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
-    check_useful_validatable_string!(endorser_did, ErrorCode::CommonInvalidParam3, DidValue);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(pool_name, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(
+        acceptance_json,
+        ErrorCode::CommonInvalidParam4,
+        TxnAuthorAgreementAcceptance
+    );
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     debug!(
-        "indy_append_request_endorser? request_json {:?}endorser_did {:?}",
-        request_json, endorser_did
+        "indy_store_txn_author_agreement_acceptance? wallet_handle {:?} \
+            pool_name {:?} acceptance_json {:?}",
+        wallet_handle, pool_name, acceptance_json
     );
 
     let locator = Locator::instance();
@@ -3428,12 +3767,161 @@ pub extern "C" fn indy_append_request_endorser(
     let action = async move {
         let res = locator
             .ledger_controller
-            .append_request_endorser(request_json, endorser_did);
+            .store_txn_author_agreement_acceptance(wallet_handle, pool_name, acceptance_json)
+            .await;
         res
     };
 
     let cb = move |res: IndyResult<_>| {
-        let (err, res) = prepare_result!(res, String::new());
+        let err = prepare_result!(res);
+
+        debug!(
+            "indy_store_txn_author_agreement_acceptance ? err {:?}",
+            err
+        );
+
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandStoreTxnAuthorAgreementAcceptance, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_store_txn_author_agreement_acceptance < {:?}", res);
+    res
+}
+
+/// Returns transaction author agreement and acceptance mechanisms active on the ledger.
+///
+/// The agreement is fetched by the first call or DOMAIN write with automatically appended acceptance
+/// and cached for the pool. It is fetched again after the pool refresh (see indy_refresh_pool_ledger)
+/// and when a request with automatically appended acceptance is rejected.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Active transaction author agreement json or null if the ledger doesn't require it:
+/// {
+///     "text": string - text of the agreement,
+///     "version": string - version of the agreement,
+///     "digest": string - digest of the agreement,
+///     "ratificationTs": int (optional) - ratification timestamp,
+///     "aml": object (optional) - active acceptance mechanisms,
+///     "amlVersion": string (optional) - version of the acceptance mechanisms,
+///     "amlContext": string (optional) - context of the acceptance mechanisms,
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_get_pool_txn_author_agreement(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, taa_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_pool_txn_author_agreement > pool_handle {:?}",
+        pool_handle
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_get_pool_txn_author_agreement? pool_handle {:?}",
+        pool_handle
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .get_txn_author_agreement(pool_handle)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+
+        debug!(
+            "indy_get_pool_txn_author_agreement ? err {:?} res {:?}",
+            err, res
+        );
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandGetPoolTxnAuthorAgreement, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_pool_txn_author_agreement < {:?}", res);
+    res
+}
+
+/// Append Endorser to an existing request.
+///
+/// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
+/// But it is expecting that the transaction will be sent by the specified Endorser.
+///
+/// Note: Both Transaction Author and Endorser must sign output request after that.
+///
+/// More about Transaction Endorser: https://github.com/hyperledger/indy-node/blob/master/design/transaction_endorser.md
+///                                  https://github.com/hyperledger/indy-sdk/blob/master/docs/configuration.md
+///
+/// #Params
+/// request_json: original request
+/// endorser_did: DID of the Endorser that will submit the transaction.
+///               The Endorser's DID must be present on the ledger.
+/// cb: Callback that takes command result as parameter.
+///     The command result is a request JSON with Endorser field appended.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_append_request_endorser(
+    command_handle: CommandHandle,
+    request_json: *const c_char,
+    endorser_did: *const c_char,
+    cb: Option<
+        extern "C" fn(
+            command_handle_: CommandHandle,
+            err: ErrorCode,
+            out_request_json: *const c_char,
+        ),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_append_request_endorser > request_json {:?} endorser_did {:?}",
+        request_json, endorser_did
+    );
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_validatable_string!(endorser_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_append_request_endorser? request_json {:?}endorser_did {:?}",
+        request_json, endorser_did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .append_request_endorser(request_json, endorser_did);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
         debug!("indy_append_request_endorser ? err {:?} res {:?}", err, res);
 
         let res = ctypes::string_to_cstring(res);
@@ -3446,3 +3934,482 @@ pub extern "C" fn indy_append_request_endorser(
     debug!("indy_append_request_endorser < {:?}", res);
     res
 }
+
+/// Builds a transaction bundle to collect signatures of the transaction author, the Endorser
+/// and other signers required by the ledger auth rules before submission.
+///
+/// The bundle can be passed between parties as JSON. Each of them signs it with indy_sign_transaction_bundle.
+/// The required number of signatures is derived from the auth rule constraints fetched from the ledger
+/// by GET_AUTH_RULE request.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: request to collect signatures for.
+///     Signatures already present in the request are verified with verkeys of the signers from the ledger
+///     and moved into the bundle.
+/// options_json: (optional) bundle options:
+/// {
+///     "signers": [string] - (optional) DIDs which must sign the request
+///                           in addition to the transaction author and the Endorser,
+///     "expiresAt": int - (optional) timestamp after which the bundle can't be signed and submitted,
+///     "authAction": string - (optional) auth rule action of the request: "ADD" (default) or "EDIT",
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction bundle json:
+/// {
+///     "ver": "1",
+///     "request": object - request without signatures,
+///     "requiredSigners": [string] - DIDs which must sign the request,
+///     "requiredSignatureCount": int - number of signatures required by the ledger auth rule of the request,
+///     "constraint": object (optional) - auth rule constraint of the request,
+///     "signatures": {did: {"verkey": string, "signature": string}} - collected signatures
+///                   with verkeys they were verified with,
+///     "expiresAt": int (optional) - expiration timestamp,
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_build_transaction_bundle(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    request_json: *const c_char,
+    options_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, bundle_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_transaction_bundle > pool_handle {:?} request_json {:?} options_json {:?}",
+        pool_handle, request_json, options_json
+    );
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+
+    check_useful_opt_validatable_json!(
+        options_json,
+        ErrorCode::CommonInvalidParam4,
+        TransactionBundleOptions
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_build_transaction_bundle? pool_handle {:?} request_json {:?} options_json {:?}",
+        pool_handle, request_json, options_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_transaction_bundle(pool_handle, request_json, options_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_build_transaction_bundle ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildTransactionBundle, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_transaction_bundle < {:?}", res);
+    res
+}
+
+/// Adds the signature of the signer to the transaction bundle.
+///
+/// Signatures already collected in the bundle are verified against its request before signing.
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// bundle_json: transaction bundle (see indy_build_transaction_bundle).
+/// signer_did: DID of the signer stored in the wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction bundle json with the signature added.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern "C" fn indy_sign_transaction_bundle(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    bundle_json: *const c_char,
+    signer_did: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, bundle_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_sign_transaction_bundle > wallet_handle {:?} bundle_json {:?} signer_did {:?}",
+        wallet_handle, bundle_json, signer_did
+    );
+
+    check_useful_validatable_json!(bundle_json, ErrorCode::CommonInvalidParam3, TransactionBundle);
+    check_useful_validatable_string!(signer_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_sign_transaction_bundle? wallet_handle {:?} bundle_json {:?} signer_did {:?}",
+        wallet_handle, bundle_json, signer_did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .sign_transaction_bundle(wallet_handle, bundle_json, signer_did)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_sign_transaction_bundle ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandSignTransactionBundle, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_sign_transaction_bundle < {:?}", res);
+    res
+}
+
+/// Returns which signatures are still missing in the transaction bundle.
+///
+/// Signatures of the bundle are verified against its request first. Whether signature keys
+/// belong to the signers on the ledger is checked only on submit (see indy_submit_transaction_bundle).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// bundle_json: transaction bundle (see indy_build_transaction_bundle).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction bundle status json:
+/// {
+///     "missingSigners": [string] - required signers which haven't signed the request yet,
+///     "missingSignatureCount": int - number of signatures still required by the ledger,
+///     "expired": bool - whether the bundle has expired,
+///     "ready": bool - whether the bundle can be submitted,
+/// }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_get_transaction_bundle_status(
+    command_handle: CommandHandle,
+    bundle_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, status_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_transaction_bundle_status > bundle_json {:?}",
+        bundle_json
+    );
+
+    check_useful_validatable_json!(bundle_json, ErrorCode::CommonInvalidParam2, TransactionBundle);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_get_transaction_bundle_status? bundle_json {:?}",
+        bundle_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .get_transaction_bundle_status(bundle_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_get_transaction_bundle_status ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandGetTransactionBundleStatus, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_transaction_bundle_status < {:?}", res);
+    res
+}
+
+/// Submits the request of the transaction bundle with all collected signatures to validator pool.
+///
+/// Each signature is verified against the request and the verkey of the signer from the ledger.
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// bundle_json: transaction bundle (see indy_build_transaction_bundle).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+///
+/// CommonInvalidState is returned if the bundle has expired or is missing signatures.
+#[no_mangle]
+pub extern "C" fn indy_submit_transaction_bundle(
+    command_handle: CommandHandle,
+    pool_handle: PoolHandle,
+    bundle_json: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_result_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_submit_transaction_bundle > pool_handle {:?} bundle_json {:?}",
+        pool_handle, bundle_json
+    );
+
+    check_useful_validatable_json!(bundle_json, ErrorCode::CommonInvalidParam3, TransactionBundle);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_submit_transaction_bundle? pool_handle {:?} bundle_json {:?}",
+        pool_handle, bundle_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .submit_transaction_bundle(pool_handle, bundle_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_submit_transaction_bundle ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandSubmitTransactionBundle, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_submit_transaction_bundle < {:?}", res);
+    res
+}
+
+/// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
+///
+/// Frozen ledgers can't be written to and are excluded from consensus and catch-up,
+/// which allows to drop abandoned plugin ledgers.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// ledgers_ids: list of ledgers IDs for freezing ledgers (json format).
+///     [u64]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_ledgers_freeze_request(
+    command_handle: CommandHandle,
+    submitter_did: *const c_char,
+    ledgers_ids: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_ledgers_freeze_request > submitter_did {:?} ledgers_ids {:?}",
+        submitter_did, ledgers_ids
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_json!(ledgers_ids, ErrorCode::CommonInvalidParam3, Vec<u64>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_build_ledgers_freeze_request? submitter_did {:?} ledgers_ids {:?}",
+        submitter_did, ledgers_ids
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_ledgers_freeze_request(submitter_did, ledgers_ids);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_build_ledgers_freeze_request ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildLedgersFreezeRequest, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_ledgers_freeze_request < {:?}", res);
+    res
+}
+
+/// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+/// frozen ledgers are defined by LEDGERS_FREEZE request.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: DID of the read request sender.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_get_frozen_ledgers_request(
+    command_handle: CommandHandle,
+    submitter_did: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, request_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_build_get_frozen_ledgers_request > submitter_did {:?}",
+        submitter_did
+    );
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_build_get_frozen_ledgers_request? submitter_did {:?}",
+        submitter_did
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .build_get_frozen_ledgers_request(submitter_did);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_build_get_frozen_ledgers_request ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandBuildGetFrozenLedgersRequest, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_build_get_frozen_ledgers_request < {:?}", res);
+    res
+}
+
+/// Parse a GET_FROZEN_LEDGERS response to get list of frozen ledgers.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_frozen_ledgers_response: response on GET_FROZEN_LEDGERS request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Frozen ledgers json (empty object if there are no frozen ledgers):
+/// {
+///     <ledger_id>: {
+///         "ledger": string - root hash of the ledger at the moment of freezing,
+///         "state": string - root hash of the ledger state at the moment of freezing,
+///         "seq_no": u64 - last sequence number of the ledger
+///     },
+///     ...
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_parse_get_frozen_ledgers_response(
+    command_handle: CommandHandle,
+    get_frozen_ledgers_response: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, frozen_ledgers_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_parse_get_frozen_ledgers_response > get_frozen_ledgers_response {:?}",
+        get_frozen_ledgers_response
+    );
+
+    check_useful_c_str!(get_frozen_ledgers_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!(
+        "indy_parse_get_frozen_ledgers_response? get_frozen_ledgers_response {:?}",
+        get_frozen_ledgers_response
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .ledger_controller
+            .parse_get_frozen_ledgers_response(get_frozen_ledgers_response);
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_parse_get_frozen_ledgers_response ? err {:?} res {:?}", err, res);
+
+        let res = ctypes::string_to_cstring(res);
+        cb(command_handle, err, res.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::LedgerCommandParseGetFrozenLedgersResponse, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_parse_get_frozen_ledgers_response < {:?}", res);
+    res
+}
@@ -1,6 +1,8 @@
 use indy_api_types::{
-    domain::wallet::Tags, errors::prelude::*, CommandHandle, ErrorCode, SearchHandle, WalletHandle,
-    INVALID_SEARCH_HANDLE,
+    domain::wallet::{Tags, WalletOperations},
+    errors::prelude::*,
+    validation::Validatable,
+    CommandHandle, ErrorCode, SearchHandle, WalletHandle, INVALID_SEARCH_HANDLE,
 };
 
 use indy_utils::ctypes;
@@ -454,6 +456,71 @@ pub extern "C" fn indy_get_wallet_record(
     res
 }
 
+/// Apply several non-secret record changes atomically.
+///
+/// Either all operations are stored or, if any of them fails, none of them is.
+/// Operations are applied in order, so a later operation can refer to a record added
+/// by an earlier one.
+///
+/// Note that plugged wallet storages don't support atomic batches.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// operations_json: list of operations as json:
+///   [
+///     {"op": "add", "type": <str>, "id": <str>, "value": <str>, "tags": (optional) {<tag_name>: <str>, ...}},
+///     {"op": "update", "type": <str>, "id": <str>, "value": <str>},
+///     {"op": "addTags", "type": <str>, "id": <str>, "tags": {<tag_name>: <str>, ...}},
+///     {"op": "updateTags", "type": <str>, "id": <str>, "tags": {<tag_name>: <str>, ...}},
+///     {"op": "deleteTags", "type": <str>, "id": <str>, "tagNames": [<str>, ...]},
+///     {"op": "delete", "type": <str>, "id": <str>},
+///   ]
+///   Tags follow the same rules as in indy_add_wallet_record.
+#[no_mangle]
+pub extern "C" fn indy_wallet_batch(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    operations_json: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_wallet_batch > wallet_handle {:?} operations_json {:?}",
+        wallet_handle, operations_json
+    );
+
+    check_useful_validatable_json!(operations_json, ErrorCode::CommonInvalidParam3, WalletOperations);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_wallet_batch ? wallet_handle {:?} operations_json {:?}",
+        wallet_handle, operations_json
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .non_secret_controller
+            .batch(wallet_handle, operations_json)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_wallet_batch ? err {:?}", err);
+
+        cb(command_handle, err);
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::NonSecretsCommandBatch, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_wallet_batch < {:?}", res);
+    res
+}
+
 /// Search for wallet records.
 ///
 /// Note instead of immediately returning of fetched records
@@ -478,6 +545,17 @@ pub extern "C" fn indy_get_wallet_record(
 ///    retrieveType: (optional, false by default) Retrieve record type,
 ///    retrieveValue: (optional, true by default) Retrieve record value,
 ///    retrieveTags: (optional, false by default) Retrieve record tags,
+///    offset: (optional) Count of matched records to skip,
+///    limit: (optional) Max count of records to return,
+///    sortBy: (optional) Sort records by the value of plaintext tag:
+///      {
+///        tagName: "~tagName", // name of plaintext tag
+///        order: (optional, "asc" by default) "asc" or "desc",
+///      }
+///      Records without the tag go after all others. Order without sortBy is storage specific but stable.
+///    cursor: (optional) Cursor returned by indy_fetch_wallet_search_next_records
+///      to continue search after the last fetched record. Records added or deleted between pages
+///      don't shift the next page. Must be used with the same type, query and sortBy. Can't be combined with offset.
 ///  }
 /// #Returns
 /// search_handle: Wallet search handle that can be used later
@@ -551,6 +629,7 @@ pub extern "C" fn indy_open_wallet_search(
 /// wallet records json:
 /// {
 ///   totalCount: <str>, // present only if retrieveTotalCount set to true
+///   cursor: <str>, // cursor to continue search from the next record, absent if all records are fetched
 ///   records: [{ // present only if retrieveRecords set to true
 ///       id: "Some id",
 ///       type: "Some type", // present only if retrieveType set to true
@@ -92,6 +92,17 @@ pub extern "C" fn indy_create_pool_ledger_config(
 ///     "number_read_nodes": int (optional) - the number of nodes to send read requests (2 by default)
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
+///     "taa_acceptance": object (optional) - transaction author agreement acceptance appended to write requests
+///         sent with indy_sign_and_submit_request if the ledger requires it. If omitted, the acceptance
+///         stored in the wallet with indy_store_txn_author_agreement_acceptance is used:
+///         {
+///             "text": string (optional), "version": string (optional) - accepted TAA (passed together),
+///             "taa_digest": string (optional) - digest of the accepted TAA (required if text and version are omitted),
+///             "mechanism": string - acceptance mechanism from the active AML,
+///             "time": int - UTC timestamp of the acceptance (rounded to the day)
+///         }
+///     "taa_change_policy": string (optional) - "fail" (default) or "warn". Whether indy_sign_and_submit_request fails
+///         or only logs a warning if the accepted TAA isn't the one active on the ledger.
 /// }
 ///
 /// #Returns
@@ -192,6 +203,206 @@ pub extern "C" fn indy_refresh_pool_ledger(
     res
 }
 
+/// Returns health of the opened pool as seen by this client.
+///
+/// Node statistics are collected from requests sent through the pool, so they reflect only
+/// the nodes that were actually contacted.
+///
+/// #Params
+/// handle: pool handle returned by indy_open_pool_ledger
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+/// status_json: {
+///     "state": string - state of the pool connection ("initialization", "gettingCatchupTarget",
+///                       "active", "syncCatchup", "terminated"),
+///     "merkleTreeSize": <int> - size of the last synced pool ledger (null if not synced yet),
+///     "merkleTreeRoot": string - base58 root hash of the last synced pool ledger (null if not synced yet),
+///     "f": <int> - number of faulty nodes the pool tolerates,
+///     "reachableNodes": <int> - nodes that are not blacklisted and answered after their last timeout.
+///                               Consensus is lost once more than f nodes are unreachable.
+///     "nodes": [{
+///         "alias": string - node alias,
+///         "address": string - node client address,
+///         "blacklisted": bool - node is excluded from the pool,
+///         "connected": bool - client has an open connection to the node,
+///         "lastReplyTime": <int> - unix time (in seconds) of the last message from the node (null if none),
+///         "latencyMs": <int> - rolling average of the reply time in milliseconds (null if unknown),
+///         "timeouts": <int> - number of requests the node didn't answer in time,
+///         "consecutiveTimeouts": <int> - timeouts since the last reply of the node,
+///     }]
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_get_pool_status(
+    command_handle: CommandHandle,
+    handle: PoolHandle,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, status_json: *const c_char)>,
+) -> ErrorCode {
+    debug!("indy_get_pool_status > handle {:?}", handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    debug!("indy_get_pool_status ? handle {:?}", handle);
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator.pool_controller.get_status(handle).await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, res) = prepare_result!(res, String::new());
+        debug!("indy_get_pool_status ? err {:?} res {:?}", err, res);
+
+        let status_json = ctypes::string_to_cstring(res);
+        cb(command_handle, err, status_json.as_ptr());
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandGetStatus, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_pool_status < {:?}", res);
+    res
+}
+
+/// Exports the pool ledger cache of the opened pool into a portable snapshot file.
+///
+/// The snapshot contains all pool ledger transactions known to the client and a GET_TXN reply
+/// for the last of them. The reply is checked as usual (audit path and multi-signature of the ledger root)
+/// and lets indy_import_pool_ledger_snapshot verify the snapshot without connecting to the pool.
+/// Call indy_refresh_pool_ledger before to export the most recent node list.
+///
+/// #Params
+/// handle: pool handle returned by indy_open_pool_ledger
+/// snapshot_path: path of the snapshot file to create.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_export_pool_ledger_snapshot(
+    command_handle: CommandHandle,
+    handle: PoolHandle,
+    snapshot_path: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_export_pool_ledger_snapshot > handle {:?} snapshot_path {:?}",
+        handle, snapshot_path
+    );
+
+    check_useful_c_str!(snapshot_path, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    debug!(
+        "indy_export_pool_ledger_snapshot ? handle {:?} snapshot_path {:?}",
+        handle, snapshot_path
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .pool_controller
+            .export_snapshot(handle, snapshot_path)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_export_pool_ledger_snapshot ? err {:?}", err);
+
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandExportSnapshot, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_export_pool_ledger_snapshot < {:?}", res);
+    res
+}
+
+/// Imports pool ledger snapshot created by indy_export_pool_ledger_snapshot.
+///
+/// If pool ledger configuration with this name doesn't exist it is created from config first.
+/// The snapshot is accepted only if:
+///     - the multi-signature of its proof is valid for the validators from the snapshot
+///     - the snapshot is consistent with the multi-signed ledger root of the proof
+///     - the snapshot extends the pool ledger of the configuration (genesis or cached transactions)
+/// After import the pool is opened with the node list from the snapshot, so only transactions written
+/// after the export are caught up. A snapshot older than the cached pool ledger is verified but not applied.
+///
+/// #Params
+/// config_name: Name of the pool ledger configuration.
+/// snapshot_path: path of the snapshot file.
+/// config (optional): Pool configuration json used if the configuration doesn't exist.
+///                    See indy_create_pool_ledger_config.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_import_pool_ledger_snapshot(
+    command_handle: CommandHandle,
+    config_name: *const c_char,
+    snapshot_path: *const c_char,
+    config: *const c_char,
+    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    debug!(
+        "indy_import_pool_ledger_snapshot > config_name {:?} snapshot_path {:?} config {:?}",
+        config_name, snapshot_path, config
+    );
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(snapshot_path, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(config, ErrorCode::CommonInvalidParam4, PoolConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_import_pool_ledger_snapshot ? config_name {:?} snapshot_path {:?} config {:?}",
+        config_name, snapshot_path, config
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .pool_controller
+            .import_snapshot(config_name, snapshot_path, config)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let err = prepare_result!(res);
+        debug!("indy_import_pool_ledger_snapshot ? err {:?}", err);
+
+        cb(command_handle, err)
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::PoolCommandImportSnapshot, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_import_pool_ledger_snapshot < {:?}", res);
+    res
+}
+
 /// Lists names of created pool ledgers
 ///
 /// #Params
@@ -8,6 +8,20 @@ use libc::c_char;
 use crate::Locator;
 use crate::services::CommandMetric;
 
+/// Create a VDR object which routes ledger requests to the ledgers registered for DID namespaces.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - handle: Handle to the created VDR object.
+///
+/// #Errors
+/// Common*
 #[no_mangle]
 pub extern "C" fn vdr_create(
     command_handle: CommandHandle,
@@ -21,13 +35,13 @@ pub extern "C" fn vdr_create(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .create_vdr()
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .create_vdr()
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, vdr_handle) = prepare_result!(res, INVALID_VDR_HANDLE);
@@ -40,13 +54,40 @@ pub extern "C" fn vdr_create(
         cb(command_handle, err, vdr_handle)
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandCreateVdr, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandCreateVdr, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_create > {:?}", res);
+    debug!("vdr_create < {:?}", res);
     res
 }
 
+/// Register Indy ledger in the VDR object.
+/// The pool for the ledger is created from the passed genesis transactions and opened.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// namespace_list: list of namespaces which should be resolved by the ledger.
+///     Example: ["sov", "indy:sovrin"]
+/// genesis_txn_data: genesis transactions of the ledger pool (new line separated).
+/// taa_config: accepted transaction author agreement (`{}` or `null` if the ledger does not require it).
+///     {
+///         "text": Optional<string> - text of the accepted agreement,
+///         "version": Optional<string> - version of the accepted agreement,
+///         "taa_digest": Optional<string> - digest of the accepted agreement (used instead of text + version),
+///         "acc_mech_type": string - mechanism how the agreement was accepted,
+///         "time": u64 - UTC timestamp when the agreement was accepted
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Ledger*
 #[no_mangle]
 pub extern "C" fn vdr_register_indy_ledger(
     command_handle: CommandHandle,
@@ -73,13 +114,13 @@ pub extern "C" fn vdr_register_indy_ledger(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .register_indy_ledger(&namespace_list, &genesis_txn_data, &taa_config)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .register_indy_ledger(handle, &namespace_list, &genesis_txn_data, &taa_config)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<()>| {
         let err = prepare_result!(res);
@@ -89,13 +130,33 @@ pub extern "C" fn vdr_register_indy_ledger(
         cb(command_handle, err)
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandRegisterIndyLedger, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandRegisterIndyLedger, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_register_indy_ledger > {:?}", res);
+    debug!("vdr_register_indy_ledger < {:?}", res);
     res
 }
 
+/// Register Cheqd ledger in the VDR object.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// namespace_list: list of namespaces which should be resolved by the ledger.
+///     Example: ["cheqd:testnet"]
+/// chain_id: name of the network.
+/// node_addrs_list: list of RPC addresses of the ledger nodes.
+///     The first node is asked first, others are used if it doesn't respond.
+///     Example: ["http://127.0.0.1:26657"]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
 #[no_mangle]
 pub extern "C" fn vdr_register_cheqd_ledger(
     command_handle: CommandHandle,
@@ -122,13 +183,13 @@ pub extern "C" fn vdr_register_cheqd_ledger(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .register_cheqd_ledger(&namespace_list, &chain_id, &node_addrs_list)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .register_cheqd_ledger(handle, &namespace_list, &chain_id, &node_addrs_list)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<()>| {
         let err = prepare_result!(res);
@@ -138,13 +199,35 @@ pub extern "C" fn vdr_register_cheqd_ledger(
         cb(command_handle, err)
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandRegisterCheqdLedger, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandRegisterCheqdLedger, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_register_cheqd_ledger > {:?}", res);
+    debug!("vdr_register_cheqd_ledger < {:?}", res);
     res
 }
 
+/// Check the availability of the ledgers registered for the namespaces.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// namespace_list: list of namespaces to ping.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - status_list: status of the ledger for each namespace
+///     {
+///         "<namespace>": {
+///             "code": "SUCCESS" | "FAIL",
+///             "message": string
+///         }
+///     }
+///
+/// #Errors
+/// Common*
 #[no_mangle]
 pub extern "C" fn vdr_ping(
     command_handle: CommandHandle,
@@ -161,13 +244,13 @@ pub extern "C" fn vdr_ping(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .ping(&namespace_list)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .ping(handle, &namespace_list)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, status_list) = prepare_result!(res, String::new());
@@ -179,34 +262,48 @@ pub extern "C" fn vdr_ping(
         cb(command_handle, err, status_list.as_ptr())
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPing, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPing, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_ping > {:?}", res);
+    debug!("vdr_ping < {:?}", res);
     res
 }
 
+/// Close and remove all the ledgers registered in the VDR object and destroy the object.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
 #[no_mangle]
 pub extern "C" fn vdr_cleanup(
     command_handle: CommandHandle,
     handle: VdrHandle,
     cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>,
 ) -> ErrorCode {
-    debug!("vdr_cleanup >");
+    debug!("vdr_cleanup > handle {:?}", handle);
 
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    debug!("vdr_cleanup ?");
+    debug!("vdr_cleanup ? handle {:?}", handle);
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .cleanup()
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .cleanup(handle)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<()>| {
         let err = prepare_result!(res);
@@ -216,13 +313,32 @@ pub extern "C" fn vdr_cleanup(
         cb(command_handle, err)
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandCleanup, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandCleanup, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_cleanup > {:?}", res);
+    debug!("vdr_cleanup < {:?}", res);
     res
 }
 
+/// Resolve DID data from the ledger registered for the DID namespace.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// fqdid: fully qualified DID to resolve.
+/// cache_options: caching options (see `indy_get_schema` for details).
+///     Resolved entities are cached in memory of the VDR object until its cleanup.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - diddoc: resolved DID data.
+///
+/// #Errors
+/// Common*
+/// Ledger*
 #[no_mangle]
 pub extern "C" fn vdr_resolve_did(
     command_handle: CommandHandle,
@@ -247,13 +363,13 @@ pub extern "C" fn vdr_resolve_did(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .reslove_did(&fqdid, &cache_options)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .resolve_did(handle, &fqdid, &cache_options)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, diddoc) = prepare_result!(res, String::new());
@@ -265,13 +381,33 @@ pub extern "C" fn vdr_resolve_did(
         cb(command_handle, err, diddoc.as_ptr())
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandResolveDid, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandResolveDid, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_resolve_did > {:?}", res);
+    debug!("vdr_resolve_did < {:?}", res);
     res
 }
 
+/// Resolve Schema from the ledger registered for the namespace of the Schema issuer.
+/// Cheqd ledgers aren't supported as cheqd-node doesn't define Schema transactions.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// fqschema: fully qualified Schema ID.
+/// cache_options: caching options (see `indy_get_schema` for details).
+///     Resolved entities are cached in memory of the VDR object until its cleanup.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - schema: Schema json (see `indy_issuer_create_schema` for details).
+///
+/// #Errors
+/// Common*
+/// Ledger*
 #[no_mangle]
 pub extern "C" fn vdr_resolve_schema(
     command_handle: CommandHandle,
@@ -296,13 +432,13 @@ pub extern "C" fn vdr_resolve_schema(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .resolve_schema(&fqschema, &cache_options)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .resolve_schema(handle, &fqschema, &cache_options)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, schema) = prepare_result!(res, String::new());
@@ -314,13 +450,33 @@ pub extern "C" fn vdr_resolve_schema(
         cb(command_handle, err, schema.as_ptr())
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandResolveSchema, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandResolveSchema, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_resolve_schema > {:?}", res);
+    debug!("vdr_resolve_schema < {:?}", res);
     res
 }
 
+/// Resolve Credential Definition from the ledger registered for the namespace of the issuer.
+/// Cheqd ledgers aren't supported as cheqd-node doesn't define Credential Definition transactions.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// fqcreddef: fully qualified Credential Definition ID.
+/// cache_options: caching options (see `indy_get_cred_def` for details).
+///     Resolved entities are cached in memory of the VDR object until its cleanup.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - cred_def: Credential Definition json (see `indy_issuer_create_and_store_credential_def` for details).
+///
+/// #Errors
+/// Common*
+/// Ledger*
 #[no_mangle]
 pub extern "C" fn vdr_resolve_cred_def(
     command_handle: CommandHandle,
@@ -345,13 +501,13 @@ pub extern "C" fn vdr_resolve_cred_def(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .resolve_cred_def(&fqschema, &cache_options)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .resolve_cred_def(handle, &fqcreddef, &cache_options)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, cred_def) = prepare_result!(res, String::new());
@@ -363,13 +519,51 @@ pub extern "C" fn vdr_resolve_cred_def(
         cb(command_handle, err, cred_def.as_ptr())
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandResolveCredDef, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandResolveCredDef, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_resolve_cred_def > {:?}", res);
+    debug!("vdr_resolve_cred_def < {:?}", res);
     res
 }
 
+/// Prepare transaction creating a DID on the ledger registered for the submitter DID namespace.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// txn_specific_params: ledger specific params of the DID.
+///     Indy:
+///     {
+///         "dest": string - target DID,
+///         "verkey": Optional<string> - target identity verification key,
+///         "alias": Optional<string> - NYM's alias,
+///         "role": Optional<string> - role of the user NYM record
+///     }
+///     Cheqd:
+///     {
+///         "did": string, "creator": string, "verkey": string, "alias": Optional<string>, "role": Optional<string>,
+///         "sender_public_key": string, "account_number": u64, "sequence_number": u64,
+///         "max_gas": u64, "max_coin_amount": u64, "max_coin_denom": string,
+///         "timeout_height": u64, "memo": Optional<string>
+///     }
+/// submitter_did: fully qualified DID of the transaction author.
+/// endorser: (Optional) fully qualified DID of the transaction endorser.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - namespace: namespace of the ledger the transaction must be submitted to.
+/// - signature_spec: type of the signature the transaction must be signed with ("ed25519" or "secp256k1").
+/// - txn_bytes_raw: a pointer to first byte of the prepared transaction.
+/// - txn_bytes_len: the length of the prepared transaction.
+/// - bytes_to_sign_raw: a pointer to first byte of the data to sign.
+/// - bytes_to_sign_len: the length of the data to sign.
+/// - endorsement_spec: (Optional) json describing the required endorsement.
+///
+/// #Errors
+/// Common*
 #[no_mangle]
 pub extern "C" fn vdr_prepare_did(
     command_handle: CommandHandle,
@@ -404,13 +598,13 @@ pub extern "C" fn vdr_prepare_did(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .prepare_did(&txn_specific_params, &submitter_did, &endorser)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .prepare_did(handle, &txn_specific_params, &submitter_did, endorser.as_deref())
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, (namespace, signature_spec, txn_bytes, bytes_to_sign, endorsement_spec)) = prepare_result!(
@@ -443,13 +637,32 @@ pub extern "C" fn vdr_prepare_did(
         )
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPrepareDid, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPrepareDid, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_prepare_did > {:?}", res);
+    debug!("vdr_prepare_did < {:?}", res);
     res
 }
 
+/// Prepare transaction creating a Schema on the ledger registered for the submitter DID namespace.
+/// Cheqd ledgers aren't supported as cheqd-node doesn't define Schema transactions.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// txn_specific_params: Schema json (see `indy_issuer_create_schema` for details).
+/// submitter_did: fully qualified DID of the transaction author.
+/// endorser: (Optional) fully qualified DID of the transaction endorser.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - namespace, signature_spec, txn_bytes, bytes_to_sign, endorsement_spec (see `vdr_prepare_did` for details).
+///
+/// #Errors
+/// Common*
 #[no_mangle]
 pub extern "C" fn vdr_prepare_schema(
     command_handle: CommandHandle,
@@ -484,13 +697,13 @@ pub extern "C" fn vdr_prepare_schema(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .prepare_schema(&txn_specific_params, &submitter_did, &endorser)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .prepare_schema(handle, &txn_specific_params, &submitter_did, endorser.as_deref())
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, (namespace, signature_spec, txn_bytes, bytes_to_sign, endorsement_spec)) = prepare_result!(
@@ -523,13 +736,32 @@ pub extern "C" fn vdr_prepare_schema(
         )
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPrepareSchema, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPrepareSchema, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_prepare_schema > {:?}", res);
+    debug!("vdr_prepare_schema < {:?}", res);
     res
 }
 
+/// Prepare transaction creating a Credential Definition on the ledger registered for the submitter DID namespace.
+/// Cheqd ledgers aren't supported as cheqd-node doesn't define Credential Definition transactions.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// txn_specific_params: Credential Definition json (see `indy_issuer_create_and_store_credential_def` for details).
+/// submitter_did: fully qualified DID of the transaction author.
+/// endorser: (Optional) fully qualified DID of the transaction endorser.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - namespace, signature_spec, txn_bytes, bytes_to_sign, endorsement_spec (see `vdr_prepare_did` for details).
+///
+/// #Errors
+/// Common*
 #[no_mangle]
 pub extern "C" fn vdr_prepare_cred_def(
     command_handle: CommandHandle,
@@ -565,13 +797,13 @@ pub extern "C" fn vdr_prepare_cred_def(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .prepare_cred_def(&txn_specific_params, &submitter_did, &endorser)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .prepare_cred_def(handle, &txn_specific_params, &submitter_did, endorser.as_deref())
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, (namespace, signature_spec, txn_bytes, bytes_to_sign, endorsement_spec)) = prepare_result!(
@@ -604,13 +836,41 @@ pub extern "C" fn vdr_prepare_cred_def(
         )
     };
 
-    //locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPrepareCredDef, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandPrepareCredDef, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_prepare_cred_def > {:?}", res);
+    debug!("vdr_prepare_cred_def < {:?}", res);
     res
 }
 
+/// Submit signed transaction to the ledger registered for the namespace.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// namespace: namespace returned by `vdr_prepare_*` function.
+/// signature_spec: signature spec returned by `vdr_prepare_*` function.
+/// txn_bytes_raw: a pointer to first byte of the prepared transaction.
+/// txn_bytes_len: the length of the prepared transaction.
+/// signature_raw: a pointer to first byte of the signature of `bytes_to_sign`.
+/// signature_len: the length of the signature.
+/// endorsement: (Optional) endorsement json matching the endorsement spec.
+///     Indy:
+///     {
+///         "endorser_did": string - DID of the endorser,
+///         "signature": string - base58 encoded endorser signature of `bytes_to_sign`
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - response: ledger response.
+///
+/// #Errors
+/// Common*
+/// Ledger*
 #[no_mangle]
 pub extern "C" fn vdr_submit_txn(
     command_handle: CommandHandle,
@@ -653,13 +913,13 @@ pub extern "C" fn vdr_submit_txn(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .submit_txn(&prepared_txn, &signature_raw, &endorsement_raw)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .submit_txn(handle, &namespace, &signature_spec, &txn_bytes_raw, &signature_raw, endorsement.as_deref())
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, response) = prepare_result!(res, String::new());
@@ -671,13 +931,31 @@ pub extern "C" fn vdr_submit_txn(
         cb(command_handle, err, response.as_ptr())
     };
 
-    //locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandSubmitTxn, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandSubmitTxn, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_submit_txn > {:?}", res);
+    debug!("vdr_submit_txn < {:?}", res);
     res
 }
 
+/// Submit raw query to the ledger registered for the namespace.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: handle of the VDR object.
+/// namespace: namespace of the ledger.
+/// query: Indy request json or Cheqd ABCI query json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - err: Error code.
+/// - response: ledger response.
+///
+/// #Errors
+/// Common*
+/// Ledger*
 #[no_mangle]
 pub extern "C" fn vdr_submit_query(
     command_handle: CommandHandle,
@@ -702,13 +980,13 @@ pub extern "C" fn vdr_submit_query(
 
     let locator = Locator::instance();
 
-    // let action = async move {
-    //     let res = locator
-    //         .vdr_controller
-    //         .submit_query(handle, namespace, query)
-    //         .await;
-    //     res
-    // };
+    let action = async move {
+        let res = locator
+            .vdr_controller
+            .submit_query(handle, &namespace, &query)
+            .await;
+        res
+    };
 
     let cb = move |res: IndyResult<_>| {
         let (err, response) = prepare_result!(res, String::new());
@@ -720,9 +998,9 @@ pub extern "C" fn vdr_submit_query(
         cb(command_handle, err, response.as_ptr())
     };
 
-    // locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandSubmitTxn, action, cb);
+    locator.executor.spawn_ok_instrumented(CommandMetric::VdrCommandSubmitQuery, action, cb);
 
     let res = ErrorCode::Success;
-    debug!("vdr_submit_query > {:?}", res);
+    debug!("vdr_submit_query < {:?}", res);
     res
 }
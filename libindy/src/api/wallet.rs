@@ -1,5 +1,5 @@
 use indy_api_types::{
-    domain::wallet::{Config, Credentials, ExportConfig, KeyConfig},
+    domain::wallet::{Config, Credentials, ExportConfig, KeyConfig, KeyRotationConfig},
     errors::prelude::*,
     validation::Validatable,
     wallet::*,
@@ -133,7 +133,7 @@ pub extern "C" fn indy_register_wallet_storage(
             get_search_total_count,
             fetch_search_next_record,
             free_search,
-        );
+        ).await;
         res
     };
 
@@ -620,6 +620,168 @@ pub extern "C" fn indy_delete_wallet(
     res
 }
 
+/// Rotates wallet data keys (keys used for encryption of record types, names, values and tags).
+/// Fresh keys are generated and all wallet records are re-encrypted in atomic batches.
+/// Searchable tags stay searchable as their HMACs are recalculated with the new keys.
+/// Wallet master key (passphrase) is not changed. Use "rekey" credential of indy_open_wallet for it.
+///
+/// The new keys are persisted before the first batch is applied, so the rotation
+/// interrupted by a failure or process termination is resumed by the next call of this
+/// function or automatically on the next indy_open_wallet.
+/// Other wallet operations wait until the rotation is completed.
+///
+/// Records are read and re-encrypted page by page, so the whole wallet isn't loaded into memory.
+///
+/// Note: Supported only for wallet storages with atomic batches support
+/// (default SQLite, MySQL and PostgreSQL).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// credentials: Wallet credentials json. Opened wallet doesn't keep the master key,
+///              so it is derived again to protect the new data keys.
+/// {
+///   "key": string, Key or passphrase the wallet was opened with.
+///   "key_derivation_method": optional<string> Algorithm used for wallet key derivation:
+///                             ARGON2I_MOD (used by default), ARGON2I_INT or RAW.
+/// }
+/// config: (optional) rotation configuration json.
+/// {
+///   "batch_size": optional<int>, Count of records re-encrypted in one atomic batch. Defaults to 100.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// progress_json: Final rotation progress json
+/// {
+///   "total": int, Count of wallet records
+///   "processed": int, Count of processed records
+///   "completed": bool, Whether rotation is completed
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_rotate_wallet_data_keys(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    credentials: *const c_char,
+    config: *const c_char,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, progress_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_rotate_wallet_data_keys > wallet_handle {:?} credentials {:?} config {:?}",
+        wallet_handle, credentials, config
+    );
+
+    check_useful_json!(credentials, ErrorCode::CommonInvalidParam3, Credentials);
+    check_useful_opt_validatable_json!(config, ErrorCode::CommonInvalidParam4, KeyRotationConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    debug!(
+        "indy_rotate_wallet_data_keys ? wallet_handle {:?} credentials {:?} config {:?}",
+        wallet_handle,
+        secret!(&credentials),
+        config
+    );
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .wallet_controller
+            .rotate_keys(wallet_handle, credentials, config)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, progress_json) = prepare_result!(res, String::new());
+        debug!(
+            "indy_rotate_wallet_data_keys ? err {:?} progress_json {:?}",
+            err, progress_json
+        );
+
+        let progress_json = ctypes::string_to_cstring(progress_json);
+        cb(command_handle, err, progress_json.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(CommandMetric::WalletCommandRotateDataKeys, action, cb);
+
+    let res = ErrorCode::Success;
+    debug!("indy_rotate_wallet_data_keys < {:?}", res);
+    res
+}
+
+/// Returns progress of the wallet data keys rotation started by indy_rotate_wallet_data_keys
+/// or resumed on wallet opening.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// progress_json: Rotation progress json or "null" if rotation wasn't started for the opened wallet
+/// {
+///   "total": int, Count of wallet records
+///   "processed": int, Count of processed records
+///   "completed": bool, Whether rotation is completed
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_get_wallet_data_keys_rotation_progress(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    cb: Option<
+        extern "C" fn(command_handle_: CommandHandle, err: ErrorCode, progress_json: *const c_char),
+    >,
+) -> ErrorCode {
+    debug!(
+        "indy_get_wallet_data_keys_rotation_progress > wallet_handle {:?}",
+        wallet_handle
+    );
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let locator = Locator::instance();
+
+    let action = async move {
+        let res = locator
+            .wallet_controller
+            .get_key_rotation_progress(wallet_handle)
+            .await;
+        res
+    };
+
+    let cb = move |res: IndyResult<_>| {
+        let (err, progress_json) = prepare_result!(res, String::new());
+        debug!(
+            "indy_get_wallet_data_keys_rotation_progress ? err {:?} progress_json {:?}",
+            err, progress_json
+        );
+
+        let progress_json = ctypes::string_to_cstring(progress_json);
+        cb(command_handle, err, progress_json.as_ptr())
+    };
+
+    locator.executor.spawn_ok_instrumented(
+        CommandMetric::WalletCommandGetDataKeysRotationProgress,
+        action,
+        cb,
+    );
+
+    let res = ErrorCode::Success;
+    debug!("indy_get_wallet_data_keys_rotation_progress < {:?}", res);
+    res
+}
+
 /// Generate wallet master key.
 /// Returned key is compatible with "RAW" key derivation method.
 /// It allows to avoid expensive key derivation for use cases when wallet keys can be stored in a secure enclave.
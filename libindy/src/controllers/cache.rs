@@ -8,8 +8,13 @@ use indy_wallet::{WalletRecord, WalletService};
 
 use crate::{
     domain::{
-        anoncreds::{credential_definition::CredentialDefinitionId, schema::SchemaId},
-        cache::{GetCacheOptions, PurgeOptions},
+        anoncreds::{
+            credential_definition::CredentialDefinitionId,
+            revocation_registry_definition::RevocationRegistryId,
+            revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1},
+            schema::SchemaId,
+        },
+        cache::{GetCacheOptions, PurgeOptions, RevocRegDeltaCacheRecord},
         crypto::did::DidValue,
     },
     services::{CryptoService, LedgerService, PoolService},
@@ -17,6 +22,10 @@ use crate::{
 
 const CRED_DEF_CACHE: &str = "cred_def_cache";
 const SCHEMA_CACHE: &str = "schema_cache";
+const NYM_CACHE: &str = "nym_cache";
+const ATTRIB_CACHE: &str = "attrib_cache";
+const REVOC_REG_DEF_CACHE: &str = "revoc_reg_def_cache";
+const REVOC_REG_DELTA_CACHE: &str = "revoc_reg_delta_cache";
 
 pub(crate) struct CacheController {
     crypto_service: Arc<CryptoService>,
@@ -28,24 +37,7 @@ pub(crate) struct CacheController {
 macro_rules! check_cache {
     ($cache: ident, $options: ident) => {
         if let Some(cache) = $cache {
-            let min_fresh = $options.min_fresh.unwrap_or(-1);
-            if min_fresh >= 0 {
-                let ts = match CacheController::_get_seconds_since_epoch() {
-                    Ok(ts) => ts,
-                    Err(err) => return Err(err),
-                };
-                if ts - min_fresh
-                    <= cache
-                        .get_tags()
-                        .unwrap_or(&Tags::new())
-                        .get("timestamp")
-                        .unwrap_or(&"-1".to_string())
-                        .parse()
-                        .unwrap_or(-1)
-                {
-                    return Ok(cache.get_value().unwrap_or("").to_string());
-                }
-            } else {
+            if CacheController::_is_fresh(&cache, &$options)? {
                 return Ok(cache.get_value().unwrap_or("").to_string());
             }
         }
@@ -180,40 +172,330 @@ impl CacheController {
         return res;
     }
 
-    pub(crate) async fn purge_schema_cache(
+    pub(crate) async fn get_nym(
         &self,
+        pool_handle: PoolHandle,
         wallet_handle: WalletHandle,
-        options: PurgeOptions,
-    ) -> IndyResult<()> {
+        submitter_did: DidValue,
+        did: DidValue,
+        options: GetCacheOptions,
+    ) -> IndyResult<String> {
         trace!(
-            "purge_schema_cache > wallet_handle {:?} options {:?}",
+            "get_nym > pool_handle {:?} wallet_handle {:?} \
+                submitter_did {:?} did {:?} options {:?}",
+            pool_handle,
             wallet_handle,
+            submitter_did,
+            did,
             options
         );
 
-        let query_json = Self::_build_query_json(options.max_age.unwrap_or(-1))?;
+        let cache = self
+            ._get_record_from_cache(wallet_handle, &did.0, &options, NYM_CACHE)
+            .await?;
 
-        let mut search = self
-            .wallet_service
-            .search_records(
-                wallet_handle,
-                SCHEMA_CACHE,
-                &query_json,
-                &json!({
-                    "retrieveType": false,
-                    "retrieveValue": false,
-                    "retrieveTags": false,
-                })
-                .to_string(),
-            )
+        check_cache!(cache, options);
+
+        if options.no_update.unwrap_or(false) {
+            let res = Err(IndyError::from(IndyErrorKind::LedgerItemNotFound));
+            trace!("get_nym < not found {:?}", res);
+            return res;
+        }
+
+        let nym_json = {
+            let request_json = {
+                self.crypto_service.validate_opt_did(Some(&submitter_did))?;
+                self.crypto_service.validate_did(&did)?;
+
+                self.ledger_service
+                    .build_get_nym_request(Some(&submitter_did), &did)?
+            };
+
+            let pool_response = self
+                .pool_service
+                .send_tx(pool_handle, &request_json)
+                .await?;
+
+            self.ledger_service.parse_get_nym_response(&pool_response)?
+        };
+
+        self._delete_and_add_record(wallet_handle, options, &did.0, &nym_json, NYM_CACHE)
+            .await
+            .to_indy(IndyErrorKind::InvalidState, "Can't update cache.")?;
+
+        let res = Ok(nym_json);
+        trace!("get_nym < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn get_attrib(
+        &self,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        submitter_did: DidValue,
+        target_did: DidValue,
+        raw: Option<String>,
+        hash: Option<String>,
+        enc: Option<String>,
+        options: GetCacheOptions,
+    ) -> IndyResult<String> {
+        trace!(
+            "get_attrib > pool_handle {:?} wallet_handle {:?} \
+                submitter_did {:?} target_did {:?} raw {:?} hash {:?} enc {:?} options {:?}",
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            target_did,
+            raw,
+            hash,
+            enc,
+            options
+        );
+
+        let cache_id =
+            Self::_attrib_cache_id(&target_did, raw.as_deref(), hash.as_deref(), enc.as_deref())?;
+
+        let cache = self
+            ._get_record_from_cache(wallet_handle, &cache_id, &options, ATTRIB_CACHE)
             .await?;
 
-        while let Some(record) = search.fetch_next_record().await? {
-            self.wallet_service
-                .delete_record(wallet_handle, SCHEMA_CACHE, record.get_id())
+        check_cache!(cache, options);
+
+        if options.no_update.unwrap_or(false) {
+            let res = Err(IndyError::from(IndyErrorKind::LedgerItemNotFound));
+            trace!("get_attrib < not found {:?}", res);
+            return res;
+        }
+
+        let attrib = {
+            let request_json = {
+                self.crypto_service.validate_opt_did(Some(&submitter_did))?;
+                self.crypto_service.validate_did(&target_did)?;
+
+                self.ledger_service.build_get_attrib_request(
+                    Some(&submitter_did),
+                    &target_did,
+                    raw.as_deref(),
+                    hash.as_deref(),
+                    enc.as_deref(),
+                )?
+            };
+
+            let pool_response = self
+                .pool_service
+                .send_tx(pool_handle, &request_json)
+                .await?;
+
+            self.ledger_service
+                .parse_get_attrib_response(&pool_response)?
+        };
+
+        self._delete_and_add_record(wallet_handle, options, &cache_id, &attrib, ATTRIB_CACHE)
+            .await
+            .to_indy(IndyErrorKind::InvalidState, "Can't update cache.")?;
+
+        let res = Ok(attrib);
+        trace!("get_attrib < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn get_revoc_reg_def(
+        &self,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        submitter_did: DidValue,
+        id: RevocationRegistryId,
+        options: GetCacheOptions,
+    ) -> IndyResult<String> {
+        trace!(
+            "get_revoc_reg_def > pool_handle {:?} wallet_handle {:?} \
+                submitter_did {:?} id {:?} options {:?}",
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            id,
+            options
+        );
+
+        let cache = self
+            ._get_record_from_cache(wallet_handle, &id.0, &options, REVOC_REG_DEF_CACHE)
+            .await?;
+
+        check_cache!(cache, options);
+
+        if options.no_update.unwrap_or(false) {
+            let res = Err(IndyError::from(IndyErrorKind::LedgerItemNotFound));
+            trace!("get_revoc_reg_def < not found {:?}", res);
+            return res;
+        }
+
+        let (_, revoc_reg_def_json) = {
+            let request_json = {
+                self.crypto_service.validate_opt_did(Some(&submitter_did))?;
+
+                self.ledger_service
+                    .build_get_revoc_reg_def_request(Some(&submitter_did), &id)?
+            };
+
+            let pool_response = self
+                .pool_service
+                .send_tx(pool_handle, &request_json)
                 .await?;
+
+            self.ledger_service
+                .parse_get_revoc_reg_def_response(&pool_response)?
+        };
+
+        // Stored under the requested id so fully qualified lookups hit the cache too.
+        self._delete_and_add_record(
+            wallet_handle,
+            options,
+            &id.0,
+            &revoc_reg_def_json,
+            REVOC_REG_DEF_CACHE,
+        )
+        .await
+        .to_indy(IndyErrorKind::InvalidState, "Can't update cache.")?;
+
+        let res = Ok(revoc_reg_def_json);
+        trace!("get_revoc_reg_def < {:?}", res);
+        res
+    }
+
+    /// Returns revocation registry delta for `[from, to]` interval and the timestamp of its accumulator.
+    ///
+    /// Deltas are cached per `(id, from)` pair. If the cached delta ends before `to`
+    /// only the missing `(cached_to, to]` interval is requested from the ledger and merged into it.
+    pub(crate) async fn get_revoc_reg_delta(
+        &self,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        submitter_did: DidValue,
+        id: RevocationRegistryId,
+        from: Option<i64>,
+        to: i64,
+        options: GetCacheOptions,
+    ) -> IndyResult<(String, u64)> {
+        trace!(
+            "get_revoc_reg_delta > pool_handle {:?} wallet_handle {:?} \
+                submitter_did {:?} id {:?} from {:?} to {:?} options {:?}",
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            id,
+            from,
+            to,
+            options
+        );
+
+        let cache_id = Self::_revoc_reg_delta_cache_id(&id, from);
+
+        let cache = self
+            ._get_record_from_cache(wallet_handle, &cache_id, &options, REVOC_REG_DELTA_CACHE)
+            .await?;
+
+        let cached = match cache {
+            Some(cache) if Self::_is_fresh(&cache, &options)? => {
+                let cached: RevocRegDeltaCacheRecord =
+                    serde_json::from_str(cache.get_value().unwrap_or("")).to_indy(
+                        IndyErrorKind::InvalidState,
+                        "Cannot deserialize cached RevocationRegistryDelta",
+                    )?;
+
+                // Delta can be extended forward but can't be shrunk to an earlier `to`.
+                if cached.to <= to {
+                    Some(cached)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(cached) = cached.as_ref().filter(|cached| cached.to == to) {
+            let res = Ok((Self::_serialize_revoc_reg_delta(cached)?, cached.timestamp));
+            trace!("get_revoc_reg_delta < cached {:?}", res);
+            return res;
+        }
+
+        if options.no_update.unwrap_or(false) {
+            let res = Err(IndyError::from(IndyErrorKind::LedgerItemNotFound));
+            trace!("get_revoc_reg_delta < not found {:?}", res);
+            return res;
         }
 
+        let merged = match cached {
+            Some(cached) => {
+                let (revoc_reg_delta, timestamp) = self
+                    ._ledger_get_revoc_reg_delta(
+                        pool_handle,
+                        &submitter_did,
+                        &id,
+                        Some(cached.to),
+                        to,
+                    )
+                    .await?;
+
+                Self::_merge_revoc_reg_deltas(cached, revoc_reg_delta, timestamp, to)
+                    .map_err(|err| {
+                        warn!("Cannot merge cached RevocationRegistryDelta {:?}", err);
+                        err
+                    })
+                    .ok()
+            }
+            None => None,
+        };
+
+        let record = match merged {
+            Some(merged) => merged,
+            None => {
+                let (revoc_reg_delta, timestamp) = self
+                    ._ledger_get_revoc_reg_delta(pool_handle, &submitter_did, &id, from, to)
+                    .await?;
+
+                RevocRegDeltaCacheRecord {
+                    from,
+                    to,
+                    revoc_reg_delta,
+                    timestamp,
+                }
+            }
+        };
+
+        let record_json = serde_json::to_string(&record).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize RevocationRegistryDelta cache record",
+        )?;
+
+        self._delete_and_add_record(
+            wallet_handle,
+            options,
+            &cache_id,
+            &record_json,
+            REVOC_REG_DELTA_CACHE,
+        )
+        .await
+        .to_indy(IndyErrorKind::InvalidState, "Can't update cache.")?;
+
+        let res = Ok((Self::_serialize_revoc_reg_delta(&record)?, record.timestamp));
+        trace!("get_revoc_reg_delta < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_schema_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_schema_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, SCHEMA_CACHE)
+            .await?;
+
         let res = Ok(());
         trace!("purge_schema_cache < {:?}", res);
         res
@@ -230,13 +512,102 @@ impl CacheController {
             options
         );
 
+        self._purge_cache(wallet_handle, options, CRED_DEF_CACHE)
+            .await?;
+
+        let res = Ok(());
+        trace!("purge_cred_def_cache <<< res: ()");
+        res
+    }
+
+    pub(crate) async fn purge_nym_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_nym_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, NYM_CACHE).await?;
+
+        let res = Ok(());
+        trace!("purge_nym_cache < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_attrib_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_attrib_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, ATTRIB_CACHE)
+            .await?;
+
+        let res = Ok(());
+        trace!("purge_attrib_cache < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_revoc_reg_def_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_revoc_reg_def_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, REVOC_REG_DEF_CACHE)
+            .await?;
+
+        let res = Ok(());
+        trace!("purge_revoc_reg_def_cache < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn purge_revoc_reg_delta_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+    ) -> IndyResult<()> {
+        trace!(
+            "purge_revoc_reg_delta_cache > wallet_handle {:?} options {:?}",
+            wallet_handle,
+            options
+        );
+
+        self._purge_cache(wallet_handle, options, REVOC_REG_DELTA_CACHE)
+            .await?;
+
+        let res = Ok(());
+        trace!("purge_revoc_reg_delta_cache < {:?}", res);
+        res
+    }
+
+    async fn _purge_cache(
+        &self,
+        wallet_handle: WalletHandle,
+        options: PurgeOptions,
+        which_cache: &str,
+    ) -> IndyResult<()> {
         let query_json = Self::_build_query_json(options.max_age.unwrap_or(-1))?;
 
         let mut search = self
             .wallet_service
             .search_records(
                 wallet_handle,
-                CRED_DEF_CACHE,
+                which_cache,
                 &query_json,
                 &json!({
                     "retrieveType": false,
@@ -249,13 +620,11 @@ impl CacheController {
 
         while let Some(record) = search.fetch_next_record().await? {
             self.wallet_service
-                .delete_record(wallet_handle, CRED_DEF_CACHE, record.get_id())
+                .delete_record(wallet_handle, which_cache, record.get_id())
                 .await?;
         }
 
-        let res = Ok(());
-        trace!("purge_cred_def_cache <<< res: ()");
-        res
+        Ok(())
     }
 
     async fn _delete_and_add_record(
@@ -312,6 +681,112 @@ impl CacheController {
         Ok(res)
     }
 
+    async fn _ledger_get_revoc_reg_delta(
+        &self,
+        pool_handle: PoolHandle,
+        submitter_did: &DidValue,
+        id: &RevocationRegistryId,
+        from: Option<i64>,
+        to: i64,
+    ) -> IndyResult<(RevocationRegistryDelta, u64)> {
+        self.crypto_service.validate_opt_did(Some(submitter_did))?;
+
+        let request_json = self.ledger_service.build_get_revoc_reg_delta_request(
+            Some(submitter_did),
+            id,
+            from,
+            to,
+        )?;
+
+        let pool_response = self
+            .pool_service
+            .send_tx(pool_handle, &request_json)
+            .await?;
+
+        let (_, revoc_reg_delta_json, timestamp) = self
+            .ledger_service
+            .parse_get_revoc_reg_delta_response(&pool_response)?;
+
+        let revoc_reg_delta = serde_json::from_str(&revoc_reg_delta_json).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot deserialize RevocationRegistryDelta",
+        )?;
+
+        Ok((revoc_reg_delta, timestamp))
+    }
+
+    fn _merge_revoc_reg_deltas(
+        cached: RevocRegDeltaCacheRecord,
+        revoc_reg_delta: RevocationRegistryDelta,
+        timestamp: u64,
+        to: i64,
+    ) -> IndyResult<RevocRegDeltaCacheRecord> {
+        let mut merged = RevocationRegistryDeltaV1::from(cached.revoc_reg_delta);
+        let revoc_reg_delta = RevocationRegistryDeltaV1::from(revoc_reg_delta);
+
+        merged.value.merge(&revoc_reg_delta.value)?;
+
+        Ok(RevocRegDeltaCacheRecord {
+            from: cached.from,
+            to,
+            revoc_reg_delta: RevocationRegistryDelta::RevocationRegistryDeltaV1(merged),
+            timestamp,
+        })
+    }
+
+    fn _serialize_revoc_reg_delta(record: &RevocRegDeltaCacheRecord) -> IndyResult<String> {
+        serde_json::to_string(&record.revoc_reg_delta).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize RevocationRegistryDelta",
+        )
+    }
+
+    fn _revoc_reg_delta_cache_id(id: &RevocationRegistryId, from: Option<i64>) -> String {
+        match from {
+            Some(from) => format!("{}:{}", id.0, from),
+            None => id.0.clone(),
+        }
+    }
+
+    fn _attrib_cache_id(
+        did: &DidValue,
+        raw: Option<&str>,
+        hash: Option<&str>,
+        enc: Option<&str>,
+    ) -> IndyResult<String> {
+        let (kind, value) = match (raw, hash, enc) {
+            (Some(raw), _, _) => ("raw", raw),
+            (None, Some(hash), _) => ("hash", hash),
+            (None, None, Some(enc)) => ("enc", enc),
+            (None, None, None) => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    "Either raw or hash or enc must be specified",
+                ))
+            }
+        };
+
+        Ok(format!("{}:{}:{}", did.0, kind, value))
+    }
+
+    fn _is_fresh(record: &WalletRecord, options: &GetCacheOptions) -> IndyResult<bool> {
+        let min_fresh = options.min_fresh.unwrap_or(-1);
+
+        if min_fresh < 0 {
+            return Ok(true);
+        }
+
+        let ts = CacheController::_get_seconds_since_epoch()?;
+
+        let cached_ts = record
+            .get_tags()
+            .and_then(|tags| tags.get("timestamp"))
+            .and_then(|ts| ts.parse().ok())
+            .unwrap_or(-1);
+
+        Ok(ts - min_fresh <= cached_ts)
+    }
+
     async fn _get_record_from_cache(
         &self,
         wallet_handle: WalletHandle,
@@ -7,24 +7,39 @@ use cosmrs::tx::SignDoc;
 use indy_api_types::errors::{IndyErrorKind, err_msg, IndyResult, IndyResultExt};
 use indy_api_types::WalletHandle;
 use indy_wallet::{RecordOptions, SearchOptions};
+use rust_base58::ToBase58;
 
-use crate::domain::cheqd_keys::{Key, KeyInfo};
+use crate::domain::cheqd_keys::{Key, KeyInfo, MnemonicOptions, MultisigMember};
 use crate::domain::cheqd_ledger::cosmos_ext::CosmosSignDocExt;
-use crate::services::{CheqdKeysService, WalletService};
+use crate::domain::cheqd_pool::DEFAULT_ACCOUNT_PREFIX;
+use crate::services::{CheqdKeysService, CheqdPoolService, WalletService};
 
 pub(crate) struct CheqdKeysController {
     cheqd_keys_service: Arc<CheqdKeysService>,
+    cheqd_pool_service: Arc<CheqdPoolService>,
     wallet_service: Arc<WalletService>,
 }
 
 impl CheqdKeysController {
-    pub(crate) fn new(cheqd_keys_service: Arc<CheqdKeysService>, wallet_service: Arc<WalletService>) -> Self {
+    pub(crate) fn new(
+        cheqd_keys_service: Arc<CheqdKeysService>,
+        cheqd_pool_service: Arc<CheqdPoolService>,
+        wallet_service: Arc<WalletService>,
+    ) -> Self {
         Self {
             cheqd_keys_service,
+            cheqd_pool_service,
             wallet_service,
         }
     }
 
+    async fn account_prefix(&self, pool_alias: Option<&str>) -> IndyResult<String> {
+        match pool_alias {
+            Some(pool_alias) => Ok(self.cheqd_pool_service.get_config(pool_alias).await?.account_prefix),
+            None => Ok(DEFAULT_ACCOUNT_PREFIX.to_string()),
+        }
+    }
+
     async fn store_key(&self, wallet_handle: WalletHandle, key: &Key) -> IndyResult<()> {
         self.wallet_service
             .add_indy_object(wallet_handle, &key.alias, &key, &HashMap::new())
@@ -47,7 +62,7 @@ impl CheqdKeysController {
         trace!("add_random > alias {:?}", alias);
         let key = self.cheqd_keys_service.new_random(&alias)?;
         self.store_key(wallet_handle, &key).await?;
-        let key_info = self.cheqd_keys_service.get_info(&key)?;
+        let key_info = self.cheqd_keys_service.get_info(&key, DEFAULT_ACCOUNT_PREFIX)?;
         let key_info = serde_json::to_string(&key_info).to_indy(
             IndyErrorKind::InvalidState,
             "Cannot serialize structure KeyInfo"
@@ -61,13 +76,15 @@ impl CheqdKeysController {
         wallet_handle: WalletHandle,
         alias: &str,
         mnemonic: &str,
+        options: Option<MnemonicOptions>,
     ) -> IndyResult<String> {
-        trace!("add_from_mnemonic > alias {:?}", alias);
+        trace!("add_from_mnemonic > alias {:?} options {:?}", alias, options);
+        let options = options.unwrap_or_default();
         let key = self
             .cheqd_keys_service
-            .new_from_mnemonic(&alias, mnemonic)?;
+            .new_from_mnemonic(&alias, mnemonic, &options)?;
         self.store_key(wallet_handle, &key).await?;
-        let key_info = self.cheqd_keys_service.get_info(&key)?;
+        let key_info = self.cheqd_keys_service.get_info(&key, DEFAULT_ACCOUNT_PREFIX)?;
         let key_info = serde_json::to_string(&key_info).to_indy(
             IndyErrorKind::InvalidState,
             "Cannot serialize structure KeyInfo"
@@ -76,10 +93,23 @@ impl CheqdKeysController {
         Ok(key_info)
     }
 
-    pub(crate) async fn get_info(&self, wallet_handle: WalletHandle, alias: &str) -> IndyResult<String> {
-        trace!("get_info > alias {:?}", alias);
+    pub(crate) fn generate_mnemonic(&self, words_count: u32) -> IndyResult<String> {
+        trace!("generate_mnemonic > words_count {:?}", words_count);
+        let mnemonic = self.cheqd_keys_service.generate_mnemonic(words_count)?;
+        trace!("generate_mnemonic <");
+        Ok(mnemonic)
+    }
+
+    pub(crate) async fn get_info(
+        &self,
+        wallet_handle: WalletHandle,
+        alias: &str,
+        pool_alias: Option<&str>,
+    ) -> IndyResult<String> {
+        trace!("get_info > alias {:?} pool_alias {:?}", alias, pool_alias);
+        let account_prefix = self.account_prefix(pool_alias).await?;
         let key = self.load_key(wallet_handle, alias).await?;
-        let key_info = self.cheqd_keys_service.get_info(&key)?;
+        let key_info = self.cheqd_keys_service.get_info(&key, &account_prefix)?;
         let key_info = serde_json::to_string(&key_info).to_indy(
             IndyErrorKind::InvalidState,
             "Cannot serialize structure KeyInfo"
@@ -88,8 +118,14 @@ impl CheqdKeysController {
         Ok(key_info)
     }
 
-    pub(crate) async fn get_list_keys(&self, wallet_handle: WalletHandle) -> IndyResult<String> {
-        trace!("get_list_keys >");
+    pub(crate) async fn get_list_keys(
+        &self,
+        wallet_handle: WalletHandle,
+        pool_alias: Option<&str>,
+    ) -> IndyResult<String> {
+        trace!("get_list_keys > pool_alias {:?}", pool_alias);
+
+        let account_prefix = self.account_prefix(pool_alias).await?;
 
         let mut key_search = self
             .wallet_service
@@ -111,7 +147,7 @@ impl CheqdKeysController {
                     )
                 })?;
 
-            let key_info = self.cheqd_keys_service.get_info(&key)?;
+            let key_info = self.cheqd_keys_service.get_info(&key, &account_prefix)?;
             keys.push(key_info);
         }
 
@@ -138,13 +174,74 @@ impl CheqdKeysController {
 
         Ok(signed)
     }
+
+    /// Signs the transaction of multisig account by one of its members.
+    pub(crate) async fn sign_multisig_partial(
+        &self,
+        wallet_handle: WalletHandle,
+        alias: &str,
+        tx: &[u8],
+    ) -> IndyResult<String> {
+        trace!("sign_multisig_partial > alias {:?}, tx {:?}", alias, tx);
+
+        let sign_doc = SignDoc::from_bytes(tx)?;
+
+        let key = self.load_key(wallet_handle, alias).await?;
+        let signature = self.cheqd_keys_service.sign_multisig_partial(&key, sign_doc).await?;
+        let signature = signature.to_base58();
+
+        trace!("sign_multisig_partial < signature {:?}", signature);
+
+        Ok(signature)
+    }
+
+    pub(crate) async fn get_multisig_info(
+        &self,
+        wallet_handle: WalletHandle,
+        threshold: u32,
+        members: Vec<MultisigMember>,
+        pool_alias: Option<&str>,
+    ) -> IndyResult<String> {
+        trace!(
+            "get_multisig_info > threshold {:?} members {:?} pool_alias {:?}",
+            threshold, members, pool_alias
+        );
+
+        let account_prefix = self.account_prefix(pool_alias).await?;
+
+        let mut pub_keys = Vec::with_capacity(members.len());
+
+        for member in members {
+            let pub_key = match member {
+                MultisigMember::Alias { alias } => {
+                    let key = self.load_key(wallet_handle, &alias).await?;
+                    self.cheqd_keys_service.get_info(&key, &account_prefix)?.pub_key
+                }
+                MultisigMember::PubKey { pub_key } => pub_key,
+            };
+
+            pub_keys.push(pub_key);
+        }
+
+        let multisig_info = self
+            .cheqd_keys_service
+            .get_multisig_info(threshold, pub_keys, &account_prefix)?;
+
+        let multisig_info = serde_json::to_string(&multisig_info).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize structure MultisigKeyInfo"
+        )?;
+
+        trace!("get_multisig_info < {:?}", multisig_info);
+        Ok(multisig_info)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use indy_api_types::errors::IndyErrorKind;
     use crate::controllers::{CheqdKeysController, WalletController};
-    use crate::services::{CheqdKeysService, WalletService, CryptoService};
+    use crate::services::{CheqdKeysService, CheqdPoolService, WalletService, CryptoService};
     use rand::{distributions::Alphanumeric, Rng};
     use crate::domain::cheqd_keys::Key;
     use async_std::sync::Arc;
@@ -158,6 +255,7 @@ mod tests {
         let cheqd_keys_service = CheqdKeysService::new();
         let wallet_service = WalletService::new();
         let cheqd_controller = CheqdKeysController::new(Arc::from(cheqd_keys_service),
+                                                        Arc::new(CheqdPoolService::new()),
                                                         Arc::from(wallet_service));
         let wallet_controller = WalletController::new(Arc::from(WalletService::new()), Arc::new(CryptoService::new()));
 
@@ -201,6 +299,7 @@ mod tests {
         let cheqd_keys_service = CheqdKeysService::new();
         let wallet_service = WalletService::new();
         let cheqd_controller = CheqdKeysController::new(Arc::from(cheqd_keys_service),
+                                                        Arc::new(CheqdPoolService::new()),
                                                         Arc::from(wallet_service));
         let wallet_controller = WalletController::new(Arc::from(WalletService::new()), Arc::new(CryptoService::new()));
 
@@ -1,8 +1,17 @@
 use crate::controllers::CheqdLedgerController;
-use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
-use crate::domain::cheqd_ledger::cosmos_ext::{CosmosSignDocExt, CosmosMsgExt};
-use cosmrs::tx::Msg;
+use indy_api_types::errors::{err_msg, IndyResult, IndyErrorKind, IndyResultExt};
+use std::collections::HashMap;
+
+use crate::domain::cheqd_keys::MultisigKeyInfo;
+use crate::domain::cheqd_ledger::cosmos_ext::{CosmosSignDocExt, CosmosMsgExt, CosmosRawExt};
+use crate::domain::cheqd_ledger::crypto::multisig::LegacyAminoPubKey;
+use crate::domain::cheqd_ledger::tx::FeeOptions;
+use cosmrs::tx::{Msg, SignDoc};
+use rust_base58::FromBase58;
 use cosmrs::rpc::endpoint::abci_query::Response as QueryResponse;
+use cosmrs::proto::cosmos::tx::v1beta1::AuthInfo as ProtoAuthInfo;
+use crate::domain::cheqd_ledger::prost_ext::ProstMessageExt;
+use crate::services::CheqdLedgerService;
 
 impl CheqdLedgerController {
     pub(crate) async fn auth_build_tx(
@@ -44,6 +53,177 @@ impl CheqdLedgerController {
         Ok(sign_doc.to_bytes()?)
     }
 
+    /// Builds transaction which gas limit and fee are set from the simulation of its execution.
+    pub(crate) async fn auth_build_tx_with_fee_estimation(
+        &self,
+        pool_alias: &str,
+        sender_public_key: &str,
+        msg: &[u8],
+        account_number: u64,
+        sequence_number: u64,
+        coin_denom: &str,
+        timeout_height: u64,
+        memo: &str,
+        fee_options: Option<FeeOptions>,
+    ) -> IndyResult<Vec<u8>> {
+        trace!("auth_build_tx_with_fee_estimation > pool_alias {:?}, sender_public_key {:?}, msg {:?}, account_number {:?}, sequence_number {:?}, coin_denom {:?}, timeout_height {:?}, memo {:?}, fee_options {:?}", pool_alias, sender_public_key, msg, account_number, sequence_number, coin_denom, timeout_height, memo, fee_options);
+
+        let pool = self.cheqd_pool_service.get_config(pool_alias).await?;
+        let msg = Msg::from_bytes(&msg)?;
+        let fee_options = fee_options.unwrap_or_default();
+
+        let sign_doc = self
+            .cheqd_ledger_service
+            .auth_build_tx(
+                &pool.chain_id,
+                sender_public_key,
+                msg.clone(),
+                account_number,
+                sequence_number,
+                0,
+                0,
+                coin_denom,
+                timeout_height,
+                memo,
+            )
+            .await?;
+
+        let query = self.cheqd_ledger_service.tx_build_query_simulate(&sign_doc)?;
+        let resp = self.cheqd_pool_service.abci_query(pool_alias, query).await?;
+        let simulation = self.cheqd_ledger_service.tx_parse_query_simulate_resp(&resp)?;
+
+        let gas_used = simulation
+            .gas_info
+            .map(|gas_info| gas_info.gas_used)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Simulation response doesn't contain gas info"))?;
+
+        let fee = self.cheqd_ledger_service.tx_estimate_fee(gas_used, &fee_options);
+
+        let sign_doc = self
+            .cheqd_ledger_service
+            .auth_build_tx(
+                &pool.chain_id,
+                sender_public_key,
+                msg,
+                account_number,
+                sequence_number,
+                fee.gas_limit,
+                fee.amount,
+                coin_denom,
+                timeout_height,
+                memo,
+            )
+            .await?;
+
+        trace!("auth_build_tx_with_fee_estimation < fee {:?}", fee);
+
+        Ok(sign_doc.to_bytes()?)
+    }
+
+    /// Builds transaction of multisig account which is going to be signed by `signers` members.
+    pub(crate) async fn auth_build_multisig_tx(
+        &self,
+        pool_alias: &str,
+        multisig_info: MultisigKeyInfo,
+        signers: Vec<String>,
+        msg: &[u8],
+        account_number: u64,
+        sequence_number: u64,
+        max_gas: u64,
+        max_coin_amount: u64,
+        max_coin_denom: &str,
+        timeout_height: u64,
+        memo: &str,
+    ) -> IndyResult<Vec<u8>> {
+        trace!("auth_build_multisig_tx > pool_alias {:?}, multisig_info {:?}, signers {:?}, msg {:?}, account_number {:?}, sequence_number {:?}, max_gas {:?}, max_coin_amount {:?}, max_coin_denom {:?}, timeout_height {:?}, memo {:?}", pool_alias, multisig_info, signers, msg, account_number, sequence_number, max_gas, max_coin_amount, max_coin_denom, timeout_height, memo);
+
+        let pool = self.cheqd_pool_service.get_config(pool_alias).await?;
+        let msg = Msg::from_bytes(&msg)?;
+
+        let multisig_pub_key = LegacyAminoPubKey::from_base58_keys(multisig_info.threshold, &multisig_info.pub_keys)?;
+        let signers = Self::multisig_members_indexes(&multisig_info, &signers)?;
+
+        let sign_doc = self.cheqd_ledger_service.auth_build_multisig_tx(
+            &pool.chain_id,
+            &multisig_pub_key,
+            &signers,
+            msg,
+            account_number,
+            sequence_number,
+            max_gas,
+            max_coin_amount,
+            max_coin_denom,
+            timeout_height,
+            memo,
+        )?;
+
+        trace!("auth_build_multisig_tx <");
+
+        Ok(sign_doc.to_bytes()?)
+    }
+
+    /// Combines signatures of multisig members (base58-encoded signature by base58-encoded member pub key)
+    /// into the transaction ready for broadcasting.
+    pub(crate) fn auth_combine_multisig_signatures(
+        &self,
+        tx: &[u8],
+        signatures: HashMap<String, String>,
+    ) -> IndyResult<Vec<u8>> {
+        trace!("auth_combine_multisig_signatures > tx {:?}, signatures {:?}", tx, signatures);
+
+        let sign_doc = SignDoc::from_bytes(tx)?;
+
+        let auth_info = ProtoAuthInfo::from_bytes(&sign_doc.auth_info_bytes)?;
+        let (multisig_pub_key, _) = CheqdLedgerService::parse_multisig_signer_info(&auth_info)?;
+
+        let mut indexed_signatures = Vec::with_capacity(signatures.len());
+
+        for (pub_key, signature) in signatures {
+            let pub_key_bytes = pub_key.from_base58().map_err(|_| {
+                err_msg(IndyErrorKind::InvalidStructure, format!("Invalid base58 pub_key {}", pub_key))
+            })?;
+
+            let index = multisig_pub_key
+                .public_keys
+                .iter()
+                .position(|member| member.key == pub_key_bytes)
+                .ok_or_else(|| err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    format!("Key {} isn't a member of the multisig account", pub_key),
+                ))?;
+
+            let signature = signature.from_base58().map_err(|_| {
+                err_msg(IndyErrorKind::InvalidStructure, format!("Invalid base58 signature of {}", pub_key))
+            })?;
+
+            indexed_signatures.push((index, signature));
+        }
+
+        let signed = self
+            .cheqd_ledger_service
+            .auth_combine_multisig_signatures(sign_doc, &indexed_signatures)?;
+
+        trace!("auth_combine_multisig_signatures <");
+
+        Ok(signed.to_bytes()?)
+    }
+
+    fn multisig_members_indexes(multisig_info: &MultisigKeyInfo, members: &[String]) -> IndyResult<Vec<usize>> {
+        members
+            .iter()
+            .map(|member| {
+                multisig_info
+                    .pub_keys
+                    .iter()
+                    .position(|pub_key| pub_key == member)
+                    .ok_or_else(|| err_msg(
+                        IndyErrorKind::InvalidStructure,
+                        format!("Key {} isn't a member of the multisig account", member),
+                    ))
+            })
+            .collect()
+    }
+
     pub(crate) fn auth_build_query_account(&self, address: &str) -> IndyResult<String> {
         trace!("auth_build_query_account >");
         let query = self
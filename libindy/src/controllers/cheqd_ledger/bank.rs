@@ -2,23 +2,29 @@ use crate::controllers::CheqdLedgerController;
 use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
 use crate::domain::cheqd_ledger::cosmos_ext::CosmosMsgExt;
 use cosmrs::rpc::endpoint::abci_query::Response as QueryResponse;
-use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use crate::domain::cheqd_ledger::tx::TxResponse;
 
 impl CheqdLedgerController {
-    pub(crate) fn bank_build_msg_send(
+    pub(crate) async fn bank_build_msg_send(
         &self,
+        pool_alias: &str,
         from_address: &str,
         to_address: &str,
         amount: &str,
         denom: &str,
     ) -> IndyResult<Vec<u8>> {
         trace!(
-            "bank_build_msg_send > from_address {:?} to_address {:?} amount {:?}, denom {:?}",
+            "bank_build_msg_send > pool_alias {:?} from_address {:?} to_address {:?} amount {:?}, denom {:?}",
+            pool_alias,
             from_address,
             to_address,
             amount,
             denom
         );
+        let pool_config = self.cheqd_pool_service.get_config(pool_alias).await?;
+        pool_config.check_account_id(from_address)?;
+        pool_config.check_account_id(to_address)?;
+
         let msg = self
             .cheqd_ledger_service
             .bank_build_msg_send(from_address, to_address, amount, denom)?;
@@ -29,7 +35,7 @@ impl CheqdLedgerController {
 
     pub(crate) fn bank_parse_msg_send_resp(&self, resp: &str) -> IndyResult<String> {
         trace!("bank_parse_msg_send_resp > resp {:?}", resp);
-        let resp: Response = serde_json::from_str(&resp).to_indy(
+        let resp: TxResponse = serde_json::from_str(&resp).to_indy(
             IndyErrorKind::InvalidStructure,
             "Cannot deserialize response after sending MsgSend request"
         )?;
@@ -42,8 +48,21 @@ impl CheqdLedgerController {
         Ok(res)
     }
 
-    pub(crate) fn bank_build_query_balance(&self, address: String, denom: String) -> IndyResult<String> {
-        trace!("bank_build_query_balance > address {:?} denom {:?}", address, denom);
+    pub(crate) async fn bank_build_query_balance(
+        &self,
+        pool_alias: &str,
+        address: String,
+        denom: String,
+    ) -> IndyResult<String> {
+        trace!(
+            "bank_build_query_balance > pool_alias {:?} address {:?} denom {:?}",
+            pool_alias,
+            address,
+            denom
+        );
+        let pool_config = self.cheqd_pool_service.get_config(pool_alias).await?;
+        pool_config.check_account_id(&address)?;
+
         let query = self.cheqd_ledger_service.bank_build_query_balance(address, denom)?;
         let json = serde_json::to_string(&query).to_indy(
             IndyErrorKind::InvalidState,
@@ -1,8 +1,12 @@
 use crate::controllers::CheqdLedgerController;
-use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
+use indy_api_types::errors::{err_msg, IndyResult, IndyErrorKind, IndyResultExt};
 use crate::domain::cheqd_ledger::cosmos_ext::CosmosMsgExt;
+use crate::domain::crypto::did::DidValue;
+use crate::domain::crypto::did_document::{DidDocument, DidDocumentMetadata, DidResolutionResult};
+use crate::domain::vdr::namespaces::{did_parts, ledger_did};
+use crate::utils::crypto::verkey_builder::build_full_verkey;
 use cosmrs::rpc::endpoint::abci_query::Response as QueryResponse;
-use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use crate::domain::cheqd_ledger::tx::TxResponse;
 
 impl CheqdLedgerController {
     pub(crate) fn cheqd_build_msg_create_nym(
@@ -31,7 +35,7 @@ impl CheqdLedgerController {
 
     pub(crate) fn cheqd_parse_msg_create_nym_resp(&self, resp: &str) -> IndyResult<String> {
         trace!("cheqd_parse_msg_create_nym_resp > resp {:?}", resp);
-        let resp: Response = serde_json::from_str(&resp).to_indy(
+        let resp: TxResponse = serde_json::from_str(&resp).to_indy(
             IndyErrorKind::InvalidStructure,
             "Cannot deserialize response after sending MsgCreateNym request"
         )?;
@@ -72,7 +76,7 @@ impl CheqdLedgerController {
 
     pub(crate) fn cheqd_parse_msg_update_nym_resp(&self, resp: &str) -> IndyResult<String> {
         trace!("cheqd_parse_msg_update_nym_resp > resp {:?}", resp);
-        let resp: Response = serde_json::from_str(resp).to_indy(
+        let resp: TxResponse = serde_json::from_str(resp).to_indy(
             IndyErrorKind::InvalidStructure,
             "Cannot deserialize response after sending MsgUpdateNym request"
         )?;
@@ -97,7 +101,7 @@ impl CheqdLedgerController {
 
     pub(crate) fn cheqd_parse_msg_delete_nym_resp(&self, resp: &str) -> IndyResult<String> {
         trace!("cheqd_parse_msg_delete_nym_resp > resp {:?}", resp);
-        let resp: Response = serde_json::from_str(resp).to_indy(
+        let resp: TxResponse = serde_json::from_str(resp).to_indy(
             IndyErrorKind::InvalidStructure,
             "Cannot deserialize response after sending MsgDeleteNym request"
         )?;
@@ -161,4 +165,44 @@ impl CheqdLedgerController {
         trace!("cheqd_parse_query_all_nym_resp < {:?}", json_result);
         Ok(json_result)
     }
-}
\ No newline at end of file
+
+    pub(crate) async fn cheqd_resolve_did_document(&self, pool_alias: &str, did: &str) -> IndyResult<String> {
+        trace!("cheqd_resolve_did_document > pool_alias {:?} did {:?}", pool_alias, did);
+        let did = DidValue(did.to_string());
+        let (namespace, id) = did_parts(&did)?;
+        if namespace != "cheqd" && !namespace.starts_with("cheqd:") {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("DID method of {} isn't supported by Cheqd ledger", did.0),
+            ));
+        }
+        let id: u64 = id.parse().to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cheqd DID identifier must be a NYM id"
+        )?;
+
+        let query = self.cheqd_ledger_service.build_query_get_nym(id)?;
+        let resp = self.cheqd_pool_service.abci_query(pool_alias, query).await?;
+        let nym = self.cheqd_ledger_service.cheqd_parse_query_get_nym_resp(&resp)?
+            .nym
+            .ok_or_else(|| err_msg(
+                IndyErrorKind::LedgerItemNotFound,
+                format!("DID {} isn't found on the ledger", did.0),
+            ))?;
+
+        let verkey = build_full_verkey(&ledger_did(&DidValue(nym.did.clone())).0, Some(&nym.verkey))?;
+        let did_document = DidDocument::new(&did, &verkey);
+        let did_document_metadata = DidDocumentMetadata {
+            alias: Some(nym.alias).filter(|alias| !alias.is_empty()),
+            role: Some(nym.role).filter(|role| !role.is_empty()),
+            creator: Some(nym.creator),
+        };
+
+        let res = serde_json::to_string(&DidResolutionResult::new(did_document, did_document_metadata)).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize DidResolutionResult object"
+        )?;
+        trace!("cheqd_resolve_did_document < {:?}", res);
+        Ok(res)
+    }
+}
@@ -7,6 +7,7 @@ use crate::services::{CheqdLedgerService, CheqdPoolService};
 mod cheqd;
 mod auth;
 mod bank;
+mod tx;
 
 pub(crate) struct CheqdLedgerController {
     cheqd_ledger_service: Arc<CheqdLedgerService>,
@@ -0,0 +1,48 @@
+use cosmrs::rpc::endpoint::abci_query::Response as QueryResponse;
+use cosmrs::tx::SignDoc;
+use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
+
+use crate::controllers::CheqdLedgerController;
+use crate::domain::cheqd_ledger::cosmos_ext::CosmosSignDocExt;
+use crate::domain::cheqd_ledger::tx::FeeOptions;
+
+impl CheqdLedgerController {
+    pub(crate) fn tx_build_query_simulate(&self, tx: &[u8]) -> IndyResult<String> {
+        trace!("tx_build_query_simulate > tx {:?}", tx);
+        let sign_doc = SignDoc::from_bytes(tx)?;
+        let query = self.cheqd_ledger_service.tx_build_query_simulate(&sign_doc)?;
+        let json = serde_json::to_string(&query).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize request for Simulate object"
+        )?;
+        trace!("tx_build_query_simulate < {:?}", json);
+        Ok(json)
+    }
+
+    pub(crate) fn tx_parse_query_simulate_resp(&self, resp_json: &str) -> IndyResult<String> {
+        trace!("tx_parse_query_simulate_resp > resp {:?}", resp_json);
+        let resp: QueryResponse = serde_json::from_str(resp_json).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize response after Simulate request"
+        )?;
+        let result = self.cheqd_ledger_service.tx_parse_query_simulate_resp(&resp)?;
+        let json_result = serde_json::to_string(&result).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize SimulateResponse object"
+        )?;
+        trace!("tx_parse_query_simulate_resp < {:?}", json_result);
+        Ok(json_result)
+    }
+
+    pub(crate) fn tx_estimate_fee(&self, gas_used: u64, options: Option<FeeOptions>) -> IndyResult<String> {
+        trace!("tx_estimate_fee > gas_used {:?} options {:?}", gas_used, options);
+        let options = options.unwrap_or_default();
+        let fee = self.cheqd_ledger_service.tx_estimate_fee(gas_used, &options);
+        let json = serde_json::to_string(&fee).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize FeeEstimation object"
+        )?;
+        trace!("tx_estimate_fee < {:?}", json);
+        Ok(json)
+    }
+}
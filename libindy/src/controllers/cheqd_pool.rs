@@ -5,6 +5,7 @@ use cosmrs::rpc;
 use cosmrs::tx::Raw;
 use indy_api_types::errors::{IndyResult, IndyErrorKind, IndyResultExt};
 
+use crate::domain::cheqd_pool::{ConnectionOptions, TrustOptions};
 use crate::services::CheqdPoolService;
 
 pub(crate) struct CheqdPoolController {
@@ -25,16 +26,22 @@ impl CheqdPoolController {
         alias: &str,
         rpc_address: &str,
         chain_id: &str,
+        account_prefix: Option<&str>,
+        trust_options: Option<TrustOptions>,
+        connection_options: Option<ConnectionOptions>,
     ) -> IndyResult<String> {
         trace!(
-            "add > alias {:?} rpc_address {:?} chain_id {:?}",
+            "add > alias {:?} rpc_address {:?} chain_id {:?} account_prefix {:?} trust_options {:?} connection_options {:?}",
             alias,
             rpc_address,
-            chain_id
+            chain_id,
+            account_prefix,
+            trust_options,
+            connection_options
         );
         let config = self
             .cheqd_pool_service
-            .add(alias, rpc_address, chain_id)
+            .add(alias, rpc_address, chain_id, account_prefix, trust_options, connection_options)
             .await?;
         let json = serde_json::to_string(&config).to_indy(
             IndyErrorKind::InvalidState,
@@ -92,6 +99,72 @@ impl CheqdPoolController {
         Ok(json)
     }
 
+    pub(crate) async fn broadcast_tx_sync(
+        &self,
+        pool_alias: &str,
+        signed_tx: &[u8],
+    ) -> IndyResult<String> {
+        trace!(
+            "broadcast_tx_sync > pool_alias {:?}, signed_tx {:?}",
+            pool_alias,
+            signed_tx
+        );
+
+        let tx_raw = Raw::from_bytes(signed_tx)?;
+        let resp = self
+            .cheqd_pool_service
+            .broadcast_tx_sync(pool_alias, tx_raw)
+            .await?;
+        let json = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response object after broadcasting_tx_sync action"
+        )?;
+
+        trace!("broadcast_tx_sync < resp {:?}", json);
+
+        Ok(json)
+    }
+
+    pub(crate) async fn broadcast_tx_async(
+        &self,
+        pool_alias: &str,
+        signed_tx: &[u8],
+    ) -> IndyResult<String> {
+        trace!(
+            "broadcast_tx_async > pool_alias {:?}, signed_tx {:?}",
+            pool_alias,
+            signed_tx
+        );
+
+        let tx_raw = Raw::from_bytes(signed_tx)?;
+        let resp = self
+            .cheqd_pool_service
+            .broadcast_tx_async(pool_alias, tx_raw)
+            .await?;
+        let json = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response object after broadcasting_tx_async action"
+        )?;
+
+        trace!("broadcast_tx_async < resp {:?}", json);
+
+        Ok(json)
+    }
+
+    pub(crate) async fn get_tx(&self, pool_alias: &str, tx_hash: &str) -> IndyResult<String> {
+        trace!("get_tx > pool_alias {:?}, tx_hash {:?}", pool_alias, tx_hash);
+
+        let resp = self.cheqd_pool_service.get_tx(pool_alias, tx_hash).await?;
+        let json = serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response object after getting transaction"
+        )?;
+
+        trace!("get_tx < resp {:?}", json);
+
+        Ok(json)
+    }
+
     pub(crate) async fn abci_query(&self, pool_alias: &str, req_json: &str) -> IndyResult<String> {
         let req: rpc::endpoint::abci_query::Request = serde_json::from_str(req_json).to_indy(
             IndyErrorKind::InvalidStructure,
@@ -6,17 +6,20 @@ use rust_base58::{FromBase58, ToBase58};
 
 use crate::{
     domain::crypto::did::{
-        Did, DidMetadata, DidMethod, DidValue, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid,
-        TheirDidInfo,
+        Did, DidMetadata, DidMethod, DidValue, DidWithMeta, MyDidInfo, ShortDidValue,
+        TemporaryDid, TheirDid, TheirDidInfo,
     },
+    domain::crypto::did_document::{DidDocument, DidDocumentMetadata, DidResolutionResult},
     domain::crypto::key::KeyInfo,
     domain::{
         ledger::attrib::{AttribData, Endpoint, GetAttrReplyResult},
-        ledger::nym::{GetNymReplyResult, GetNymResultDataV0},
+        ledger::nym::{GetNymReplyResult, GetNymResultDataV0, NymData},
         ledger::response::Reply,
         pairwise::Pairwise,
+        vdr::namespaces::{did_parts, ledger_did},
     },
     services::{CryptoService, LedgerService, PoolService},
+    utils::crypto::verkey_builder::build_full_verkey,
 };
 
 pub(crate) struct DidController {
@@ -638,6 +641,71 @@ impl DidController {
         res
     }
 
+    pub(crate) async fn resolve_did_document(
+        &self,
+        pool_handle: PoolHandle,
+        did: DidValue,
+    ) -> IndyResult<String> {
+        trace!(
+            "resolve_did_document > pool_handle {:?} did {:?}",
+            pool_handle, did
+        );
+
+        self.crypto_service.validate_did(&did)?;
+
+        let did = Self::_qualify_indy_did(&did)?;
+        let dest = ledger_did(&did);
+
+        let get_nym_request = self.ledger_service.build_get_nym_request(None, &dest)?;
+
+        let get_nym_reply = self
+            .pool_service
+            .send_tx(pool_handle, &get_nym_request)
+            .await?;
+
+        let nym_data = Self::_parse_get_nym_reply(&get_nym_reply)?.ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::LedgerItemNotFound,
+                format!("DID {} isn't found on the ledger", did.0),
+            )
+        })?;
+
+        let verkey = build_full_verkey(&dest.0, nym_data.verkey.as_deref())?;
+        self.crypto_service.validate_key(&verkey).await?;
+
+        let mut did_document = DidDocument::new(&did, &verkey);
+
+        let get_attrib_request = self
+            .ledger_service
+            .build_get_attrib_request(None, &dest, Some("endpoint"), None, None)?;
+
+        let get_attrib_reply = self
+            .pool_service
+            .send_tx(pool_handle, &get_attrib_request)
+            .await?;
+
+        if let Some((_, endpoint)) = Self::_parse_get_attrib_reply(&get_attrib_reply)? {
+            did_document.add_endpoint(&endpoint.ha, endpoint.verkey.as_deref());
+        }
+
+        let did_document_metadata = DidDocumentMetadata {
+            role: nym_data.role,
+            ..DidDocumentMetadata::default()
+        };
+
+        let res = serde_json::to_string(&DidResolutionResult::new(
+            did_document,
+            did_document_metadata,
+        ))
+        .to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize DidResolutionResult",
+        )?;
+
+        trace!("resolve_did_document < {:?}", res);
+        Ok(res)
+    }
+
     pub(crate) async fn get_nym_ack_process_and_store_their_did(
         &self,
         wallet_handle: WalletHandle,
@@ -653,33 +721,16 @@ impl DidController {
 
         let get_nym_reply = get_nym_reply_result?;
 
-        let get_nym_response: Reply<GetNymReplyResult> = serde_json::from_str(&get_nym_reply)
-            .to_indy(
-                IndyErrorKind::InvalidState,
-                "Invalid GetNymReplyResult json",
-            )?;
-
-        let their_did_info = match get_nym_response.result() {
-            GetNymReplyResult::GetNymReplyResultV0(res) => {
-                if let Some(data) = &res.data {
-                    let gen_nym_result_data: GetNymResultDataV0 = serde_json::from_str(data)
-                        .to_indy(IndyErrorKind::InvalidState, "Invalid GetNymResultData json")?;
-
-                    TheirDidInfo::new(
-                        gen_nym_result_data.dest.qualify(did.get_method()),
-                        gen_nym_result_data.verkey,
-                    )
-                } else {
-                    return Err(err_msg(
-                        IndyErrorKind::WalletItemNotFound,
-                        "Their DID isn't found on the ledger",
-                    )); //TODO FIXME use separate error
-                }
+        let their_did_info = match Self::_parse_get_nym_reply(&get_nym_reply)? {
+            Some(nym_data) => {
+                TheirDidInfo::new(nym_data.did.qualify(did.get_method()), nym_data.verkey)
+            }
+            None => {
+                return Err(err_msg(
+                    IndyErrorKind::WalletItemNotFound,
+                    "Their DID isn't found on the ledger",
+                )); //TODO FIXME use separate error
             }
-            GetNymReplyResult::GetNymReplyResultV1(res) => TheirDidInfo::new(
-                res.txn.data.did.qualify(did.get_method()),
-                res.txn.data.verkey,
-            ),
         };
 
         let their_did = self
@@ -735,21 +786,12 @@ impl DidController {
 
         let get_attrib_reply = get_attrib_reply_result?;
 
-        let get_attrib_reply: Reply<GetAttrReplyResult> = serde_json::from_str(&get_attrib_reply)
-            .to_indy(
-            IndyErrorKind::InvalidState,
-            "Invalid GetAttrReplyResult json",
-        )?;
-
-        let (raw, did) = match get_attrib_reply.result() {
-            GetAttrReplyResult::GetAttrReplyResultV0(res) => (res.data, res.dest),
-            GetAttrReplyResult::GetAttrReplyResultV1(res) => (res.txn.data.raw, res.txn.data.did),
-        };
-
-        let attrib_data: AttribData = serde_json::from_str(&raw)
-            .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttReply json")?;
-
-        let endpoint = Endpoint::new(attrib_data.endpoint.ha, attrib_data.endpoint.verkey);
+        let (did, endpoint) = Self::_parse_get_attrib_reply(&get_attrib_reply)?.ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidState,
+                "Endpoint isn't found on the ledger",
+            )
+        })?;
 
         self.wallet_service
             .add_indy_object(wallet_handle, &did.0, &endpoint, &HashMap::new())
@@ -814,6 +856,81 @@ impl DidController {
         .await
     }
 
+    fn _parse_get_nym_reply(get_nym_reply: &str) -> IndyResult<Option<NymData>> {
+        let get_nym_response: Reply<GetNymReplyResult> = serde_json::from_str(get_nym_reply)
+            .to_indy(
+                IndyErrorKind::InvalidState,
+                "Invalid GetNymReplyResult json",
+            )?;
+
+        let nym_data = match get_nym_response.result() {
+            GetNymReplyResult::GetNymReplyResultV0(res) => match res.data {
+                Some(data) => {
+                    let get_nym_result_data: GetNymResultDataV0 = serde_json::from_str(&data)
+                        .to_indy(IndyErrorKind::InvalidState, "Invalid GetNymResultData json")?;
+
+                    Some(NymData {
+                        did: get_nym_result_data.dest,
+                        verkey: get_nym_result_data.verkey,
+                        role: get_nym_result_data.role,
+                    })
+                }
+                None => None,
+            },
+            GetNymReplyResult::GetNymReplyResultV1(res) => Some(NymData {
+                did: res.txn.data.did,
+                verkey: res.txn.data.verkey,
+                role: res.txn.data.role,
+            }),
+        };
+
+        Ok(nym_data)
+    }
+
+    fn _parse_get_attrib_reply(
+        get_attrib_reply: &str,
+    ) -> IndyResult<Option<(ShortDidValue, Endpoint)>> {
+        let get_attrib_reply: Reply<GetAttrReplyResult> = serde_json::from_str(get_attrib_reply)
+            .to_indy(
+                IndyErrorKind::InvalidState,
+                "Invalid GetAttrReplyResult json",
+            )?;
+
+        let (raw, did) = match get_attrib_reply.result() {
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => match res.data {
+                Some(data) => (data, res.dest),
+                None => return Ok(None),
+            },
+            GetAttrReplyResult::GetAttrReplyResultV1(res) => (res.txn.data.raw, res.txn.data.did),
+        };
+
+        let attrib_data: AttribData = serde_json::from_str(&raw)
+            .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttReply json")?;
+
+        let endpoint = Endpoint::new(attrib_data.endpoint.ha, attrib_data.endpoint.verkey);
+
+        Ok(Some((did, endpoint)))
+    }
+
+    /// Returns fully qualified form of DID written on Indy ledger.
+    /// Unqualified DIDs are treated as `did:sov`.
+    fn _qualify_indy_did(did: &DidValue) -> IndyResult<DidValue> {
+        if !did.is_fully_qualified() {
+            return Ok(did.qualify("sov"));
+        }
+
+        let (namespace, _) = did_parts(did)?;
+
+        if namespace != "sov" && namespace != "indy" && !namespace.starts_with("indy:") {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("DID method of {} isn't supported by Indy ledger", did.0),
+            ));
+        }
+
+        Ok(did.clone())
+    }
+
     async fn _wallet_get_my_did(
         &self,
         wallet_handle: WalletHandle,
@@ -1,12 +1,11 @@
-use std::{string::ToString, sync::Arc};
+use std::{cmp, string::ToString, sync::Arc};
 
-use indy_api_types::{errors::prelude::*, PoolHandle, WalletHandle};
+use indy_api_types::{errors::prelude::*, ErrorCode, PoolHandle, WalletHandle};
 use indy_wallet::{RecordOptions, WalletService};
-use rust_base58::ToBase58;
+use rust_base58::{FromBase58, ToBase58};
 use serde_json::{self, Value};
 
 use crate::{
-    api::ledger::{CustomFree, CustomTransactionParser},
     domain::{
         anoncreds::{
             credential_definition::{
@@ -19,21 +18,33 @@ use crate::{
             schema::{Schema, SchemaId, SchemaV1},
         },
         crypto::{
-            did::{Did, DidValue},
+            did::{Did, DidValue, ShortDidValue},
             key::Key,
         },
         ledger::{
             auth_rule::{AuthRules, Constraint},
-            author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData},
+            author_agreement::{
+                AcceptanceMechanisms, ActiveTxnAuthorAgreement, GetTxnAuthorAgreementData,
+                TxnAuthorAgreementAcceptance, TxnAuthorAgreementChangePolicy,
+            },
+            bundle::{TransactionBundle, TransactionBundleOptions, TransactionBundleSignature},
+            constants::{DOMAIN_WRITE_REQUESTS, READ_REQUESTS},
             node::NodeOperationData,
+            nym::NymData,
             pool::Schedule,
             request::Request,
+            txn::{LedgerReplicaTxnsFilter, ReplicateLedgerOptions},
         },
     },
-    services::{CryptoService, LedgerService, PoolService},
-    utils::crypto::signature_serializer::serialize_signature,
+    services::{
+        get_sp_parser, parse_ledger_type, register_sp_parser, CryptoService, LedgerReplica,
+        LedgerService, PoolService, StateProofParser,
+    },
+    utils::crypto::{signature_serializer::serialize_signature, verkey_builder::build_full_verkey},
 };
 
+const DEFAULT_REPLICATION_BATCH_SIZE: usize = 100;
+
 enum SignatureType {
     Single,
     Multi,
@@ -61,22 +72,17 @@ impl LedgerController {
         }
     }
 
-    #[allow(dead_code)] // FIXME [async] TODO implement external SP parsers
     pub(crate) fn register_sp_parser(
         &self,
         txn_type: String,
-        parser: CustomTransactionParser,
-        free: CustomFree,
+        parser: Arc<dyn StateProofParser>,
     ) -> IndyResult<()> {
-        debug!(
-            "register_sp_parser > txn_type {:?} parser {:?} free {:?}",
-            txn_type, parser, free
-        );
+        debug!("register_sp_parser > txn_type {:?}", txn_type);
+
+        let res = register_sp_parser(&txn_type, parser);
 
-        unimplemented!();
-        // FIXME: !!!
-        // PoolService::register_sp_parser(txn_type, parser, free)
-        //     .map_err(IndyError::from)
+        debug!("register_sp_parser < {:?}", res);
+        res
     }
 
     pub(crate) async fn sign_and_submit_request(
@@ -92,6 +98,10 @@ impl LedgerController {
             pool_handle, wallet_handle, submitter_did, request_json
         );
 
+        let (request_json, taa_digest) = self
+            ._append_txn_author_agreement_acceptance(pool_handle, wallet_handle, request_json)
+            .await?;
+
         let signed_request = self
             ._sign_request(
                 wallet_handle,
@@ -105,6 +115,11 @@ impl LedgerController {
             ._submit_request(pool_handle, signed_request.as_str())
             .await?;
 
+        if let Some(taa_digest) = taa_digest {
+            self._check_txn_author_agreement_change(pool_handle, &taa_digest, &res)
+                .await?;
+        }
+
         let res = Ok(res);
         debug!("sign_and_submit_request < {:?}", res);
         res
@@ -127,6 +142,49 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn submit_requests_batch(
+        &self,
+        handle: PoolHandle,
+        requests: Vec<Value>,
+    ) -> IndyResult<String> {
+        debug!(
+            "submit_requests_batch > handle {:?} requests {:?}",
+            handle, requests
+        );
+
+        let requests = requests
+            .iter()
+            .map(|request| {
+                let request = request.to_string();
+                self._validate_read_request(&request)?;
+                Ok(request)
+            })
+            .collect::<IndyResult<Vec<String>>>()?;
+
+        let results: Vec<Value> = self
+            .pool_service
+            .send_txs_batch(handle, &requests)
+            .await?
+            .into_iter()
+            .map(|res| match res {
+                Ok(response) => json!({ "response": response }),
+                Err(err) => json!({
+                    "error": {
+                        "code": ErrorCode::from(err.kind()) as i32,
+                        "message": err.to_string(),
+                    }
+                }),
+            })
+            .collect();
+
+        let res = serde_json::to_string(&results)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize batch results")?;
+
+        let res = Ok(res);
+        debug!("submit_requests_batch < {:?}", res);
+        res
+    }
+
     pub(crate) async fn submit_action(
         &self,
         handle: PoolHandle,
@@ -151,6 +209,131 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn replicate_ledger(
+        &self,
+        handle: PoolHandle,
+        ledger_type: String,
+        options: ReplicateLedgerOptions,
+    ) -> IndyResult<String> {
+        debug!(
+            "replicate_ledger > handle {:?} ledger_type {:?} options {:?}",
+            handle, ledger_type, options
+        );
+
+        let ledger = parse_ledger_type(&ledger_type)?;
+        let pool_name = self.pool_service.get_name(handle).await?;
+
+        // Serializes reading of the last stored seqNo, fetching and appending of next transactions
+        let lock = LedgerReplica::lock(&pool_name, &ledger).await;
+        let _guard = lock.lock().await;
+
+        let mut replica = LedgerReplica::open(&pool_name, &ledger)?;
+
+        let from_seq_no = replica.seq_no() + 1;
+        let batch_size = cmp::max(options.batch_size.unwrap_or(DEFAULT_REPLICATION_BATCH_SIZE), 1);
+        let mut complete = false;
+
+        while !complete {
+            let next_seq_no = replica.seq_no() + 1;
+
+            let mut count = batch_size;
+
+            if let Some(max_txns) = options.max_txns {
+                count = cmp::min(count, (from_seq_no + max_txns).saturating_sub(next_seq_no));
+            }
+
+            if let Some((ledger_size, _)) = replica.signed_root() {
+                count = cmp::min(count, ledger_size.saturating_sub(replica.seq_no()));
+            }
+
+            if count == 0 {
+                break;
+            }
+
+            let requests = (next_seq_no..next_seq_no + count)
+                .map(|seq_no| {
+                    self.ledger_service
+                        .build_get_txn_request(None, Some(&ledger_type), seq_no as i32)
+                })
+                .collect::<IndyResult<Vec<String>>>()?;
+
+            for response in self.pool_service.send_txs_batch(handle, &requests).await? {
+                if !replica.append(&response?)? {
+                    complete = true;
+                    break;
+                }
+            }
+
+            if let Some((ledger_size, _)) = replica.signed_root() {
+                complete = complete || ledger_size == replica.seq_no();
+            }
+        }
+
+        let (ledger_size, signed_root_hash) = match replica.signed_root() {
+            Some((ledger_size, root_hash)) => (Some(ledger_size), Some(root_hash)),
+            None => (None, None),
+        };
+
+        let res = json!({
+            "ledgerType": ledger_type,
+            "fromSeqNo": from_seq_no,
+            "seqNo": replica.seq_no(),
+            "rootHash": replica.root_hash(),
+            "ledgerSize": ledger_size,
+            "signedRootHash": signed_root_hash,
+            "complete": complete,
+        })
+        .to_string();
+
+        let res = Ok(res);
+        debug!("replicate_ledger < {:?}", res);
+        res
+    }
+
+    pub(crate) fn get_ledger_replica_txns(
+        &self,
+        pool_name: String,
+        ledger_type: String,
+        filter: LedgerReplicaTxnsFilter,
+    ) -> IndyResult<String> {
+        debug!(
+            "get_ledger_replica_txns > pool_name {:?} ledger_type {:?} filter {:?}",
+            pool_name, ledger_type, filter
+        );
+
+        let ledger = parse_ledger_type(&ledger_type)?;
+        let replica = LedgerReplica::open(&pool_name, &ledger)?;
+
+        let authors: Option<Vec<String>> = filter
+            .authors
+            .map(|authors| authors.iter().map(|did| did.to_unqualified().0).collect());
+
+        let txns: Vec<Value> = replica
+            .txns(filter.from.unwrap_or(1), filter.to.unwrap_or_else(|| replica.seq_no()))?
+            .into_iter()
+            .filter(|txn| match authors {
+                Some(ref authors) => ["from", "endorser"].iter().any(|key| {
+                    txn["txn"]["metadata"][key]
+                        .as_str()
+                        .map(|did| authors.iter().any(|author| author == did))
+                        .unwrap_or(false)
+                }),
+                None => true,
+            })
+            .collect();
+
+        let res = json!({
+            "seqNo": replica.seq_no(),
+            "rootHash": replica.root_hash(),
+            "txns": txns,
+        })
+        .to_string();
+
+        let res = Ok(res);
+        debug!("get_ledger_replica_txns < {:?}", res);
+        res
+    }
+
     pub(crate) async fn sign_request(
         &self,
         wallet_handle: WalletHandle,
@@ -1088,6 +1271,56 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn store_txn_author_agreement_acceptance(
+        &self,
+        wallet_handle: WalletHandle,
+        pool_name: String,
+        acceptance: TxnAuthorAgreementAcceptance,
+    ) -> IndyResult<()> {
+        debug!(
+            "store_txn_author_agreement_acceptance > wallet_handle {:?} \
+                pool_name {:?} acceptance {:?}",
+            wallet_handle, pool_name, acceptance
+        );
+
+        self.ledger_service.prepare_acceptance_data(
+            acceptance.text.as_deref(),
+            acceptance.version.as_deref(),
+            acceptance.taa_digest.as_deref(),
+            &acceptance.mechanism,
+            acceptance.time,
+        )?;
+
+        self.wallet_service
+            .upsert_indy_object(wallet_handle, &pool_name, &acceptance)
+            .await?;
+
+        let res = Ok(());
+        debug!("store_txn_author_agreement_acceptance < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn get_txn_author_agreement(
+        &self,
+        pool_handle: PoolHandle,
+    ) -> IndyResult<String> {
+        debug!("get_txn_author_agreement > pool_handle {:?}", pool_handle);
+
+        let state = self
+            .pool_service
+            .get_txn_author_agreement(pool_handle)
+            .await?;
+
+        let res = serde_json::to_string(&state.active).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize transaction author agreement",
+        )?;
+
+        let res = Ok(res);
+        debug!("get_txn_author_agreement < {:?}", res);
+        res
+    }
+
     pub(crate) fn append_request_endorser(
         &self,
         request_json: String,
@@ -1119,6 +1352,216 @@ impl LedgerController {
         res
     }
 
+    pub(crate) async fn build_transaction_bundle(
+        &self,
+        pool_handle: PoolHandle,
+        request_json: String,
+        options: Option<TransactionBundleOptions>,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_transaction_bundle > pool_handle {:?} request_json {:?} options {:?}",
+            pool_handle, request_json, options
+        );
+
+        let get_auth_rule_request = self
+            .ledger_service
+            .build_get_auth_rule_request(None, None, None, None, None, None)?;
+
+        let response = self
+            ._submit_request(pool_handle, &get_auth_rule_request)
+            .await?;
+
+        let auth_rules = self.ledger_service.parse_get_auth_rule_response(&response)?;
+
+        let (mut bundle, request_signatures) = self.ledger_service.build_transaction_bundle(
+            &request_json,
+            &auth_rules,
+            options.unwrap_or_default(),
+        )?;
+
+        for (signer, signature) in request_signatures {
+            let signature = TransactionBundleSignature {
+                verkey: self._get_ledger_verkey(pool_handle, &signer).await?,
+                signature,
+            };
+
+            self._verify_transaction_bundle_signature(&bundle.request, &signer, &signature)
+                .await?;
+
+            self.ledger_service.add_transaction_bundle_signature(
+                &mut bundle,
+                &signer,
+                signature,
+                LedgerController::_now(),
+            )?;
+        }
+
+        let res = serde_json::to_string(&bundle).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize transaction bundle",
+        )?;
+
+        let res = Ok(res);
+        debug!("build_transaction_bundle < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn sign_transaction_bundle(
+        &self,
+        wallet_handle: WalletHandle,
+        mut bundle: TransactionBundle,
+        signer_did: DidValue,
+    ) -> IndyResult<String> {
+        debug!(
+            "sign_transaction_bundle > wallet_handle {:?} bundle {:?} signer_did {:?}",
+            wallet_handle, bundle, signer_did
+        );
+
+        self.crypto_service.validate_did(&signer_did)?;
+
+        self._verify_transaction_bundle_signatures(&bundle).await?;
+
+        let (did, verkey, signature) = self
+            ._create_request_signature(wallet_handle, &signer_did, &bundle.request)
+            .await?;
+
+        let signature = TransactionBundleSignature {
+            verkey,
+            signature: signature.to_base58(),
+        };
+
+        self._verify_transaction_bundle_signature(&bundle.request, &did.0, &signature)
+            .await?;
+
+        self.ledger_service.add_transaction_bundle_signature(
+            &mut bundle,
+            &did.0,
+            signature,
+            LedgerController::_now(),
+        )?;
+
+        let res = serde_json::to_string(&bundle).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize transaction bundle",
+        )?;
+
+        let res = Ok(res);
+        debug!("sign_transaction_bundle < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn get_transaction_bundle_status(
+        &self,
+        bundle: TransactionBundle,
+    ) -> IndyResult<String> {
+        debug!("get_transaction_bundle_status > bundle {:?}", bundle);
+
+        // Status is computed only for signatures made for the bundle request
+        self._verify_transaction_bundle_signatures(&bundle).await?;
+
+        let status = self
+            .ledger_service
+            .get_transaction_bundle_status(&bundle, LedgerController::_now());
+
+        let res = serde_json::to_string(&status).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize transaction bundle status",
+        )?;
+
+        let res = Ok(res);
+        debug!("get_transaction_bundle_status < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn submit_transaction_bundle(
+        &self,
+        pool_handle: PoolHandle,
+        bundle: TransactionBundle,
+    ) -> IndyResult<String> {
+        debug!(
+            "submit_transaction_bundle > pool_handle {:?} bundle {:?}",
+            pool_handle, bundle
+        );
+
+        self._verify_transaction_bundle_signatures(&bundle).await?;
+
+        for (signer, signature) in bundle.signatures.iter() {
+            if self._get_ledger_verkey(pool_handle, signer).await? != signature.verkey {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    format!("Transaction bundle signature of {} is made by a key unknown to the ledger", signer),
+                ));
+            }
+        }
+
+        let request = self
+            .ledger_service
+            .finalize_transaction_bundle(bundle, LedgerController::_now())?;
+
+        let res = self._submit_request(pool_handle, &request).await?;
+
+        let res = Ok(res);
+        debug!("submit_transaction_bundle < {:?}", res);
+        res
+    }
+
+    pub(crate) fn build_ledgers_freeze_request(
+        &self,
+        submitter_did: DidValue,
+        ledgers_ids: Vec<u64>,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_ledgers_freeze_request > submitter_did {:?} ledgers_ids {:?}",
+            submitter_did, ledgers_ids
+        );
+
+        self.crypto_service.validate_did(&submitter_did)?;
+
+        let res = self
+            .ledger_service
+            .build_ledgers_freeze_request(&submitter_did, ledgers_ids)?;
+
+        let res = Ok(res);
+        debug!("build_ledgers_freeze_request < {:?}", res);
+        res
+    }
+
+    pub(crate) fn build_get_frozen_ledgers_request(
+        &self,
+        submitter_did: DidValue,
+    ) -> IndyResult<String> {
+        debug!(
+            "build_get_frozen_ledgers_request > submitter_did {:?}",
+            submitter_did
+        );
+
+        self.crypto_service.validate_did(&submitter_did)?;
+
+        let res = self
+            .ledger_service
+            .build_get_frozen_ledgers_request(&submitter_did)?;
+
+        let res = Ok(res);
+        debug!("build_get_frozen_ledgers_request < {:?}", res);
+        res
+    }
+
+    pub(crate) fn parse_get_frozen_ledgers_response(&self, response: String) -> IndyResult<String> {
+        debug!("parse_get_frozen_ledgers_response > response {:?}", response);
+
+        let res = self
+            .ledger_service
+            .parse_get_frozen_ledgers_response(&response)?;
+
+        let res = Ok(res);
+        debug!("parse_get_frozen_ledgers_response < {:?}", res);
+        res
+    }
+
+    fn _now() -> u64 {
+        time::get_time().sec as u64
+    }
+
     fn _validate_opt_did(&self, did: Option<&DidValue>) -> IndyResult<()> {
         match did {
             Some(did) => Ok(self.crypto_service.validate_did(did)?),
@@ -1138,16 +1581,6 @@ impl LedgerController {
             wallet_handle, submitter_did, request_json
         );
 
-        let my_did: Did = self
-            .wallet_service
-            .get_indy_object(wallet_handle, &submitter_did.0, &RecordOptions::id_value())
-            .await?;
-
-        let my_key: Key = self
-            .wallet_service
-            .get_indy_object(wallet_handle, &my_did.verkey, &RecordOptions::id_value())
-            .await?;
-
         let mut request: Value = serde_json::from_str(request_json)
             .to_indy(IndyErrorKind::InvalidStructure, "Message is invalid json")?;
 
@@ -1161,15 +1594,10 @@ impl LedgerController {
             return res;
         }
 
-        let serialized_request = serialize_signature(request.clone())?;
-
-        let signature = self
-            .crypto_service
-            .sign(&my_key, &serialized_request.as_bytes().to_vec())
+        let (did, _, signature) = self
+            ._create_request_signature(wallet_handle, submitter_did, &request)
             .await?;
 
-        let did = my_did.did.to_short();
-
         match signature_type {
             SignatureType::Single => {
                 request["signature"] = Value::String(signature.to_base58());
@@ -1213,6 +1641,267 @@ impl LedgerController {
         res
     }
 
+    /// Signs the request by the key of `submitter_did` and returns the short DID and the verkey with the signature.
+    async fn _create_request_signature(
+        &self,
+        wallet_handle: WalletHandle,
+        submitter_did: &DidValue,
+        request: &Value,
+    ) -> IndyResult<(ShortDidValue, String, Vec<u8>)> {
+        let my_did: Did = self
+            .wallet_service
+            .get_indy_object(wallet_handle, &submitter_did.0, &RecordOptions::id_value())
+            .await?;
+
+        let my_key: Key = self
+            .wallet_service
+            .get_indy_object(wallet_handle, &my_did.verkey, &RecordOptions::id_value())
+            .await?;
+
+        let serialized_request = serialize_signature(request.clone())?;
+
+        let signature = self
+            .crypto_service
+            .sign(&my_key, &serialized_request.as_bytes().to_vec())
+            .await?;
+
+        Ok((my_did.did.to_short(), my_did.verkey, signature))
+    }
+
+    /// Checks that the signature of `signer` is made for the bundle request by the key of the signature.
+    async fn _verify_transaction_bundle_signature(
+        &self,
+        request: &Value,
+        signer: &str,
+        signature: &TransactionBundleSignature,
+    ) -> IndyResult<()> {
+        let serialized_request = serialize_signature(request.clone())?;
+
+        let signature_bytes = signature.signature.from_base58().map_err(|_| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Transaction bundle signature of {} isn't base58 encoded", signer),
+            )
+        })?;
+
+        let valid = self
+            .crypto_service
+            .verify(&signature.verkey, serialized_request.as_bytes(), &signature_bytes)
+            .await?;
+
+        if !valid {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Transaction bundle signature of {} doesn't match the request", signer),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn _verify_transaction_bundle_signatures(&self, bundle: &TransactionBundle) -> IndyResult<()> {
+        for (signer, signature) in bundle.signatures.iter() {
+            self._verify_transaction_bundle_signature(&bundle.request, signer, signature)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the full verkey of the DID from its NYM transaction on the ledger.
+    async fn _get_ledger_verkey(&self, pool_handle: PoolHandle, did: &str) -> IndyResult<String> {
+        let did = DidValue(did.to_string());
+
+        let get_nym_request = self.ledger_service.build_get_nym_request(None, &did)?;
+        let get_nym_response = self._submit_request(pool_handle, &get_nym_request).await?;
+
+        let nym_data = self.ledger_service.parse_get_nym_response(&get_nym_response)?;
+
+        let nym_data: NymData = serde_json::from_str(&nym_data)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize NYM data")?;
+
+        build_full_verkey(&did.0, nym_data.verkey.as_deref())
+    }
+
+    /// Appends the acceptance of the pool transaction author agreement to DOMAIN write request without it.
+    ///
+    /// The acceptance is taken from the pool open config or from the wallet record stored for the pool.
+    /// Returns the request and the digest of the accepted agreement if the acceptance has been appended.
+    async fn _append_txn_author_agreement_acceptance(
+        &self,
+        pool_handle: PoolHandle,
+        wallet_handle: WalletHandle,
+        request_json: String,
+    ) -> IndyResult<(String, Option<String>)> {
+        let mut request: Value = serde_json::from_str(&request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let is_domain_write = request["operation"]["type"]
+            .as_str()
+            .map(|txn_type| DOMAIN_WRITE_REQUESTS.contains(&txn_type))
+            .unwrap_or(false);
+
+        if !is_domain_write || !request["taaAcceptance"].is_null() {
+            return Ok((request_json, None));
+        }
+
+        // Agreement isn't fetched from the ledger for pools without the acceptance
+        let acceptance = match self
+            .pool_service
+            .get_txn_author_agreement_acceptance(pool_handle)
+            .await?
+        {
+            Some(acceptance) => Some(acceptance),
+            None => {
+                let pool_name = self.pool_service.get_name(pool_handle).await?;
+
+                self.wallet_service
+                    .get_indy_opt_object::<TxnAuthorAgreementAcceptance>(
+                        wallet_handle,
+                        &pool_name,
+                        &RecordOptions::id_value(),
+                    )
+                    .await?
+            }
+        };
+
+        let acceptance = match acceptance {
+            Some(acceptance) => acceptance,
+            None => return Ok((request_json, None)),
+        };
+
+        let state = match self.pool_service.get_txn_author_agreement(pool_handle).await {
+            Ok(state) => state,
+            Err(err) if err.kind() == IndyErrorKind::InvalidPoolHandle => return Err(err),
+            Err(err) => {
+                warn!("Can't get transaction author agreement of the pool: {}", err);
+                return Ok((request_json, None));
+            }
+        };
+
+        let active = match state.active {
+            Some(active) => active,
+            None => return Ok((request_json, None)),
+        };
+
+        let acceptance_data = self.ledger_service.prepare_acceptance_data(
+            acceptance.text.as_deref(),
+            acceptance.version.as_deref(),
+            acceptance.taa_digest.as_deref(),
+            &acceptance.mechanism,
+            acceptance.time,
+        )?;
+
+        if !acceptance_data.taa_digest.eq_ignore_ascii_case(&active.digest) {
+            self._handle_txn_author_agreement_change(
+                state.change_policy,
+                &acceptance_data.taa_digest,
+                &active,
+            )?;
+        }
+
+        if let Some(ref aml) = active.aml {
+            if !aml.0.contains_key(&acceptance.mechanism) {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    format!(
+                        "Acceptance mechanism {:?} isn't in the active acceptance mechanisms list",
+                        acceptance.mechanism
+                    ),
+                ));
+            }
+        }
+
+        let taa_digest = acceptance_data.taa_digest.clone();
+        request["taaAcceptance"] = json!(acceptance_data);
+
+        let request_json = serde_json::to_string(&request).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize request after adding author agreement acceptance data",
+        )?;
+
+        Ok((request_json, Some(taa_digest)))
+    }
+
+    /// Re-fetches the transaction author agreement if the request with appended acceptance
+    /// has been rejected, so the change of the agreement on the ledger is handled by the pool policy.
+    async fn _check_txn_author_agreement_change(
+        &self,
+        pool_handle: PoolHandle,
+        taa_digest: &str,
+        response: &str,
+    ) -> IndyResult<()> {
+        let response: Value = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        if response["op"] != "REJECT" && response["op"] != "REQNACK" {
+            return Ok(());
+        }
+
+        let state = match self
+            .pool_service
+            .refresh_txn_author_agreement(pool_handle)
+            .await
+        {
+            Ok(state) => state,
+            Err(err) => {
+                warn!("Can't fetch transaction author agreement of the pool: {}", err);
+                return Ok(());
+            }
+        };
+
+        match state.active {
+            Some(ref active) if !taa_digest.eq_ignore_ascii_case(&active.digest) => {
+                self._handle_txn_author_agreement_change(state.change_policy, taa_digest, active)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn _handle_txn_author_agreement_change(
+        &self,
+        change_policy: TxnAuthorAgreementChangePolicy,
+        taa_digest: &str,
+        active: &ActiveTxnAuthorAgreement,
+    ) -> IndyResult<()> {
+        let msg = format!(
+            "Accepted transaction author agreement {} isn't active on the ledger. \
+                Active agreement: version {} digest {}",
+            taa_digest, active.version, active.digest
+        );
+
+        match change_policy {
+            TxnAuthorAgreementChangePolicy::Fail => {
+                Err(err_msg(IndyErrorKind::InvalidState, msg))
+            }
+            TxnAuthorAgreementChangePolicy::Warn => {
+                warn!("{}", msg);
+                Ok(())
+            }
+        }
+    }
+
+    fn _validate_read_request(&self, request_json: &str) -> IndyResult<()> {
+        let request = serde_json::from_str::<Request<serde_json::Value>>(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let txn_type = request.operation["type"].as_str().ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                "No valid type field in request",
+            )
+        })?;
+
+        if READ_REQUESTS.contains(&txn_type) || get_sp_parser(txn_type).is_some() {
+            Ok(())
+        } else {
+            Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Only read requests can be submitted in batch: {}", txn_type),
+            ))
+        }
+    }
+
     async fn _submit_request<'a>(
         &self,
         handle: PoolHandle,
@@ -10,6 +10,7 @@ mod non_secrets;
 pub mod payments;
 mod pairwise;
 mod pool;
+mod vdr;
 #[cfg(feature = "cheqd")]
 mod cheqd_ledger;
 #[cfg(feature = "cheqd")]
@@ -29,6 +30,7 @@ pub(crate) use metrics::MetricsController;
 pub(crate) use non_secrets::NonSecretsController;
 pub(crate) use pairwise::PairwiseController;
 pub(crate) use pool::PoolController;
+pub(crate) use vdr::VdrController;
 pub(crate) use wallet::WalletController;
 #[cfg(feature = "cheqd")]
 pub(crate) use cheqd_ledger::CheqdLedgerController;
@@ -1,7 +1,11 @@
 use std::{collections::HashMap, sync::Arc};
 
 use futures::lock::Mutex;
-use indy_api_types::{domain::wallet::Tags, errors::prelude::*, SearchHandle, WalletHandle};
+use indy_api_types::{
+    domain::wallet::{Tags, WalletOperations},
+    errors::prelude::*,
+    SearchHandle, WalletHandle,
+};
 use indy_utils::next_search_handle;
 use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
 
@@ -228,6 +232,30 @@ impl NonSecretsController {
         res
     }
 
+    pub(crate) async fn batch(
+        &self,
+        wallet_handle: WalletHandle,
+        operations: WalletOperations,
+    ) -> IndyResult<()> {
+        trace!(
+            "batch > wallet_handle {:?} operations {:?}",
+            wallet_handle,
+            operations
+        );
+
+        for operation in operations.0.iter() {
+            self._check_type(operation.type_())?;
+        }
+
+        self.wallet_service
+            .transaction(wallet_handle, &operations.0)
+            .await?;
+
+        let res = Ok(());
+        trace!("batch < {:?}", res);
+        res
+    }
+
     pub(crate) async fn open_search(
         &self,
         wallet_handle: WalletHandle,
@@ -305,6 +333,7 @@ impl NonSecretsController {
 
         let search_result = SearchRecords {
             total_count: search.get_total_count()?,
+            cursor: search.get_cursor()?,
             records: if records.is_empty() {
                 None
             } else {
@@ -355,5 +384,7 @@ impl NonSecretsController {
 #[serde(rename_all = "camelCase")]
 pub struct SearchRecords {
     pub total_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
     pub records: Option<Vec<WalletRecord>>,
 }
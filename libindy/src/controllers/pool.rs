@@ -86,6 +86,44 @@ impl PoolController {
         res
     }
 
+    pub(crate) async fn get_status(&self, handle: PoolHandle) -> IndyResult<String> {
+        trace!("get_status > handle {:?}", handle);
+
+        let status = self.pool_service.get_status(handle).await?;
+
+        let res = Ok(status);
+        trace!("get_status < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn export_snapshot(&self, handle: PoolHandle, path: String) -> IndyResult<()> {
+        trace!("export_snapshot > handle {:?} path {:?}", handle, path);
+
+        self.pool_service.export_snapshot(handle, &path).await?;
+
+        let res = Ok(());
+        trace!("export_snapshot < {:?}", res);
+        res
+    }
+
+    pub(crate) async fn import_snapshot(
+        &self,
+        name: String,
+        path: String,
+        config: Option<PoolConfig>,
+    ) -> IndyResult<()> {
+        trace!(
+            "import_snapshot > name {:?} path {:?} config {:?}",
+            name, path, config
+        );
+
+        self.pool_service.import_snapshot(&name, &path, config).await?;
+
+        let res = Ok(());
+        trace!("import_snapshot < {:?}", res);
+        res
+    }
+
     pub(crate) fn set_protocol_version(&self, version: usize) -> IndyResult<()> {
         trace!("set_protocol_version > version {:?}", version);
 
@@ -0,0 +1,412 @@
+//! VDR routes ledger operations to the Indy and Cheqd ledgers registered for DID namespaces
+
+use std::{collections::HashMap, fs, io::Write, sync::Arc};
+
+use futures::lock::Mutex;
+use indy_api_types::{errors::prelude::*, validation::Validatable, VdrHandle};
+use indy_utils::next_vdr_handle;
+
+use crate::{
+    domain::{
+        pool::PoolConfig,
+        vdr::{
+            namespaces::parse_namespace_list,
+            ping_status::{PingStatus, PingStatusList},
+            taa_config::TAAConfig,
+            vdr::{IndyLedger, Ledger, VDR},
+        },
+    },
+    services::{LedgerService, PoolService},
+    utils::environment,
+};
+
+#[cfg(feature = "cheqd")]
+use crate::{
+    domain::{cheqd_pool::ConnectionOptions, vdr::vdr::CheqdLedger},
+    services::{CheqdLedgerService, CheqdPoolService},
+};
+
+mod prepare;
+mod resolve;
+mod submit;
+
+pub(crate) struct VdrController {
+    pool_service: Arc<PoolService>,
+    ledger_service: Arc<LedgerService>,
+    #[cfg(feature = "cheqd")]
+    cheqd_pool_service: Arc<CheqdPoolService>,
+    #[cfg(feature = "cheqd")]
+    cheqd_ledger_service: Arc<CheqdLedgerService>,
+    vdrs: Mutex<HashMap<VdrHandle, VDR>>,
+}
+
+impl VdrController {
+    #[cfg(feature = "cheqd")]
+    pub(crate) fn new(
+        pool_service: Arc<PoolService>,
+        ledger_service: Arc<LedgerService>,
+        cheqd_pool_service: Arc<CheqdPoolService>,
+        cheqd_ledger_service: Arc<CheqdLedgerService>,
+    ) -> VdrController {
+        VdrController {
+            pool_service,
+            ledger_service,
+            cheqd_pool_service,
+            cheqd_ledger_service,
+            vdrs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[cfg(not(feature = "cheqd"))]
+    pub(crate) fn new(
+        pool_service: Arc<PoolService>,
+        ledger_service: Arc<LedgerService>,
+    ) -> VdrController {
+        VdrController {
+            pool_service,
+            ledger_service,
+            vdrs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) async fn create_vdr(&self) -> IndyResult<VdrHandle> {
+        trace!("create_vdr >");
+
+        let handle = next_vdr_handle();
+        self.vdrs.lock().await.insert(handle, VDR::new());
+
+        trace!("create_vdr < {:?}", handle);
+        Ok(handle)
+    }
+
+    pub(crate) async fn register_indy_ledger(
+        &self,
+        handle: VdrHandle,
+        namespace_list: &str,
+        genesis_txn_data: &str,
+        taa_config: &str,
+    ) -> IndyResult<()> {
+        trace!(
+            "register_indy_ledger > handle {:?} namespace_list {:?} genesis_txn_data {:?} taa_config {:?}",
+            handle, namespace_list, genesis_txn_data, taa_config
+        );
+
+        let namespaces = parse_namespace_list(namespace_list)?;
+        let taa_config = Self::_parse_taa_config(taa_config)?;
+
+        self._ensure_not_registered(handle, &namespaces).await?;
+
+        let pool_name = Self::_ledger_name(handle, &namespaces);
+
+        if environment::pool_path(&pool_name).exists() {
+            warn!("register_indy_ledger ? removing stale pool config {:?}", pool_name);
+            self.pool_service.delete(&pool_name).await?;
+        }
+
+        self._create_pool(&pool_name, genesis_txn_data)?;
+
+        let pool_handle = match self.pool_service.open(pool_name.clone(), None).await {
+            Ok(pool_handle) => pool_handle,
+            Err(err) => {
+                self.pool_service.delete(&pool_name).await.ok();
+                return Err(err);
+            }
+        };
+
+        let ledger = Ledger::Indy(IndyLedger {
+            pool_handle,
+            pool_name,
+            taa_config,
+        });
+
+        if let Err(err) = self._register(handle, namespaces, ledger.clone()).await {
+            self._cleanup_ledger(&ledger).await.ok();
+            return Err(err);
+        }
+
+        trace!("register_indy_ledger <");
+        Ok(())
+    }
+
+    #[cfg(feature = "cheqd")]
+    pub(crate) async fn register_cheqd_ledger(
+        &self,
+        handle: VdrHandle,
+        namespace_list: &str,
+        chain_id: &str,
+        node_addrs_list: &str,
+    ) -> IndyResult<()> {
+        trace!(
+            "register_cheqd_ledger > handle {:?} namespace_list {:?} chain_id {:?} node_addrs_list {:?}",
+            handle, namespace_list, chain_id, node_addrs_list
+        );
+
+        let namespaces = parse_namespace_list(namespace_list)?;
+
+        let node_addrs: Vec<String> = serde_json::from_str(node_addrs_list).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize list of node addresses",
+        )?;
+
+        let (rpc_address, backup_rpc_addresses) = node_addrs.split_first().ok_or_else(|| {
+            err_msg(IndyErrorKind::InvalidStructure, "List of node addresses is empty")
+        })?;
+
+        // Nodes other than the first one are asked if it doesn't respond
+        let connection_options = ConnectionOptions {
+            backup_rpc_addresses: backup_rpc_addresses.to_vec(),
+            ..ConnectionOptions::default()
+        };
+
+        connection_options
+            .validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        self._ensure_not_registered(handle, &namespaces).await?;
+
+        let pool_alias = Self::_ledger_name(handle, &namespaces);
+
+        if environment::cheqd_pool_path(&pool_alias).exists() {
+            warn!("register_cheqd_ledger ? removing stale pool config {:?}", pool_alias);
+            self.cheqd_pool_service.delete(&pool_alias).await?;
+        }
+
+        self.cheqd_pool_service
+            .add(
+                &pool_alias,
+                rpc_address,
+                chain_id,
+                None,
+                None,
+                Some(connection_options),
+            )
+            .await?;
+
+        let ledger = Ledger::Cheqd(CheqdLedger {
+            pool_alias,
+            chain_id: chain_id.to_string(),
+        });
+
+        if let Err(err) = self._register(handle, namespaces, ledger.clone()).await {
+            self._cleanup_ledger(&ledger).await.ok();
+            return Err(err);
+        }
+
+        trace!("register_cheqd_ledger <");
+        Ok(())
+    }
+
+    #[cfg(not(feature = "cheqd"))]
+    pub(crate) async fn register_cheqd_ledger(
+        &self,
+        handle: VdrHandle,
+        namespace_list: &str,
+        chain_id: &str,
+        node_addrs_list: &str,
+    ) -> IndyResult<()> {
+        trace!(
+            "register_cheqd_ledger > handle {:?} namespace_list {:?} chain_id {:?} node_addrs_list {:?}",
+            handle, namespace_list, chain_id, node_addrs_list
+        );
+
+        Err(err_msg(
+            IndyErrorKind::InvalidState,
+            "Cheqd ledgers are not supported: library was built without `cheqd` feature",
+        ))
+    }
+
+    pub(crate) async fn ping(&self, handle: VdrHandle, namespace_list: &str) -> IndyResult<String> {
+        trace!("ping > handle {:?} namespace_list {:?}", handle, namespace_list);
+
+        let namespaces = parse_namespace_list(namespace_list)?;
+
+        let mut statuses = PingStatusList::new();
+
+        for namespace in namespaces {
+            let ledger = self._get_ledger(handle, &namespace).await?;
+            let status = self._ping_ledger(&ledger).await;
+            statuses.insert(namespace, status);
+        }
+
+        let res = serde_json::to_string(&statuses).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize list of PingStatus objects",
+        )?;
+
+        trace!("ping < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) async fn cleanup(&self, handle: VdrHandle) -> IndyResult<()> {
+        trace!("cleanup > handle {:?}", handle);
+
+        let vdr = self.vdrs.lock().await.remove(&handle).ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Unknown VDR handle: {}", handle),
+            )
+        })?;
+
+        for ledger in vdr.ledgers() {
+            if let Err(err) = self._cleanup_ledger(&ledger).await {
+                warn!("cleanup ? unable to release ledger {:?}: {}", ledger, err);
+            }
+        }
+
+        trace!("cleanup <");
+        Ok(())
+    }
+
+    async fn _get_ledger(&self, handle: VdrHandle, namespace: &str) -> IndyResult<Ledger> {
+        self.vdrs
+            .lock()
+            .await
+            .get(&handle)
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidState,
+                    format!("Unknown VDR handle: {}", handle),
+                )
+            })?
+            .resolve_ledger(namespace)
+    }
+
+    async fn _ensure_not_registered(&self, handle: VdrHandle, namespaces: &[String]) -> IndyResult<()> {
+        self.vdrs
+            .lock()
+            .await
+            .get(&handle)
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidState,
+                    format!("Unknown VDR handle: {}", handle),
+                )
+            })?
+            .ensure_not_registered(namespaces)
+    }
+
+    async fn _register(&self, handle: VdrHandle, namespaces: Vec<String>, ledger: Ledger) -> IndyResult<()> {
+        self.vdrs
+            .lock()
+            .await
+            .get_mut(&handle)
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidState,
+                    format!("Unknown VDR handle: {}", handle),
+                )
+            })?
+            .register(namespaces, ledger)
+    }
+
+    async fn _ping_ledger(&self, ledger: &Ledger) -> PingStatus {
+        match ledger {
+            Ledger::Indy(ledger) => {
+                let res = async {
+                    let request = self
+                        .ledger_service
+                        .build_get_txn_request(None, Some("POOL"), 1)?;
+
+                    self.pool_service
+                        .send_tx(ledger.pool_handle, &request)
+                        .await
+                }
+                .await;
+
+                match res {
+                    Ok(_) => PingStatus::success("Pool is available".to_string()),
+                    Err(err) => PingStatus::fail(err.to_string()),
+                }
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(ledger) => {
+                match self.cheqd_pool_service.abci_info(&ledger.pool_alias).await {
+                    Ok(info) => PingStatus::success(format!(
+                        "Pool is available, last block height: {}",
+                        info.response.last_block_height
+                    )),
+                    Err(err) => PingStatus::fail(err.to_string()),
+                }
+            }
+        }
+    }
+
+    async fn _cleanup_ledger(&self, ledger: &Ledger) -> IndyResult<()> {
+        match ledger {
+            Ledger::Indy(ledger) => {
+                self.pool_service.close(ledger.pool_handle).await?;
+                self.pool_service.delete(&ledger.pool_name).await
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(ledger) => self.cheqd_pool_service.delete(&ledger.pool_alias).await,
+        }
+    }
+
+    fn _create_pool(&self, pool_name: &str, genesis_txn_data: &str) -> IndyResult<()> {
+        fs::create_dir_all(environment::tmp_path())
+            .to_indy(IndyErrorKind::IOError, "Can't create temporary directory")?;
+
+        let genesis_txn_path = environment::tmp_file_path(&format!("{}.txn", pool_name));
+
+        {
+            let mut f = fs::File::create(&genesis_txn_path)
+                .to_indy(IndyErrorKind::IOError, "Can't create genesis txn file")?;
+
+            f.write_all(genesis_txn_data.as_bytes())
+                .to_indy(IndyErrorKind::IOError, "Can't write to genesis txn file")?;
+
+            f.flush()
+                .to_indy(IndyErrorKind::IOError, "Can't write to genesis txn file")?;
+        }
+
+        let config = PoolConfig {
+            genesis_txn: genesis_txn_path.to_string_lossy().to_string(),
+        };
+
+        let res = self.pool_service.create(pool_name, Some(config));
+
+        fs::remove_file(&genesis_txn_path).ok();
+
+        res
+    }
+
+    fn _parse_taa_config(taa_config: &str) -> IndyResult<Option<TAAConfig>> {
+        let taa_config: serde_json::Value = serde_json::from_str(taa_config).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize TAA config",
+        )?;
+
+        let is_empty = taa_config.is_null()
+            || taa_config.as_object().map(|obj| obj.is_empty()).unwrap_or(false);
+
+        if is_empty {
+            return Ok(None);
+        }
+
+        let taa_config: TAAConfig = serde_json::from_value(taa_config).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize TAA config",
+        )?;
+
+        taa_config
+            .validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        Ok(Some(taa_config))
+    }
+
+    #[cfg(feature = "cheqd")]
+    fn _ensure_no_cheqd_endorser(endorser: Option<&str>) -> IndyResult<()> {
+        match endorser {
+            Some(_) => Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Endorsement isn't supported by Cheqd ledgers",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn _ledger_name(handle: VdrHandle, namespaces: &[String]) -> String {
+        format!("vdr_{}_{}", handle, namespaces[0].replace(':', "_"))
+    }
+}
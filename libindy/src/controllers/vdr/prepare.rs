@@ -0,0 +1,260 @@
+use indy_api_types::{errors::prelude::*, VdrHandle};
+use serde_json::Value;
+
+use crate::{
+    controllers::VdrController,
+    domain::{
+        anoncreds::{
+            credential_definition::{CredentialDefinition, CredentialDefinitionV1},
+            schema::{Schema, SchemaV1},
+        },
+        crypto::did::DidValue,
+        vdr::{
+            namespaces::{did_namespace, ledger_did},
+            prepared_txn::{IndyEndorsementSpec, PreparedTxn, SIGNATURE_SPEC_ED25519},
+            txn_params::IndyNymParams,
+            vdr::{IndyLedger, Ledger},
+        },
+    },
+    utils::crypto::signature_serializer::serialize_signature,
+};
+
+#[cfg(feature = "cheqd")]
+use crate::domain::{
+    cheqd_ledger::cosmos_ext::CosmosSignDocExt,
+    vdr::{
+        prepared_txn::SIGNATURE_SPEC_SECP256K1,
+        txn_params::{CheqdNymParams, CheqdTxParams},
+        vdr::CheqdLedger,
+    },
+};
+
+type PreparedTxnResult = (String, String, Vec<u8>, Vec<u8>, Option<String>);
+
+impl VdrController {
+    pub(crate) async fn prepare_did(
+        &self,
+        handle: VdrHandle,
+        txn_specific_params: &str,
+        submitter_did: &str,
+        endorser: Option<&str>,
+    ) -> IndyResult<PreparedTxnResult> {
+        trace!(
+            "prepare_did > handle {:?} txn_specific_params {:?} submitter_did {:?} endorser {:?}",
+            handle, txn_specific_params, submitter_did, endorser
+        );
+
+        let submitter_did = DidValue(submitter_did.to_string());
+        let namespace = did_namespace(&submitter_did)?;
+
+        let prepared = match self._get_ledger(handle, &namespace).await? {
+            Ledger::Indy(ledger) => {
+                let params: IndyNymParams = serde_json::from_str(txn_specific_params).to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    "Cannot deserialize NYM transaction params",
+                )?;
+
+                let request = self.ledger_service.build_nym_request(
+                    &ledger_did(&submitter_did),
+                    &ledger_did(&params.dest),
+                    params.verkey.as_deref(),
+                    params.alias.as_deref(),
+                    params.role.as_deref(),
+                )?;
+
+                self._prepare_indy_txn(namespace, &ledger, &request, endorser)?
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(ledger) => {
+                Self::_ensure_no_cheqd_endorser(endorser)?;
+
+                let params: CheqdNymParams = serde_json::from_str(txn_specific_params).to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    "Cannot deserialize NYM transaction params",
+                )?;
+
+                let msg = self.cheqd_ledger_service.cheqd_build_msg_create_nym(
+                    &params.did,
+                    &params.creator,
+                    &params.verkey,
+                    &params.alias,
+                    &params.role,
+                )?;
+
+                self._prepare_cheqd_txn(namespace, &ledger, msg, &params.tx).await?
+            }
+        };
+
+        trace!("prepare_did < {:?}", prepared);
+        Ok(prepared.into_tuple())
+    }
+
+    pub(crate) async fn prepare_schema(
+        &self,
+        handle: VdrHandle,
+        txn_specific_params: &str,
+        submitter_did: &str,
+        endorser: Option<&str>,
+    ) -> IndyResult<PreparedTxnResult> {
+        trace!(
+            "prepare_schema > handle {:?} txn_specific_params {:?} submitter_did {:?} endorser {:?}",
+            handle, txn_specific_params, submitter_did, endorser
+        );
+
+        let submitter_did = DidValue(submitter_did.to_string());
+        let namespace = did_namespace(&submitter_did)?;
+
+        let prepared = match self._get_ledger(handle, &namespace).await? {
+            Ledger::Indy(ledger) => {
+                let schema: Schema = serde_json::from_str(txn_specific_params).to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    "Cannot deserialize Schema",
+                )?;
+
+                let request = self
+                    .ledger_service
+                    .build_schema_request(&ledger_did(&submitter_did), SchemaV1::from(schema))?;
+
+                self._prepare_indy_txn(namespace, &ledger, &request, endorser)?
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(_) => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidState,
+                    "Schemas aren't supported by Cheqd ledgers: cheqd-node doesn't define Schema transactions",
+                ));
+            }
+        };
+
+        trace!("prepare_schema < {:?}", prepared);
+        Ok(prepared.into_tuple())
+    }
+
+    pub(crate) async fn prepare_cred_def(
+        &self,
+        handle: VdrHandle,
+        txn_specific_params: &str,
+        submitter_did: &str,
+        endorser: Option<&str>,
+    ) -> IndyResult<PreparedTxnResult> {
+        trace!(
+            "prepare_cred_def > handle {:?} txn_specific_params {:?} submitter_did {:?} endorser {:?}",
+            handle, txn_specific_params, submitter_did, endorser
+        );
+
+        let submitter_did = DidValue(submitter_did.to_string());
+        let namespace = did_namespace(&submitter_did)?;
+
+        let prepared = match self._get_ledger(handle, &namespace).await? {
+            Ledger::Indy(ledger) => {
+                let cred_def: CredentialDefinition = serde_json::from_str(txn_specific_params)
+                    .to_indy(
+                        IndyErrorKind::InvalidStructure,
+                        "Cannot deserialize CredentialDefinition",
+                    )?;
+
+                let request = self.ledger_service.build_cred_def_request(
+                    &ledger_did(&submitter_did),
+                    CredentialDefinitionV1::from(cred_def),
+                )?;
+
+                self._prepare_indy_txn(namespace, &ledger, &request, endorser)?
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(_) => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidState,
+                    "Credential definitions aren't supported by Cheqd ledgers: cheqd-node doesn't define Credential Definition transactions",
+                ));
+            }
+        };
+
+        trace!("prepare_cred_def < {:?}", prepared);
+        Ok(prepared.into_tuple())
+    }
+
+    fn _prepare_indy_txn(
+        &self,
+        namespace: String,
+        ledger: &IndyLedger,
+        request: &str,
+        endorser: Option<&str>,
+    ) -> IndyResult<PreparedTxn> {
+        let mut request: Value = serde_json::from_str(request)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize request")?;
+
+        if let Some(ref taa_config) = ledger.taa_config {
+            request["taaAcceptance"] = json!(self.ledger_service.prepare_acceptance_data(
+                taa_config.text.as_deref(),
+                taa_config.version.as_deref(),
+                taa_config.taa_digest.as_deref(),
+                &taa_config.acc_mech_type,
+                taa_config.time,
+            )?);
+        }
+
+        let endorsement_spec = match endorser {
+            Some(endorser) => {
+                let endorser_did = DidValue(endorser.to_string());
+                request["endorser"] = json!(ledger_did(&endorser_did));
+
+                let spec = serde_json::to_string(&IndyEndorsementSpec { endorser_did })
+                    .to_indy(
+                        IndyErrorKind::InvalidState,
+                        "Cannot serialize IndyEndorsementSpec",
+                    )?;
+
+                Some(spec)
+            }
+            None => None,
+        };
+
+        let bytes_to_sign = serialize_signature(request.clone())?.into_bytes();
+
+        let txn_bytes = serde_json::to_vec(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize request")?;
+
+        Ok(PreparedTxn {
+            namespace,
+            signature_spec: SIGNATURE_SPEC_ED25519.to_string(),
+            txn_bytes,
+            bytes_to_sign,
+            endorsement_spec,
+        })
+    }
+
+    #[cfg(feature = "cheqd")]
+    async fn _prepare_cheqd_txn(
+        &self,
+        namespace: String,
+        ledger: &CheqdLedger,
+        msg: cosmrs::tx::Msg,
+        tx: &CheqdTxParams,
+    ) -> IndyResult<PreparedTxn> {
+        let sign_doc = self
+            .cheqd_ledger_service
+            .auth_build_tx(
+                &ledger.chain_id,
+                &tx.sender_public_key,
+                msg,
+                tx.account_number,
+                tx.sequence_number,
+                tx.max_gas,
+                tx.max_coin_amount,
+                &tx.max_coin_denom,
+                tx.timeout_height,
+                &tx.memo,
+            )
+            .await?;
+
+        let sign_doc_bytes = sign_doc.to_bytes()?;
+
+        Ok(PreparedTxn {
+            namespace,
+            signature_spec: SIGNATURE_SPEC_SECP256K1.to_string(),
+            txn_bytes: sign_doc_bytes.clone(),
+            bytes_to_sign: sign_doc_bytes,
+            endorsement_spec: None,
+        })
+    }
+}
@@ -0,0 +1,263 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use indy_api_types::{errors::prelude::*, VdrHandle};
+
+use crate::{
+    controllers::VdrController,
+    domain::{
+        anoncreds::{credential_definition::CredentialDefinitionId, schema::SchemaId},
+        cache::GetCacheOptions,
+        crypto::did::DidValue,
+        vdr::{
+            namespaces::{cred_def_id_namespace, did_parts, schema_id_namespace},
+            vdr::Ledger,
+        },
+    },
+};
+
+impl VdrController {
+    pub(crate) async fn resolve_did(
+        &self,
+        handle: VdrHandle,
+        fqdid: &str,
+        cache_options: &str,
+    ) -> IndyResult<String> {
+        trace!(
+            "resolve_did > handle {:?} fqdid {:?} cache_options {:?}",
+            handle, fqdid, cache_options
+        );
+
+        let cache_options = Self::_parse_cache_options(cache_options)?;
+
+        let did = DidValue(fqdid.to_string());
+        let (namespace, id) = did_parts(&did)?;
+
+        if let Some(cached) = self._get_cached(handle, fqdid, &cache_options).await? {
+            trace!("resolve_did < cached {:?}", cached);
+            return Ok(cached);
+        }
+
+        let res = match self._get_ledger(handle, &namespace).await? {
+            Ledger::Indy(ledger) => {
+                let request = self
+                    .ledger_service
+                    .build_get_nym_request(None, &DidValue(id))?;
+
+                let response = self
+                    .pool_service
+                    .send_tx(ledger.pool_handle, &request)
+                    .await?;
+
+                self.ledger_service.parse_get_nym_response(&response)?
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(ledger) => {
+                let id = id.parse::<u64>().to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    format!("Cheqd DID `{}` must end with numeric NYM id", fqdid),
+                )?;
+
+                let query = self.cheqd_ledger_service.build_query_get_nym(id)?;
+
+                let response = self
+                    .cheqd_pool_service
+                    .abci_query(&ledger.pool_alias, query)
+                    .await?;
+
+                let response = self
+                    .cheqd_ledger_service
+                    .cheqd_parse_query_get_nym_resp(&response)?;
+
+                let nym = response.nym.ok_or_else(|| {
+                    err_msg(IndyErrorKind::LedgerItemNotFound, "Nym not found")
+                })?;
+
+                serde_json::to_string(&nym).to_indy(
+                    IndyErrorKind::InvalidState,
+                    "Cannot serialize Nym object",
+                )?
+            }
+        };
+
+        self._store_cached(handle, fqdid, &res, &cache_options).await?;
+
+        trace!("resolve_did < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) async fn resolve_schema(
+        &self,
+        handle: VdrHandle,
+        fqschema: &str,
+        cache_options: &str,
+    ) -> IndyResult<String> {
+        trace!(
+            "resolve_schema > handle {:?} fqschema {:?} cache_options {:?}",
+            handle, fqschema, cache_options
+        );
+
+        let cache_options = Self::_parse_cache_options(cache_options)?;
+
+        let id = SchemaId(fqschema.to_string());
+        let namespace = schema_id_namespace(&id)?;
+
+        if let Some(cached) = self._get_cached(handle, fqschema, &cache_options).await? {
+            trace!("resolve_schema < cached {:?}", cached);
+            return Ok(cached);
+        }
+
+        let res = match self._get_ledger(handle, &namespace).await? {
+            Ledger::Indy(ledger) => {
+                let request = self.ledger_service.build_get_schema_request(None, &id)?;
+
+                let response = self
+                    .pool_service
+                    .send_tx(ledger.pool_handle, &request)
+                    .await?;
+
+                let (_, schema) = self
+                    .ledger_service
+                    .parse_get_schema_response(&response, id.get_method().as_deref())?;
+
+                schema
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(_) => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidState,
+                    "Schemas aren't supported by Cheqd ledgers: cheqd-node doesn't define Schema transactions",
+                ));
+            }
+        };
+
+        self._store_cached(handle, fqschema, &res, &cache_options).await?;
+
+        trace!("resolve_schema < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) async fn resolve_cred_def(
+        &self,
+        handle: VdrHandle,
+        fqcreddef: &str,
+        cache_options: &str,
+    ) -> IndyResult<String> {
+        trace!(
+            "resolve_cred_def > handle {:?} fqcreddef {:?} cache_options {:?}",
+            handle, fqcreddef, cache_options
+        );
+
+        let cache_options = Self::_parse_cache_options(cache_options)?;
+
+        let id = CredentialDefinitionId(fqcreddef.to_string());
+        let namespace = cred_def_id_namespace(&id)?;
+
+        if let Some(cached) = self._get_cached(handle, fqcreddef, &cache_options).await? {
+            trace!("resolve_cred_def < cached {:?}", cached);
+            return Ok(cached);
+        }
+
+        let res = match self._get_ledger(handle, &namespace).await? {
+            Ledger::Indy(ledger) => {
+                let request = self.ledger_service.build_get_cred_def_request(None, &id)?;
+
+                let response = self
+                    .pool_service
+                    .send_tx(ledger.pool_handle, &request)
+                    .await?;
+
+                let (_, cred_def) = self
+                    .ledger_service
+                    .parse_get_cred_def_response(&response, id.get_method().as_deref())?;
+
+                cred_def
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(_) => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidState,
+                    "Credential definitions aren't supported by Cheqd ledgers: cheqd-node doesn't define Credential Definition transactions",
+                ));
+            }
+        };
+
+        self._store_cached(handle, fqcreddef, &res, &cache_options).await?;
+
+        trace!("resolve_cred_def < {:?}", res);
+        Ok(res)
+    }
+
+    async fn _get_cached(
+        &self,
+        handle: VdrHandle,
+        id: &str,
+        options: &GetCacheOptions,
+    ) -> IndyResult<Option<String>> {
+        if !options.no_cache.unwrap_or(false) {
+            let now = Self::_get_seconds_since_epoch()?;
+
+            let cached = self
+                .vdrs
+                .lock()
+                .await
+                .get(&handle)
+                .ok_or_else(|| {
+                    err_msg(
+                        IndyErrorKind::InvalidState,
+                        format!("Unknown VDR handle: {}", handle),
+                    )
+                })?
+                .get_cached(id, options.min_fresh.unwrap_or(-1), now);
+
+            if cached.is_some() {
+                return Ok(cached);
+            }
+        }
+
+        if options.no_update.unwrap_or(false) {
+            return Err(err_msg(
+                IndyErrorKind::LedgerItemNotFound,
+                format!("Entity {} isn't cached and ledger update is disabled", id),
+            ));
+        }
+
+        Ok(None)
+    }
+
+    async fn _store_cached(
+        &self,
+        handle: VdrHandle,
+        id: &str,
+        data: &str,
+        options: &GetCacheOptions,
+    ) -> IndyResult<()> {
+        if options.no_store.unwrap_or(false) {
+            return Ok(());
+        }
+
+        let now = Self::_get_seconds_since_epoch()?;
+
+        // VDR object may be cleaned up while the ledger request was in progress
+        if let Some(vdr) = self.vdrs.lock().await.get_mut(&handle) {
+            vdr.store_cached(id, data, now);
+        }
+
+        Ok(())
+    }
+
+    fn _get_seconds_since_epoch() -> IndyResult<i32> {
+        let ts = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .to_indy(IndyErrorKind::InvalidState, "Can't get system time")?
+            .as_secs() as i32;
+
+        Ok(ts)
+    }
+
+    fn _parse_cache_options(cache_options: &str) -> IndyResult<GetCacheOptions> {
+        serde_json::from_str(cache_options).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Cannot deserialize cache options",
+        )
+    }
+}
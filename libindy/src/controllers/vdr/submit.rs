@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use indy_api_types::{errors::prelude::*, VdrHandle};
+use rust_base58::ToBase58;
+use serde_json::Value;
+
+use crate::{
+    controllers::VdrController,
+    domain::vdr::{
+        namespaces::ledger_did,
+        prepared_txn::{IndyEndorsement, SIGNATURE_SPEC_ED25519},
+        vdr::{IndyLedger, Ledger},
+    },
+};
+
+#[cfg(feature = "cheqd")]
+use crate::domain::{
+    cheqd_ledger::prost_ext::ProstMessageExt,
+    vdr::{prepared_txn::SIGNATURE_SPEC_SECP256K1, vdr::CheqdLedger},
+};
+#[cfg(feature = "cheqd")]
+use cosmrs::proto::cosmos::tx::v1beta1::{SignDoc as ProtoSignDoc, TxRaw};
+
+impl VdrController {
+    pub(crate) async fn submit_txn(
+        &self,
+        handle: VdrHandle,
+        namespace: &str,
+        signature_spec: &str,
+        txn_bytes: &[u8],
+        signature: &[u8],
+        endorsement: Option<&str>,
+    ) -> IndyResult<String> {
+        trace!(
+            "submit_txn > handle {:?} namespace {:?} signature_spec {:?} txn_bytes {:?} signature {:?} endorsement {:?}",
+            handle, namespace, signature_spec, txn_bytes, signature, endorsement
+        );
+
+        let res = match self._get_ledger(handle, namespace).await? {
+            Ledger::Indy(ledger) => {
+                Self::_check_signature_spec(signature_spec, SIGNATURE_SPEC_ED25519)?;
+                self._submit_indy_txn(&ledger, txn_bytes, signature, endorsement)
+                    .await?
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(ledger) => {
+                Self::_check_signature_spec(signature_spec, SIGNATURE_SPEC_SECP256K1)?;
+                Self::_ensure_no_cheqd_endorser(endorsement)?;
+                self._submit_cheqd_txn(&ledger, txn_bytes, signature).await?
+            }
+        };
+
+        trace!("submit_txn < {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) async fn submit_query(
+        &self,
+        handle: VdrHandle,
+        namespace: &str,
+        query: &str,
+    ) -> IndyResult<String> {
+        trace!(
+            "submit_query > handle {:?} namespace {:?} query {:?}",
+            handle, namespace, query
+        );
+
+        let res = match self._get_ledger(handle, namespace).await? {
+            Ledger::Indy(ledger) => {
+                self.pool_service
+                    .send_tx(ledger.pool_handle, query)
+                    .await?
+            }
+            #[cfg(feature = "cheqd")]
+            Ledger::Cheqd(ledger) => {
+                let query: cosmrs::rpc::endpoint::abci_query::Request = serde_json::from_str(query)
+                    .to_indy(
+                        IndyErrorKind::InvalidStructure,
+                        "Cannot deserialize ABCI query Request object",
+                    )?;
+
+                let resp = self
+                    .cheqd_pool_service
+                    .abci_query(&ledger.pool_alias, query)
+                    .await?;
+
+                serde_json::to_string(&resp).to_indy(
+                    IndyErrorKind::InvalidState,
+                    "Cannot serialize ABCI query Response object",
+                )?
+            }
+        };
+
+        trace!("submit_query < {:?}", res);
+        Ok(res)
+    }
+
+    async fn _submit_indy_txn(
+        &self,
+        ledger: &IndyLedger,
+        txn_bytes: &[u8],
+        signature: &[u8],
+        endorsement: Option<&str>,
+    ) -> IndyResult<String> {
+        let mut request: Value = serde_json::from_slice(txn_bytes)
+            .to_indy(IndyErrorKind::InvalidStructure, "Transaction is invalid json")?;
+
+        match endorsement {
+            Some(endorsement) => {
+                let endorsement: IndyEndorsement = serde_json::from_str(endorsement).to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    "Cannot deserialize IndyEndorsement",
+                )?;
+
+                let identifier = request["identifier"]
+                    .as_str()
+                    .ok_or_else(|| {
+                        err_msg(
+                            IndyErrorKind::InvalidStructure,
+                            "Transaction doesn't contain identifier",
+                        )
+                    })?
+                    .to_string();
+
+                let mut signatures = HashMap::new();
+                signatures.insert(identifier, signature.to_base58());
+                signatures.insert(ledger_did(&endorsement.endorser_did).0, endorsement.signature);
+
+                request["signatures"] = json!(signatures);
+            }
+            None => {
+                request["signature"] = Value::String(signature.to_base58());
+            }
+        }
+
+        let request = serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize request")?;
+
+        self.pool_service
+            .send_tx(ledger.pool_handle, &request)
+            .await
+    }
+
+    #[cfg(feature = "cheqd")]
+    async fn _submit_cheqd_txn(
+        &self,
+        ledger: &CheqdLedger,
+        txn_bytes: &[u8],
+        signature: &[u8],
+    ) -> IndyResult<String> {
+        let sign_doc = ProtoSignDoc::from_bytes(txn_bytes)?;
+
+        let tx_raw = TxRaw {
+            body_bytes: sign_doc.body_bytes,
+            auth_info_bytes: sign_doc.auth_info_bytes,
+            signatures: vec![signature.to_vec()],
+        };
+
+        let resp = self
+            .cheqd_pool_service
+            .broadcast_tx_commit(&ledger.pool_alias, tx_raw.into())
+            .await?;
+
+        serde_json::to_string(&resp).to_indy(
+            IndyErrorKind::InvalidState,
+            "Cannot serialize Response object after broadcasting_tx_commit action",
+        )
+    }
+
+    fn _check_signature_spec(signature_spec: &str, expected: &str) -> IndyResult<()> {
+        if signature_spec != expected {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!(
+                    "Unsupported signature spec `{}`, `{}` expected",
+                    signature_spec, expected
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use async_std::task::spawn_blocking;
 
 use indy_api_types::{
-    domain::wallet::{Config, Credentials, ExportConfig, KeyConfig},
+    domain::wallet::{Config, Credentials, ExportConfig, KeyConfig, KeyRotationConfig},
     errors::prelude::*,
     wallet::*,
     WalletHandle,
@@ -34,7 +34,7 @@ impl WalletController {
         }
     }
 
-    pub(crate) fn register_type(
+    pub(crate) async fn register_type(
         &self,
         type_: String,
         create: WalletCreate,
@@ -90,7 +90,7 @@ impl WalletController {
             get_search_total_count,
             fetch_search_next_record,
             free_search,
-        )?;
+        ).await?;
 
         trace!("register_type < res: ()");
         Ok(())
@@ -246,6 +246,62 @@ impl WalletController {
         res
     }
 
+    pub(crate) async fn rotate_keys(
+        &self,
+        wallet_handle: WalletHandle,
+        credentials: Credentials,
+        config: Option<KeyRotationConfig>,
+    ) -> IndyResult<String> {
+        trace!(
+            "rotate_keys > handle: {:?} credentials: {:?} config: {:?}",
+            wallet_handle,
+            secret!(&credentials),
+            config
+        );
+
+        let config = config.unwrap_or_default();
+
+        let key_derivation_data = self
+            .wallet_service
+            .rotate_keys_prepare(wallet_handle, &credentials)
+            .await?;
+
+        let key = Self::_derive_key(key_derivation_data).await?;
+
+        let progress = self
+            .wallet_service
+            .rotate_keys_continue(wallet_handle, &config, &key)
+            .await?;
+
+        let res = serde_json::to_string(&progress).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize keys rotation progress",
+        )?;
+
+        trace!("rotate_keys < res: {:?}", res);
+        Ok(res)
+    }
+
+    pub(crate) async fn get_key_rotation_progress(
+        &self,
+        wallet_handle: WalletHandle,
+    ) -> IndyResult<String> {
+        trace!("get_key_rotation_progress > handle: {:?}", wallet_handle);
+
+        let progress = self
+            .wallet_service
+            .get_key_rotation_progress(wallet_handle)
+            .await?;
+
+        let res = serde_json::to_string(&progress).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize keys rotation progress",
+        )?;
+
+        trace!("get_key_rotation_progress < res: {:?}", res);
+        Ok(res)
+    }
+
     pub(crate) fn generate_key(&self, config: Option<KeyConfig>) -> IndyResult<String> {
         trace!("generate_key > config: {:?}", secret!(&config));
 
@@ -181,6 +181,21 @@ impl CredentialDefinitionId {
     }
 
     pub fn parts(&self) -> Option<(DidValue, String, SchemaId, String)> {
+        if self.is_fully_qualified() {
+            // creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:3:tag
+            // creddef:indy:sovrin:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:indy:sovrin:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag
+            let method = self.get_method()?;
+            let id = qualifier::to_unqualified_nested(
+                &self.0,
+                &[Self::PREFIX, SchemaId::PREFIX, DidValue::PREFIX],
+                &method,
+            );
+
+            return CredentialDefinitionId(id).parts().map(|(did, signature_type, schema_id, tag)| {
+                (did.qualify(&method), signature_type, schema_id.qualify(&method), tag)
+            });
+        }
+
         let parts = self.0.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         if parts.len() == 4 {
@@ -219,24 +234,6 @@ impl CredentialDefinitionId {
             return Some((DidValue(did), signature_type, SchemaId(schema_id), tag));
         }
 
-        if parts.len() == 9 {
-            // creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:3:tag
-            let did = parts[2..5].join(DELIMITER);
-            let signature_type = parts[6].to_string();
-            let schema_id = parts[7].to_string();
-            let tag = parts[8].to_string();
-            return Some((DidValue(did), signature_type, SchemaId(schema_id), tag));
-        }
-
-        if parts.len() == 16 {
-            // creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag
-            let did = parts[2..5].join(DELIMITER);
-            let signature_type = parts[6].to_string();
-            let schema_id = parts[7..15].join(DELIMITER);
-            let tag = parts[15].to_string();
-            return Some((DidValue(did), signature_type, SchemaId(schema_id), tag));
-        }
-
         None
     }
 
@@ -341,9 +338,29 @@ mod tests {
         CredentialDefinitionId("creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag".to_string())
     }
 
+    fn _cred_def_id_indy() -> CredentialDefinitionId {
+        CredentialDefinitionId("creddef:indy:sovrin:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:indy:sovrin:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag".to_string())
+    }
+
     mod to_unqualified {
         use super::*;
 
+        #[test]
+        fn test_cred_def_id_parts_for_did_indy() {
+            assert_eq!(
+                _cred_def_id_unqualified(),
+                _cred_def_id_indy().to_unqualified()
+            );
+        }
+
+        #[test]
+        fn test_cred_def_id_qualify_for_did_indy_round_trip() {
+            assert_eq!(
+                _cred_def_id_indy(),
+                _cred_def_id_indy().to_unqualified().qualify("indy:sovrin")
+            );
+        }
+
         #[test]
         fn test_cred_def_id_parts_for_id_as_unqualified() {
             assert_eq!(
@@ -397,6 +414,18 @@ mod tests {
     mod parts {
         use super::*;
 
+        #[test]
+        fn test_cred_def_id_parts_for_did_indy() {
+            let (did, signature_type, schema_id, tag) = _cred_def_id_indy().parts().unwrap();
+            assert_eq!(DidValue("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e".to_string()), did);
+            assert_eq!(_signature_type(), signature_type);
+            assert_eq!(
+                SchemaId("schema:indy:sovrin:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string()),
+                schema_id
+            );
+            assert_eq!(_tag(), tag);
+        }
+
         #[test]
         fn test_cred_def_id_parts_for_id_as_unqualified() {
             let (did, signature_type, schema_id, tag) = _cred_def_id_unqualified().parts().unwrap();
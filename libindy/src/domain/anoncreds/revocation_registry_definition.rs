@@ -6,7 +6,7 @@ use regex::Regex;
 use ursa::cl::{RevocationKeyPrivate, RevocationKeyPublic};
 
 use super::super::{
-        anoncreds::{credential_definition::CredentialDefinitionId, DELIMITER},
+        anoncreds::{credential_definition::CredentialDefinitionId, schema::SchemaId, DELIMITER},
         crypto::did::DidValue,
 };
 
@@ -16,7 +16,7 @@ pub const CL_ACCUM: &str = "CL_ACCUM";
 pub const REV_REG_DEG_MARKER: &str = "4";
 
 lazy_static! {
-    static ref QUALIFIED_REV_REG_ID: Regex = Regex::new("(^revreg:(?P<method>[a-z0-9]+):)?(?P<did>.+):4:(?P<cred_def_id>.+):(?P<rev_reg_type>.+):(?P<tag>.+)$").unwrap();
+    static ref REV_REG_ID: Regex = Regex::new("^(?P<did>.+):4:(?P<cred_def_id>.+):(?P<rev_reg_type>.+):(?P<tag>.+)$").unwrap();
 }
 
 #[derive(Deserialize, Debug, Serialize)]
@@ -172,7 +172,26 @@ impl RevocationRegistryId {
     }
 
     pub fn parts(&self) -> Option<(DidValue, CredentialDefinitionId, String, String)> {
-        match QUALIFIED_REV_REG_ID.captures(&self.0) {
+        if self.is_fully_qualified() {
+            // revreg:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:4:creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:TAG_1
+            let method = self.get_method()?;
+            let id = qualifier::to_unqualified_nested(
+                &self.0,
+                &[
+                    Self::PREFIX,
+                    CredentialDefinitionId::PREFIX,
+                    SchemaId::PREFIX,
+                    DidValue::PREFIX,
+                ],
+                &method,
+            );
+
+            return RevocationRegistryId(id).parts().map(|(did, cred_def_id, rev_reg_type, tag)| {
+                (did.qualify(&method), cred_def_id.qualify(&method), rev_reg_type, tag)
+            });
+        }
+
+        match REV_REG_ID.captures(&self.0) {
             Some(caps) => Some((
                 DidValue(caps["did"].to_string()),
                 CredentialDefinitionId(caps["cred_def_id"].to_string()),
@@ -266,9 +285,21 @@ mod tests {
         RevocationRegistryId("revreg:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:4:creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag:CL_ACCUM:TAG_1".to_string())
     }
 
+    fn _rev_reg_id_indy() -> RevocationRegistryId {
+        RevocationRegistryId("revreg:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:4:creddef:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag:CL_ACCUM:TAG_1".to_string())
+    }
+
     mod to_unqualified {
         use super::*;
 
+        #[test]
+        fn test_rev_reg_id_parts_for_did_indy() {
+            assert_eq!(
+                _rev_reg_id_unqualified(),
+                _rev_reg_id_indy().to_unqualified()
+            );
+        }
+
         #[test]
         fn test_rev_reg_id_parts_for_id_as_unqualified() {
             assert_eq!(
@@ -289,6 +320,16 @@ mod tests {
     mod parts {
         use super::*;
 
+        #[test]
+        fn test_rev_reg_id_parts_for_did_indy() {
+            let (did, cred_def_id, rev_reg_type, tag) = _rev_reg_id_indy().parts().unwrap();
+            assert_eq!(DidValue("did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e".to_string()), did);
+            assert_eq!(Some("indy:sovrin:staging".to_string()), cred_def_id.get_method());
+            assert_eq!(_cred_def_id_unqualified(), cred_def_id.to_unqualified());
+            assert_eq!(_rev_reg_type(), rev_reg_type);
+            assert_eq!(_tag(), tag);
+        }
+
         #[test]
         fn test_rev_reg_id_parts_for_id_as_unqualified() {
             let (did, cred_def_id, rev_reg_type, tag) = _rev_reg_id_unqualified().parts().unwrap();
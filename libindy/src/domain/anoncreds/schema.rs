@@ -127,6 +127,17 @@ impl SchemaId {
     }
 
     pub fn parts(&self) -> Option<(DidValue, String, String)> {
+        if self.is_fully_qualified() {
+            // schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0
+            // schema:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0
+            let method = self.get_method()?;
+            let id = qualifier::to_unqualified_nested(&self.0, &[Self::PREFIX, DidValue::PREFIX], &method);
+
+            return SchemaId(id)
+                .parts()
+                .map(|(did, name, version)| (did.qualify(&method), name, version));
+        }
+
         let parts = self.0.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         if parts.len() == 1 {
@@ -142,14 +153,6 @@ impl SchemaId {
             return Some((DidValue(did), name, version));
         }
 
-        if parts.len() == 8 {
-            // schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0
-            let did = parts[2..5].join(DELIMITER);
-            let name = parts[6].to_string();
-            let version = parts[7].to_string();
-            return Some((DidValue(did), name, version));
-        }
-
         None
     }
 
@@ -208,6 +211,14 @@ mod tests {
         SchemaId("schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string())
     }
 
+    fn _did_indy() -> DidValue {
+        DidValue("did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e".to_string())
+    }
+
+    fn _schema_id_indy() -> SchemaId {
+        SchemaId("schema:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string())
+    }
+
     fn _schema_id_invalid() -> SchemaId {
         SchemaId("NcYxiDXkpYi6ov5FcYDi1e:2".to_string())
     }
@@ -234,6 +245,25 @@ mod tests {
         fn test_schema_id_parts_for_invalid_unqualified() {
             assert_eq!(_schema_id_invalid(), _schema_id_invalid().to_unqualified());
         }
+
+        #[test]
+        fn test_schema_id_unqualify_for_did_indy() {
+            assert_eq!(_schema_id_unqualified(), _schema_id_indy().to_unqualified());
+        }
+
+        #[test]
+        fn test_schema_id_qualify_for_did_indy_round_trip() {
+            assert_eq!(
+                Some("indy:sovrin:staging".to_string()),
+                _schema_id_indy().get_method()
+            );
+            assert_eq!(
+                _schema_id_indy(),
+                _schema_id_indy()
+                    .to_unqualified()
+                    .qualify("indy:sovrin:staging")
+            );
+        }
     }
 
     mod parts {
@@ -260,6 +290,14 @@ mod tests {
         fn test_schema_id_parts_for_invalid_unqualified() {
             assert!(_schema_id_invalid().parts().is_none());
         }
+
+        #[test]
+        fn test_schema_id_parts_for_did_indy() {
+            let (did, name, version) = _schema_id_indy().parts().unwrap();
+            assert_eq!(_did_indy(), did);
+            assert_eq!("gvt", name);
+            assert_eq!("1.0", version);
+        }
     }
 
     mod validate {
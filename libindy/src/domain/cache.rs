@@ -1,3 +1,5 @@
+use super::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PurgeOptions {
@@ -11,4 +13,15 @@ pub struct GetCacheOptions {
     pub no_update: Option<bool>,    // Use only cached data, do not try to update.
     pub no_store: Option<bool>,     // Skip storing fresh data if updated
     pub min_fresh: Option<i32>,     // Return cached data if not older than this many seconds. -1 means do not check age.
-}
\ No newline at end of file
+}
+
+/// Cached revocation registry delta accumulated for the interval `[from, to]`.
+/// Extended in place when a later `to` is requested.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegDeltaCacheRecord {
+    pub from: Option<i64>,
+    pub to: i64,
+    pub revoc_reg_delta: RevocationRegistryDelta,
+    pub timestamp: u64,
+}
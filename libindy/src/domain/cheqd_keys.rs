@@ -53,3 +53,59 @@ impl KeyInfo {
         }
     }
 }
+
+/// Member of multisig account: key stored in the wallet or public key of a foreign key.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum MultisigMember {
+    Alias { alias: String },
+    // Base58-encoded SEC1-encoded secp256k1 ECDSA key
+    PubKey { pub_key: String },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MultisigKeyInfo {
+    pub account_id: String,
+    pub threshold: u32,
+    // Base58-encoded SEC1-encoded secp256k1 ECDSA keys of members in the order of the multisig key
+    pub pub_keys: Vec<String>,
+}
+
+impl MultisigKeyInfo {
+    pub fn new(account_id: String, threshold: u32, pub_keys: Vec<String>) -> Self {
+        MultisigKeyInfo {
+            account_id,
+            threshold,
+            pub_keys,
+        }
+    }
+}
+
+/// BIP-44 path used by Cosmos SDK based chains (coin type 118).
+pub const DEFAULT_HD_PATH: &str = "m/44'/118'/0'/0/0";
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum MnemonicDerivationMethod {
+    // BIP-39 seed derived into BIP-32/44 hierarchical key. Compatible with Keplr and cheqd-noded.
+    BIP44,
+    // Key generated by StdRng seeded with SHA3-256 of the mnemonic. Kept for keys created before BIP44 support.
+    LEGACY_SHA3,
+}
+
+impl Default for MnemonicDerivationMethod {
+    fn default() -> Self {
+        MnemonicDerivationMethod::BIP44
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct MnemonicOptions {
+    #[serde(default)]
+    pub derivation_method: MnemonicDerivationMethod,
+    pub hd_path: Option<String>,
+    #[derivative(Debug = "ignore")]
+    pub passphrase: Option<String>,
+}
@@ -1,4 +1,5 @@
 pub use pub_key::PubKey;
 
 mod pub_key;
+pub mod multisig;
 pub mod secp256k1;
@@ -0,0 +1,170 @@
+use indy_api_types::errors::{err_msg, IndyErrorKind, IndyResult, IndyResultExt};
+use indy_api_types::IndyError;
+use prost_types::Any;
+use rust_base58::FromBase58;
+use sha2::{Digest, Sha256};
+
+use super::super::super::proto::cosmos::crypto::multisig::LegacyAminoPubKey as ProtoLegacyAminoPubKey;
+use super::super::super::prost_ext::ProstMessageExt;
+use super::super::super::CheqdProto;
+use super::super::secp256k1;
+
+pub const LEGACY_AMINO_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.multisig.LegacyAminoPubKey";
+pub const SECP256K1_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+// Amino prefixes of `tendermint/PubKeyMultisigThreshold` and `tendermint/PubKeySecp256k1`
+const AMINO_MULTISIG_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+const AMINO_SECP256K1_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+
+/// Multisig threshold public key. Account of this key is able to sign transactions
+/// when at least `threshold` of `public_keys` signed them.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct LegacyAminoPubKey {
+    pub threshold: u32,
+    pub public_keys: Vec<secp256k1::PubKey>,
+}
+
+impl LegacyAminoPubKey {
+    pub fn new(threshold: u32, public_keys: Vec<secp256k1::PubKey>) -> Self {
+        LegacyAminoPubKey {
+            threshold,
+            public_keys,
+        }
+    }
+
+    /// Builds the key from base58-encoded SEC1 secp256k1 keys of members.
+    pub fn from_base58_keys(threshold: u32, pub_keys: &[String]) -> IndyResult<Self> {
+        if threshold == 0 || threshold as usize > pub_keys.len() {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Multisig threshold must be in range 1..={}, got {}", pub_keys.len(), threshold),
+            ));
+        }
+
+        let public_keys = pub_keys
+            .iter()
+            .map(|pub_key| {
+                let bytes = pub_key.from_base58().map_err(|_| {
+                    err_msg(IndyErrorKind::InvalidStructure, format!("Invalid base58 pub_key {}", pub_key))
+                })?;
+
+                k256::ecdsa::VerifyingKey::from_sec1_bytes(&bytes).to_indy(
+                    IndyErrorKind::InvalidStructure,
+                    "Error was raised while creating verifying key object k256::ecdsa::VerifyingKey"
+                )?;
+
+                Ok(secp256k1::PubKey::new(bytes))
+            })
+            .collect::<IndyResult<Vec<secp256k1::PubKey>>>()?;
+
+        Ok(Self::new(threshold, public_keys))
+    }
+
+    pub fn to_any(&self) -> IndyResult<Any> {
+        Ok(Any {
+            type_url: LEGACY_AMINO_PUB_KEY_TYPE_URL.to_string(),
+            value: self.to_proto().to_bytes()?,
+        })
+    }
+
+    /// Amino encoding of the key. Cosmos SDK derives multisig addresses from it.
+    pub fn to_amino_bytes(&self) -> Vec<u8> {
+        let mut bytes = AMINO_MULTISIG_PREFIX.to_vec();
+
+        bytes.push(0x08);
+        prost::encoding::encode_varint(self.threshold as u64, &mut bytes);
+
+        for public_key in &self.public_keys {
+            let mut key_bytes = AMINO_SECP256K1_PREFIX.to_vec();
+            prost::encoding::encode_varint(public_key.key.len() as u64, &mut key_bytes);
+            key_bytes.extend_from_slice(&public_key.key);
+
+            bytes.push(0x12);
+            prost::encoding::encode_varint(key_bytes.len() as u64, &mut bytes);
+            bytes.extend_from_slice(&key_bytes);
+        }
+
+        bytes
+    }
+
+    /// Account address bytes: first 20 bytes of SHA-256 of the amino encoded key.
+    pub fn address(&self) -> Vec<u8> {
+        Sha256::digest(&self.to_amino_bytes())[..20].to_vec()
+    }
+}
+
+impl CheqdProto for LegacyAminoPubKey {
+    type Proto = ProtoLegacyAminoPubKey;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            threshold: self.threshold,
+            public_keys: self
+                .public_keys
+                .iter()
+                .map(|public_key| Any {
+                    type_url: SECP256K1_PUB_KEY_TYPE_URL.to_string(),
+                    // Encoding of bytes field into Vec can't fail
+                    value: public_key.to_proto_bytes().unwrap_or_default(),
+                })
+                .collect(),
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let public_keys = proto
+            .public_keys
+            .iter()
+            .map(|public_key| match &public_key.type_url[..] {
+                SECP256K1_PUB_KEY_TYPE_URL => secp256k1::PubKey::from_proto_bytes(&public_key.value),
+                unknown_type => Err(IndyError::from_msg(
+                    IndyErrorKind::InvalidStructure,
+                    format!("Unsupported type of multisig member pub_key: {}", unknown_type),
+                )),
+            })
+            .collect::<IndyResult<Vec<secp256k1::PubKey>>>()?;
+
+        Ok(Self::new(proto.threshold, public_keys))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn _public_keys() -> Vec<secp256k1::PubKey> {
+        vec![
+            secp256k1::PubKey::new(vec![2; 33]),
+            secp256k1::PubKey::new(vec![3; 33]),
+        ]
+    }
+
+    #[test]
+    fn test_legacy_amino_pub_key() {
+        let msg = LegacyAminoPubKey::new(2, _public_keys());
+
+        let proto = msg.to_proto();
+        let decoded = LegacyAminoPubKey::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+
+    #[test]
+    fn test_legacy_amino_pub_key_amino_bytes() {
+        let bytes = LegacyAminoPubKey::new(2, _public_keys()).to_amino_bytes();
+
+        // prefix + threshold + 2 * (field tag + length + prefix + length + key)
+        assert_eq!(4 + 2 + 2 * (1 + 1 + 4 + 1 + 33), bytes.len());
+        assert_eq!(AMINO_MULTISIG_PREFIX, bytes[..4]);
+        assert_eq!([0x08, 0x02, 0x12, 0x26], bytes[4..8]);
+    }
+
+    #[test]
+    fn test_legacy_amino_pub_key_address_depends_on_threshold() {
+        let address_1 = LegacyAminoPubKey::new(1, _public_keys()).address();
+        let address_2 = LegacyAminoPubKey::new(2, _public_keys()).address();
+
+        assert_eq!(20, address_1.len());
+        assert_ne!(address_1, address_2);
+    }
+}
@@ -0,0 +1,3 @@
+pub use legacy_amino_pub_key::{LegacyAminoPubKey, LEGACY_AMINO_PUB_KEY_TYPE_URL, SECP256K1_PUB_KEY_TYPE_URL};
+
+mod legacy_amino_pub_key;
@@ -5,12 +5,14 @@ use indy_api_types::IndyError;
 
 use super::super::CheqdProto;
 
+use super::multisig;
 use super::secp256k1;
 
 #[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PubKey {
     Secp256k1(secp256k1::PubKey),
+    LegacyAminoPubKey(multisig::LegacyAminoPubKey),
 }
 
 impl CheqdProto for PubKey {
@@ -26,6 +28,10 @@ impl CheqdProto for PubKey {
                 let val = secp256k1::PubKey::from_proto_bytes(&proto.value)?;
                 Ok(PubKey::Secp256k1(val))
             }
+            multisig::LEGACY_AMINO_PUB_KEY_TYPE_URL => {
+                let val = multisig::LegacyAminoPubKey::from_proto_bytes(&proto.value)?;
+                Ok(PubKey::LegacyAminoPubKey(val))
+            }
             unknown_type => Err(IndyError::from_msg(
                 IndyErrorKind::InvalidStructure,
                 format!("Unknown pub_key type: {}", unknown_type),
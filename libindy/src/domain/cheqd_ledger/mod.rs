@@ -12,6 +12,7 @@ pub mod bank;
 pub mod auth;
 pub mod base;
 pub mod crypto;
+pub mod tx;
 
 pub trait CheqdProto: Eq + Debug + Sized {
     type Proto: prost::Message + Default;
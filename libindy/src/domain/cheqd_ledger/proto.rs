@@ -11,6 +11,24 @@ pub mod cheqdid {
     }
 }
 
+pub mod cosmos {
+    pub mod crypto {
+        pub mod multisig {
+            // `cosmos/crypto/multisig/keys.proto` isn't compiled into cosmos-sdk-proto.
+
+            /// LegacyAminoPubKey specifies a public key type which nests multiple public keys
+            /// and a threshold, it uses legacy amino address rules.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct LegacyAminoPubKey {
+                #[prost(uint32, tag = "1")]
+                pub threshold: u32,
+                #[prost(message, repeated, tag = "2")]
+                pub public_keys: Vec<::prost_types::Any>,
+            }
+        }
+    }
+}
+
 impl MsgProto for cheqdid::cheqdnode::cheqd::MsgCreateNym {
     const TYPE_URL: &'static str = "/cheqdid.cheqdnode.cheqd.MsgCreateNym";
 }
@@ -0,0 +1,54 @@
+use indy_api_types::validation::Validatable;
+
+pub const DEFAULT_GAS_PRICE: f64 = 0.025;
+pub const DEFAULT_GAS_ADJUSTMENT: f64 = 1.3;
+
+/// Options used to turn simulated gas into the gas limit and fee of a transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FeeOptions {
+    /// Price of a gas unit in the fee denomination.
+    #[serde(default = "FeeOptions::default_gas_price")]
+    pub gas_price: f64,
+    /// Multiplier applied to the simulated gas to cover estimation inaccuracy.
+    #[serde(default = "FeeOptions::default_gas_adjustment")]
+    pub gas_adjustment: f64,
+}
+
+impl FeeOptions {
+    fn default_gas_price() -> f64 {
+        DEFAULT_GAS_PRICE
+    }
+
+    fn default_gas_adjustment() -> f64 {
+        DEFAULT_GAS_ADJUSTMENT
+    }
+}
+
+impl Default for FeeOptions {
+    fn default() -> Self {
+        FeeOptions {
+            gas_price: FeeOptions::default_gas_price(),
+            gas_adjustment: FeeOptions::default_gas_adjustment(),
+        }
+    }
+}
+
+impl Validatable for FeeOptions {
+    fn validate(&self) -> Result<(), String> {
+        if !self.gas_price.is_finite() || self.gas_price < 0.0 {
+            return Err(String::from("`gas_price` must be a non-negative number"));
+        }
+        if !self.gas_adjustment.is_finite() || self.gas_adjustment < 1.0 {
+            return Err(String::from("`gas_adjustment` must be greater than or equal to 1"));
+        }
+        Ok(())
+    }
+}
+
+/// Gas limit and fee amount suggested for a transaction.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct FeeEstimation {
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    pub amount: u64,
+}
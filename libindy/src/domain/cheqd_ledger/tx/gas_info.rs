@@ -0,0 +1,52 @@
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo as ProtoGasInfo;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+
+/// GasInfo defines tx execution gas context.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct GasInfo {
+    /// GasWanted is the maximum units of work we allow this tx to perform.
+    pub gas_wanted: u64,
+    /// GasUsed is the amount of gas actually consumed.
+    pub gas_used: u64,
+}
+
+impl GasInfo {
+    pub fn new(gas_wanted: u64, gas_used: u64) -> Self {
+        GasInfo {
+            gas_wanted,
+            gas_used,
+        }
+    }
+}
+
+impl CheqdProto for GasInfo {
+    type Proto = ProtoGasInfo;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            gas_wanted: self.gas_wanted,
+            gas_used: self.gas_used,
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        Ok(Self::new(proto.gas_wanted, proto.gas_used))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gas_info() {
+        let msg = GasInfo::new(200000, 53412);
+
+        let proto = msg.to_proto();
+        let decoded = GasInfo::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
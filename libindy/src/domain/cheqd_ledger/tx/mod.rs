@@ -0,0 +1,9 @@
+pub use fee_options::{FeeEstimation, FeeOptions};
+pub use gas_info::GasInfo;
+pub use simulate_response::SimulateResponse;
+pub use tx_response::TxResponse;
+
+mod fee_options;
+mod gas_info;
+mod simulate_response;
+mod tx_response;
@@ -0,0 +1,54 @@
+use cosmrs::proto::cosmos::tx::v1beta1::SimulateResponse as ProtoSimulateResponse;
+use indy_api_types::errors::IndyResult;
+
+use super::super::CheqdProto;
+use super::GasInfo;
+
+/// SimulateResponse is the response type for the Service.Simulate RPC method.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SimulateResponse {
+    /// gas_info is the information about gas used in the simulation.
+    pub gas_info: Option<GasInfo>,
+}
+
+impl SimulateResponse {
+    pub fn new(gas_info: Option<GasInfo>) -> Self {
+        SimulateResponse { gas_info }
+    }
+}
+
+impl CheqdProto for SimulateResponse {
+    type Proto = ProtoSimulateResponse;
+
+    fn to_proto(&self) -> Self::Proto {
+        Self::Proto {
+            gas_info: self.gas_info.as_ref().map(|gas_info| gas_info.to_proto()),
+            result: None,
+        }
+    }
+
+    fn from_proto(proto: &Self::Proto) -> IndyResult<Self> {
+        let gas_info = proto
+            .gas_info
+            .as_ref()
+            .map(|gas_info| GasInfo::from_proto(gas_info))
+            .transpose()?;
+
+        Ok(Self::new(gas_info))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_simulate_response() {
+        let msg = SimulateResponse::new(Some(GasInfo::new(0, 53412)));
+
+        let proto = msg.to_proto();
+        let decoded = SimulateResponse::from_proto(&proto).unwrap();
+
+        assert_eq!(msg, decoded);
+    }
+}
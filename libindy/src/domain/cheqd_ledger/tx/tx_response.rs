@@ -0,0 +1,35 @@
+use cosmrs::rpc::endpoint::broadcast::tx_commit;
+use cosmrs::rpc::endpoint::tx;
+use cosmrs::tendermint::abci;
+
+/// Result of transaction execution.
+/// It is returned either by `broadcast_tx_commit` or by the lookup of a transaction by hash.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum TxResponse {
+    Commit(tx_commit::Response),
+    Lookup(tx::Response),
+}
+
+impl TxResponse {
+    pub fn code(&self) -> abci::Code {
+        match self {
+            TxResponse::Commit(resp) => resp.deliver_tx.code,
+            TxResponse::Lookup(resp) => resp.tx_result.code,
+        }
+    }
+
+    pub fn log(&self) -> String {
+        match self {
+            TxResponse::Commit(resp) => resp.deliver_tx.log.to_string(),
+            TxResponse::Lookup(resp) => resp.tx_result.log.to_string(),
+        }
+    }
+
+    pub fn data(&self) -> Option<Vec<u8>> {
+        match self {
+            TxResponse::Commit(resp) => resp.deliver_tx.data.as_ref().map(|data| data.value().to_vec()),
+            TxResponse::Lookup(resp) => Some(resp.tx_result.data.value().to_vec()),
+        }
+    }
+}
@@ -1,16 +1,279 @@
+use std::str::FromStr;
+
+use cosmrs::AccountId;
+use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
+
+/// Bech32 human-readable prefix of cheqd network accounts.
+pub const DEFAULT_ACCOUNT_PREFIX: &str = "cheqd";
+
+/// Two weeks, which is below the default unbonding period of Cosmos SDK chains.
+pub const DEFAULT_TRUSTING_PERIOD: u64 = 14 * 24 * 60 * 60;
+pub const DEFAULT_CLOCK_DRIFT: u64 = 5;
+
+pub const DEFAULT_REQUEST_TIMEOUT: u64 = 20;
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+pub const DEFAULT_RETRY_BACKOFF: u64 = 500;
+
+fn default_account_prefix() -> String {
+    DEFAULT_ACCOUNT_PREFIX.to_string()
+}
+
+/// Fraction of the trusted validator set voting power that must sign a header
+/// to skip intermediate headers during bisection.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TrustLevel {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl Default for TrustLevel {
+    fn default() -> Self {
+        TrustLevel {
+            numerator: 1,
+            denominator: 3,
+        }
+    }
+}
+
+/// How requests are sent to the nodes of a pool.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ConnectionOptions {
+    /// RPC addresses used when `rpc_address` of the pool doesn't respond.
+    #[serde(default)]
+    pub backup_rpc_addresses: Vec<String>,
+    /// Timeout of a single request in seconds.
+    #[serde(default = "ConnectionOptions::default_timeout")]
+    pub timeout: u64,
+    /// How many times all nodes are retried after connection errors.
+    #[serde(default = "ConnectionOptions::default_max_retries")]
+    pub max_retries: u32,
+    /// Delay in milliseconds before the first retry. It is doubled for each next one.
+    #[serde(default = "ConnectionOptions::default_retry_backoff")]
+    pub retry_backoff: u64,
+}
+
+impl ConnectionOptions {
+    fn default_timeout() -> u64 {
+        DEFAULT_REQUEST_TIMEOUT
+    }
+
+    fn default_max_retries() -> u32 {
+        DEFAULT_MAX_RETRIES
+    }
+
+    fn default_retry_backoff() -> u64 {
+        DEFAULT_RETRY_BACKOFF
+    }
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        ConnectionOptions {
+            backup_rpc_addresses: Vec::new(),
+            timeout: ConnectionOptions::default_timeout(),
+            max_retries: ConnectionOptions::default_max_retries(),
+            retry_backoff: ConnectionOptions::default_retry_backoff(),
+        }
+    }
+}
+
+impl Validatable for ConnectionOptions {
+    fn validate(&self) -> Result<(), String> {
+        if self.timeout == 0 {
+            return Err(String::from("`timeout` must be greater than 0"));
+        }
+        if self.backup_rpc_addresses.iter().any(|address| address.is_empty()) {
+            return Err(String::from("`backup_rpc_addresses` must not contain empty addresses"));
+        }
+        Ok(())
+    }
+}
+
+/// Header the light client trusts initially. It must be obtained out of band.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TrustOptions {
+    /// Height of the trusted header.
+    pub height: u64,
+    /// Hex encoded hash of the trusted header.
+    pub hash: String,
+    /// Period in seconds during which validators of a verified header can be trusted.
+    #[serde(default = "TrustOptions::default_trusting_period")]
+    pub trusting_period: u64,
+    /// Allowed clock drift in seconds between the client and the network.
+    #[serde(default = "TrustOptions::default_clock_drift")]
+    pub clock_drift: u64,
+    #[serde(default)]
+    pub trust_level: TrustLevel,
+}
+
+impl TrustOptions {
+    fn default_trusting_period() -> u64 {
+        DEFAULT_TRUSTING_PERIOD
+    }
+
+    fn default_clock_drift() -> u64 {
+        DEFAULT_CLOCK_DRIFT
+    }
+}
+
+impl Validatable for TrustOptions {
+    fn validate(&self) -> Result<(), String> {
+        if self.height == 0 {
+            return Err(String::from("`height` must be greater than 0"));
+        }
+        if self.hash.len() != 64 || !self.hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(String::from("`hash` must be a hex encoded SHA-256 hash"));
+        }
+        if self.trusting_period == 0 {
+            return Err(String::from("`trusting_period` must be greater than 0"));
+        }
+
+        let TrustLevel { numerator, denominator } = self.trust_level;
+        if denominator == 0 || numerator.saturating_mul(3) < denominator || numerator > denominator {
+            return Err(String::from("`trust_level` must be within [1/3, 1]"));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PoolConfig {
     pub alias: String,
     pub rpc_address: String,
     pub chain_id: String,
+    #[serde(default = "default_account_prefix")]
+    pub account_prefix: String,
+    /// Query proofs are verified by the light client only if trust options are set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust_options: Option<TrustOptions>,
+    #[serde(default)]
+    pub connection_options: ConnectionOptions,
 }
 
 impl PoolConfig {
-    pub fn new(alias: String, rpc_address: String, chain_id: String) -> Self {
+    pub fn new(
+        alias: String,
+        rpc_address: String,
+        chain_id: String,
+        account_prefix: Option<String>,
+        trust_options: Option<TrustOptions>,
+        connection_options: Option<ConnectionOptions>,
+    ) -> Self {
         PoolConfig {
             alias,
             rpc_address,
             chain_id,
+            account_prefix: account_prefix.unwrap_or_else(default_account_prefix),
+            trust_options,
+            connection_options: connection_options.unwrap_or_default(),
         }
-    } 
+    }
+
+    /// All RPC addresses of the pool starting with the primary one.
+    pub fn rpc_addresses(&self) -> Vec<&str> {
+        let mut addresses = vec![self.rpc_address.as_str()];
+        addresses.extend(self.connection_options.backup_rpc_addresses.iter().map(String::as_str));
+        addresses
+    }
+
+    /// Checks that `account_id` is a bech32 address of this network.
+    pub fn check_account_id(&self, account_id: &str) -> IndyResult<()> {
+        let account = AccountId::from_str(account_id)?;
+
+        if account.prefix() != self.account_prefix {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!(
+                    "Account {} doesn't belong to the pool {:?}: expected prefix {:?}",
+                    account_id, self.alias, self.account_prefix
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _config(account_prefix: Option<&str>) -> PoolConfig {
+        PoolConfig::new(
+            "pool".to_string(),
+            "http://127.0.0.1:26657".to_string(),
+            "cheqd".to_string(),
+            account_prefix.map(String::from),
+            None,
+            None,
+        )
+    }
+
+    fn _trust_options() -> TrustOptions {
+        serde_json::from_value(json!({
+            "height": 1,
+            "hash": "6B68DCE8C5E48A1E4A1C1A4DBA4E7C8B2A2D4D25A1AA9D1B3AC2C2C5C2E1A1F0",
+        })).unwrap()
+    }
+
+    #[test]
+    fn deserialize_works_for_config_without_account_prefix() {
+        let config: PoolConfig = serde_json::from_str(
+            r#"{"alias":"pool","rpc_address":"http://127.0.0.1:26657","chain_id":"cheqd"}"#,
+        ).unwrap();
+
+        assert_eq!(DEFAULT_ACCOUNT_PREFIX, config.account_prefix);
+        assert_eq!(ConnectionOptions::default(), config.connection_options);
+        assert_eq!(vec!["http://127.0.0.1:26657"], config.rpc_addresses());
+    }
+
+    #[test]
+    fn rpc_addresses_works_for_backup_addresses() {
+        let mut config = _config(None);
+        config.connection_options.backup_rpc_addresses = vec!["http://127.0.0.2:26657".to_string()];
+
+        assert_eq!(vec!["http://127.0.0.1:26657", "http://127.0.0.2:26657"], config.rpc_addresses());
+    }
+
+    #[test]
+    fn check_account_id_works() {
+        _config(None).check_account_id("cheqd19rl4cm2hmr8afy4kldpxz3fka4jguq0ajzpa5y").unwrap();
+        _config(Some("cosmos")).check_account_id("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4").unwrap();
+    }
+
+    #[test]
+    fn check_account_id_works_for_other_prefix() {
+        let err = _config(None)
+            .check_account_id("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4")
+            .unwrap_err();
+
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn deserialize_works_for_trust_options_defaults() {
+        let trust_options = _trust_options();
+
+        assert_eq!(DEFAULT_TRUSTING_PERIOD, trust_options.trusting_period);
+        assert_eq!(DEFAULT_CLOCK_DRIFT, trust_options.clock_drift);
+        assert_eq!(TrustLevel::default(), trust_options.trust_level);
+        trust_options.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_trust_options_works_for_invalid_hash() {
+        let mut trust_options = _trust_options();
+        trust_options.hash = "hash".to_string();
+
+        trust_options.validate().unwrap_err();
+    }
+
+    #[test]
+    fn validate_trust_options_works_for_low_trust_level() {
+        let mut trust_options = _trust_options();
+        trust_options.trust_level = TrustLevel { numerator: 1, denominator: 4 };
+
+        trust_options.validate().unwrap_err();
+    }
 }
@@ -11,7 +11,9 @@ pub struct DidMethod(pub String);
 impl Validatable for DidMethod {
     fn validate(&self) -> Result<(), String> {
         lazy_static! {
-            static ref REGEX_METHOD_NAME: Regex = Regex::new("^[a-z0-9]+$").unwrap();
+            // did:indy methods include the ledger namespace with optional sub-namespace: indy:sovrin:staging
+            static ref REGEX_METHOD_NAME: Regex =
+                Regex::new("^([a-z0-9]+|indy(:[a-z0-9_-]+){1,2})$").unwrap();
         }
         if !REGEX_METHOD_NAME.is_match(&self.0) {
             return Err(format!(
@@ -183,3 +185,51 @@ impl From<TemporaryDid> for Did {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const DID_SOV: &str = "did:sov:NcYxiDXkpYi6ov5FcYDi1e";
+    const DID_INDY: &str = "did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e";
+    const DID_INDY_SUB_NAMESPACE: &str = "did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e";
+
+    #[test]
+    fn did_value_get_method_works() {
+        assert_eq!(None, DidValue(DID.to_string()).get_method());
+        assert_eq!(Some("sov".to_string()), DidValue(DID_SOV.to_string()).get_method());
+        assert_eq!(Some("indy:sovrin".to_string()), DidValue(DID_INDY.to_string()).get_method());
+        assert_eq!(
+            Some("indy:sovrin:staging".to_string()),
+            DidValue(DID_INDY_SUB_NAMESPACE.to_string()).get_method()
+        );
+    }
+
+    #[test]
+    fn did_value_to_unqualified_works() {
+        for did in &[DID, DID_SOV, DID_INDY, DID_INDY_SUB_NAMESPACE] {
+            assert_eq!(DidValue(DID.to_string()), DidValue(did.to_string()).to_unqualified());
+        }
+    }
+
+    #[test]
+    fn did_value_qualify_works_for_round_trip() {
+        for did in &[DID_SOV, DID_INDY, DID_INDY_SUB_NAMESPACE] {
+            let did = DidValue(did.to_string());
+            let method = did.get_method().unwrap();
+            assert_eq!(did, did.to_unqualified().qualify(&method));
+        }
+    }
+
+    #[test]
+    fn did_method_validate_works() {
+        DidMethod("sov".to_string()).validate().unwrap();
+        DidMethod("indy:sovrin".to_string()).validate().unwrap();
+        DidMethod("indy:sovrin:staging".to_string()).validate().unwrap();
+
+        DidMethod("sov:sovrin".to_string()).validate().unwrap_err();
+        DidMethod("indy:sovrin:staging:extra".to_string()).validate().unwrap_err();
+        DidMethod("indy::sovrin".to_string()).validate().unwrap_err();
+    }
+}
@@ -0,0 +1,165 @@
+use super::did::DidValue;
+
+pub const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const ED25519_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2018/v1";
+pub const ED25519_VERIFICATION_KEY_2018: &str = "Ed25519VerificationKey2018";
+pub const DID_DOCUMENT_CONTENT_TYPE: &str = "application/did+ld+json";
+
+pub const VERKEY_FRAGMENT: &str = "verkey";
+pub const ENDPOINT_FRAGMENT: &str = "endpoint";
+pub const ENDPOINT_SERVICE_TYPE: &str = "endpoint";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipient_keys: Vec<String>,
+}
+
+/// DID Document as defined by W3C DID Core specification.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub service: Vec<Service>,
+}
+
+impl DidDocument {
+    /// Builds DID Document controlled by `did` with a single Ed25519 `verkey`.
+    /// `verkey` must be in the full (not abbreviated) form.
+    pub fn new(did: &DidValue, verkey: &str) -> DidDocument {
+        let key_id = format!("{}#{}", did.0, VERKEY_FRAGMENT);
+
+        DidDocument {
+            context: vec![DID_CONTEXT.to_string(), ED25519_CONTEXT.to_string()],
+            id: did.0.clone(),
+            verification_method: vec![VerificationMethod {
+                id: key_id.clone(),
+                type_: ED25519_VERIFICATION_KEY_2018.to_string(),
+                controller: did.0.clone(),
+                public_key_base58: verkey.to_string(),
+            }],
+            authentication: vec![key_id.clone()],
+            assertion_method: vec![key_id],
+            service: Vec::new(),
+        }
+    }
+
+    /// Adds service built from the endpoint stored on the ledger.
+    /// Optional transport key of the endpoint becomes a recipient key.
+    pub fn add_endpoint(&mut self, endpoint: &str, transport_key: Option<&str>) {
+        self.service.push(Service {
+            id: format!("{}#{}", self.id, ENDPOINT_FRAGMENT),
+            type_: ENDPOINT_SERVICE_TYPE.to_string(),
+            service_endpoint: endpoint.to_string(),
+            recipient_keys: transport_key.map(|key| vec![key.to_string()]).unwrap_or_default(),
+        });
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+    pub content_type: String,
+}
+
+impl Default for DidResolutionMetadata {
+    fn default() -> Self {
+        DidResolutionMetadata {
+            content_type: DID_DOCUMENT_CONTENT_TYPE.to_string(),
+        }
+    }
+}
+
+/// Ledger specific information about resolved DID Document.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResult {
+    pub did_document: DidDocument,
+    pub did_resolution_metadata: DidResolutionMetadata,
+    pub did_document_metadata: DidDocumentMetadata,
+}
+
+impl DidResolutionResult {
+    pub fn new(did_document: DidDocument, did_document_metadata: DidDocumentMetadata) -> DidResolutionResult {
+        DidResolutionResult {
+            did_document,
+            did_resolution_metadata: DidResolutionMetadata::default(),
+            did_document_metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "did:sov:NcYxiDXkpYi6ov5FcYDi1e";
+    const VERKEY: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+
+    #[test]
+    fn did_document_new_works() {
+        let doc = DidDocument::new(&DidValue(DID.to_string()), VERKEY);
+
+        let expected = json!({
+            "@context": [DID_CONTEXT, ED25519_CONTEXT],
+            "id": DID,
+            "verificationMethod": [{
+                "id": "did:sov:NcYxiDXkpYi6ov5FcYDi1e#verkey",
+                "type": "Ed25519VerificationKey2018",
+                "controller": DID,
+                "publicKeyBase58": VERKEY,
+            }],
+            "authentication": ["did:sov:NcYxiDXkpYi6ov5FcYDi1e#verkey"],
+            "assertionMethod": ["did:sov:NcYxiDXkpYi6ov5FcYDi1e#verkey"],
+        });
+
+        assert_eq!(expected, serde_json::to_value(&doc).unwrap());
+    }
+
+    #[test]
+    fn did_document_add_endpoint_works() {
+        let mut doc = DidDocument::new(&DidValue(DID.to_string()), VERKEY);
+        doc.add_endpoint("127.0.0.1:9700", Some(VERKEY));
+
+        let expected = json!([{
+            "id": "did:sov:NcYxiDXkpYi6ov5FcYDi1e#endpoint",
+            "type": "endpoint",
+            "serviceEndpoint": "127.0.0.1:9700",
+            "recipientKeys": [VERKEY],
+        }]);
+
+        assert_eq!(expected, serde_json::to_value(&doc).unwrap()["service"]);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod key;
 pub mod did;
+pub mod did_document;
 pub mod combo_box;
 pub mod pack;
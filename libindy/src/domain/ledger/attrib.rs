@@ -1,5 +1,5 @@
 use super::constants::{ATTRIB, GET_ATTR};
-use super::response::GetReplyResultV1;
+use super::response::{GetReplyResultV1, ReplyType};
 use super::super::crypto::did::ShortDidValue;
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -60,13 +60,19 @@ pub enum GetAttrReplyResult {
     GetAttrReplyResultV1(GetReplyResultV1<GetAttResultDataV1>)
 }
 
+impl ReplyType for GetAttrReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_ATTR
+    }
+}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAttResultV0 {
     pub  identifier: ShortDidValue,
-    pub  data: String,
+    pub  data: Option<String>,
     pub  dest: ShortDidValue,
-    pub  raw: String
+    pub  raw: Option<String>
 }
 
 #[derive(Deserialize, Eq, PartialEq, Debug)]
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use indy_api_types::validation::Validatable;
 
 use super::constants::{GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML, DISABLE_ALL_TXN_AUTHR_AGRMTS};
+use super::response::ReplyType;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct TxnAuthorAgreementOperation {
@@ -144,4 +145,99 @@ impl GetAcceptanceMechanismOperation {
             version,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetTxnAuthorAgreementReplyResult {
+    pub data: Option<GetTxnAuthorAgreementResultData>,
+}
+
+impl ReplyType for GetTxnAuthorAgreementReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetTxnAuthorAgreementResultData {
+    pub text: Option<String>,
+    pub version: String,
+    pub digest: Option<String>,
+    pub ratification_ts: Option<u64>,
+    pub retirement_ts: Option<u64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetAcceptanceMechanismsReplyResult {
+    pub data: Option<GetAcceptanceMechanismsResultData>,
+}
+
+impl ReplyType for GetAcceptanceMechanismsReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT_AML
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAcceptanceMechanismsResultData {
+    pub aml: AcceptanceMechanisms,
+    pub version: String,
+    pub aml_context: Option<String>,
+}
+
+/// Transaction author agreement and acceptance mechanisms active on the ledger.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveTxnAuthorAgreement {
+    pub text: String,
+    pub version: String,
+    pub digest: String,
+    pub ratification_ts: Option<u64>,
+    pub aml: Option<AcceptanceMechanisms>,
+    pub aml_version: Option<String>,
+    pub aml_context: Option<String>,
+}
+
+/// Acceptance of the transaction author agreement appended to write requests automatically.
+///
+/// Either `taa_digest` or `text` with `version` must be specified.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct TxnAuthorAgreementAcceptance {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taa_digest: Option<String>,
+    pub mechanism: String,
+    pub time: u64,
+}
+
+impl Validatable for TxnAuthorAgreementAcceptance {
+    fn validate(&self) -> Result<(), String> {
+        if self.mechanism.is_empty() {
+            return Err(String::from("Empty acceptance mechanism has been passed"));
+        }
+
+        match (self.text.as_ref(), self.version.as_ref(), self.taa_digest.as_ref()) {
+            (Some(_), Some(_), _) | (None, None, Some(_)) => Ok(()),
+            (None, None, None) => Err(String::from("Either combination `text` + `version` or `taa_digest` must be passed")),
+            _ => Err(String::from("`text` and `version` should be passed or skipped together")),
+        }
+    }
+}
+
+/// What to do if the accepted transaction author agreement isn't the one active on the ledger.
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum TxnAuthorAgreementChangePolicy {
+    Warn,
+    Fail,
+}
+
+impl Default for TxnAuthorAgreementChangePolicy {
+    fn default() -> Self {
+        TxnAuthorAgreementChangePolicy::Fail
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use indy_api_types::validation::Validatable;
+use serde_json::Value;
+
+use super::super::crypto::did::DidValue;
+use super::auth_rule::Constraint;
+
+pub const TRANSACTION_BUNDLE_VERSION: &str = "1";
+
+/// Request passed between the author, the endorser and other signers to collect their signatures.
+///
+/// `request` is kept without signatures, they are collected in `signatures` by short DIDs
+/// and moved into the request only on submission.
+///
+/// `required_signers` must sign the request in any case, while `required_signature_count`
+/// is the number of signatures required by the ledger auth rule of the request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBundle {
+    pub ver: String,
+    pub request: Value,
+    pub required_signers: Vec<String>,
+    pub required_signature_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<Constraint>,
+    #[serde(default)]
+    pub signatures: BTreeMap<String, TransactionBundleSignature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+/// Signature of the bundle request with the verkey it was verified with when added.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TransactionBundleSignature {
+    pub verkey: String,
+    pub signature: String,
+}
+
+impl TransactionBundle {
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.map(|expires_at| now >= expires_at).unwrap_or(false)
+    }
+}
+
+impl Validatable for TransactionBundle {
+    fn validate(&self) -> Result<(), String> {
+        if self.ver != TRANSACTION_BUNDLE_VERSION {
+            return Err(format!("Unsupported transaction bundle version: {}", self.ver));
+        }
+
+        let request = self
+            .request
+            .as_object()
+            .ok_or_else(|| String::from("Transaction bundle request isn't json object"))?;
+
+        if request.contains_key("signature") || request.contains_key("signatures") {
+            return Err(String::from("Transaction bundle request must not contain signatures"));
+        }
+
+        let identifier = request
+            .get("identifier")
+            .and_then(Value::as_str)
+            .ok_or_else(|| String::from("Transaction bundle request doesn't contain identifier"))?;
+
+        if !self.required_signers.iter().any(|signer| signer == identifier) {
+            return Err(String::from("Transaction author isn't in the list of required signers"));
+        }
+
+        if let Some(endorser) = request.get("endorser").and_then(Value::as_str) {
+            if !self.required_signers.iter().any(|signer| signer == endorser) {
+                return Err(String::from("Endorser isn't in the list of required signers"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBundleOptions {
+    #[serde(default)]
+    pub signers: Vec<DidValue>,
+    pub expires_at: Option<u64>,
+    pub auth_action: Option<String>,
+}
+
+impl Validatable for TransactionBundleOptions {
+    fn validate(&self) -> Result<(), String> {
+        for signer in self.signers.iter() {
+            signer.validate()?;
+        }
+
+        match self.auth_action.as_deref() {
+            None | Some("ADD") | Some("EDIT") => Ok(()),
+            Some(action) => Err(format!("Unsupported auth action: {}", action)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBundleStatus {
+    pub missing_signers: Vec<String>,
+    pub missing_signature_count: usize,
+    pub expired: bool,
+    pub ready: bool,
+}
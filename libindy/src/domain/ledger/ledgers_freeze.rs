@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use super::constants::{GET_FROZEN_LEDGERS, LEDGERS_FREEZE};
+use super::response::ReplyType;
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct LedgersFreezeOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub ledgers_ids: Vec<u64>,
+}
+
+impl LedgersFreezeOperation {
+    pub fn new(ledgers_ids: Vec<u64>) -> LedgersFreezeOperation {
+        LedgersFreezeOperation {
+            _type: LEDGERS_FREEZE.to_string(),
+            ledgers_ids,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetFrozenLedgersOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+}
+
+impl GetFrozenLedgersOperation {
+    pub fn new() -> GetFrozenLedgersOperation {
+        GetFrozenLedgersOperation {
+            _type: GET_FROZEN_LEDGERS.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct FrozenLedger {
+    pub ledger: String,
+    pub state: String,
+    pub seq_no: u64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetFrozenLedgersReplyResult {
+    pub data: Option<BTreeMap<u64, FrozenLedger>>,
+}
+
+impl ReplyType for GetFrozenLedgersReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_FROZEN_LEDGERS
+    }
+}
@@ -1,4 +1,5 @@
 use super::constants::GET_TXN;
+use super::super::crypto::did::DidValue;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetTxnOperation {
@@ -35,3 +36,18 @@ impl LedgerType {
         }
     }
 }
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplicateLedgerOptions {
+    pub batch_size: Option<usize>,
+    pub max_txns: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerReplicaTxnsFilter {
+    pub from: Option<usize>,
+    pub to: Option<usize>,
+    pub authors: Option<Vec<DidValue>>,
+}
@@ -4,6 +4,7 @@ pub mod ledger;
 pub mod pairwise;
 pub mod pool;
 pub mod cache;
+pub mod vdr;
 #[cfg(feature = "cheqd")]
 pub mod cheqd_keys;
 #[cfg(feature = "cheqd")]
@@ -1,5 +1,7 @@
 use indy_api_types::validation::Validatable;
 
+use super::ledger::author_agreement::{TxnAuthorAgreementAcceptance, TxnAuthorAgreementChangePolicy};
+
 pub const POOL_CON_ACTIVE_TO: i64 = 5;
 pub const POOL_ACK_TIMEOUT: i64 = 20;
 pub const POOL_REPLY_TIMEOUT: i64 = 60;
@@ -33,6 +35,10 @@ pub struct PoolOpenConfig {
     pub preordered_nodes: Vec<String>,
     #[serde(default = "PoolOpenConfig::default_number_read_nodes")]
     pub number_read_nodes: u8,
+    #[serde(default)]
+    pub taa_acceptance: Option<TxnAuthorAgreementAcceptance>,
+    #[serde(default)]
+    pub taa_change_policy: TxnAuthorAgreementChangePolicy,
 }
 
 impl Validatable for PoolOpenConfig {
@@ -52,6 +58,9 @@ impl Validatable for PoolOpenConfig {
         if self.number_read_nodes == 0 {
             return Err(String::from("`number_read_nodes` must be greater than 0"));
         }
+        if let Some(ref taa_acceptance) = self.taa_acceptance {
+            taa_acceptance.validate()?;
+        }
         Ok(())
     }
 }
@@ -65,6 +74,8 @@ impl Default for PoolOpenConfig {
             conn_active_timeout: PoolOpenConfig::default_conn_active_timeout(),
             preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
             number_read_nodes: PoolOpenConfig::default_number_read_nodes(),
+            taa_acceptance: None,
+            taa_change_policy: TxnAuthorAgreementChangePolicy::default(),
         }
     }
 }
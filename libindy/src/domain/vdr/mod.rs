@@ -0,0 +1,6 @@
+pub mod namespaces;
+pub mod ping_status;
+pub mod prepared_txn;
+pub mod taa_config;
+pub mod txn_params;
+pub mod vdr;
@@ -0,0 +1,201 @@
+use indy_api_types::errors::prelude::*;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::super::anoncreds::credential_definition::CredentialDefinitionId;
+use super::super::anoncreds::schema::SchemaId;
+use super::super::crypto::did::DidValue;
+
+pub const NAMESPACE_DELIMITER: &str = ":";
+
+lazy_static! {
+    static ref NAMESPACE_REGEX: Regex = Regex::new("^[a-z0-9_-]+(:[a-z0-9_-]+)*$").unwrap();
+}
+
+/// Parses JSON list of namespaces which should be served by a single ledger.
+pub fn parse_namespace_list(namespace_list: &str) -> IndyResult<Vec<String>> {
+    let namespaces: Vec<String> = serde_json::from_str(namespace_list).to_indy(
+        IndyErrorKind::InvalidStructure,
+        "Cannot deserialize list of namespaces",
+    )?;
+
+    if namespaces.is_empty() {
+        return Err(err_msg(
+            IndyErrorKind::InvalidStructure,
+            "List of namespaces is empty",
+        ));
+    }
+
+    if let Some(namespace) = namespaces.iter().find(|ns| !NAMESPACE_REGEX.is_match(ns)) {
+        return Err(err_msg(
+            IndyErrorKind::InvalidStructure,
+            format!("Invalid namespace: {}", namespace),
+        ));
+    }
+
+    Ok(namespaces)
+}
+
+/// Splits fully qualified DID `did:<method>[:<sub-namespace>]*:<id>`
+/// into namespace `<method>[:<sub-namespace>]*` and ledger specific identifier `<id>`.
+pub fn did_parts(did: &DidValue) -> IndyResult<(String, String)> {
+    let parts = did.0.split(NAMESPACE_DELIMITER).collect::<Vec<&str>>();
+
+    if parts.len() < 3 || parts[0] != DidValue::PREFIX || parts.iter().any(|part| part.is_empty()) {
+        return Err(err_msg(
+            IndyErrorKind::InvalidStructure,
+            format!("DID `{}` isn't fully qualified", did.0),
+        ));
+    }
+
+    let namespace = parts[1..parts.len() - 1].join(NAMESPACE_DELIMITER);
+    let id = parts[parts.len() - 1].to_string();
+
+    Ok((namespace, id))
+}
+
+pub fn did_namespace(did: &DidValue) -> IndyResult<String> {
+    did_parts(did).map(|(namespace, _)| namespace)
+}
+
+/// Returns ledger specific identifier of DID. Unqualified DIDs are returned as is.
+pub fn ledger_did(did: &DidValue) -> DidValue {
+    match did_parts(did) {
+        Ok((_, id)) => DidValue(id),
+        Err(_) => did.clone(),
+    }
+}
+
+pub fn schema_id_namespace(id: &SchemaId) -> IndyResult<String> {
+    let (did, _, _) = id
+        .parts()
+        .filter(|_| id.is_fully_qualified())
+        .ok_or_else(|| err_msg(
+            IndyErrorKind::InvalidStructure,
+            format!("Schema ID `{}` isn't fully qualified", id.0),
+        ))?;
+
+    did_namespace(&did)
+}
+
+pub fn cred_def_id_namespace(id: &CredentialDefinitionId) -> IndyResult<String> {
+    let (did, _, _, _) = id
+        .parts()
+        .filter(|_| id.is_fully_qualified())
+        .ok_or_else(|| err_msg(
+            IndyErrorKind::InvalidStructure,
+            format!("Credential Definition ID `{}` isn't fully qualified", id.0),
+        ))?;
+
+    did_namespace(&did)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod parse_namespace_list {
+        use super::*;
+
+        #[test]
+        fn parse_namespace_list_works() {
+            let namespaces = parse_namespace_list(r#"["sov", "indy:sovrin"]"#).unwrap();
+            assert_eq!(vec!["sov".to_string(), "indy:sovrin".to_string()], namespaces);
+        }
+
+        #[test]
+        fn parse_namespace_list_works_for_empty_list() {
+            let err = parse_namespace_list("[]").unwrap_err();
+            assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+        }
+
+        #[test]
+        fn parse_namespace_list_works_for_invalid_namespace() {
+            let err = parse_namespace_list(r#"["sov", "Indy::sovrin"]"#).unwrap_err();
+            assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+        }
+
+        #[test]
+        fn parse_namespace_list_works_for_invalid_json() {
+            let err = parse_namespace_list("sov").unwrap_err();
+            assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+        }
+    }
+
+    mod did_parts {
+        use super::*;
+
+        #[test]
+        fn did_parts_works_for_method_namespace() {
+            let (namespace, id) = did_parts(&DidValue("did:sov:NcYxiDXkpYi6ov5FcYDi1e".to_string())).unwrap();
+            assert_eq!("sov", namespace);
+            assert_eq!("NcYxiDXkpYi6ov5FcYDi1e", id);
+        }
+
+        #[test]
+        fn did_parts_works_for_sub_namespace() {
+            let (namespace, id) = did_parts(&DidValue("did:cheqd:testnet:123".to_string())).unwrap();
+            assert_eq!("cheqd:testnet", namespace);
+            assert_eq!("123", id);
+        }
+
+        #[test]
+        fn did_parts_works_for_unqualified_did() {
+            let err = did_parts(&DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string())).unwrap_err();
+            assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+        }
+
+        #[test]
+        fn did_parts_works_for_empty_id() {
+            let err = did_parts(&DidValue("did:sov:".to_string())).unwrap_err();
+            assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+        }
+
+        #[test]
+        fn ledger_did_works() {
+            let id = DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string());
+            assert_eq!(id, ledger_did(&DidValue("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e".to_string())));
+            assert_eq!(id, ledger_did(&id));
+        }
+    }
+
+    mod ids_namespace {
+        use super::*;
+
+        #[test]
+        fn schema_id_namespace_works() {
+            let id = SchemaId("schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string());
+            assert_eq!("sov", schema_id_namespace(&id).unwrap());
+        }
+
+        #[test]
+        fn schema_id_namespace_works_for_did_indy() {
+            let id = SchemaId("schema:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string());
+            assert_eq!("indy:sovrin:staging", schema_id_namespace(&id).unwrap());
+        }
+
+        #[test]
+        fn schema_id_namespace_works_for_unqualified() {
+            let id = SchemaId("NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string());
+            schema_id_namespace(&id).unwrap_err();
+        }
+
+        #[test]
+        fn cred_def_id_namespace_works() {
+            let id = CredentialDefinitionId("creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string());
+            assert_eq!("sov", cred_def_id_namespace(&id).unwrap());
+        }
+
+        #[test]
+        fn cred_def_id_namespace_works_for_did_indy() {
+            let id = CredentialDefinitionId("creddef:indy:sovrin:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string());
+            assert_eq!("indy:sovrin", cred_def_id_namespace(&id).unwrap());
+        }
+
+        #[test]
+        fn cred_def_id_namespace_works_for_unqualified() {
+            let id = CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string());
+            cred_def_id_namespace(&id).unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PingStatusCode {
+    Success,
+    Fail,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PingStatus {
+    pub code: PingStatusCode,
+    pub message: String,
+}
+
+impl PingStatus {
+    pub fn success(message: String) -> PingStatus {
+        PingStatus {
+            code: PingStatusCode::Success,
+            message,
+        }
+    }
+
+    pub fn fail(message: String) -> PingStatus {
+        PingStatus {
+            code: PingStatusCode::Fail,
+            message,
+        }
+    }
+}
+
+/// Ping results keyed by namespace
+pub type PingStatusList = HashMap<String, PingStatus>;
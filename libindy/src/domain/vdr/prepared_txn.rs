@@ -0,0 +1,34 @@
+use super::super::crypto::did::DidValue;
+
+pub const SIGNATURE_SPEC_ED25519: &str = "ed25519";
+pub const SIGNATURE_SPEC_SECP256K1: &str = "secp256k1";
+
+/// Transaction prepared by VDR for signing on the caller side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedTxn {
+    pub namespace: String,
+    pub signature_spec: String,
+    pub txn_bytes: Vec<u8>,
+    pub bytes_to_sign: Vec<u8>,
+    pub endorsement_spec: Option<String>,
+}
+
+impl PreparedTxn {
+    pub fn into_tuple(self) -> (String, String, Vec<u8>, Vec<u8>, Option<String>) {
+        (self.namespace, self.signature_spec, self.txn_bytes, self.bytes_to_sign, self.endorsement_spec)
+    }
+}
+
+/// Describes who must endorse an Indy transaction before it can be submitted.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndyEndorsementSpec {
+    pub endorser_did: DidValue,
+}
+
+/// Endorsement passed together with the author signature on Indy transaction submission.
+/// Signature is base58 encoded signature of the same `bytes_to_sign`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndyEndorsement {
+    pub endorser_did: DidValue,
+    pub signature: String,
+}
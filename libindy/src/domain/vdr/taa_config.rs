@@ -0,0 +1,22 @@
+use indy_api_types::validation::Validatable;
+
+/// Transaction Author Agreement acceptance which VDR appends to every write request
+/// sent to the Indy ledger it was registered for.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TAAConfig {
+    pub text: Option<String>,
+    pub version: Option<String>,
+    pub taa_digest: Option<String>,
+    pub acc_mech_type: String,
+    pub time: u64,
+}
+
+impl Validatable for TAAConfig {
+    fn validate(&self) -> Result<(), String> {
+        match (&self.text, &self.version, &self.taa_digest) {
+            (None, None, None) => Err(String::from("Either combination `text` + `version` or `taa_digest` must be passed")),
+            (Some(_), None, _) | (None, Some(_), _) => Err(String::from("`text` and `version` should be passed or skipped together")),
+            _ => Ok(())
+        }
+    }
+}
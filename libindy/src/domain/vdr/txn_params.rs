@@ -0,0 +1,40 @@
+use super::super::crypto::did::DidValue;
+
+/// Transaction specific params of `vdr_prepare_did` for Indy ledgers
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndyNymParams {
+    pub dest: DidValue,
+    pub verkey: Option<String>,
+    pub alias: Option<String>,
+    pub role: Option<String>,
+}
+
+/// Common params required to build a Cosmos transaction
+#[cfg(feature = "cheqd")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CheqdTxParams {
+    pub sender_public_key: String,
+    pub account_number: u64,
+    pub sequence_number: u64,
+    pub max_gas: u64,
+    pub max_coin_amount: u64,
+    pub max_coin_denom: String,
+    pub timeout_height: u64,
+    #[serde(default)]
+    pub memo: String,
+}
+
+/// Transaction specific params of `vdr_prepare_did` for Cheqd ledgers
+#[cfg(feature = "cheqd")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CheqdNymParams {
+    pub did: String,
+    pub creator: String,
+    pub verkey: String,
+    #[serde(default)]
+    pub alias: String,
+    #[serde(default)]
+    pub role: String,
+    #[serde(flatten)]
+    pub tx: CheqdTxParams,
+}
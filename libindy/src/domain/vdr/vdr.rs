@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use indy_api_types::{errors::prelude::*, PoolHandle};
+
+use super::taa_config::TAAConfig;
+
+#[derive(Debug, Clone)]
+pub struct IndyLedger {
+    pub pool_handle: PoolHandle,
+    pub pool_name: String,
+    pub taa_config: Option<TAAConfig>,
+}
+
+#[cfg(feature = "cheqd")]
+#[derive(Debug, Clone)]
+pub struct CheqdLedger {
+    pub pool_alias: String,
+    pub chain_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Ledger {
+    Indy(IndyLedger),
+    #[cfg(feature = "cheqd")]
+    Cheqd(CheqdLedger),
+}
+
+/// Ledger entity cached within a VDR object after resolution.
+#[derive(Debug, Clone)]
+pub struct CachedEntity {
+    pub data: String,
+    pub timestamp: i32,
+}
+
+/// Set of ledgers registered within a single VDR object.
+/// Several namespaces may be served by the same ledger.
+#[derive(Debug, Default)]
+pub struct VDR {
+    pub namespaces: HashMap<String, Ledger>,
+    pub cache: HashMap<String, CachedEntity>,
+}
+
+impl VDR {
+    pub fn new() -> VDR {
+        VDR::default()
+    }
+
+    pub fn ensure_not_registered(&self, namespaces: &[String]) -> IndyResult<()> {
+        match namespaces.iter().find(|ns| self.namespaces.contains_key(*ns)) {
+            Some(namespace) => Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Ledger is already registered for namespace: {}", namespace),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn register(&mut self, namespaces: Vec<String>, ledger: Ledger) -> IndyResult<()> {
+        self.ensure_not_registered(&namespaces)?;
+
+        for namespace in namespaces {
+            self.namespaces.insert(namespace, ledger.clone());
+        }
+
+        Ok(())
+    }
+
+    pub fn resolve_ledger(&self, namespace: &str) -> IndyResult<Ledger> {
+        self.namespaces.get(namespace).cloned().ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Ledger not found for namespace: {}", namespace),
+            )
+        })
+    }
+
+    /// Returns cached entity if it isn't older than `min_fresh` seconds.
+    /// Negative `min_fresh` means the age isn't checked.
+    pub fn get_cached(&self, id: &str, min_fresh: i32, now: i32) -> Option<String> {
+        self.cache
+            .get(id)
+            .filter(|entity| min_fresh < 0 || now - min_fresh <= entity.timestamp)
+            .map(|entity| entity.data.clone())
+    }
+
+    pub fn store_cached(&mut self, id: &str, data: &str, now: i32) {
+        self.cache.insert(
+            id.to_string(),
+            CachedEntity {
+                data: data.to_string(),
+                timestamp: now,
+            },
+        );
+    }
+
+    /// Returns every registered ledger exactly once.
+    pub fn ledgers(&self) -> Vec<Ledger> {
+        let mut ledgers: Vec<Ledger> = Vec::new();
+
+        for ledger in self.namespaces.values() {
+            let known = ledgers.iter().any(|known| match (known, ledger) {
+                (Ledger::Indy(known), Ledger::Indy(ledger)) => known.pool_name == ledger.pool_name,
+                #[cfg(feature = "cheqd")]
+                (Ledger::Cheqd(known), Ledger::Cheqd(ledger)) => known.pool_alias == ledger.pool_alias,
+                #[cfg(feature = "cheqd")]
+                _ => false,
+            });
+
+            if !known {
+                ledgers.push(ledger.clone());
+            }
+        }
+
+        ledgers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _indy_ledger(pool_name: &str) -> Ledger {
+        Ledger::Indy(IndyLedger {
+            pool_handle: 1,
+            pool_name: pool_name.to_string(),
+            taa_config: None,
+        })
+    }
+
+    #[test]
+    fn register_works() {
+        let mut vdr = VDR::new();
+        vdr.register(vec!["sov".to_string(), "indy:sovrin".to_string()], _indy_ledger("pool")).unwrap();
+
+        vdr.resolve_ledger("sov").unwrap();
+        vdr.resolve_ledger("indy:sovrin").unwrap();
+        assert_eq!(1, vdr.ledgers().len());
+    }
+
+    #[test]
+    fn register_works_for_duplicated_namespace() {
+        let mut vdr = VDR::new();
+        vdr.register(vec!["sov".to_string()], _indy_ledger("pool_1")).unwrap();
+
+        let err = vdr.register(vec!["sov".to_string()], _indy_ledger("pool_2")).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn get_cached_works() {
+        let mut vdr = VDR::new();
+        vdr.store_cached("did:sov:123", "nym", 100);
+
+        assert_eq!(Some("nym".to_string()), vdr.get_cached("did:sov:123", -1, 1000));
+        assert_eq!(Some("nym".to_string()), vdr.get_cached("did:sov:123", 10, 105));
+        assert_eq!(None, vdr.get_cached("did:sov:123", 10, 200));
+        assert_eq!(None, vdr.get_cached("did:sov:456", -1, 100));
+    }
+
+    #[test]
+    fn resolve_ledger_works_for_unknown_namespace() {
+        let vdr = VDR::new();
+
+        let err = vdr.resolve_ledger("sov").unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+}
@@ -41,7 +41,8 @@ use crate::{
     controllers::{
         BlobStorageController, CacheController, ConfigController, CryptoController, DidController,
         IssuerController, LedgerController, MetricsController, NonSecretsController,
-        PairwiseController, PoolController, ProverController, VerifierController, WalletController,
+        PairwiseController, PoolController, ProverController, VdrController, VerifierController,
+        WalletController,
     },
     services::{
         BlobStorageService, CryptoService, IssuerService, LedgerService, MetricsService,
@@ -124,6 +125,7 @@ cfg_if::cfg_if! {
             pub(crate) non_secret_controller: NonSecretsController,
             pub(crate) cache_controller: CacheController,
             pub(crate) metrics_controller: MetricsController,
+            pub(crate) vdr_controller: VdrController,
             pub(crate) executor: InstrumentedThreadPool,
         }
     } else {
@@ -143,6 +145,7 @@ cfg_if::cfg_if! {
             pub(crate) non_secret_controller: NonSecretsController,
             pub(crate) cache_controller: CacheController,
             pub(crate) metrics_controller: MetricsController,
+            pub(crate) vdr_controller: VdrController,
             pub(crate) executor: InstrumentedThreadPool,
         }
     }
@@ -229,7 +232,7 @@ impl Locator {
 
                 let cheqd_pool_controller = CheqdPoolController::new(cheqd_pool_service.clone());
 
-                let cheqd_keys_controller = CheqdKeysController::new(cheqd_keys_service.clone(), wallet_service.clone());
+                let cheqd_keys_controller = CheqdKeysController::new(cheqd_keys_service.clone(), cheqd_pool_service.clone(), wallet_service.clone());
 
                 let did_controller = DidController::new(
                     wallet_service.clone(),
@@ -253,6 +256,13 @@ impl Locator {
                     wallet_service.clone(),
                 );
 
+                let vdr_controller = VdrController::new(
+                    pool_service.clone(),
+                    ledger_service.clone(),
+                    cheqd_pool_service.clone(),
+                    cheqd_ledger_service.clone(),
+                );
+
                 let res = Locator {
                     issuer_controller,
                     prover_controller,
@@ -272,6 +282,7 @@ impl Locator {
                     non_secret_controller,
                     cache_controller,
                     metrics_controller,
+                    vdr_controller,
                     executor,
                 };
 
@@ -367,6 +378,11 @@ impl Locator {
                     wallet_service.clone(),
                 );
 
+                let vdr_controller = VdrController::new(
+                    pool_service.clone(),
+                    ledger_service.clone(),
+                );
+
                 let res = Locator {
                     issuer_controller,
                     prover_controller,
@@ -383,6 +399,7 @@ impl Locator {
                     non_secret_controller,
                     cache_controller,
                     metrics_controller,
+                    vdr_controller,
                     executor,
                 };
 
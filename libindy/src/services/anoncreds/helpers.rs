@@ -259,6 +259,9 @@ mod tests {
             "NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag";
         const REV_REG_ID_QUALIFIED: &str = "revreg:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:4:creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag:CL_ACCUM:TAG_1";
         const REV_REG_ID_UNQUALIFIED: &str = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag:CL_ACCUM:TAG_1";
+        const DID_INDY_QUALIFIED: &str = "did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e";
+        const SCHEMA_ID_INDY_QUALIFIED: &str = "schema:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0";
+        const CRED_DEF_ID_INDY_QUALIFIED: &str = "creddef:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:indy:sovrin:staging:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag";
         const SCHEMA_ID_WITH_SPACES_QUALIFIED: &str =
             "schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:Passport Schema:1.0";
         const SCHEMA_ID_WITH_SPACES_UNQUALIFIED: &str =
@@ -316,5 +319,21 @@ mod tests {
                 AnoncredsHelpers::to_unqualified(REV_REG_ID_UNQUALIFIED).unwrap()
             );
         }
+
+        #[test]
+        fn test_to_unqualified_for_did_indy() {
+            assert_eq!(
+                DID_UNQUALIFIED,
+                AnoncredsHelpers::to_unqualified(DID_INDY_QUALIFIED).unwrap()
+            );
+            assert_eq!(
+                SCHEMA_ID_UNQUALIFIED,
+                AnoncredsHelpers::to_unqualified(SCHEMA_ID_INDY_QUALIFIED).unwrap()
+            );
+            assert_eq!(
+                CRED_DEF_ID_UNQUALIFIED,
+                AnoncredsHelpers::to_unqualified(CRED_DEF_ID_INDY_QUALIFIED).unwrap()
+            );
+        }
     }
 }
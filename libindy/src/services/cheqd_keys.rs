@@ -1,15 +1,22 @@
 //! Service to manage Cosmos keys
 
+use std::str::FromStr;
+
+use bip32::{DerivationPath, XPrv};
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
 use cosmrs::crypto::secp256k1::EcdsaSigner;
 use cosmrs::crypto::secp256k1::SigningKey as CosmosSigningKey;
+use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
 use cosmrs::tx::{Raw, SignDoc};
-use indy_api_types::errors::{IndyResult, IndyResultExt, IndyErrorKind};
+use cosmrs::AccountId;
+use indy_api_types::errors::{err_msg, IndyResult, IndyResultExt, IndyErrorKind};
 use k256::ecdsa::signature::rand_core::OsRng;
 use k256::ecdsa::SigningKey;
 use rand::rngs::StdRng;
 use rust_base58::ToBase58;
 
-use crate::domain::cheqd_keys::{Key, KeyInfo};
+use crate::domain::cheqd_keys::{DEFAULT_HD_PATH, Key, KeyInfo, MnemonicDerivationMethod, MnemonicOptions, MultisigKeyInfo};
+use crate::domain::cheqd_ledger::crypto::multisig::LegacyAminoPubKey;
 use sha3::Digest;
 use rand::SeedableRng;
 
@@ -40,22 +47,72 @@ impl CheqdKeysService {
         Ok(key)
     }
 
+    pub(crate) fn generate_mnemonic(&self, words_count: u32) -> IndyResult<String> {
+        let mnemonic_type = MnemonicType::for_word_count(words_count as usize).map_err(|err| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Unsupported mnemonic words count {}: {}", words_count, err),
+            )
+        })?;
+
+        let mnemonic = Mnemonic::new(mnemonic_type, Language::English);
+        Ok(mnemonic.phrase().to_string())
+    }
+
     pub(crate) fn new_from_mnemonic(
         &self,
         alias: &str,
         mnemonic: &str,
+        options: &MnemonicOptions,
     ) -> IndyResult<Key> {
+        let priv_key = match options.derivation_method {
+            MnemonicDerivationMethod::BIP44 => Self::bip44_priv_key(mnemonic, options)?,
+            MnemonicDerivationMethod::LEGACY_SHA3 => Self::legacy_sha3_priv_key(mnemonic),
+        };
+
+        let key = Key::new(alias.to_string(), priv_key);
+        Ok(key)
+    }
+
+    fn bip44_priv_key(mnemonic: &str, options: &MnemonicOptions) -> IndyResult<Vec<u8>> {
+        let mnemonic = Mnemonic::from_phrase(mnemonic, Language::English).map_err(|err| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Invalid BIP-39 mnemonic: {}", err),
+            )
+        })?;
+
+        let seed = Seed::new(&mnemonic, options.passphrase.as_deref().unwrap_or(""));
+
+        let hd_path = options.hd_path.as_deref().unwrap_or(DEFAULT_HD_PATH);
+        let hd_path = DerivationPath::from_str(hd_path).map_err(|err| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Invalid HD path {}: {}", hd_path, err),
+            )
+        })?;
+
+        let xprv = XPrv::derive_from_path(seed.as_bytes(), &hd_path).map_err(|err| {
+            err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Error was raised while deriving key from mnemonic: {}", err),
+            )
+        })?;
+
+        Ok(xprv.private_key().to_bytes().to_vec())
+    }
+
+    fn legacy_sha3_priv_key(mnemonic: &str) -> Vec<u8> {
         let seed = sha3::Sha3_256::digest(&mnemonic.as_bytes());
         let mut rng = StdRng::from_seed(seed.into());
         let sig_key = k256::ecdsa::SigningKey::random(&mut rng);
-        let key = Key::new(alias.to_string(), sig_key.to_bytes().to_vec());
-        Ok(key)
+        sig_key.to_bytes().to_vec()
     }
 
-    pub(crate) fn get_info(&self, key: &Key) -> IndyResult<KeyInfo> {
+    pub(crate) fn get_info(&self, key: &Key, account_prefix: &str) -> IndyResult<KeyInfo> {
         let sig_key = Self::bytes_to_cosmos_signing_key(&key.priv_key)?;
         let pub_key = sig_key.public_key();
-        let account_id = pub_key.account_id("cosmos")?;
+        let account_id = pub_key.account_id(account_prefix)?;
 
         let key_info = KeyInfo::new(
             key.alias.to_owned(),
@@ -70,6 +127,28 @@ impl CheqdKeysService {
         let sig_key = Self::bytes_to_cosmos_signing_key(&key.priv_key)?;
         Ok(tx.sign(&sig_key)?)
     }
+
+    /// Signature of one member of multisig account. Signatures of members are combined
+    /// into the multisig transaction by `CheqdLedgerService::auth_combine_multisig_signatures`.
+    pub(crate) async fn sign_multisig_partial(&self, key: &Key, tx: SignDoc) -> IndyResult<Vec<u8>> {
+        let signed: TxRaw = self.sign(key, tx).await?.into();
+
+        signed.signatures.into_iter().next().ok_or_else(|| {
+            err_msg(IndyErrorKind::InvalidState, "Signed transaction doesn't contain signature")
+        })
+    }
+
+    pub(crate) fn get_multisig_info(
+        &self,
+        threshold: u32,
+        pub_keys: Vec<String>,
+        account_prefix: &str,
+    ) -> IndyResult<MultisigKeyInfo> {
+        let multisig_pub_key = LegacyAminoPubKey::from_base58_keys(threshold, &pub_keys)?;
+        let account_id = AccountId::new(account_prefix, &multisig_pub_key.address())?;
+
+        Ok(MultisigKeyInfo::new(account_id.to_string(), threshold, pub_keys))
+    }
 }
 
 #[cfg(test)]
@@ -81,6 +160,8 @@ mod test {
     use k256::ecdsa::signature::Signer;
     use k256::elliptic_curve::rand_core::OsRng;
 
+    use crate::domain::cheqd_pool::DEFAULT_ACCOUNT_PREFIX;
+
     use super::*;
 
     #[async_std::test]
@@ -92,23 +173,102 @@ mod test {
         assert_eq!(key.alias, "alice")
     }
 
+    fn _legacy_options() -> MnemonicOptions {
+        MnemonicOptions {
+            derivation_method: MnemonicDerivationMethod::LEGACY_SHA3,
+            ..MnemonicOptions::default()
+        }
+    }
+
     #[async_std::test]
     async fn test_add_from_mnemonic() {
         let cheqd_keys_service = CheqdKeysService::new();
 
         let alice = cheqd_keys_service
-            .new_from_mnemonic("alice", "secret phrase")
+            .new_from_mnemonic("alice", "secret phrase", &_legacy_options())
             .unwrap();
-        let alice_info = cheqd_keys_service.get_info(&alice).unwrap();
+        let alice_info = cheqd_keys_service.get_info(&alice, DEFAULT_ACCOUNT_PREFIX).unwrap();
 
         let bob = cheqd_keys_service
-            .new_from_mnemonic("bob", "secret phrase")
+            .new_from_mnemonic("bob", "secret phrase", &_legacy_options())
             .unwrap();
-        let bob_info = cheqd_keys_service.get_info(&bob).unwrap();
+        let bob_info = cheqd_keys_service.get_info(&bob, DEFAULT_ACCOUNT_PREFIX).unwrap();
 
         assert_eq!(alice_info.pub_key, bob_info.pub_key)
     }
 
+    #[test]
+    fn test_add_from_bip39_mnemonic() {
+        let cheqd_keys_service = CheqdKeysService::new();
+
+        // Account of this test phrase with the default HD path, as derived by `keys add --recover`.
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let key = cheqd_keys_service
+            .new_from_mnemonic("alice", mnemonic, &MnemonicOptions::default())
+            .unwrap();
+        let key_info = cheqd_keys_service.get_info(&key, "cosmos").unwrap();
+
+        assert_eq!("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4", key_info.account_id);
+    }
+
+    #[test]
+    fn test_get_info_for_account_prefix() {
+        let cheqd_keys_service = CheqdKeysService::new();
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let key = cheqd_keys_service
+            .new_from_mnemonic("alice", mnemonic, &MnemonicOptions::default())
+            .unwrap();
+        let key_info = cheqd_keys_service.get_info(&key, DEFAULT_ACCOUNT_PREFIX).unwrap();
+
+        assert_eq!("cheqd19rl4cm2hmr8afy4kldpxz3fka4jguq0ajzpa5y", key_info.account_id);
+    }
+
+    #[test]
+    fn test_add_from_bip39_mnemonic_for_hd_path() {
+        let cheqd_keys_service = CheqdKeysService::new();
+
+        let mnemonic = cheqd_keys_service.generate_mnemonic(24).unwrap();
+
+        let key_1 = cheqd_keys_service
+            .new_from_mnemonic("alice", &mnemonic, &MnemonicOptions::default())
+            .unwrap();
+
+        let options = MnemonicOptions {
+            hd_path: Some("m/44'/118'/0'/0/1".to_string()),
+            ..MnemonicOptions::default()
+        };
+        let key_2 = cheqd_keys_service
+            .new_from_mnemonic("alice", &mnemonic, &options)
+            .unwrap();
+
+        assert_ne!(key_1.priv_key, key_2.priv_key);
+    }
+
+    #[test]
+    fn test_add_from_bip39_mnemonic_for_invalid_mnemonic() {
+        let cheqd_keys_service = CheqdKeysService::new();
+
+        let err = cheqd_keys_service
+            .new_from_mnemonic("alice", "secret phrase", &MnemonicOptions::default())
+            .unwrap_err();
+
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn test_generate_mnemonic() {
+        let cheqd_keys_service = CheqdKeysService::new();
+
+        let mnemonic = cheqd_keys_service.generate_mnemonic(12).unwrap();
+        assert_eq!(12, mnemonic.split_whitespace().count());
+
+        let err = cheqd_keys_service.generate_mnemonic(13).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
     #[test]
     fn test_private_key_import_export() {
         let key = k256::ecdsa::SigningKey::random(&mut OsRng);
@@ -147,4 +307,49 @@ mod test {
 
         assert_eq!(pub_key, cosmos_pub_key);
     }
+
+    fn _pub_keys(cheqd_keys_service: &CheqdKeysService, count: usize) -> Vec<String> {
+        (0..count)
+            .map(|i| {
+                let key = cheqd_keys_service.new_random(&format!("key{}", i)).unwrap();
+                cheqd_keys_service.get_info(&key, DEFAULT_ACCOUNT_PREFIX).unwrap().pub_key
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_get_multisig_info() {
+        let cheqd_keys_service = CheqdKeysService::new();
+        let pub_keys = _pub_keys(&cheqd_keys_service, 3);
+
+        let info_1 = cheqd_keys_service.get_multisig_info(2, pub_keys.clone(), DEFAULT_ACCOUNT_PREFIX).unwrap();
+        let info_2 = cheqd_keys_service.get_multisig_info(2, pub_keys.clone(), DEFAULT_ACCOUNT_PREFIX).unwrap();
+        let info_3 = cheqd_keys_service.get_multisig_info(3, pub_keys, DEFAULT_ACCOUNT_PREFIX).unwrap();
+
+        assert!(info_1.account_id.starts_with("cheqd1"));
+        assert_eq!(info_1.account_id, info_2.account_id);
+        assert_ne!(info_1.account_id, info_3.account_id);
+    }
+
+    #[test]
+    fn test_get_multisig_info_for_invalid_threshold() {
+        let cheqd_keys_service = CheqdKeysService::new();
+        let pub_keys = _pub_keys(&cheqd_keys_service, 2);
+
+        let err = cheqd_keys_service.get_multisig_info(0, pub_keys.clone(), DEFAULT_ACCOUNT_PREFIX).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+
+        let err = cheqd_keys_service.get_multisig_info(3, pub_keys, DEFAULT_ACCOUNT_PREFIX).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn test_get_multisig_info_for_invalid_pub_key() {
+        let cheqd_keys_service = CheqdKeysService::new();
+
+        let err = cheqd_keys_service
+            .get_multisig_info(1, vec!["invalid".to_string()], DEFAULT_ACCOUNT_PREFIX)
+            .unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
 }
@@ -5,9 +5,19 @@ use cosmrs::{Coin, tx, AccountId};
 use cosmrs::crypto::PublicKey;
 use cosmrs::rpc::endpoint::abci_query;
 use cosmrs::tendermint::block::Height;
-use cosmrs::tx::{AuthInfo, Fee, Msg, SignDoc, SignerInfo};
-use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyResultExt, IndyError};
+use cosmrs::tx::{AuthInfo, Fee, Msg, Raw, SignDoc, SignerInfo};
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::crypto::multisig::v1beta1::{CompactBitArray, MultiSignature};
+use cosmrs::proto::cosmos::tx::signing::v1beta1::SignMode;
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    mode_info, AuthInfo as ProtoAuthInfo, Fee as ProtoFee, ModeInfo, SignDoc as ProtoSignDoc,
+    SignerInfo as ProtoSignerInfo, TxBody as ProtoTxBody, TxRaw,
+};
+use indy_api_types::errors::{err_msg, IndyErrorKind, IndyResult, IndyResultExt, IndyError};
+use k256::ecdsa::signature::{Signature as _, Verifier};
 use crate::domain::cheqd_ledger::auth::{QueryAccountRequest, QueryAccountResponse, Account};
+use crate::domain::cheqd_ledger::crypto::multisig::{LegacyAminoPubKey, LEGACY_AMINO_PUB_KEY_TYPE_URL};
+use crate::domain::cheqd_ledger::prost_ext::ProstMessageExt;
 use crate::domain::cheqd_ledger::CheqdProto;
 use crate::services::CheqdLedgerService;
 use crate::utils::cheqd_crypto::check_proofs;
@@ -70,6 +80,199 @@ impl CheqdLedgerService {
         Ok(signer_info)
     }
 
+    /// Builds transaction of multisig account. Members which are going to sign the transaction
+    /// are fixed at this point as they are part of the signed data in `SIGN_MODE_DIRECT`.
+    pub(crate) fn auth_build_multisig_tx(
+        &self,
+        chain_id: &str,
+        multisig_pub_key: &LegacyAminoPubKey,
+        signers: &[usize],
+        msg: Msg,
+        account_number: u64,
+        sequence_number: u64,
+        max_gas: u64,
+        max_coin_amount: u64,
+        max_coin_denom: &str,
+        timeout_height: u64,
+        memo: &str,
+    ) -> IndyResult<SignDoc> {
+        let members_count = multisig_pub_key.public_keys.len();
+        let signers_count = Self::count_distinct(signers);
+
+        if signers_count < multisig_pub_key.threshold as usize {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("At least {} signers are required by multisig threshold, got {}", multisig_pub_key.threshold, signers_count),
+            ));
+        }
+
+        if let Some(signer) = signers.iter().find(|signer| **signer >= members_count) {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Signer index {} is out of multisig members range", signer),
+            ));
+        }
+
+        let timeout_height: Height = timeout_height.try_into()?;
+        let tx_body: ProtoTxBody = tx::Body::new(vec![msg], memo, timeout_height).into();
+
+        let single_direct = ModeInfo {
+            sum: Some(mode_info::Sum::Single(mode_info::Single {
+                mode: SignMode::Direct as i32,
+            })),
+        };
+
+        let signer_info = ProtoSignerInfo {
+            public_key: Some(multisig_pub_key.to_any()?),
+            mode_info: Some(ModeInfo {
+                sum: Some(mode_info::Sum::Multi(mode_info::Multi {
+                    bitarray: Some(Self::build_compact_bit_array(members_count, signers)),
+                    mode_infos: vec![single_direct; signers_count],
+                })),
+            }),
+            sequence: sequence_number,
+        };
+
+        let auth_info = ProtoAuthInfo {
+            signer_infos: vec![signer_info],
+            fee: Some(ProtoFee {
+                amount: vec![ProtoCoin {
+                    denom: max_coin_denom.to_string(),
+                    amount: max_coin_amount.to_string(),
+                }],
+                gas_limit: max_gas,
+                ..ProtoFee::default()
+            }),
+        };
+
+        let sign_doc = ProtoSignDoc {
+            body_bytes: tx_body.to_bytes()?,
+            auth_info_bytes: auth_info.to_bytes()?,
+            chain_id: chain_id.to_string(),
+            account_number,
+        };
+
+        Ok(sign_doc.into())
+    }
+
+    /// Combines signatures of multisig members into the transaction ready for broadcasting.
+    /// `signatures` contains signature of every member index set in the transaction, others are ignored.
+    pub(crate) fn auth_combine_multisig_signatures(
+        &self,
+        sign_doc: SignDoc,
+        signatures: &[(usize, Vec<u8>)],
+    ) -> IndyResult<Raw> {
+        let sign_doc: ProtoSignDoc = sign_doc.into();
+        let sign_doc_bytes = sign_doc.to_bytes()?;
+        let auth_info = ProtoAuthInfo::from_bytes(&sign_doc.auth_info_bytes)?;
+
+        let (multisig_pub_key, bitarray) = Self::parse_multisig_signer_info(&auth_info)?;
+
+        let mut multi_signature = MultiSignature { signatures: Vec::new() };
+
+        for index in Self::parse_compact_bit_array(&bitarray) {
+            let signature = signatures
+                .iter()
+                .find(|(signer, _)| *signer == index)
+                .map(|(_, signature)| signature)
+                .ok_or_else(|| err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    format!("Signature of multisig member {} is missing", index),
+                ))?;
+
+            let pub_key = multisig_pub_key.public_keys.get(index).ok_or_else(|| err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Multisig member {} is out of public keys range", index),
+            ))?;
+
+            Self::verify_signature(&pub_key.key, &sign_doc_bytes, signature)?;
+
+            multi_signature.signatures.push(signature.clone());
+        }
+
+        let raw = TxRaw {
+            body_bytes: sign_doc.body_bytes,
+            auth_info_bytes: sign_doc.auth_info_bytes,
+            signatures: vec![multi_signature.to_bytes()?],
+        };
+
+        Ok(raw.into())
+    }
+
+    /// Multisig key and signers bitarray of the multisig transaction.
+    pub(crate) fn parse_multisig_signer_info(auth_info: &ProtoAuthInfo) -> IndyResult<(LegacyAminoPubKey, CompactBitArray)> {
+        let signer_info = match &auth_info.signer_infos[..] {
+            [signer_info] => signer_info,
+            _ => return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Multisig transaction must contain exactly one signer info",
+            )),
+        };
+
+        let multisig_pub_key = match &signer_info.public_key {
+            Some(public_key) if public_key.type_url == LEGACY_AMINO_PUB_KEY_TYPE_URL => {
+                LegacyAminoPubKey::from_proto_bytes(&public_key.value)?
+            }
+            _ => return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Transaction signer isn't a multisig account",
+            )),
+        };
+
+        let bitarray = match signer_info.mode_info.as_ref().and_then(|mode_info| mode_info.sum.as_ref()) {
+            Some(mode_info::Sum::Multi(multi)) => multi.bitarray.clone(),
+            _ => None,
+        }.ok_or_else(|| err_msg(
+            IndyErrorKind::InvalidStructure,
+            "Multisig transaction doesn't contain signers bitarray",
+        ))?;
+
+        Ok((multisig_pub_key, bitarray))
+    }
+
+    fn verify_signature(pub_key: &[u8], msg: &[u8], signature: &[u8]) -> IndyResult<()> {
+        let verifying_key = k256::ecdsa::VerifyingKey::from_sec1_bytes(pub_key).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Error was raised while creating verifying key object k256::ecdsa::VerifyingKey"
+        )?;
+
+        let signature = k256::ecdsa::Signature::from_bytes(signature).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Invalid secp256k1 signature of multisig member"
+        )?;
+
+        verifying_key.verify(msg, &signature).to_indy(
+            IndyErrorKind::InvalidStructure,
+            "Signature of multisig member doesn't match the transaction"
+        )
+    }
+
+    fn count_distinct(signers: &[usize]) -> usize {
+        let mut signers = signers.to_vec();
+        signers.sort();
+        signers.dedup();
+        signers.len()
+    }
+
+    fn build_compact_bit_array(bits: usize, set: &[usize]) -> CompactBitArray {
+        let mut elems = vec![0u8; (bits + 7) / 8];
+
+        for index in set {
+            elems[index / 8] |= 1 << (7 - index % 8);
+        }
+
+        CompactBitArray {
+            extra_bits_stored: (bits % 8) as u32,
+            elems,
+        }
+    }
+
+    fn parse_compact_bit_array(bitarray: &CompactBitArray) -> Vec<usize> {
+        (0..bitarray.elems.len() * 8)
+            .filter(|index| bitarray.elems[index / 8] & (1 << (7 - index % 8)) != 0)
+            .collect()
+    }
+
     pub(crate) fn auth_build_query_account_without_proof(
         &self,
         address: &str,
@@ -140,4 +343,13 @@ mod tests {
         assert!(err.to_string().contains(IndyErrorKind::QueryAccountDoesNotexist.as_fail().to_string().as_str()));
 
     }
+
+    #[test]
+    fn compact_bit_array_roundtrip() {
+        let bitarray = CheqdLedgerService::build_compact_bit_array(10, &[0, 3, 9]);
+
+        assert_eq!(2, bitarray.extra_bits_stored);
+        assert_eq!(vec![0b1001_0000, 0b0100_0000], bitarray.elems);
+        assert_eq!(vec![0, 3, 9], CheqdLedgerService::parse_compact_bit_array(&bitarray));
+    }
 }
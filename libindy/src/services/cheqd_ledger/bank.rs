@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use cosmrs::rpc::endpoint::abci_query;
-use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use crate::domain::cheqd_ledger::tx::TxResponse;
 use cosmrs::tx::Msg;
 use cosmrs::tx::MsgType;
 use indy_api_types::errors::IndyResult;
@@ -46,7 +46,7 @@ impl CheqdLedgerService {
     #[logfn(Info)]
     pub(crate) fn bank_parse_msg_send_resp(
         &self,
-        resp: &Response
+        resp: &TxResponse
     ) -> IndyResult<MsgSendResponse> {
         self.parse_msg_resp(resp)
     }
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use cosmrs::rpc;
 use cosmrs::rpc::endpoint::abci_query;
-use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
+use crate::domain::cheqd_ledger::tx::TxResponse;
 use cosmrs::tx::Msg;
 use cosmrs::tx::MsgType;
 use indy_api_types::IndyError;
@@ -50,7 +50,7 @@ impl CheqdLedgerService {
     #[logfn(Info)]
     pub(crate) fn cheqd_parse_msg_create_nym_resp(
         &self,
-        resp: &Response,
+        resp: &TxResponse,
     ) -> IndyResult<MsgCreateNymResponse> {
         self.parse_msg_resp(resp)
     }
@@ -80,7 +80,7 @@ impl CheqdLedgerService {
     #[logfn(Info)]
     pub(crate) fn cheqd_parse_msg_update_nym_resp(
         &self,
-        resp: &Response,
+        resp: &TxResponse,
     ) -> IndyResult<MsgUpdateNymResponse> {
         self.parse_msg_resp(resp)
     }
@@ -98,7 +98,7 @@ impl CheqdLedgerService {
     #[logfn(Info)]
     pub(crate) fn cheqd_parse_msg_delete_nym_resp(
         &self,
-        resp: &Response,
+        resp: &TxResponse,
     ) -> IndyResult<MsgDeleteNymResponse> {
         self.parse_msg_resp(resp)
     }
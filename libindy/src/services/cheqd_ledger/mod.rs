@@ -1,17 +1,18 @@
 //! Ledger service for Cheqd back-end
 
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxMsgData;
-use cosmrs::rpc::endpoint::broadcast::tx_commit::Response;
 use indy_api_types::errors::{IndyErrorKind, IndyResult};
 use indy_api_types::IndyError;
 use log_derive::logfn;
 
 use crate::domain::cheqd_ledger::prost_ext::ProstMessageExt;
 use crate::domain::cheqd_ledger::CheqdProto;
+use crate::domain::cheqd_ledger::tx::TxResponse;
 
 mod auth;
 mod cheqd;
 mod bank;
+mod tx;
 
 pub(crate) struct CheqdLedgerService {}
 
@@ -21,15 +22,21 @@ impl CheqdLedgerService {
     }
 
     #[logfn(Info)]
-    fn parse_msg_resp<R>(&self, resp: &Response) -> IndyResult<R>
+    fn parse_msg_resp<R>(&self, resp: &TxResponse) -> IndyResult<R>
         where
             R: CheqdProto,
     {
-        let data = resp.deliver_tx.data.as_ref().ok_or(IndyError::from_msg(
+        if resp.code().is_err() {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidTransaction,
+                format!("Transaction has failed with code {}: {}", resp.code().value(), resp.log()),
+            ));
+        }
+
+        let data = resp.data().ok_or(IndyError::from_msg(
             IndyErrorKind::InvalidState,
             "Expected response data but got None",
         ))?;
-        let data = data.value();
         let tx_msg = TxMsgData::from_bytes(&data)?;
         let result = R::from_proto_bytes(&tx_msg.data[0].data)?;
 
@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    AuthInfo as ProtoAuthInfo, SimulateRequest, Tx as ProtoTx, TxBody as ProtoTxBody,
+};
+use cosmrs::rpc::endpoint::abci_query;
+use cosmrs::tx::SignDoc;
+use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyError};
+use log_derive::logfn;
+
+use crate::domain::cheqd_ledger::prost_ext::ProstMessageExt;
+use crate::domain::cheqd_ledger::tx::{FeeEstimation, FeeOptions, SimulateResponse};
+use crate::domain::cheqd_ledger::CheqdProto;
+use crate::services::CheqdLedgerService;
+
+impl CheqdLedgerService {
+    /// Builds ABCI query simulating execution of the not signed transaction.
+    /// Node doesn't verify signatures in simulation mode, so empty ones are passed.
+    #[logfn(Info)]
+    pub(crate) fn tx_build_query_simulate(
+        &self,
+        sign_doc: &SignDoc,
+    ) -> IndyResult<abci_query::Request> {
+        let body = ProtoTxBody::from_bytes(&sign_doc.body_bytes)?;
+        let auth_info = ProtoAuthInfo::from_bytes(&sign_doc.auth_info_bytes)?;
+        let signatures = vec![Vec::new(); auth_info.signer_infos.len()];
+
+        let query_data = SimulateRequest {
+            tx: Some(ProtoTx {
+                body: Some(body),
+                auth_info: Some(auth_info),
+                signatures,
+            }),
+            ..SimulateRequest::default()
+        };
+
+        let path = format!("/cosmos.tx.v1beta1.Service/Simulate");
+        let path = cosmrs::tendermint::abci::Path::from_str(&path)?;
+        let req = abci_query::Request::new(Some(path), query_data.to_bytes()?, None, false);
+        Ok(req)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn tx_parse_query_simulate_resp(
+        &self,
+        resp: &abci_query::Response,
+    ) -> IndyResult<SimulateResponse> {
+        if resp.response.code.is_err() {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Transaction simulation failed: {:?}", resp.response.log),
+            ));
+        }
+
+        let result = SimulateResponse::from_proto_bytes(&resp.response.value)?;
+        Ok(result)
+    }
+
+    /// Suggests gas limit and fee for the transaction which consumed `gas_used` in simulation.
+    pub(crate) fn tx_estimate_fee(&self, gas_used: u64, options: &FeeOptions) -> FeeEstimation {
+        let gas_limit = (gas_used as f64 * options.gas_adjustment).ceil() as u64;
+        let amount = (gas_limit as f64 * options.gas_price).ceil() as u64;
+
+        FeeEstimation {
+            gas_used,
+            gas_limit,
+            amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_fee() {
+        let cheqd_ledger_service = CheqdLedgerService::new();
+
+        let options = FeeOptions {
+            gas_price: 0.025,
+            gas_adjustment: 1.5,
+        };
+        let fee = cheqd_ledger_service.tx_estimate_fee(100001, &options);
+
+        assert_eq!(150002, fee.gas_limit);
+        assert_eq!(3751, fee.amount);
+    }
+
+    #[test]
+    fn test_estimate_fee_for_zero_gas_price() {
+        let cheqd_ledger_service = CheqdLedgerService::new();
+
+        let options = FeeOptions {
+            gas_price: 0.0,
+            ..FeeOptions::default()
+        };
+        let fee = cheqd_ledger_service.tx_estimate_fee(100000, &options);
+
+        assert_eq!(130000, fee.gas_limit);
+        assert_eq!(0, fee.amount);
+    }
+}
@@ -1,35 +1,102 @@
 //! Pool service for Tendermint back-end
 
+use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fs;
 use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use async_std::net::TcpStream;
+use futures::lock::Mutex;
 
 use http_client::HttpClient;
 use http_client::http_types::{Method,
                               Request as HttpRequest,
                               Response as HttpResponse,
-                              Body};
+                              Body,
+                              Url};
 use http_client::h1::H1Client;
 
 use cosmrs::rpc;
 use cosmrs::rpc::{Request, Response};
 use cosmrs::rpc::endpoint::broadcast;
-use cosmrs::tendermint::abci;
+use cosmrs::tendermint::{abci, block, node, validator, Hash, Time};
+use cosmrs::tendermint::block::Header;
+use cosmrs::tendermint::hash::Algorithm;
 use cosmrs::tx::Raw;
+use tendermint_light_client::light_client::Options;
+use tendermint_light_client::types::{LightBlock, TrustThreshold};
+use tendermint_light_client::verifier::{ProdVerifier, Verdict, Verifier};
 use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyResultExt};
 use indy_api_types::errors::*;
 use indy_api_types::IndyError;
 
-use crate::domain::cheqd_pool::PoolConfig;
+use crate::domain::cheqd_pool::{ConnectionOptions, PoolConfig, TrustOptions};
+use crate::utils::cheqd_crypto::check_proofs_with_app_hash;
 use crate::utils::environment;
 
-pub(crate) struct CheqdPoolService {}
+const VALIDATORS_PER_PAGE: u8 = 100;
+const NEXT_BLOCK_WAIT_ATTEMPTS: usize = 5;
+const NEXT_BLOCK_WAIT_PERIOD: Duration = Duration::from_secs(1);
+
+/// Health of pool nodes observed by the requests sent to them.
+#[derive(Debug, Default)]
+struct NodesHealth {
+    // Node that answered the last request. It is asked first until it fails.
+    sticky: Option<String>,
+    // Number of consecutive failed requests per node
+    failures: HashMap<String, u32>,
+}
+
+impl NodesHealth {
+    /// Orders addresses from the healthiest node keeping the configured order for equal ones.
+    fn order<'a>(&self, mut rpc_addresses: Vec<&'a str>) -> Vec<&'a str> {
+        rpc_addresses.sort_by_key(|address| {
+            let is_sticky = self.sticky.as_deref() == Some(*address);
+            let failures = self.failures.get(*address).cloned().unwrap_or(0);
+            (!is_sticky, failures)
+        });
+        rpc_addresses
+    }
+
+    fn succeeded(&mut self, rpc_address: &str) {
+        self.failures.remove(rpc_address);
+        self.sticky = Some(rpc_address.to_string());
+    }
+
+    fn failed(&mut self, rpc_address: &str) {
+        *self.failures.entry(rpc_address.to_string()).or_insert(0) += 1;
+
+        if self.sticky.as_deref() == Some(rpc_address) {
+            self.sticky = None;
+        }
+    }
+}
+
+/// Failure of a request sent to a single node.
+enum NodeError {
+    /// Connection to the node wasn't established, so the request hasn't reached it.
+    NotSent(IndyError),
+    /// The request could have been processed by the node.
+    Sent(IndyError),
+}
 
-// ToDo: it can be used only in "unstable-config".
-// const CLIENT_TIMEOUT: u64 = 10;
+pub(crate) struct CheqdPoolService {
+    http_client: H1Client,
+    // Health of nodes for each pool alias
+    nodes_health: Mutex<HashMap<String, NodesHealth>>,
+    // The latest light block verified for each pool alias
+    trusted_blocks: Mutex<HashMap<String, LightBlock>>,
+}
 
 impl CheqdPoolService {
     pub(crate) fn new() -> Self {
-        Self {}
+        Self {
+            http_client: H1Client::new(),
+            nodes_health: Mutex::new(HashMap::new()),
+            trusted_blocks: Mutex::new(HashMap::new()),
+        }
     }
 
     pub(crate) async fn add(
@@ -37,11 +104,17 @@ impl CheqdPoolService {
         alias: &str,
         rpc_address: &str,
         chain_id: &str,
+        account_prefix: Option<&str>,
+        trust_options: Option<TrustOptions>,
+        connection_options: Option<ConnectionOptions>,
     ) -> IndyResult<PoolConfig> {
         let config = PoolConfig::new(
             alias.to_string(),
             rpc_address.to_string(),
             chain_id.to_string(),
+            account_prefix.map(String::from),
+            trust_options,
+            connection_options,
         );
 
         let mut path = environment::cheqd_pool_path(alias);
@@ -77,6 +150,24 @@ impl CheqdPoolService {
         Ok(config)
     }
 
+    pub(crate) async fn delete(&self, alias: &str) -> IndyResult<()> {
+        let path = environment::cheqd_pool_path(alias);
+
+        if !path.exists() {
+            let error_msg = format!("Can't find cheqd pool config file: {}", alias);
+            warn!("{}", error_msg);
+            return Err(IndyError::from_msg(IndyErrorKind::IOError, error_msg));
+        }
+
+        fs::remove_dir_all(path)
+            .to_indy(IndyErrorKind::IOError, "Can't delete cheqd pool config directory")?;
+
+        self.nodes_health.lock().await.remove(alias);
+        self.trusted_blocks.lock().await.remove(alias);
+
+        Ok(())
+    }
+
     pub(crate) async fn get_config(&self, alias: &str) -> IndyResult<PoolConfig> {
         let mut path = environment::cheqd_pool_path(alias);
 
@@ -160,7 +251,7 @@ impl CheqdPoolService {
 
         let tx_bytes = tx.to_bytes()?;
         let req = broadcast::tx_commit::Request::new(tx_bytes.into());
-        let resp = self.send_req(req, &pool.rpc_address).await?;
+        let resp = self.send_req(req, &pool).await?;
 
         if let abci::Code::Err(code) = resp.check_tx.code {
             return Err(IndyError::from(resp.check_tx));
@@ -173,13 +264,122 @@ impl CheqdPoolService {
         Ok(resp)
     }
 
+    // Send and wait for the check of transaction only
+    pub(crate) async fn broadcast_tx_sync(
+        &self,
+        pool_alias: &str,
+        tx: Raw,
+    ) -> IndyResult<rpc::endpoint::broadcast::tx_sync::Response> {
+        let pool = self.get_config(pool_alias).await?;
+
+        let tx_bytes = tx.to_bytes()?;
+        let req = broadcast::tx_sync::Request::new(tx_bytes.into());
+        let resp: rpc::endpoint::broadcast::tx_sync::Response = self.send_req(req, &pool).await?;
+
+        if let abci::Code::Err(code) = resp.code {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("check_tx: error code: {}, log: {}", code, resp.log),
+            ));
+        }
+
+        Ok(resp)
+    }
+
+    // Send without waiting for the check of transaction
+    pub(crate) async fn broadcast_tx_async(
+        &self,
+        pool_alias: &str,
+        tx: Raw,
+    ) -> IndyResult<rpc::endpoint::broadcast::tx_async::Response> {
+        let pool = self.get_config(pool_alias).await?;
+
+        let tx_bytes = tx.to_bytes()?;
+        let req = broadcast::tx_async::Request::new(tx_bytes.into());
+        let resp = self.send_req(req, &pool).await?;
+
+        Ok(resp)
+    }
+
+    pub(crate) async fn get_tx(
+        &self,
+        pool_alias: &str,
+        tx_hash: &str,
+    ) -> IndyResult<rpc::endpoint::tx::Response> {
+        let pool = self.get_config(pool_alias).await?;
+
+        let hash = abci::transaction::Hash::from_str(&tx_hash.to_uppercase()).map_err(|err| {
+            err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Invalid transaction hash {}: {}", tx_hash, err),
+            )
+        })?;
+
+        // `tx` endpoint reports missing transactions only with the text of an internal error,
+        // while search just returns an empty list for them
+        let query = rpc::query::Query::eq("tx.hash", hash.to_string());
+        let req = rpc::endpoint::tx_search::Request::new(query, false, 1, 1, rpc::Order::Ascending);
+        let resp = self.send_req(req, &pool).await?;
+
+        let tx = resp.txs.into_iter().next().ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::LedgerItemNotFound,
+                format!("Transaction {} is not found", tx_hash),
+            )
+        })?;
+
+        Ok(rpc::endpoint::tx::Response {
+            hash: tx.hash,
+            height: tx.height,
+            index: tx.index,
+            tx_result: tx.tx_result,
+            tx: tx.tx,
+            proof: tx.proof,
+        })
+    }
+
     pub(crate) async fn abci_query(
         &self,
         pool_alias: &str,
         req: rpc::endpoint::abci_query::Request,
     ) -> IndyResult<rpc::endpoint::abci_query::Response> {
         let pool = self.get_config(pool_alias).await?;
-        let resp = self.send_req(req, pool.rpc_address.as_str()).await?;
+        let prove = req.prove;
+        let key = req.data.clone();
+        let path = req.path.as_ref().map(|path| path.to_string());
+        let resp: rpc::endpoint::abci_query::Response =
+            self.send_req(req, &pool).await?;
+
+        if prove && pool.trust_options.is_some() {
+            // Error responses have no proof, so a node could use them to hide existing data
+            if let abci::Code::Err(code) = resp.response.code {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidLedgerProof,
+                    format!(
+                        "Node returned error response without proof: code: {}, log: {}",
+                        code, resp.response.log
+                    ),
+                ));
+            }
+
+            // Only raw store queries are proved: "/store/<store name>/key"
+            let store = path
+                .as_ref()
+                .and_then(|path| path.strip_prefix("/store/"))
+                .and_then(|path| path.strip_suffix("/key"))
+                .ok_or_else(|| {
+                    err_msg(
+                        IndyErrorKind::InvalidLedgerProof,
+                        format!("Proofs can't be checked for query path {:?}", path),
+                    )
+                })?;
+
+            // App hash of the state at height H is committed in the header H+1
+            let proof_height = resp.response.height.value() + 1;
+            let header = self.verified_header(&pool, proof_height).await?;
+            check_proofs_with_app_hash(resp.clone(), store.as_bytes(), &key, &header.app_hash.value())?;
+        }
+
         Ok(resp)
     }
 
@@ -189,49 +389,567 @@ impl CheqdPoolService {
     ) -> IndyResult<rpc::endpoint::abci_info::Response> {
         let pool = self.get_config(pool_alias).await?;
         let req = rpc::endpoint::abci_info::Request {};
-        let resp = self.send_req(req, pool.rpc_address.as_str()).await?;
+        let resp = self.send_req(req, &pool).await?;
         Ok(resp)
     }
 
-    async fn send_req<R>(&self, req: R, rpc_address: &str) -> IndyResult<R::Response>
+    /// Returns the header at `height` verified starting from the trusted header of the pool.
+    /// The lock of trusted blocks isn't held while headers are fetched from nodes.
+    async fn verified_header(&self, pool: &PoolConfig, height: u64) -> IndyResult<Header> {
+        let trusted = self.trusted_blocks.lock().await.get(&pool.alias).cloned();
+
+        let trusted = match trusted {
+            Some(trusted) => trusted,
+            None => {
+                let trusted = self.trusted_block(pool).await?;
+                self.update_trusted_block(pool, &trusted).await;
+                trusted
+            }
+        };
+
+        if height <= trusted.height().value() {
+            return self
+                .verify_backward(pool, &trusted.signed_header.header, height)
+                .await;
+        }
+
+        let verified = self.verify_forward(pool, trusted, height).await?;
+        self.update_trusted_block(pool, &verified).await;
+
+        Ok(verified.signed_header.header)
+    }
+
+    /// Remembers the verified block unless a concurrent request has verified a higher one.
+    async fn update_trusted_block(&self, pool: &PoolConfig, verified: &LightBlock) {
+        let mut trusted_blocks = self.trusted_blocks.lock().await;
+
+        let is_higher = trusted_blocks
+            .get(&pool.alias)
+            .map(|trusted| trusted.height() < verified.height())
+            .unwrap_or(true);
+
+        if is_higher {
+            trusted_blocks.insert(pool.alias.clone(), verified.clone());
+        }
+    }
+
+    /// Fetches the light block pointed by trust options of the pool and checks its hash.
+    async fn trusted_block(&self, pool: &PoolConfig) -> IndyResult<LightBlock> {
+        let trust_options = pool.trust_options.as_ref().ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Trust options are not set for cheqd pool {:?}", pool.alias),
+            )
+        })?;
+
+        let trusted_hash = Hash::from_hex_upper(Algorithm::Sha256, &trust_options.hash.to_uppercase())?;
+        let light_block = self.light_block(pool, trust_options.height).await?;
+
+        if light_block.signed_header.header.hash() != trusted_hash {
+            return Err(err_msg(
+                IndyErrorKind::InvalidLedgerProof,
+                format!(
+                    "Header at the trusted height {} doesn't match the trusted hash {}",
+                    trust_options.height, trust_options.hash
+                ),
+            ));
+        }
+
+        // Validator sets aren't covered by the trusted hash directly, only through the header
+        let header = &light_block.signed_header.header;
+
+        if light_block.validators.hash() != header.validators_hash
+            || light_block.next_validators.hash() != header.next_validators_hash
+        {
+            return Err(err_msg(
+                IndyErrorKind::InvalidLedgerProof,
+                format!(
+                    "Validator sets at the trusted height {} don't match the trusted header",
+                    trust_options.height
+                ),
+            ));
+        }
+
+        Ok(light_block)
+    }
+
+    /// Verifies the light block at `height` using skipping verification.
+    /// If the trusted validators don't sign enough voting power of the target block
+    /// the range is bisected and the middle block is verified first.
+    async fn verify_forward(
+        &self,
+        pool: &PoolConfig,
+        trusted: LightBlock,
+        height: u64,
+    ) -> IndyResult<LightBlock> {
+        let options = Self::light_client_options(pool)?;
+        let verifier = ProdVerifier::default();
+
+        let mut trusted = trusted;
+        let mut pending = vec![self.light_block(pool, height).await?];
+
+        while let Some(untrusted) = pending.last() {
+            match verifier.verify(untrusted, &trusted, &options, Time::now()) {
+                Verdict::Success => {
+                    trusted = pending.pop().unwrap();
+                }
+                Verdict::NotEnoughTrust(tally) => {
+                    let trusted_height = trusted.height().value();
+                    let pivot_height = (trusted_height + untrusted.height().value()) / 2;
+
+                    if pivot_height == trusted_height {
+                        return Err(err_msg(
+                            IndyErrorKind::InvalidLedgerProof,
+                            format!("Not enough trust to verify adjacent header: {:?}", tally),
+                        ));
+                    }
+
+                    let pivot = self.light_block(pool, pivot_height).await?;
+                    pending.push(pivot);
+                }
+                Verdict::Invalid(err) => {
+                    return Err(err_msg(
+                        IndyErrorKind::InvalidLedgerProof,
+                        format!(
+                            "Header at height {} is invalid: {:?}",
+                            untrusted.height(), err
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(trusted)
+    }
+
+    /// Verifies the header at `height` below the trusted one by following hashes of previous blocks.
+    async fn verify_backward(
+        &self,
+        pool: &PoolConfig,
+        trusted: &Header,
+        height: u64,
+    ) -> IndyResult<Header> {
+        let mut trusted = trusted.clone();
+
+        while trusted.height.value() > height {
+            let prev_height = trusted.height.value() - 1;
+            let req = rpc::endpoint::commit::Request::new(block::Height::try_from(prev_height)?);
+            let prev = self.send_req(req, pool).await?.signed_header.header;
+
+            let last_block_hash = trusted.last_block_id.as_ref().map(|id| id.hash);
+            if last_block_hash != Some(prev.hash()) {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidLedgerProof,
+                    format!("Header at height {} isn't linked with the trusted one", prev_height),
+                ));
+            }
+
+            trusted = prev;
+        }
+
+        Ok(trusted)
+    }
+
+    fn light_client_options(pool: &PoolConfig) -> IndyResult<Options> {
+        let trust_options = pool.trust_options.as_ref().ok_or_else(|| {
+            err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Trust options are not set for cheqd pool {:?}", pool.alias),
+            )
+        })?;
+
+        Ok(Options {
+            trust_threshold: TrustThreshold::new(
+                trust_options.trust_level.numerator,
+                trust_options.trust_level.denominator,
+            )?,
+            trusting_period: Duration::from_secs(trust_options.trusting_period),
+            clock_drift: Duration::from_secs(trust_options.clock_drift),
+        })
+    }
+
+    /// Fetches the signed header and validator sets at `height`.
+    /// The header following the latest one is awaited for a few seconds.
+    async fn light_block(&self, pool: &PoolConfig, height: u64) -> IndyResult<LightBlock> {
+        let block_height = block::Height::try_from(height)?;
+
+        let mut attempt = 1;
+        let signed_header = loop {
+            let req = rpc::endpoint::commit::Request::new(block_height);
+            match self.send_req(req, pool).await {
+                Ok(resp) => break resp.signed_header,
+                Err(err) if attempt < NEXT_BLOCK_WAIT_ATTEMPTS => {
+                    trace!("light_block > header at height {} is not available: {:?}", height, err);
+                    attempt += 1;
+                    async_std::task::sleep(NEXT_BLOCK_WAIT_PERIOD).await;
+                }
+                Err(err) => return Err(err),
+            }
+        };
+
+        let validators = self.validators(pool, height).await?;
+        let next_validators = self.validators(pool, height + 1).await?;
+
+        Ok(LightBlock::new(
+            signed_header,
+            validators,
+            next_validators,
+            node::Id::new([0; 20]),
+        ))
+    }
+
+    async fn validators(&self, pool: &PoolConfig, height: u64) -> IndyResult<validator::Set> {
+        let block_height = block::Height::try_from(height)?;
+
+        let mut validators = Vec::new();
+        let mut page = 1;
+        loop {
+            let req = rpc::endpoint::validators::Request::new(
+                Some(block_height),
+                Some(rpc::PageNumber::from(page)),
+                Some(rpc::PerPage::from(VALIDATORS_PER_PAGE)),
+            );
+            let resp = self.send_req(req, pool).await?;
+            let received = resp.validators.len();
+            validators.extend(resp.validators);
+
+            if received == 0 || validators.len() >= resp.total as usize {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(validator::Set::without_proposer(validators))
+    }
+
+    /// Sends the request to nodes of the pool starting with the healthiest one.
+    /// A node that isn't reachable or doesn't respond in time is replaced with the next one.
+    /// If all nodes fail, they are retried with exponential backoff.
+    ///
+    /// Broadcasts aren't idempotent, so they are passed to another node only if the connection
+    /// to the previous one has failed and the transaction surely hasn't been submitted.
+    async fn send_req<R>(&self, req: R, pool: &PoolConfig) -> IndyResult<R::Response>
         where
             R: Request,
     {
+        let idempotent = Self::is_idempotent(req.method());
         let req_json = req.into_json();
-        let mut req = HttpRequest::new( Method::Post,
-                                    rpc_address);
-        req.append_header("Content-Type", "application/json");
-        req.append_header("User-Agent", format!("indy-sdk/{}", env!("CARGO_PKG_VERSION")));
-        req.set_body(Body::from_string(req_json));
+        let options = &pool.connection_options;
+        let timeout = Duration::from_secs(options.timeout);
+        let mut backoff = Duration::from_millis(options.retry_backoff);
+        let mut last_err = None;
+
+        for attempt in 0..=options.max_retries {
+            if attempt > 0 {
+                trace!("send_req > retry {} in {:?}", attempt, backoff);
+                async_std::task::sleep(backoff).await;
+                backoff *= 2;
+            }
+
+            let rpc_addresses = {
+                let nodes_health = self.nodes_health.lock().await;
+                match nodes_health.get(&pool.alias) {
+                    Some(health) => health.order(pool.rpc_addresses()),
+                    None => pool.rpc_addresses(),
+                }
+            };
 
-        let client = H1Client::new();
-        // ToDo: it can be changed only in "unstable-config".
-        // let config = client.config();
-        // config.timeout(std::time::Duration::from_secs(CLIENT_TIMEOUT));
-        // client.set_config(config);
+            for rpc_address in rpc_addresses {
+                let res = if idempotent {
+                    self.send_to_node(&req_json, rpc_address, timeout).await
+                } else {
+                    match self.connect_to_node(rpc_address, timeout).await {
+                        Ok(()) => self.send_to_node(&req_json, rpc_address, timeout).await,
+                        Err(err) => Err(err),
+                    }
+                };
+
+                let mut nodes_health = self.nodes_health.lock().await;
+                let health = nodes_health.entry(pool.alias.clone()).or_default();
+
+                match res {
+                    Ok(resp_str) => {
+                        health.succeeded(rpc_address);
+
+                        let resp = R::Response::from_string(resp_str).to_indy(
+                            IndyErrorKind::InvalidStructure,
+                            "Error was raised while converting tendermint_rpc::request::Request into string"
+                        )?;
+
+                        return Ok(resp);
+                    }
+                    Err(NodeError::NotSent(err)) => {
+                        warn!("Cheqd pool {:?} node {} isn't reachable: {}", pool.alias, rpc_address, err);
+                        health.failed(rpc_address);
+                        last_err = Some(err);
+                    }
+                    Err(NodeError::Sent(err)) => {
+                        warn!("Cheqd pool {:?} node {} has failed: {}", pool.alias, rpc_address, err);
+                        health.failed(rpc_address);
+
+                        // The node could have accepted the transaction, so it mustn't be sent again
+                        if !idempotent {
+                            return Err(err);
+                        }
+
+                        last_err = Some(err);
+                    }
+                }
+            }
+        }
 
-        let mut resp: HttpResponse = client.send(req).await?;
-        let resp_str = resp.body_string().await?;
-        let resp = R::Response::from_string(resp_str).to_indy(
-            IndyErrorKind::InvalidStructure,
-            "Error was raised while converting tendermint_rpc::request::Request into string"
-        )?;
+        Err(last_err.unwrap_or_else(|| {
+            err_msg(IndyErrorKind::InvalidState, "Cheqd pool doesn't have RPC addresses")
+        }))
+    }
 
-        Ok(resp)
+    fn is_idempotent(method: rpc::Method) -> bool {
+        match method {
+            rpc::Method::BroadcastTxAsync
+            | rpc::Method::BroadcastTxSync
+            | rpc::Method::BroadcastTxCommit => false,
+            _ => true,
+        }
+    }
+
+    /// Checks that the node accepts connections before a request that mustn't be repeated is sent.
+    async fn connect_to_node(&self, rpc_address: &str, timeout: Duration) -> Result<(), NodeError> {
+        let url = Self::parse_rpc_address(rpc_address).map_err(NodeError::NotSent)?;
+
+        let host = url.host_str().unwrap_or_default().to_string();
+        let port = url.port_or_known_default().unwrap_or_default();
+
+        async_std::future::timeout(timeout, TcpStream::connect((host.as_str(), port)))
+            .await
+            .map_err(|_| {
+                NodeError::NotSent(err_msg(
+                    IndyErrorKind::PoolTimeout,
+                    format!("Node {} hasn't accepted connection in {:?}", rpc_address, timeout),
+                ))
+            })?
+            .to_indy(IndyErrorKind::IOError, format!("Can't connect to node {}", rpc_address))
+            .map_err(NodeError::NotSent)?;
+
+        Ok(())
+    }
+
+    async fn send_to_node(&self, req_json: &str, rpc_address: &str, timeout: Duration) -> Result<String, NodeError> {
+        let url = Self::parse_rpc_address(rpc_address).map_err(NodeError::NotSent)?;
+
+        let mut req = HttpRequest::new(Method::Post, url);
+        req.append_header("Content-Type", "application/json");
+        req.append_header("User-Agent", format!("indy-sdk/{}", env!("CARGO_PKG_VERSION")));
+        req.set_body(Body::from_string(req_json.to_string()));
+
+        let send = async {
+            let mut resp: HttpResponse = self.http_client.send(req).await?;
+            let resp_str = resp.body_string().await?;
+            Ok::<String, IndyError>(resp_str)
+        };
+
+        async_std::future::timeout(timeout, send)
+            .await
+            .map_err(|_| err_msg(
+                IndyErrorKind::PoolTimeout,
+                format!("Node {} hasn't responded in {:?}", rpc_address, timeout),
+            ))
+            .and_then(|res| res)
+            .map_err(NodeError::Sent)
+    }
+
+    fn parse_rpc_address(rpc_address: &str) -> IndyResult<Url> {
+        Url::parse(rpc_address)
+            .to_indy(IndyErrorKind::IOError, format!("Invalid RPC address: {}", rpc_address))
     }
 }
 
 #[cfg(test)]
 mod send_req {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_std::io::{ReadExt, WriteExt};
+    use async_std::net::{TcpListener, TcpStream};
+    use futures::StreamExt;
+
     use crate::CheqdPoolService;
-    use cosmrs::rpc::endpoint::abci_info::Request;
+    use crate::domain::cheqd_pool::{ConnectionOptions, PoolConfig};
+    use cosmrs::rpc::endpoint::{abci_info, broadcast, health};
+    use indy_api_types::errors::IndyErrorKind;
+
+    const HEALTH_RESPONSE: &str = r#"{"jsonrpc":"2.0","id":1,"result":{}}"#;
+
+    async fn _read_request(stream: &mut TcpStream) {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+
+        loop {
+            let read = stream.read(&mut buf).await.unwrap();
+            if read == 0 {
+                return;
+            }
+            request.extend_from_slice(&buf[..read]);
+
+            let request = String::from_utf8_lossy(&request);
+            if let Some(headers_end) = request.find("\r\n\r\n") {
+                let content_length = request[..headers_end]
+                    .lines()
+                    .find_map(|line| {
+                        let line = line.to_lowercase();
+                        line.strip_prefix("content-length:").map(|len| len.trim().parse::<usize>().unwrap())
+                    })
+                    .unwrap_or(0);
+
+                if request.len() >= headers_end + 4 + content_length {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Starts a stub node answering health requests. A hung node accepts connections but never responds.
+    async fn _stub_node(hung: bool) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let rpc_address = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let requests_ = requests.clone();
+        async_std::task::spawn(async move {
+            let mut hung_streams = Vec::new();
+            let mut incoming = listener.incoming();
+
+            while let Some(stream) = incoming.next().await {
+                let mut stream = stream.unwrap();
+                requests_.fetch_add(1, Ordering::SeqCst);
+
+                if hung {
+                    hung_streams.push(stream);
+                    continue;
+                }
+
+                _read_request(&mut stream).await;
+                let resp = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    HEALTH_RESPONSE.len(),
+                    HEALTH_RESPONSE
+                );
+                stream.write_all(resp.as_bytes()).await.unwrap();
+            }
+        });
+
+        (rpc_address, requests)
+    }
+
+    async fn _refused_rpc_address() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    fn _pool_config(rpc_address: &str, backup_rpc_addresses: Vec<String>) -> PoolConfig {
+        PoolConfig::new(
+            "pool".to_string(),
+            rpc_address.to_string(),
+            "cheqd".to_string(),
+            None,
+            None,
+            Some(ConnectionOptions {
+                backup_rpc_addresses,
+                timeout: 1,
+                max_retries: 2,
+                retry_backoff: 10,
+            }),
+        )
+    }
 
     #[async_std::test]
     async fn client_close_if_connection_refused() {
         let pool_service = CheqdPoolService::new();
-        let req = Request {};
-        pool_service.send_req(req, "http://127.0.0.2:12345").await.map_err(|err| {
+        let req = abci_info::Request {};
+        let pool = _pool_config("http://127.0.0.2:12345", Vec::new());
+        pool_service.send_req(req, &pool).await.map_err(|err| {
             assert!(err.to_string().contains("Connection refused"))
         });
     }
+
+    #[async_std::test]
+    async fn send_req_works_for_failover_to_backup_node() {
+        let (hung_address, hung_requests) = _stub_node(true).await;
+        let (backup_address, backup_requests) = _stub_node(false).await;
+
+        let pool_service = CheqdPoolService::new();
+        let pool = _pool_config(&hung_address, vec![backup_address]);
+
+        pool_service.send_req(health::Request {}, &pool).await.unwrap();
+        pool_service.send_req(health::Request {}, &pool).await.unwrap();
+
+        // The backup node became sticky after the hung node had timed out
+        assert_eq!(1, hung_requests.load(Ordering::SeqCst));
+        assert_eq!(2, backup_requests.load(Ordering::SeqCst));
+    }
+
+    #[async_std::test]
+    async fn send_req_works_for_refused_primary_node() {
+        let refused_address = _refused_rpc_address().await;
+        let (backup_address, backup_requests) = _stub_node(false).await;
+
+        let pool_service = CheqdPoolService::new();
+        let pool = _pool_config(&refused_address, vec![backup_address]);
+
+        pool_service.send_req(health::Request {}, &pool).await.unwrap();
+        assert_eq!(1, backup_requests.load(Ordering::SeqCst));
+    }
+
+    #[async_std::test]
+    async fn send_req_works_for_hung_nodes() {
+        let (hung_address, hung_requests) = _stub_node(true).await;
+
+        let pool_service = CheqdPoolService::new();
+        let pool = _pool_config(&hung_address, Vec::new());
+
+        let err = pool_service.send_req(health::Request {}, &pool).await.unwrap_err();
+
+        assert_eq!(IndyErrorKind::PoolTimeout, err.kind());
+        assert!(hung_requests.load(Ordering::SeqCst) > 0);
+    }
+
+    #[async_std::test]
+    async fn send_req_works_for_broadcast_to_hung_node() {
+        let (hung_address, hung_requests) = _stub_node(true).await;
+        let (backup_address, backup_requests) = _stub_node(false).await;
+
+        let pool_service = CheqdPoolService::new();
+        let pool = _pool_config(&hung_address, vec![backup_address]);
+
+        let req = broadcast::tx_sync::Request::new(vec![1, 2, 3].into());
+        let err = pool_service.send_req(req, &pool).await.unwrap_err();
+
+        // The transaction could have been accepted by the hung node, so it isn't sent again
+        assert_eq!(IndyErrorKind::PoolTimeout, err.kind());
+        assert!(hung_requests.load(Ordering::SeqCst) > 0);
+        assert_eq!(0, backup_requests.load(Ordering::SeqCst));
+    }
+
+    #[async_std::test]
+    async fn send_req_works_for_broadcast_to_refused_primary_node() {
+        let refused_address = _refused_rpc_address().await;
+        let (backup_address, backup_requests) = _stub_node(false).await;
+
+        let pool_service = CheqdPoolService::new();
+        let pool = _pool_config(&refused_address, vec![backup_address]);
+
+        let req = broadcast::tx_sync::Request::new(vec![1, 2, 3].into());
+        let _ = pool_service.send_req(req, &pool).await;
+
+        // The transaction hasn't reached the refused node, so it is passed to the backup one
+        assert!(backup_requests.load(Ordering::SeqCst) > 0);
+    }
+
+    #[async_std::test]
+    async fn send_req_works_for_unreachable_nodes() {
+        let pool_service = CheqdPoolService::new();
+        let pool = _pool_config(&_refused_rpc_address().await, vec![_refused_rpc_address().await]);
+
+        let err = pool_service.send_req(health::Request {}, &pool).await.unwrap_err();
+        assert_eq!(IndyErrorKind::IOError, err.kind());
+    }
 }
@@ -129,6 +129,35 @@ impl MerkleTree {
         Ok(true)
     }
 
+    /// Builds the proof that the tree ending with `leaf` is a prefix of the tree the audit path of `leaf` leads to.
+    ///
+    /// While the leaf is a right child its audit path contains left siblings only, so they are folded
+    /// into the hash of the biggest perfect subtree ending with the leaf. The rest of the audit path
+    /// is exactly what `MerkleTree::consistency_proof` consumes on the way to the new root.
+    pub fn consistency_proof_from_audit_path(leaf: &[u8], seq_no: usize, audit_path: &[Vec<u8>]) -> IndyResult<Vec<Vec<u8>>> {
+        let mut node = seq_no - 1;
+        let mut hash = Hash::hash_leaf(&leaf.to_vec())?;
+        let mut path = audit_path.iter();
+
+        while node % 2 != 0 {
+            let sibling = path.next().ok_or_else(|| {
+                err_msg(IndyErrorKind::InvalidState, "Audit path is too short")
+            })?;
+            hash = Hash::hash_nodes(sibling, &hash)?;
+            node /= 2;
+        }
+
+        let mut proof = Vec::new();
+
+        if node != 0 {
+            proof.push(hash);
+        }
+
+        proof.extend(path.cloned());
+
+        Ok(proof)
+    }
+
     pub fn append(&mut self, node: TreeLeafData) -> IndyResult<()> {
         if self.count == 0 {
             // empty tree
@@ -1,5 +1,7 @@
 pub mod merkletree;
 
+use std::collections::BTreeMap;
+
 use hex::FromHex;
 use indy_api_types::errors::prelude::*;
 use indy_utils::crypto::hash::hash as openssl_hash;
@@ -22,15 +24,22 @@ use crate::domain::{
     },
     crypto::did::DidValue,
     ledger::{
-        attrib::{AttribOperation, GetAttribOperation},
+        attrib::{AttribOperation, GetAttrReplyResult, GetAttribOperation},
         auth_rule::*,
         author_agreement::*,
+        bundle::{
+            TransactionBundle, TransactionBundleOptions, TransactionBundleSignature,
+            TransactionBundleStatus, TRANSACTION_BUNDLE_VERSION,
+        },
         constants::{
             txn_name_to_code, ENDORSER, GET_VALIDATOR_INFO, NETWORK_MONITOR, POOL_RESTART, ROLES,
             ROLE_REMOVE, STEWARD, TRUSTEE,
         },
         cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult},
         ddo::GetDdoOperation,
+        ledgers_freeze::{
+            GetFrozenLedgersOperation, GetFrozenLedgersReplyResult, LedgersFreezeOperation,
+        },
         node::{NodeOperation, NodeOperationData},
         nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymData, NymOperation},
         pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, Schedule},
@@ -212,6 +221,24 @@ impl LedgerService {
         )
     }
 
+    #[logfn(Info)]
+    pub(crate) fn parse_get_attrib_response(
+        &self,
+        get_attrib_response: &str,
+    ) -> IndyResult<String> {
+        let reply: Reply<GetAttrReplyResult> =
+            LedgerService::parse_response(get_attrib_response)?;
+
+        let res = match reply.result() {
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => res.data.ok_or_else(|| {
+                err_msg(IndyErrorKind::LedgerItemNotFound, "Attribute not found")
+            })?,
+            GetAttrReplyResult::GetAttrReplyResultV1(res) => res.txn.data.raw,
+        };
+
+        Ok(res)
+    }
+
     #[logfn(Info)]
     pub(crate) fn build_schema_request(
         &self,
@@ -806,6 +833,33 @@ impl LedgerService {
         )
     }
 
+    #[logfn(Info)]
+    pub(crate) fn build_ledgers_freeze_request(
+        &self,
+        identifier: &DidValue,
+        ledgers_ids: Vec<u64>,
+    ) -> IndyResult<String> {
+        build_result!(LedgersFreezeOperation, Some(identifier), ledgers_ids)
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn build_get_frozen_ledgers_request(
+        &self,
+        identifier: &DidValue,
+    ) -> IndyResult<String> {
+        build_result!(GetFrozenLedgersOperation, Some(identifier))
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn parse_get_frozen_ledgers_response(&self, response: &str) -> IndyResult<String> {
+        let reply: Reply<GetFrozenLedgersReplyResult> = LedgerService::parse_response(response)?;
+
+        let frozen_ledgers = reply.result().data.unwrap_or_default();
+
+        serde_json::to_string(&frozen_ledgers)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize frozen ledgers")
+    }
+
     #[logfn(Info)]
     pub(crate) fn parse_response<T>(response: &str) -> IndyResult<Reply<T>>
     where
@@ -922,7 +976,6 @@ impl LedgerService {
         Ok(())
     }
 
-    #[allow(dead_code)] // FIXME [async] TODO check why unused
     pub(crate) fn parse_get_auth_rule_response(&self, response: &str) -> IndyResult<Vec<AuthRule>> {
         trace!("parse_get_auth_rule_response >>> response: {:?}", response);
 
@@ -940,6 +993,232 @@ impl LedgerService {
 
         Ok(res)
     }
+
+    /// Builds transaction bundle to collect signatures of the request author, endorser and `options.signers`.
+    ///
+    /// Signatures already present in the request are removed from it and returned by signers, so the caller
+    /// can verify them before adding to the bundle. The number of required signatures is derived
+    /// from the strictest of `auth_rules` applicable to the request.
+    #[logfn(Info)]
+    pub(crate) fn build_transaction_bundle(
+        &self,
+        request_json: &str,
+        auth_rules: &[AuthRule],
+        options: TransactionBundleOptions,
+    ) -> IndyResult<(TransactionBundle, BTreeMap<String, String>)> {
+        let mut request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let identifier = request["identifier"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "No identifier in request"))?;
+
+        let txn_type = request["operation"]["type"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "No valid type field in request"))?;
+
+        let mut signatures = BTreeMap::new();
+
+        if let Some(request) = request.as_object_mut() {
+            if let Some(Value::String(signature)) = request.remove("signature") {
+                signatures.insert(identifier.clone(), signature);
+            }
+
+            if let Some(Value::Object(multi_signatures)) = request.remove("signatures") {
+                for (did, signature) in multi_signatures {
+                    if let Value::String(signature) = signature {
+                        signatures.insert(did, signature);
+                    }
+                }
+            }
+        }
+
+        let mut required_signers = vec![identifier];
+
+        let endorser = request["endorser"].as_str().map(String::from);
+        let signers = options.signers.iter().map(|signer| signer.to_short().0);
+
+        for signer in endorser.into_iter().chain(signers) {
+            if !required_signers.contains(&signer) {
+                required_signers.push(signer);
+            }
+        }
+
+        let auth_action = options.auth_action.as_deref().unwrap_or("ADD");
+
+        let (constraint, signature_count) = LedgerService::_select_auth_constraint(
+            &request["operation"],
+            &txn_type,
+            auth_action,
+            auth_rules,
+        )?;
+
+        let bundle = TransactionBundle {
+            ver: TRANSACTION_BUNDLE_VERSION.to_string(),
+            request,
+            required_signers,
+            required_signature_count: signature_count,
+            constraint,
+            signatures: BTreeMap::new(),
+            expires_at: options.expires_at,
+        };
+
+        Ok((bundle, signatures))
+    }
+
+    /// Adds the signature of `signer` to the bundle. The signature must be verified
+    /// against the bundle request and `signature.verkey` by the caller.
+    #[logfn(Info)]
+    pub(crate) fn add_transaction_bundle_signature(
+        &self,
+        bundle: &mut TransactionBundle,
+        signer: &str,
+        signature: TransactionBundleSignature,
+        now: u64,
+    ) -> IndyResult<()> {
+        if bundle.is_expired(now) {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Transaction bundle has expired",
+            ));
+        }
+
+        bundle.signatures.insert(signer.to_string(), signature);
+        Ok(())
+    }
+
+    #[logfn(Info)]
+    pub(crate) fn get_transaction_bundle_status(
+        &self,
+        bundle: &TransactionBundle,
+        now: u64,
+    ) -> TransactionBundleStatus {
+        let missing_signers: Vec<String> = bundle
+            .required_signers
+            .iter()
+            .filter(|signer| !bundle.signatures.contains_key(*signer))
+            .cloned()
+            .collect();
+
+        let missing_signature_count = bundle
+            .required_signature_count
+            .saturating_sub(bundle.signatures.len());
+
+        let expired = bundle.is_expired(now);
+
+        TransactionBundleStatus {
+            ready: !expired && missing_signers.is_empty() && missing_signature_count == 0,
+            missing_signers,
+            missing_signature_count,
+            expired,
+        }
+    }
+
+    /// Returns the bundle request with all collected signatures if it's ready for submission.
+    #[logfn(Info)]
+    pub(crate) fn finalize_transaction_bundle(
+        &self,
+        bundle: TransactionBundle,
+        now: u64,
+    ) -> IndyResult<String> {
+        let status = self.get_transaction_bundle_status(&bundle, now);
+
+        if status.expired {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Transaction bundle has expired",
+            ));
+        }
+
+        if !status.ready {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                format!(
+                    "Transaction bundle isn't signed yet. Missing signers: {:?}, missing signatures: {}",
+                    status.missing_signers, status.missing_signature_count
+                ),
+            ));
+        }
+
+        let signatures: BTreeMap<String, String> = bundle
+            .signatures
+            .into_iter()
+            .map(|(signer, signature)| (signer, signature.signature))
+            .collect();
+
+        let mut request = bundle.request;
+        request["signatures"] = json!(signatures);
+
+        serde_json::to_string(&request).to_indy(
+            IndyErrorKind::InvalidState,
+            "Can't serialize transaction bundle request",
+        )
+    }
+
+    /// Selects the strictest of auth rules applicable to the operation.
+    ///
+    /// Returns the constraint with the number of signatures it requires,
+    /// or no constraint if there is no rule for the transaction type.
+    fn _select_auth_constraint(
+        operation: &Value,
+        txn_type: &str,
+        auth_action: &str,
+        auth_rules: &[AuthRule],
+    ) -> IndyResult<(Option<Constraint>, usize)> {
+        let field_value = |field: &str| match operation[field] {
+            Value::Null => String::new(),
+            Value::String(ref value) => value.to_string(),
+            ref value => value.to_string(),
+        };
+
+        let rules: Vec<&AuthRule> = auth_rules
+            .iter()
+            .filter(|rule| rule.auth_type == txn_type && rule.auth_action == auth_action)
+            .filter(|rule| match rule.new_value.as_deref() {
+                _ if rule.field == "*" => true,
+                None | Some("*") => true,
+                Some(new_value) => field_value(&rule.field) == new_value,
+            })
+            .collect();
+
+        if rules.is_empty() {
+            return Ok((None, 0));
+        }
+
+        rules
+            .into_iter()
+            .filter_map(|rule| {
+                LedgerService::_required_signature_count(&rule.constraint)
+                    .map(|count| (Some(rule.constraint.clone()), count))
+            })
+            .max_by_key(|(_, count)| *count)
+            .ok_or_else(|| {
+                err_msg(
+                    IndyErrorKind::InvalidStructure,
+                    format!("Action {} is forbidden for transaction type {}", auth_action, txn_type),
+                )
+            })
+    }
+
+    /// Returns the number of signatures required by the constraint or `None` if it's forbidden.
+    fn _required_signature_count(constraint: &Constraint) -> Option<usize> {
+        match constraint {
+            Constraint::RoleConstraint(constraint) => Some(constraint.sig_count as usize),
+            Constraint::AndConstraint(constraint) => constraint
+                .auth_constraints
+                .iter()
+                .map(LedgerService::_required_signature_count)
+                .sum(),
+            Constraint::OrConstraint(constraint) => constraint
+                .auth_constraints
+                .iter()
+                .filter_map(LedgerService::_required_signature_count)
+                .min(),
+            Constraint::ForbiddenConstraint(_) => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1801,6 +2080,407 @@ mod tests {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        fn build_ledgers_freeze_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": LEDGERS_FREEZE,
+                "ledgers_ids": [0, 1, 909]
+            });
+
+            let request = ledger_service
+                .build_ledgers_freeze_request(&identifier(), vec![0, 1, 909])
+                .unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_frozen_ledgers_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_FROZEN_LEDGERS,
+            });
+
+            let request = ledger_service
+                .build_get_frozen_ledgers_request(&identifier())
+                .unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_FROZEN_LEDGERS,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "data": {
+                        "909": {
+                            "ledger": "GKot5hBsd81kMupNCXHaqbhv3huEbxAFMLnpcX2hniwn",
+                            "state": "DqQ9EYsk7MXx5xCtXMBZqUMeaLfGDS5JyrhtBjchxKr2",
+                            "seq_no": 10
+                        }
+                    }
+                }
+            }).to_string();
+
+            let frozen_ledgers = ledger_service
+                .parse_get_frozen_ledgers_response(&response)
+                .unwrap();
+
+            let expected = json!({
+                "909": {
+                    "ledger": "GKot5hBsd81kMupNCXHaqbhv3huEbxAFMLnpcX2hniwn",
+                    "state": "DqQ9EYsk7MXx5xCtXMBZqUMeaLfGDS5JyrhtBjchxKr2",
+                    "seq_no": 10
+                }
+            });
+            assert_eq!(expected, serde_json::from_str::<serde_json::Value>(&frozen_ledgers).unwrap());
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works_for_no_frozen_ledgers() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_FROZEN_LEDGERS,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "data": null
+                }
+            }).to_string();
+
+            let frozen_ledgers = ledger_service
+                .parse_get_frozen_ledgers_response(&response)
+                .unwrap();
+            assert_eq!("{}", frozen_ledgers);
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works_for_other_type() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_NYM,
+                    "data": null
+                }
+            }).to_string();
+
+            let res = ledger_service.parse_get_frozen_ledgers_response(&response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+    }
+
+    mod get_attrib {
+        use super::*;
+
+        #[test]
+        fn parse_get_attrib_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_ATTR,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "dest": DEST,
+                    "raw": "endpoint",
+                    "data": r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#
+                }
+            }).to_string();
+
+            let attrib = ledger_service.parse_get_attrib_response(&response).unwrap();
+            assert_eq!(r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#, attrib);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_ATTR,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "dest": DEST,
+                    "raw": "endpoint",
+                    "data": null
+                }
+            }).to_string();
+
+            let res = ledger_service.parse_get_attrib_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+    }
+
+    mod transaction_bundle {
+        use super::*;
+
+        use indy_api_types::validation::Validatable;
+
+        const ENDORSER: &str = "V4SGRU86Z58d6TV7PBUe6f";
+        const SIGNATURE: &str = "signature";
+        const VERKEY: &str = "verkey";
+
+        fn _role_constraint(sig_count: u32) -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count,
+                metadata: None,
+                role: Some("0".to_string()),
+                need_to_be_owner: false,
+                off_ledger_signature: false,
+            })
+        }
+
+        fn _auth_rule(auth_type: &str, field: &str, new_value: &str, constraint: Constraint) -> AuthRule {
+            AuthRule {
+                auth_type: auth_type.to_string(),
+                auth_action: "ADD".to_string(),
+                field: field.to_string(),
+                old_value: None,
+                new_value: Some(new_value.to_string()),
+                constraint,
+            }
+        }
+
+        fn _request() -> Value {
+            json!({
+                "reqId": 1,
+                "identifier": IDENTIFIER,
+                "endorser": ENDORSER,
+                "protocolVersion": 2,
+                "operation": {
+                    "type": SCHEMA,
+                    "data": {"name": "gvt", "version": "1.0", "attr_names": ["name"]}
+                }
+            })
+        }
+
+        fn _bundle(auth_rules: &[AuthRule]) -> TransactionBundle {
+            let (bundle, signatures) = LedgerService::new()
+                .build_transaction_bundle(
+                    &_request().to_string(),
+                    auth_rules,
+                    TransactionBundleOptions::default(),
+                )
+                .unwrap();
+
+            assert!(signatures.is_empty());
+            bundle
+        }
+
+        fn _signature() -> TransactionBundleSignature {
+            TransactionBundleSignature {
+                verkey: VERKEY.to_string(),
+                signature: SIGNATURE.to_string(),
+            }
+        }
+
+        #[test]
+        fn build_transaction_bundle_works() {
+            let auth_rules = vec![_auth_rule(SCHEMA, "*", "*", _role_constraint(1))];
+
+            let bundle = _bundle(&auth_rules);
+
+            assert_eq!(_request(), bundle.request);
+            assert_eq!(vec![IDENTIFIER.to_string(), ENDORSER.to_string()], bundle.required_signers);
+            assert_eq!(1, bundle.required_signature_count);
+            assert_eq!(Some(_role_constraint(1)), bundle.constraint);
+            assert!(bundle.signatures.is_empty());
+            assert!(bundle.validate().is_ok());
+        }
+
+        #[test]
+        fn build_transaction_bundle_works_for_signed_request() {
+            let mut request = _request();
+            request["signature"] = json!(SIGNATURE);
+
+            let (bundle, signatures) = LedgerService::new()
+                .build_transaction_bundle(&request.to_string(), &[], TransactionBundleOptions::default())
+                .unwrap();
+
+            assert_eq!(_request(), bundle.request);
+            assert!(bundle.signatures.is_empty());
+            assert_eq!(Some(&SIGNATURE.to_string()), signatures.get(IDENTIFIER));
+            assert!(bundle.validate().is_ok());
+        }
+
+        #[test]
+        fn build_transaction_bundle_works_for_additional_signers() {
+            let options = TransactionBundleOptions {
+                signers: vec![dest(), DidValue(ENDORSER.to_string())],
+                ..TransactionBundleOptions::default()
+            };
+
+            let (bundle, _) = LedgerService::new()
+                .build_transaction_bundle(&_request().to_string(), &[], options)
+                .unwrap();
+
+            assert_eq!(
+                vec![IDENTIFIER.to_string(), ENDORSER.to_string(), DEST.to_string()],
+                bundle.required_signers
+            );
+            assert_eq!(0, bundle.required_signature_count);
+            assert_eq!(None, bundle.constraint);
+        }
+
+        #[test]
+        fn build_transaction_bundle_works_for_combination_constraints() {
+            let constraint = Constraint::AndConstraint(CombinationConstraint {
+                auth_constraints: vec![
+                    _role_constraint(2),
+                    Constraint::OrConstraint(CombinationConstraint {
+                        auth_constraints: vec![
+                            _role_constraint(3),
+                            _role_constraint(1),
+                            Constraint::ForbiddenConstraint(ForbiddenConstraint {}),
+                        ],
+                    }),
+                ],
+            });
+
+            let auth_rules = vec![
+                _auth_rule(NYM, "role", "101", _role_constraint(5)),
+                _auth_rule(SCHEMA, "*", "*", constraint.clone()),
+            ];
+
+            let bundle = _bundle(&auth_rules);
+
+            assert_eq!(3, bundle.required_signature_count);
+            assert_eq!(Some(constraint), bundle.constraint);
+        }
+
+        #[test]
+        fn build_transaction_bundle_works_for_field_value_rules() {
+            let mut request = _request();
+            request["operation"] = json!({"type": NYM, "dest": DEST, "role": "101"});
+
+            let auth_rules = vec![
+                _auth_rule(NYM, "role", "", _role_constraint(1)),
+                _auth_rule(NYM, "role", "101", _role_constraint(3)),
+            ];
+
+            let (bundle, _) = LedgerService::new()
+                .build_transaction_bundle(&request.to_string(), &auth_rules, TransactionBundleOptions::default())
+                .unwrap();
+
+            assert_eq!(3, bundle.required_signature_count);
+        }
+
+        #[test]
+        fn build_transaction_bundle_works_for_forbidden_action() {
+            let auth_rules = vec![_auth_rule(
+                SCHEMA,
+                "*",
+                "*",
+                Constraint::ForbiddenConstraint(ForbiddenConstraint {}),
+            )];
+
+            let res = LedgerService::new().build_transaction_bundle(
+                &_request().to_string(),
+                &auth_rules,
+                TransactionBundleOptions::default(),
+            );
+
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn get_transaction_bundle_status_works() {
+            let ledger_service = LedgerService::new();
+            let mut bundle = _bundle(&[_auth_rule(SCHEMA, "*", "*", _role_constraint(3))]);
+
+            let status = ledger_service.get_transaction_bundle_status(&bundle, 0);
+            assert_eq!(vec![IDENTIFIER.to_string(), ENDORSER.to_string()], status.missing_signers);
+            assert_eq!(3, status.missing_signature_count);
+            assert!(!status.ready);
+
+            ledger_service
+                .add_transaction_bundle_signature(&mut bundle, IDENTIFIER, _signature(), 0)
+                .unwrap();
+
+            ledger_service
+                .add_transaction_bundle_signature(&mut bundle, ENDORSER, _signature(), 0)
+                .unwrap();
+
+            let status = ledger_service.get_transaction_bundle_status(&bundle, 0);
+            assert!(status.missing_signers.is_empty());
+            assert_eq!(1, status.missing_signature_count);
+            assert!(!status.ready);
+
+            ledger_service
+                .add_transaction_bundle_signature(&mut bundle, DEST, _signature(), 0)
+                .unwrap();
+
+            let status = ledger_service.get_transaction_bundle_status(&bundle, 0);
+            assert_eq!(0, status.missing_signature_count);
+            assert!(status.ready);
+        }
+
+        #[test]
+        fn finalize_transaction_bundle_works() {
+            let ledger_service = LedgerService::new();
+            let mut bundle = _bundle(&[]);
+
+            bundle.signatures.insert(IDENTIFIER.to_string(), _signature());
+            bundle.signatures.insert(ENDORSER.to_string(), _signature());
+
+            let request = ledger_service.finalize_transaction_bundle(bundle, 0).unwrap();
+            let request: Value = serde_json::from_str(&request).unwrap();
+
+            let expected_signatures = json!({ IDENTIFIER: SIGNATURE, ENDORSER: SIGNATURE });
+            assert_eq!(expected_signatures, request["signatures"]);
+        }
+
+        #[test]
+        fn finalize_transaction_bundle_works_for_missed_signature() {
+            let mut bundle = _bundle(&[]);
+            bundle.signatures.insert(IDENTIFIER.to_string(), _signature());
+
+            let res = LedgerService::new().finalize_transaction_bundle(bundle, 0);
+            assert_kind!(IndyErrorKind::InvalidState, res);
+        }
+
+        #[test]
+        fn transaction_bundle_works_for_expired() {
+            let ledger_service = LedgerService::new();
+
+            let mut bundle = _bundle(&[]);
+            bundle.expires_at = Some(100);
+            bundle.signatures.insert(IDENTIFIER.to_string(), _signature());
+
+            let res = ledger_service.add_transaction_bundle_signature(
+                &mut bundle,
+                ENDORSER,
+                _signature(),
+                100,
+            );
+            assert_kind!(IndyErrorKind::InvalidState, res);
+
+            bundle.signatures.insert(ENDORSER.to_string(), _signature());
+
+            assert!(ledger_service.get_transaction_bundle_status(&bundle, 99).ready);
+            assert!(ledger_service.get_transaction_bundle_status(&bundle, 100).expired);
+
+            let res = ledger_service.finalize_transaction_bundle(bundle, 100);
+            assert_kind!(IndyErrorKind::InvalidState, res);
+        }
+    }
+
     #[test]
     fn datetime_to_date() {
         assert_eq!(0, LedgerService::datetime_to_date_timestamp(0));
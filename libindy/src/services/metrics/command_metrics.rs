@@ -74,6 +74,9 @@ pub enum CommandMetric {
     LedgerCommandSignAndSubmitRequest,
     // LedgerCommand
     LedgerCommandSubmitRequest,
+    LedgerCommandSubmitRequestsBatch,
+    LedgerCommandReplicateLedger,
+    LedgerCommandGetLedgerReplicaTxns,
     LedgerCommandSubmitAck,
     LedgerCommandSubmitAction,
     LedgerCommandSignRequest,
@@ -117,7 +120,16 @@ pub enum CommandMetric {
     LedgerCommandBuildAcceptanceMechanismRequests,
     LedgerCommandBuildGetAcceptanceMechanismsRequest,
     LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest,
+    LedgerCommandStoreTxnAuthorAgreementAcceptance,
+    LedgerCommandGetPoolTxnAuthorAgreement,
     LedgerCommandAppendRequestEndorser,
+    LedgerCommandBuildTransactionBundle,
+    LedgerCommandSignTransactionBundle,
+    LedgerCommandGetTransactionBundleStatus,
+    LedgerCommandSubmitTransactionBundle,
+    LedgerCommandBuildLedgersFreezeRequest,
+    LedgerCommandBuildGetFrozenLedgersRequest,
+    LedgerCommandParseGetFrozenLedgersResponse,
     // CheqdLedger - Cheqd Module
     CheqdLedgerCommandBuildMsgCreateNym,
     CheqdLedgerCommandBuildMsgUpdateNym,
@@ -129,8 +141,12 @@ pub enum CommandMetric {
     CheqdLedgerCommandParseMsgDeleteNymResp,
     CheqdLedgerCommandParseQueryGetNymResp,
     CheqdLedgerCommandParseQueryAllNymResp,
+    CheqdLedgerCommandResolveDidDocument,
     // CheqdLedger - Auth Module
     CheqdLedgerCommandBuildTx,
+    CheqdLedgerCommandBuildTxWithFeeEstimation,
+    CheqdLedgerCommandBuildMultisigTx,
+    CheqdLedgerCommandCombineMultisigSignatures,
     CheqdLedgerCommandBuildQueryCosmosAuthAccount,
     CheqdLedgerCommandParseQueryCosmosAuthAccountResp,
     // CheqdLedger - Bank Module
@@ -138,19 +154,29 @@ pub enum CommandMetric {
     CheqdLedgerCommandParseMsgSendResp,
     CheqdLedgerCommandBuildQueryBalance,
     CheqdLedgerCommandParseQueryBalanceResp,
+    // CheqdLedger - Tx Module
+    CheqdLedgerCommandBuildQuerySimulate,
+    CheqdLedgerCommandParseQuerySimulateResp,
+    CheqdLedgerCommandEstimateFee,
     // CheqdPool
     CheqdPoolCommandAdd,
     CheqdPoolCommandGetConfig,
     CheqdPoolCommandGetAllConfig,
     CheqdPoolCommandBroadcastTxCommit,
+    CheqdPoolCommandBroadcastTxSync,
+    CheqdPoolCommandBroadcastTxAsync,
+    CheqdPoolCommandGetTx,
     CheqdPoolCommandAbciQuery,
     CheqdPoolCommandAbciInfo,
     // CheqdKeys
     CheqdKeysAddRandom,
     CheqdKeysAddFromMnemonic,
+    CheqdKeysGenerateMnemonic,
     CheqdKeysKeyInfo,
     CheqdKeysSign,
     CheqdKeysGetListKeys,
+    CheqdKeysGetMultisigInfo,
+    CheqdKeysSignMultisigPartial,
     // PoolCommand
     PoolCommandCreate,
     PoolCommandDelete,
@@ -162,6 +188,9 @@ pub enum CommandMetric {
     PoolCommandRefresh,
     PoolCommandRefreshAck,
     PoolCommandSetProtocolVersion,
+    PoolCommandGetStatus,
+    PoolCommandExportSnapshot,
+    PoolCommandImportSnapshot,
     // DidCommand
     DidCommandCreateAndStoreMyDid,
     DidCommandReplaceKeysStart,
@@ -179,6 +208,7 @@ pub enum CommandMetric {
     DidCommandGetNymAck,
     DidCommandGetAttribAck,
     DidCommandQualifyDid,
+    DidCommandResolveDidDocument,
     // WalletCommand
     WalletCommandRegisterWalletType,
     WalletCommandCreate,
@@ -189,6 +219,8 @@ pub enum CommandMetric {
     WalletCommandImport,
     WalletCommandGenerateKey,
     WalletCommandDeriveKey,
+    WalletCommandRotateDataKeys,
+    WalletCommandGetDataKeysRotationProgress,
     // PairwiseCommand
     PairwiseCommandPairwiseExists,
     PairwiseCommandCreatePairwise,
@@ -206,6 +238,7 @@ pub enum CommandMetric {
     NonSecretsCommandOpenSearch,
     NonSecretsCommandFetchSearchNextRecords,
     NonSecretsCommandCloseSearch,
+    NonSecretsCommandBatch,
     // PaymentsCommand
     PaymentsCommandRegisterMethod,
     PaymentsCommandCreateAddress,
@@ -246,6 +279,14 @@ pub enum CommandMetric {
     CacheCommandGetCredDef,
     CacheCommandPurgeSchemaCache,
     CacheCommandPurgeCredDefCache,
+    CacheCommandGetNym,
+    CacheCommandGetAttrib,
+    CacheCommandGetRevocRegDef,
+    CacheCommandGetRevocRegDelta,
+    CacheCommandPurgeNymCache,
+    CacheCommandPurgeAttribCache,
+    CacheCommandPurgeRevocRegDefCache,
+    CacheCommandPurgeRevocRegDeltaCache,
     // MetricsCommand
     MetricsCommandCollectMetrics,
     // Exit
@@ -263,4 +304,5 @@ pub enum CommandMetric {
     VdrCommandPrepareSchema,
     VdrCommandPrepareCredDef,
     VdrCommandSubmitTxn,
+    VdrCommandSubmitQuery,
 }
\ No newline at end of file
@@ -23,7 +23,10 @@ pub(crate) use ledger::LedgerService;
 pub(crate) use metrics::MetricsService;
 pub(crate) use metrics::command_metrics::CommandMetric;
 pub(crate) use payments::{PaymentsService, PaymentsMethodCBs, RequesterInfo, Fees};
-pub(crate) use pool::PoolService;
+pub(crate) use pool::{
+    get_sp_parser, parse_ledger_type, register_sp_parser, LedgerReplica, PoolService,
+};
+pub use pool::StateProofParser;
 #[cfg(feature = "cheqd")]
 pub(crate) use cheqd_keys::CheqdKeysService;
 #[cfg(feature = "cheqd")]
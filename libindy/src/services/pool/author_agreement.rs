@@ -0,0 +1,229 @@
+use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::hash::hash;
+
+use crate::domain::ledger::{
+    author_agreement::{
+        ActiveTxnAuthorAgreement, GetAcceptanceMechanismOperation,
+        GetAcceptanceMechanismsReplyResult, GetTxnAuthorAgreementOperation,
+        GetTxnAuthorAgreementReplyResult, TxnAuthorAgreementAcceptance,
+        TxnAuthorAgreementChangePolicy,
+    },
+    request::Request,
+};
+use crate::services::LedgerService;
+
+/// Transaction author agreement of the opened pool and the acceptance configured for it.
+#[derive(Clone, Debug)]
+pub(crate) struct TxnAuthorAgreementState {
+    pub(crate) acceptance: Option<TxnAuthorAgreementAcceptance>,
+    pub(crate) change_policy: TxnAuthorAgreementChangePolicy,
+    pub(crate) active: Option<ActiveTxnAuthorAgreement>,
+    pub(super) fetched: bool,
+}
+
+impl TxnAuthorAgreementState {
+    pub(super) fn new(
+        acceptance: Option<TxnAuthorAgreementAcceptance>,
+        change_policy: TxnAuthorAgreementChangePolicy,
+    ) -> TxnAuthorAgreementState {
+        TxnAuthorAgreementState {
+            acceptance,
+            change_policy,
+            active: None,
+            fetched: false,
+        }
+    }
+}
+
+pub(super) fn build_get_txn_author_agreement_request() -> IndyResult<String> {
+    Request::build_request(None, GetTxnAuthorAgreementOperation::new(None))
+        .map_err(|err| err_msg(IndyErrorKind::InvalidState, err))
+}
+
+pub(super) fn build_get_acceptance_mechanisms_request() -> IndyResult<String> {
+    Request::build_request(None, GetAcceptanceMechanismOperation::new(None, None))
+        .map_err(|err| err_msg(IndyErrorKind::InvalidState, err))
+}
+
+/// Parses GET_TXN_AUTHR_AGRMT and GET_TXN_AUTHR_AGRMT_AML replies for the latest versions.
+///
+/// Returns `None` if the ledger doesn't require the transaction author agreement
+/// (it was never set, was disabled or retired).
+pub(super) fn parse_active_txn_author_agreement(
+    taa_response: &str,
+    aml_response: &str,
+) -> IndyResult<Option<ActiveTxnAuthorAgreement>> {
+    let taa = match LedgerService::parse_response::<GetTxnAuthorAgreementReplyResult>(taa_response)?
+        .result()
+        .data
+    {
+        Some(taa) => taa,
+        None => return Ok(None),
+    };
+
+    let text = match taa.text {
+        Some(ref text) if !text.is_empty() && taa.retirement_ts.is_none() => text.to_string(),
+        _ => return Ok(None),
+    };
+
+    let digest = match taa.digest {
+        Some(digest) => digest,
+        None => hex::encode(hash((taa.version.clone() + &text).as_bytes())?),
+    };
+
+    let aml = LedgerService::parse_response::<GetAcceptanceMechanismsReplyResult>(aml_response)?
+        .result()
+        .data;
+
+    let (aml, aml_version, aml_context) = match aml {
+        Some(aml) => (Some(aml.aml), Some(aml.version), aml.aml_context),
+        None => (None, None, None),
+    };
+
+    Ok(Some(ActiveTxnAuthorAgreement {
+        text,
+        version: taa.version,
+        digest,
+        ratification_ts: taa.ratification_ts,
+        aml,
+        aml_version,
+        aml_context,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "some agreement text";
+    const VERSION: &str = "1.0.0";
+    const DIGEST: &str = "050e52a57837fff904d3d059c8a123e3a04177042bf467db2b2c27abd8045d5e";
+
+    fn _taa_response(data: serde_json::Value) -> String {
+        json!({
+            "op": "REPLY",
+            "result": {
+                "type": "6",
+                "data": data,
+            }
+        })
+        .to_string()
+    }
+
+    fn _aml_response(data: serde_json::Value) -> String {
+        json!({
+            "op": "REPLY",
+            "result": {
+                "type": "7",
+                "data": data,
+            }
+        })
+        .to_string()
+    }
+
+    fn _aml_data() -> serde_json::Value {
+        json!({
+            "aml": {"click_agreement": "agreed by clicking"},
+            "version": "1",
+            "amlContext": "some context",
+        })
+    }
+
+    #[test]
+    fn parse_active_txn_author_agreement_works() {
+        let taa_response = _taa_response(json!({
+            "text": TEXT,
+            "version": VERSION,
+            "digest": DIGEST,
+            "ratification_ts": 123456789,
+        }));
+
+        let taa = parse_active_txn_author_agreement(&taa_response, &_aml_response(_aml_data()))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(TEXT, taa.text);
+        assert_eq!(VERSION, taa.version);
+        assert_eq!(DIGEST, taa.digest);
+        assert_eq!(Some(123456789), taa.ratification_ts);
+        assert!(taa.aml.unwrap().0.contains_key("click_agreement"));
+        assert_eq!(Some("1".to_string()), taa.aml_version);
+        assert_eq!(Some("some context".to_string()), taa.aml_context);
+    }
+
+    #[test]
+    fn parse_active_txn_author_agreement_works_for_missed_digest() {
+        let taa_response = _taa_response(json!({
+            "text": TEXT,
+            "version": VERSION,
+        }));
+
+        let taa = parse_active_txn_author_agreement(&taa_response, &_aml_response(_aml_data()))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(DIGEST, taa.digest);
+    }
+
+    #[test]
+    fn parse_active_txn_author_agreement_works_for_missed_aml() {
+        let taa_response = _taa_response(json!({
+            "text": TEXT,
+            "version": VERSION,
+            "digest": DIGEST,
+        }));
+
+        let taa = parse_active_txn_author_agreement(&taa_response, &_aml_response(json!(null)))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(None, taa.aml);
+        assert_eq!(None, taa.aml_version);
+    }
+
+    #[test]
+    fn parse_active_txn_author_agreement_works_for_not_set() {
+        let taa = parse_active_txn_author_agreement(
+            &_taa_response(json!(null)),
+            &_aml_response(json!(null)),
+        )
+        .unwrap();
+
+        assert_eq!(None, taa);
+    }
+
+    #[test]
+    fn parse_active_txn_author_agreement_works_for_disabled() {
+        let empty_text = _taa_response(json!({
+            "text": "",
+            "version": VERSION,
+            "digest": DIGEST,
+        }));
+
+        let taa = parse_active_txn_author_agreement(&empty_text, &_aml_response(_aml_data())).unwrap();
+        assert_eq!(None, taa);
+
+        let retired = _taa_response(json!({
+            "text": TEXT,
+            "version": VERSION,
+            "digest": DIGEST,
+            "retirement_ts": 123456789,
+        }));
+
+        let taa = parse_active_txn_author_agreement(&retired, &_aml_response(_aml_data())).unwrap();
+        assert_eq!(None, taa);
+    }
+
+    #[test]
+    fn parse_active_txn_author_agreement_works_for_reject() {
+        let reject = json!({
+            "op": "REJECT",
+            "reqId": 1,
+            "reason": "some reason",
+        })
+        .to_string();
+
+        let res = parse_active_txn_author_agreement(&reject, &_aml_response(_aml_data()));
+        assert_kind!(IndyErrorKind::InvalidTransaction, res);
+    }
+}
@@ -4,8 +4,8 @@ use crate::services::pool::events::PoolEvent;
 use zmq;
 
 use byteorder::{ByteOrder, LittleEndian};
-use indy_api_types::INVALID_COMMAND_HANDLE;
-use crate::services::pool::{COMMAND_CONNECT, COMMAND_EXIT, COMMAND_REFRESH};
+use indy_api_types::{CommandHandle, INVALID_COMMAND_HANDLE};
+use crate::services::pool::{COMMAND_BATCH, COMMAND_CONNECT, COMMAND_EXIT, COMMAND_REFRESH, COMMAND_STATUS};
 
 pub struct Commander {
     cmd_socket: zmq::Socket,
@@ -39,6 +39,20 @@ impl Commander {
             Some(PoolEvent::Refresh(id))
         } else if COMMAND_CONNECT.eq(cmd_s.as_str()) {
             Some(PoolEvent::CheckCache(id))
+        } else if COMMAND_STATUS.eq(cmd_s.as_str()) {
+            Some(PoolEvent::GetStatus(id))
+        } else if COMMAND_BATCH.eq(cmd_s.as_str()) {
+            let requests = cmd_parts[1..].chunks(2)
+                .map(|parts| {
+                    let cmd_id = LittleEndian::read_i32(parts[0].as_slice());
+                    let request = String::from_utf8(parts.get(1)?.clone())
+                        .to_indy(IndyErrorKind::InvalidState, "Invalid utf8 sequence in command") // FIXME: review kind
+                        .map_err(map_err_trace!()).ok()?;
+                    Some((cmd_id, request))
+                })
+                .collect::<Option<Vec<(CommandHandle, String)>>>()?;
+
+            Some(PoolEvent::SendRequestsBatch(requests))
         } else {
             let timeout = LittleEndian::read_i32(cmd_parts[2].as_slice());
             let timeout = if timeout == -1 { None } else { Some(timeout) };
@@ -138,6 +152,36 @@ mod commander_tests {
         assert_match!(Some(PoolEvent::CheckCache(cmd_id_)), cmd.fetch_events(), cmd_id_, cmd_id);
     }
 
+    #[test]
+    pub fn commander_fetch_get_status_event_works() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("get_status");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        let cmd_id: CommandHandle = next_command_handle();
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        send_cmd_sock.send_multipart(&[COMMAND_STATUS.as_bytes(), &buf], zmq::DONTWAIT).expect("FIXME");
+        assert_match!(Some(PoolEvent::GetStatus(cmd_id_)), cmd.fetch_events(), cmd_id_, cmd_id);
+    }
+
+    #[test]
+    pub fn commander_fetch_requests_batch_event_works() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("requests_batch");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        let cmd_id_1: CommandHandle = next_command_handle();
+        let cmd_id_2: CommandHandle = next_command_handle();
+        let mut buf_1 = [0u8; 4];
+        let mut buf_2 = [0u8; 4];
+        LittleEndian::write_i32(&mut buf_1, cmd_id_1);
+        LittleEndian::write_i32(&mut buf_2, cmd_id_2);
+        send_cmd_sock.send_multipart(&[COMMAND_BATCH.as_bytes(), &buf_1, "request_1".as_bytes(), &buf_2, "request_2".as_bytes()], zmq::DONTWAIT).expect("FIXME");
+        assert_match!(Some(PoolEvent::SendRequestsBatch(requests)), cmd.fetch_events(), requests,
+                      vec![(cmd_id_1, "request_1".to_string()), (cmd_id_2, "request_2".to_string())]);
+    }
+
     #[test]
     pub fn commander_fetch_send_request_event_works() {
         let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("send_request");
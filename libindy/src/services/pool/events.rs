@@ -5,6 +5,7 @@ use crate::domain::ledger::constants;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::types::*;
+use crate::services::pool::get_sp_parser;
 use indy_api_types::CommandHandle;
 
 pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
@@ -66,12 +67,18 @@ pub enum NetworkerEvent {
         String, //req_id
         Option<String>, //node_alias
     ),
+    NodeTimeout(
+        String, //req_id
+        String, //node_alias
+    ),
     Timeout,
 }
 
 pub const COMMAND_EXIT : &str = "exit";
 pub const COMMAND_CONNECT : &str = "connect";
 pub const COMMAND_REFRESH : &str = "refresh";
+pub const COMMAND_STATUS : &str = "status";
+pub const COMMAND_BATCH : &str = "batch";
 
 #[derive(Clone, Debug)]
 pub enum PoolEvent {
@@ -86,6 +93,9 @@ pub enum PoolEvent {
     Refresh(
         CommandHandle
     ),
+    GetStatus(
+        CommandHandle
+    ),
     CatchupTargetFound(
         Vec<u8>, //target_mt_root
         usize, //target_mt_size
@@ -108,6 +118,9 @@ pub enum PoolEvent {
         Option<i32>, // timeout
         Option<String>, // node list
     ),
+    SendRequestsBatch(
+        Vec<(CommandHandle, String)>, // requests with their own command handles
+    ),
     Timeout(
         String, //req_id
         String, //node alias
@@ -245,9 +258,8 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                         let key = super::state_proof::parse_key_from_request_for_builtin_sp(&req);
                         let timestamps = _parse_timestamp_from_req_for_builtin_sp(req, &op);
                         Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), key, timestamps))
-                    // FIXME: Do we need custom parsers???
-                    // } else if PoolService::get_sp_parser(&op.as_str()).is_some() {
-                    //     Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None)))
+                    } else if get_sp_parser(&op.as_str()).is_some() {
+                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None)))
                     } else {
                         Some(RequestEvent::CustomConsensusRequest(msg, req_id.clone()))
                     }
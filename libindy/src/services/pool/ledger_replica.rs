@@ -0,0 +1,711 @@
+use std::collections::HashMap;
+use std::{fs, io};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use futures::lock::Mutex;
+use lazy_static::lazy_static;
+use rust_base58::{FromBase58, ToBase58};
+use serde_json;
+use serde_json::Value as SJsonValue;
+
+use indy_api_types::errors::prelude::*;
+use crate::domain::ledger::response::Message;
+use crate::domain::ledger::txn::LedgerType;
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::pool::state_proof;
+use crate::utils::environment;
+
+const REPLICA_EXT: &str = "btxn";
+const INDEX_EXT: &str = "idx";
+const ROOT_HASH_LEN: usize = 32;
+const INDEX_ENTRY_LEN: u64 = 8 + ROOT_HASH_LEN as u64;
+
+lazy_static! {
+    static ref REPLICA_LOCKS: Mutex<HashMap<(String, i32), Arc<Mutex<()>>>> = Mutex::new(HashMap::new());
+}
+
+/// Local append-only copy of a DOMAIN or CONFIG ledger.
+///
+/// Transactions are stored in the order of their sequence numbers using the same length-prefixed
+/// MessagePack format as the pool ledger cache. The index file keeps the end offset of each
+/// stored transaction and the root hash of the ledger ending with it, so the last sequence number
+/// and stored transactions are found without reading the whole replica. Bytes written after
+/// the last indexed transaction (e.g. by interrupted append) are ignored and overwritten.
+///
+/// The merkle tree is restored from the stored transactions on the first append, so replication
+/// resumes right after the last stored sequence number.
+pub(crate) struct LedgerReplica {
+    path: PathBuf,
+    index_path: PathBuf,
+    index: Vec<(u64, Vec<u8>)>,
+    tree: Option<MerkleTree>,
+    signed_root: Option<(usize, Vec<u8>)>,
+}
+
+impl LedgerReplica {
+    pub(crate) fn open(pool_name: &str, ledger_type: &LedgerType) -> IndyResult<LedgerReplica> {
+        let path = _replica_path(pool_name, ledger_type)?;
+
+        let mut index_path = path.clone();
+        index_path.set_extension(INDEX_EXT);
+
+        let index = _read_index(&index_path)?;
+
+        Ok(LedgerReplica {
+            path,
+            index_path,
+            index,
+            tree: None,
+            signed_root: None,
+        })
+    }
+
+    /// Lock to be held while the replica of the pool ledger is extended.
+    ///
+    /// Replication reads the last stored sequence number, fetches next transactions and appends
+    /// them, so concurrent replications of the same ledger must be serialized.
+    pub(crate) async fn lock(pool_name: &str, ledger_type: &LedgerType) -> Arc<Mutex<()>> {
+        REPLICA_LOCKS
+            .lock()
+            .await
+            .entry((pool_name.to_string(), ledger_type.to_id()))
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone()
+    }
+
+    /// Sequence number of the last stored transaction.
+    pub(crate) fn seq_no(&self) -> usize {
+        self.index.len()
+    }
+
+    pub(crate) fn root_hash(&self) -> Option<String> {
+        self.index.last().map(|(_, root_hash)| root_hash.to_base58())
+    }
+
+    /// Size and root hash of the ledger confirmed by the last verified reply.
+    pub(crate) fn signed_root(&self) -> Option<(usize, String)> {
+        self.signed_root
+            .as_ref()
+            .map(|(size, root)| (*size, root.to_base58()))
+    }
+
+    /// Verifies GET_TXN reply for the next sequence number and appends the transaction to the replica.
+    ///
+    /// The transaction is accepted only if the replica extended with it is consistent with
+    /// the multi-signed ledger root from the reply. Returns `false` if the ledger doesn't
+    /// contain the transaction yet.
+    pub(crate) fn append(&mut self, reply: &str) -> IndyResult<bool> {
+        let message: Message<SJsonValue> = serde_json::from_str(reply)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Invalid GET_TXN reply")?;
+
+        let result = match message {
+            Message::Reject(response) | Message::ReqNACK(response) => {
+                return Err(err_msg(
+                    IndyErrorKind::InvalidTransaction,
+                    format!("Transaction has been failed: {:?}", response.reason),
+                ));
+            }
+            Message::Reply(reply) => reply.result(),
+        };
+
+        let data = &result["data"];
+
+        let txn = _stored_txn(data);
+
+        let leaf = match _txn_to_leaf_opt(&txn)? {
+            Some(leaf) => leaf,
+            None => return Ok(false),
+        };
+
+        let seq_no = self.seq_no() + 1;
+
+        if txn["txnMetadata"]["seqNo"].as_u64() != Some(seq_no as u64) {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Reply doesn't contain transaction with seqNo {}", seq_no),
+            ));
+        }
+
+        let (ledger_size, root_hash, audit_path) = parse_audit_proof(&result)?;
+
+        if ledger_size < seq_no {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Ledger size {} is less than seqNo {}", ledger_size, seq_no),
+            ));
+        }
+
+        let mut tree = self._tree()?.clone();
+        tree.append(leaf.clone())?;
+
+        let proof = MerkleTree::consistency_proof_from_audit_path(&leaf, seq_no, &audit_path)?;
+
+        if !tree.consistency_proof(&root_hash, ledger_size, &proof)? {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Transaction with seqNo {} is inconsistent with the multi-signed ledger root", seq_no),
+            ));
+        }
+
+        let end = _append_txn_to_file(&self.path, self._end(), &txn)?;
+        _append_index_entry(&self.index_path, seq_no, end, tree.root_hash())?;
+
+        self.index.push((end, tree.root_hash().clone()));
+        self.tree = Some(tree);
+        self.signed_root = Some((ledger_size, root_hash));
+
+        Ok(true)
+    }
+
+    /// Returns stored transactions with sequence numbers in `from..=to`.
+    pub(crate) fn txns(&self, from: usize, to: usize) -> IndyResult<Vec<SJsonValue>> {
+        let from = std::cmp::max(from, 1);
+        let to = std::cmp::min(to, self.seq_no());
+
+        if from > to {
+            return Ok(Vec::new());
+        }
+
+        _read_txns(&self.path, self._offset(from), to - from + 1)
+    }
+
+    fn _tree(&mut self) -> IndyResult<&MerkleTree> {
+        if self.tree.is_none() {
+            let txns = _read_txns(&self.path, 0, self.seq_no())?;
+
+            let mut tree = MerkleTree::from_vec(Vec::new())?;
+
+            for txn in txns {
+                tree.append(_txn_to_leaf(&txn)?)?;
+            }
+
+            let root_hash = if tree.is_empty() { None } else { Some(tree.root_hash()) };
+
+            if self.index.last().map(|(_, root_hash)| root_hash) != root_hash {
+                return Err(err_msg(IndyErrorKind::InvalidState, "Ledger replica doesn't match its index"));
+            }
+
+            self.tree = Some(tree);
+        }
+
+        Ok(self.tree.as_ref().unwrap())
+    }
+
+    /// Offset of the transaction with the given sequence number in the replica file.
+    fn _offset(&self, seq_no: usize) -> u64 {
+        if seq_no <= 1 {
+            0
+        } else {
+            self.index[seq_no - 2].0
+        }
+    }
+
+    /// Offset right after the last stored transaction.
+    fn _end(&self) -> u64 {
+        self._offset(self.seq_no() + 1)
+    }
+}
+
+pub(crate) fn parse_ledger_type(ledger_type: &str) -> IndyResult<LedgerType> {
+    match serde_json::from_str::<LedgerType>(&format!(r#""{}""#, ledger_type)) {
+        Ok(LedgerType::POOL) | Err(_) => Err(err_msg(
+            IndyErrorKind::InvalidStructure,
+            format!("Invalid Ledger type: {}. Only DOMAIN and CONFIG ledgers can be replicated", ledger_type),
+        )),
+        Ok(ledger_type) => Ok(ledger_type),
+    }
+}
+
+fn _replica_path(pool_name: &str, ledger_type: &LedgerType) -> IndyResult<PathBuf> {
+    let mut path = environment::ledger_replica_path(pool_name);
+
+    fs::create_dir_all(path.as_path())
+        .to_indy(IndyErrorKind::IOError, "Can't create ledger replica directory")?;
+
+    path.push(match ledger_type {
+        LedgerType::POOL => "pool",
+        LedgerType::DOMAIN => "domain",
+        LedgerType::CONFIG => "config",
+    });
+    path.set_extension(REPLICA_EXT);
+
+    Ok(path)
+}
+
+fn _stored_txn(data: &SJsonValue) -> SJsonValue {
+    let mut txn = json!({});
+
+    for key in &["txn", "txnMetadata", "ver", "reqSignature"] {
+        if !data[key].is_null() {
+            txn[key] = data[key].clone();
+        }
+    }
+
+    txn
+}
+
+fn _txn_to_leaf_opt(txn: &SJsonValue) -> IndyResult<Option<Vec<u8>>> {
+    let value = state_proof::parse_txn_audit_value(txn)
+        .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+    match value {
+        Some(value) => Ok(Some(
+            rmp_serde::to_vec(&value)
+                .to_indy(IndyErrorKind::InvalidState, "Can't encode transaction as message pack")?,
+        )),
+        None => Ok(None),
+    }
+}
+
+fn _txn_to_leaf(txn: &SJsonValue) -> IndyResult<Vec<u8>> {
+    _txn_to_leaf_opt(txn)?.ok_or_else(|| {
+        err_msg(IndyErrorKind::InvalidState, "Ledger replica contains empty transaction")
+    })
+}
+
+pub(super) fn parse_audit_proof(result: &SJsonValue) -> IndyResult<(usize, Vec<u8>, Vec<Vec<u8>>)> {
+    let data = &result["data"];
+
+    let root_hash = data["rootHash"]
+        .as_str()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "No root hash in GET_TXN reply"))?;
+
+    if result["state_proof"]["multi_signature"]["value"]["txn_root_hash"].as_str() != Some(root_hash) {
+        return Err(err_msg(
+            IndyErrorKind::InvalidState,
+            "Root hash of GET_TXN reply is not multi-signed",
+        ));
+    }
+
+    let ledger_size = data["ledgerSize"]
+        .as_u64()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "No ledger size in GET_TXN reply"))?;
+
+    let audit_path = data["auditPath"]
+        .as_array()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "No audit path in GET_TXN reply"))?
+        .iter()
+        .map(|hash| {
+            hash.as_str()
+                .and_then(|hash| hash.from_base58().ok())
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid audit path in GET_TXN reply"))
+        })
+        .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+    let root_hash = root_hash
+        .from_base58()
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Invalid root hash in GET_TXN reply"))?;
+
+    Ok((ledger_size as usize, root_hash, audit_path))
+}
+
+fn _read_index(path: &PathBuf) -> IndyResult<Vec<(u64, Vec<u8>)>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut f = fs::File::open(path)
+        .to_indy(IndyErrorKind::IOError, "Can't open ledger replica index file")?;
+
+    let mut index = Vec::new();
+
+    loop {
+        let end = match f.read_u64::<LittleEndian>() {
+            Ok(end) => end,
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.to_indy(IndyErrorKind::IOError, "Can't read from ledger replica index file"))
+        };
+
+        let mut root_hash = vec![0; ROOT_HASH_LEN];
+
+        match f.read_exact(root_hash.as_mut()) {
+            Ok(()) => (),
+            // Entry of interrupted append
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.to_indy(IndyErrorKind::IOError, "Can't read from ledger replica index file"))
+        }
+
+        index.push((end, root_hash));
+    }
+
+    Ok(index)
+}
+
+fn _append_index_entry(path: &PathBuf, seq_no: usize, end: u64, root_hash: &[u8]) -> IndyResult<()> {
+    if root_hash.len() != ROOT_HASH_LEN {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Unexpected ledger root hash length"));
+    }
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(path)
+        .to_indy(IndyErrorKind::IOError, "Can't open ledger replica index file")?;
+
+    // Drops the entry of interrupted append if any
+    let offset = (seq_no as u64 - 1) * INDEX_ENTRY_LEN;
+
+    file.set_len(offset)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica index file")?;
+    file.seek(SeekFrom::Start(offset))
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica index file")?;
+
+    let mut buf = Vec::with_capacity(INDEX_ENTRY_LEN as usize);
+
+    buf.write_u64::<LittleEndian>(end)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica index file")?;
+    buf.write_all(root_hash)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica index file")?;
+
+    file.write_all(&buf)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica index file")?;
+
+    file.sync_data()
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica index file")
+}
+
+fn _read_txns(path: &PathBuf, offset: u64, count: usize) -> IndyResult<Vec<SJsonValue>> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut f = fs::File::open(path)
+        .to_indy(IndyErrorKind::IOError, "Can't open ledger replica file")?;
+
+    f.seek(SeekFrom::Start(offset))
+        .to_indy(IndyErrorKind::IOError, "Can't read from ledger replica file")?;
+
+    let mut f = io::BufReader::new(f);
+
+    let mut txns = Vec::with_capacity(count);
+
+    while txns.len() < count {
+        let bytes = f.read_u64::<LittleEndian>()
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::UnexpectedEof => e.to_indy(IndyErrorKind::InvalidState, "Malformed ledger replica file"),
+                _ => e.to_indy(IndyErrorKind::IOError, "Can't read from ledger replica file")
+            })?;
+
+        let mut buf = vec![0; bytes as usize];
+
+        match f.read_exact(buf.as_mut()) {
+            Ok(()) => (),
+            Err(e) => match e.kind() {
+                io::ErrorKind::UnexpectedEof => return Err(e.to_indy(IndyErrorKind::InvalidState, "Malformed ledger replica file")),
+                _ => return Err(e.to_indy(IndyErrorKind::IOError, "Can't read from ledger replica file"))
+            }
+        }
+
+        let txn: SJsonValue = rmp_serde::from_slice(&buf)
+            .to_indy(IndyErrorKind::InvalidState, "Malformed ledger replica file")?;
+
+        txns.push(txn);
+    }
+
+    Ok(txns)
+}
+
+/// Writes the transaction at `offset` and returns the offset right after it.
+fn _append_txn_to_file(path: &PathBuf, offset: u64, txn: &SJsonValue) -> IndyResult<u64> {
+    let txn = rmp_serde::to_vec_named(txn)
+        .to_indy(IndyErrorKind::InvalidState, "Can't encode transaction as message pack")?;
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(path)
+        .to_indy(IndyErrorKind::IOError, "Can't open ledger replica file")?;
+
+    // Drops bytes of interrupted append if any
+    file.set_len(offset)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica file")?;
+    file.seek(SeekFrom::Start(offset))
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica file")?;
+
+    let mut buf = Vec::with_capacity(txn.len() + 8);
+
+    buf.write_u64::<LittleEndian>(txn.len() as u64)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica file")?;
+    buf.write_all(&txn)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica file")?;
+
+    file.write_all(&buf)
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica file")?;
+
+    file.sync_data()
+        .to_indy(IndyErrorKind::IOError, "Can't write to ledger replica file")?;
+
+    Ok(offset + buf.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::ledger::merkletree::proof::Positioned;
+    use crate::utils::test;
+
+    use super::*;
+
+    fn _txn(seq_no: usize, dest: &str) -> SJsonValue {
+        json!({
+            "txn": {
+                "type": "1",
+                "data": {"dest": dest},
+                "metadata": {"from": "V4SGRU86Z58d6TV7PBUe6f"}
+            },
+            "txnMetadata": {"seqNo": seq_no, "txnTime": 1613000000 + seq_no},
+            "ver": "1",
+            "reqSignature": {}
+        })
+    }
+
+    fn _ledger(size: usize) -> Vec<SJsonValue> {
+        (1..=size).map(|seq_no| _txn(seq_no, &format!("Did{}", seq_no))).collect()
+    }
+
+    fn _tree(ledger: &[SJsonValue]) -> MerkleTree {
+        MerkleTree::from_vec(ledger.iter().map(|txn| _txn_to_leaf(txn).unwrap()).collect()).unwrap()
+    }
+
+    fn _audit_path(ledger: &[SJsonValue], seq_no: usize) -> Vec<Vec<u8>> {
+        let proof = _tree(ledger)
+            .gen_proof(_txn_to_leaf(&ledger[seq_no - 1]).unwrap())
+            .unwrap()
+            .unwrap();
+
+        let mut path = Vec::new();
+        let mut lemma = Some(&proof.lemma);
+
+        while let Some(current) = lemma {
+            match current.sibling_hash {
+                Some(Positioned::Left(ref hash)) | Some(Positioned::Right(ref hash)) => path.push(hash.clone()),
+                None => {}
+            }
+            lemma = current.sub_lemma.as_ref().map(|sub_lemma| sub_lemma.as_ref());
+        }
+
+        path.reverse();
+        path
+    }
+
+    fn _reply(ledger: &[SJsonValue], seq_no: usize) -> String {
+        let root_hash = _tree(ledger).root_hash().to_base58();
+
+        let mut data = ledger[seq_no - 1].clone();
+        data["auditPath"] = json!(_audit_path(ledger, seq_no).iter().map(|hash| hash.to_base58()).collect::<Vec<String>>());
+        data["ledgerSize"] = json!(ledger.len());
+        data["rootHash"] = json!(root_hash);
+
+        json!({
+            "op": "REPLY",
+            "result": {
+                "type": "3",
+                "seqNo": seq_no,
+                "data": data,
+                "state_proof": {
+                    "multi_signature": {
+                        "value": {"txn_root_hash": root_hash}
+                    }
+                }
+            }
+        }).to_string()
+    }
+
+    #[test]
+    fn consistency_proof_from_audit_path_works() {
+        for size in 1..=9 {
+            let ledger = _ledger(size);
+            let new_root = _tree(&ledger).root_hash().clone();
+
+            for seq_no in 1..=size {
+                let leaf = _txn_to_leaf(&ledger[seq_no - 1]).unwrap();
+                let proof = MerkleTree::consistency_proof_from_audit_path(&leaf, seq_no, &_audit_path(&ledger, seq_no)).unwrap();
+
+                let tree = _tree(&ledger[..seq_no]);
+                assert!(tree.consistency_proof(&new_root, size, &proof).unwrap(), "size {} seq_no {}", size, seq_no);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_ledger_type_works() {
+        assert_eq!(LedgerType::DOMAIN.to_id(), parse_ledger_type("DOMAIN").unwrap().to_id());
+        assert_eq!(LedgerType::CONFIG.to_id(), parse_ledger_type("CONFIG").unwrap().to_id());
+        assert_kind!(IndyErrorKind::InvalidStructure, parse_ledger_type("POOL"));
+        assert_kind!(IndyErrorKind::InvalidStructure, parse_ledger_type("OTHER"));
+    }
+
+    #[test]
+    fn ledger_replica_append_works() {
+        test::cleanup_storage("ledger_replica_append_works");
+
+        let ledger = _ledger(5);
+
+        let mut replica = LedgerReplica::open("ledger_replica_append_works", &LedgerType::DOMAIN).unwrap();
+        assert_eq!(0, replica.seq_no());
+        assert_eq!(None, replica.root_hash());
+
+        for seq_no in 1..=5 {
+            assert!(replica.append(&_reply(&ledger, seq_no)).unwrap());
+        }
+
+        let root_hash = _tree(&ledger).root_hash().to_base58();
+
+        assert_eq!(5, replica.seq_no());
+        assert_eq!(Some(root_hash.clone()), replica.root_hash());
+        assert_eq!(Some((5, root_hash)), replica.signed_root());
+        assert_eq!(ledger[1..3].to_vec(), replica.txns(2, 3).unwrap());
+
+        test::cleanup_storage("ledger_replica_append_works");
+    }
+
+    #[test]
+    fn ledger_replica_open_works_for_resume() {
+        test::cleanup_storage("ledger_replica_open_works_for_resume");
+
+        let ledger = _ledger(4);
+
+        {
+            let mut replica = LedgerReplica::open("ledger_replica_open_works_for_resume", &LedgerType::CONFIG).unwrap();
+            assert!(replica.append(&_reply(&ledger[..2], 1)).unwrap());
+            assert!(replica.append(&_reply(&ledger[..2], 2)).unwrap());
+        }
+
+        let mut replica = LedgerReplica::open("ledger_replica_open_works_for_resume", &LedgerType::CONFIG).unwrap();
+        assert_eq!(2, replica.seq_no());
+
+        assert!(replica.append(&_reply(&ledger, 3)).unwrap());
+        assert!(replica.append(&_reply(&ledger, 4)).unwrap());
+        assert_eq!(Some(_tree(&ledger).root_hash().to_base58()), replica.root_hash());
+
+        test::cleanup_storage("ledger_replica_open_works_for_resume");
+    }
+
+    #[test]
+    fn ledger_replica_txns_works_for_range_out_of_replica() {
+        test::cleanup_storage("ledger_replica_txns_works_for_range_out_of_replica");
+
+        let ledger = _ledger(3);
+
+        let mut replica = LedgerReplica::open("ledger_replica_txns_works_for_range_out_of_replica", &LedgerType::DOMAIN).unwrap();
+        assert_eq!(Vec::<SJsonValue>::new(), replica.txns(1, 10).unwrap());
+
+        for seq_no in 1..=3 {
+            assert!(replica.append(&_reply(&ledger, seq_no)).unwrap());
+        }
+
+        assert_eq!(ledger.clone(), replica.txns(0, 10).unwrap());
+        assert_eq!(ledger[2..].to_vec(), replica.txns(3, 10).unwrap());
+        assert_eq!(Vec::<SJsonValue>::new(), replica.txns(4, 10).unwrap());
+
+        test::cleanup_storage("ledger_replica_txns_works_for_range_out_of_replica");
+    }
+
+    #[test]
+    fn ledger_replica_open_works_after_interrupted_append() {
+        test::cleanup_storage("ledger_replica_open_works_after_interrupted_append");
+
+        let ledger = _ledger(3);
+
+        let (path, index_path) = {
+            let mut replica = LedgerReplica::open("ledger_replica_open_works_after_interrupted_append", &LedgerType::DOMAIN).unwrap();
+            assert!(replica.append(&_reply(&ledger[..2], 1)).unwrap());
+            assert!(replica.append(&_reply(&ledger[..2], 2)).unwrap());
+            (replica.path.clone(), replica.index_path.clone())
+        };
+
+        for path in &[path, index_path] {
+            let mut file = fs::OpenOptions::new().append(true).open(path).unwrap();
+            file.write_all(&[1, 2, 3]).unwrap();
+        }
+
+        let mut replica = LedgerReplica::open("ledger_replica_open_works_after_interrupted_append", &LedgerType::DOMAIN).unwrap();
+        assert_eq!(2, replica.seq_no());
+        assert_eq!(ledger[..2].to_vec(), replica.txns(1, 2).unwrap());
+
+        assert!(replica.append(&_reply(&ledger, 3)).unwrap());
+
+        let replica = LedgerReplica::open("ledger_replica_open_works_after_interrupted_append", &LedgerType::DOMAIN).unwrap();
+        assert_eq!(3, replica.seq_no());
+        assert_eq!(Some(_tree(&ledger).root_hash().to_base58()), replica.root_hash());
+        assert_eq!(ledger, replica.txns(1, 3).unwrap());
+
+        test::cleanup_storage("ledger_replica_open_works_after_interrupted_append");
+    }
+
+    #[async_std::test]
+    async fn ledger_replica_lock_works() {
+        let lock = LedgerReplica::lock("ledger_replica_lock_works", &LedgerType::DOMAIN).await;
+
+        assert!(Arc::ptr_eq(&lock, &LedgerReplica::lock("ledger_replica_lock_works", &LedgerType::DOMAIN).await));
+        assert!(!Arc::ptr_eq(&lock, &LedgerReplica::lock("ledger_replica_lock_works", &LedgerType::CONFIG).await));
+
+        let _guard = lock.lock().await;
+        assert!(LedgerReplica::lock("ledger_replica_lock_works", &LedgerType::DOMAIN).await.try_lock().is_none());
+    }
+
+    #[test]
+    fn ledger_replica_append_works_for_unknown_txn() {
+        test::cleanup_storage("ledger_replica_append_works_for_unknown_txn");
+
+        let mut replica = LedgerReplica::open("ledger_replica_append_works_for_unknown_txn", &LedgerType::DOMAIN).unwrap();
+
+        let reply = json!({"op": "REPLY", "result": {"type": "3", "seqNo": 1, "data": null}}).to_string();
+        assert!(!replica.append(&reply).unwrap());
+        assert_eq!(0, replica.seq_no());
+
+        test::cleanup_storage("ledger_replica_append_works_for_unknown_txn");
+    }
+
+    #[test]
+    fn ledger_replica_append_fails_for_inconsistent_txn() {
+        test::cleanup_storage("ledger_replica_append_fails_for_inconsistent_txn");
+
+        let ledger = _ledger(3);
+
+        let mut other_ledger = _ledger(3);
+        other_ledger[0] = _txn(1, "OtherDid");
+
+        let mut replica = LedgerReplica::open("ledger_replica_append_fails_for_inconsistent_txn", &LedgerType::DOMAIN).unwrap();
+        assert!(replica.append(&_reply(&ledger, 1)).unwrap());
+
+        let res = replica.append(&_reply(&other_ledger, 2));
+        assert_kind!(IndyErrorKind::InvalidState, res);
+        assert_eq!(1, replica.seq_no());
+
+        let replica = LedgerReplica::open("ledger_replica_append_fails_for_inconsistent_txn", &LedgerType::DOMAIN).unwrap();
+        assert_eq!(1, replica.seq_no());
+
+        test::cleanup_storage("ledger_replica_append_fails_for_inconsistent_txn");
+    }
+
+    #[test]
+    fn ledger_replica_append_fails_for_unexpected_seq_no() {
+        test::cleanup_storage("ledger_replica_append_fails_for_unexpected_seq_no");
+
+        let mut replica = LedgerReplica::open("ledger_replica_append_fails_for_unexpected_seq_no", &LedgerType::DOMAIN).unwrap();
+
+        let res = replica.append(&_reply(&_ledger(3), 2));
+        assert_kind!(IndyErrorKind::InvalidState, res);
+
+        test::cleanup_storage("ledger_replica_append_fails_for_unexpected_seq_no");
+    }
+
+    #[test]
+    fn ledger_replica_append_fails_for_not_multi_signed_root() {
+        test::cleanup_storage("ledger_replica_append_fails_for_not_multi_signed_root");
+
+        let mut replica = LedgerReplica::open("ledger_replica_append_fails_for_not_multi_signed_root", &LedgerType::DOMAIN).unwrap();
+
+        let mut reply: SJsonValue = serde_json::from_str(&_reply(&_ledger(2), 1)).unwrap();
+        reply["result"]["state_proof"] = SJsonValue::Null;
+
+        let res = replica.append(&reply.to_string());
+        assert_kind!(IndyErrorKind::InvalidState, res);
+        assert_eq!(0, replica.seq_no());
+
+        test::cleanup_storage("ledger_replica_append_fails_for_not_multi_signed_root");
+    }
+}
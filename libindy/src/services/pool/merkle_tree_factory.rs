@@ -131,6 +131,25 @@ pub fn dump_new_txns(pool_name: &str, txns: &[Vec<u8>]) -> IndyResult<()> {
     _dump_vec_to_file(txns, &mut file)
 }
 
+/// Replaces the pool ledger cache with `txns`.
+pub fn replace_stored(pool_name: &str, txns: &[Vec<u8>]) -> IndyResult<()> {
+    let p = get_pool_stored_path(pool_name, false);
+    let p_tmp = get_pool_stored_path_base(pool_name, false, "stored", "btxn.tmp");
+
+    {
+        let mut file = fs::File::create(&p_tmp)
+            .to_indy(IndyErrorKind::IOError, "Can't create pool ledger cache file")?;
+
+        _dump_vec_to_file(txns, &mut file)?;
+
+        file.sync_all()
+            .to_indy(IndyErrorKind::IOError, "Can't write to pool ledger cache file")?;
+    }
+
+    fs::rename(&p_tmp, &p)
+        .to_indy(IndyErrorKind::IOError, "Can't replace pool ledger cache file")
+}
+
 fn _dump_genesis_to_stored(p: &PathBuf, pool_name: &str) -> IndyResult<()> {
     let p_genesis = get_pool_stored_path_base(pool_name, false, pool_name, POOL_EXT);
 
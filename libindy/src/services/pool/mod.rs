@@ -15,32 +15,43 @@ use serde_json;
 use ursa::bls::VerKey;
 use zmq::Socket;
 
-//use crate::api::ledger::{CustomFree, CustomTransactionParser}; FIXME: !!!
 use crate::{
     domain::{
-        ledger::response::{Message, Reply, ResponseMetadata},
+        ledger::{
+            author_agreement::TxnAuthorAgreementAcceptance,
+            response::{Message, Reply, ResponseMetadata},
+        },
         pool::{PoolConfig, PoolOpenConfig},
     },
     utils::environment,
 };
 
 use self::{
-    events::{COMMAND_CONNECT, COMMAND_EXIT, COMMAND_REFRESH},
+    author_agreement::TxnAuthorAgreementState,
+    events::{COMMAND_BATCH, COMMAND_CONNECT, COMMAND_EXIT, COMMAND_REFRESH, COMMAND_STATUS},
     pool::{Pool, ZMQPool},
+    snapshot::PoolLedgerSnapshot,
 };
 
+mod author_agreement;
 mod catchup;
 mod commander;
 mod events;
+mod ledger_replica;
 mod merkle_tree_factory;
 mod networker;
 mod pool;
 mod request_handler;
+mod snapshot;
+mod sp_parser;
 mod state_proof;
 mod types;
 
+pub(crate) use self::ledger_replica::{parse_ledger_type, LedgerReplica};
+pub(crate) use self::sp_parser::{get_sp_parser, register_sp_parser};
+pub use self::sp_parser::StateProofParser;
+
 lazy_static! {
-    //static ref REGISTERED_SP_PARSERS: Mutex<HashMap<String, (CustomTransactionParser, CustomFree)>> = Mutex::new(HashMap::new()); FXIME: !!!
     static ref POOL_HANDLE_SENDERS: Arc<Mutex<HashMap<PoolHandle, oneshot::Sender<IndyResult<PoolHandle>>>>> = Arc::new(Mutex::new(HashMap::new()));
     static ref SUBMIT_SENDERS: Arc<Mutex<HashMap<PoolHandle, oneshot::Sender<IndyResult<String>>>>> = Arc::new(Mutex::new(HashMap::new()));
     static ref CLOSE_SENDERS: Arc<Mutex<HashMap<PoolHandle, oneshot::Sender<IndyResult<()>>>>> = Arc::new(Mutex::new(HashMap::new()));
@@ -51,6 +62,7 @@ type Nodes = HashMap<String, Option<VerKey>>;
 pub(crate) struct PoolService {
     open_pools: Mutex<HashMap<PoolHandle, Arc<ZMQPool>>>,
     pending_pools: Mutex<HashSet<String>>,
+    txn_author_agreements: Mutex<HashMap<PoolHandle, TxnAuthorAgreementState>>,
 }
 
 impl PoolService {
@@ -58,6 +70,7 @@ impl PoolService {
         PoolService {
             open_pools: Mutex::new(HashMap::new()),
             pending_pools: Mutex::new(HashSet::new()),
+            txn_author_agreements: Mutex::new(HashMap::new()),
         }
     }
 
@@ -191,6 +204,9 @@ impl PoolService {
 
         let config = config.unwrap_or_default();
 
+        let taa_state =
+            TxnAuthorAgreementState::new(config.taa_acceptance.clone(), config.taa_change_policy);
+
         let pool_handle: PoolHandle = next_pool_handle();
         let mut new_pool = Pool::new(&name, pool_handle, config);
 
@@ -213,6 +229,11 @@ impl PoolService {
                 new_pool.get_id(),
                 Arc::new(ZMQPool::new(new_pool, send_cmd_sock)),
             );
+
+            self.txn_author_agreements
+                .lock()
+                .await
+                .insert(pool_handle, taa_state);
         }
 
         res
@@ -239,6 +260,65 @@ impl PoolService {
         self.send_action(handle, msg, None, None).await
     }
 
+    /// Dispatches all requests with a single pool command. Each request gets its own command handle,
+    /// so results are reported independently and keep the order of `msgs`.
+    pub(crate) async fn send_txs_batch(
+        &self,
+        handle: PoolHandle,
+        msgs: &[String],
+    ) -> IndyResult<Vec<IndyResult<String>>> {
+        trace!("send_txs_batch >> msgs {:?}", msgs.len());
+
+        let receivers = {
+            let pools = self.open_pools.lock().await;
+
+            let pool = pools
+                .get(&handle)
+                .ok_or(err_msg(
+                    IndyErrorKind::InvalidPoolHandle,
+                    format!("No pool with requested handle {:?}", handle),
+                ))
+                .clone()?;
+
+            let mut cmd_ids = Vec::with_capacity(msgs.len());
+            let mut receivers = Vec::with_capacity(msgs.len());
+
+            for _ in msgs {
+                let cmd_id: CommandHandle = next_command_handle();
+
+                let (sender, receiver) = oneshot::channel::<IndyResult<String>>();
+                SUBMIT_SENDERS.lock().await.insert(cmd_id, sender);
+
+                cmd_ids.push(cmd_id);
+                receivers.push(receiver);
+            }
+
+            let res = self._send_batch(&cmd_ids, msgs, &pool.cmd_socket.lock().unwrap());
+
+            if let Err(err) = res {
+                let mut senders = SUBMIT_SENDERS.lock().await;
+
+                for cmd_id in cmd_ids {
+                    senders.remove(&cmd_id);
+                }
+
+                return Err(err);
+            }
+
+            receivers
+        };
+
+        let mut res = Vec::with_capacity(receivers.len());
+
+        for receiver in receivers {
+            res.push(receiver.await?);
+        }
+
+        trace!("send_txs_batch <<< {:?}", res);
+
+        Ok(res)
+    }
+
     pub(crate) async fn send_action(
         &self,
         handle: PoolHandle,
@@ -264,13 +344,18 @@ impl PoolService {
             let (sender, receiver) = oneshot::channel::<IndyResult<String>>();
             SUBMIT_SENDERS.lock().await.insert(cmd_id, sender);
 
-            self._send_msg(
+            let res = self._send_msg(
                 cmd_id,
                 msg,
                 &pool.cmd_socket.lock().unwrap(),
                 nodes,
                 timeout,
-            )?;
+            );
+
+            if let Err(err) = res {
+                SUBMIT_SENDERS.lock().await.remove(&cmd_id);
+                return Err(err);
+            }
 
             receiver
         };
@@ -289,33 +374,9 @@ impl PoolService {
         sender.send(result).unwrap(); //FIXME
     }
 
-    // FIXME: !!!
-
-    // pub fn register_sp_parser(txn_type: &str,
-    //                           parser: CustomTransactionParser, free: CustomFree) -> IndyResult<()> {
-    //     if events::REQUESTS_FOR_STATE_PROOFS.contains(&txn_type) {
-    //         return Err(err_msg(IndyErrorKind::InvalidStructure,
-    //                            format!("Try to override StateProof parser for default TXN_TYPE {}", txn_type)));
-    //     }
-
-    //     REGISTERED_SP_PARSERS.lock()
-    //         .map(|mut map| {
-    //             map.insert(txn_type.to_owned(), (parser, free));
-    //         })
-    //         .unwrap(); // FIXME: Can we avoid unwrap?
-
-    //     Ok(())
-    // }
-
-    // FIXME:!!!
-
-    // pub fn get_sp_parser(txn_type: &str) -> Option<(CustomTransactionParser, CustomFree)> {
-    //     let parsers = REGISTERED_SP_PARSERS.lock().unwrap(); // FIXME: Can we avoid unwrap here?
-    //     parsers.get(txn_type).map(Clone::clone)
-    // }
-
     pub(crate) async fn close(&self, handle: PoolHandle) -> IndyResult<()> {
         let pool = self.open_pools.lock().await.remove(&handle);
+        self.txn_author_agreements.lock().await.remove(&handle);
 
         let (sender, receiver) = oneshot::channel::<IndyResult<()>>();
         CLOSE_SENDERS.lock().await.insert(handle, sender);
@@ -348,8 +409,163 @@ impl PoolService {
 
     pub(crate) async fn refresh(&self, handle: PoolHandle) -> IndyResult<()> {
         self.send_action(handle, COMMAND_REFRESH, None, None)
+            .await?;
+
+        // Agreement is fetched again by the next write that needs it
+        if let Some(state) = self.txn_author_agreements.lock().await.get_mut(&handle) {
+            state.fetched = false;
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the active transaction author agreement and acceptance mechanisms from the ledger.
+    pub(crate) async fn refresh_txn_author_agreement(
+        &self,
+        handle: PoolHandle,
+    ) -> IndyResult<TxnAuthorAgreementState> {
+        let taa_response = self
+            .send_tx(handle, &author_agreement::build_get_txn_author_agreement_request()?)
+            .await?;
+
+        let aml_response = self
+            .send_tx(handle, &author_agreement::build_get_acceptance_mechanisms_request()?)
+            .await?;
+
+        let active =
+            author_agreement::parse_active_txn_author_agreement(&taa_response, &aml_response)?;
+
+        let mut states = self.txn_author_agreements.lock().await;
+
+        let state = states.get_mut(&handle).ok_or(err_msg(
+            IndyErrorKind::InvalidPoolHandle,
+            format!("No pool with requested handle {:?}", handle),
+        ))?;
+
+        state.active = active;
+        state.fetched = true;
+
+        Ok(state.clone())
+    }
+
+    /// Returns the transaction author agreement acceptance set in the pool open config.
+    pub(crate) async fn get_txn_author_agreement_acceptance(
+        &self,
+        handle: PoolHandle,
+    ) -> IndyResult<Option<TxnAuthorAgreementAcceptance>> {
+        self.txn_author_agreements
+            .lock()
+            .await
+            .get(&handle)
+            .map(|state| state.acceptance.clone())
+            .ok_or(err_msg(
+                IndyErrorKind::InvalidPoolHandle,
+                format!("No pool with requested handle {:?}", handle),
+            ))
+    }
+
+    /// Returns the cached transaction author agreement state. It is fetched from the ledger
+    /// on the first call for the pool and after the pool refresh.
+    pub(crate) async fn get_txn_author_agreement(
+        &self,
+        handle: PoolHandle,
+    ) -> IndyResult<TxnAuthorAgreementState> {
+        let state = self
+            .txn_author_agreements
+            .lock()
             .await
-            .map(|_| ())
+            .get(&handle)
+            .cloned()
+            .ok_or(err_msg(
+                IndyErrorKind::InvalidPoolHandle,
+                format!("No pool with requested handle {:?}", handle),
+            ))?;
+
+        if state.fetched {
+            Ok(state)
+        } else {
+            self.refresh_txn_author_agreement(handle).await
+        }
+    }
+
+    pub(crate) async fn get_status(&self, handle: PoolHandle) -> IndyResult<String> {
+        self.send_action(handle, COMMAND_STATUS, None, None).await
+    }
+
+    /// Exports the verified pool ledger cache with a multi-signed proof of its last transaction.
+    pub(crate) async fn export_snapshot(&self, handle: PoolHandle, path: &str) -> IndyResult<()> {
+        let pool_name = self.get_name(handle).await?;
+        let merkle = merkle_tree_factory::create(&pool_name)?;
+
+        let request = PoolLedgerSnapshot::build_proof_request(&merkle)?;
+        let proof = self.send_tx(handle, &request).await?;
+
+        let snapshot = PoolLedgerSnapshot::new(&merkle, &proof)?;
+        snapshot.verify(&merkle)?;
+
+        snapshot.to_file(path)
+    }
+
+    /// Imports pool ledger snapshot into the pool config, creating it from `config` if it doesn't exist.
+    ///
+    /// The snapshot must extend the pool ledger known to the config (its genesis or cached transactions)
+    /// and be signed by its validators, so a snapshot of another pool can't be imported.
+    /// The newer cache is kept if the snapshot is older.
+    pub(crate) async fn import_snapshot(
+        &self,
+        name: &str,
+        path: &str,
+        config: Option<PoolConfig>,
+    ) -> IndyResult<()> {
+        if self
+            .open_pools
+            .lock()
+            .await
+            .values()
+            .find(|pool| pool.pool.lock().unwrap().get_name().eq(name))
+            .is_some()
+        {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Can't import pool ledger snapshot - pool is open now",
+            ));
+        }
+
+        let snapshot = PoolLedgerSnapshot::from_file(path)?;
+
+        let created = !environment::pool_path(name).exists();
+
+        if created {
+            self.create(name, config)?;
+        }
+
+        let res = merkle_tree_factory::create(name).and_then(|merkle| {
+            let txns = snapshot.verify(&merkle)?;
+
+            if merkle.count() < txns.len() {
+                merkle_tree_factory::replace_stored(name, &txns)?;
+            }
+
+            Ok(())
+        });
+
+        if res.is_err() && created {
+            fs::remove_dir_all(environment::pool_path(name)).ok();
+        }
+
+        res
+    }
+
+    pub(crate) async fn get_name(&self, handle: PoolHandle) -> IndyResult<String> {
+        let pools = self.open_pools.lock().await;
+
+        let pool = pools.get(&handle).ok_or(err_msg(
+            IndyErrorKind::InvalidPoolHandle,
+            format!("No pool with requested handle {:?}", handle),
+        ))?;
+
+        let name = pool.pool.lock().unwrap().get_name().to_string();
+        Ok(name)
     }
 
     fn _send_msg(
@@ -375,6 +591,19 @@ impl PoolService {
         }
     }
 
+    fn _send_batch(&self, cmd_ids: &[CommandHandle], msgs: &[String], socket: &Socket) -> IndyResult<()> {
+        let mut parts: Vec<Vec<u8>> = vec![COMMAND_BATCH.as_bytes().to_vec()];
+
+        for (cmd_id, msg) in cmd_ids.iter().zip(msgs) {
+            let mut buf = [0u8; 4];
+            LittleEndian::write_i32(&mut buf, *cmd_id);
+            parts.push(buf.to_vec());
+            parts.push(msg.as_bytes().to_vec());
+        }
+
+        Ok(socket.send_multipart(parts, zmq::DONTWAIT)?)
+    }
+
     pub(crate) fn list(&self) -> IndyResult<Vec<serde_json::Value>> {
         let mut pool = Vec::new();
         let pool_home_path = environment::pool_home_path();
@@ -659,6 +888,86 @@ pub mod tests {
             pool_mock.join().unwrap();
         }
 
+        #[async_std::test]
+        async fn pool_service_send_txs_batch_works() {
+            test::cleanup_storage("pool_service_send_txs_batch_works");
+
+            let ps = PoolService::new();
+            let pool_id = next_pool_handle();
+            let (send_cmd_sock, recv_cmd_sock) =
+                pool_create_pair_of_sockets("pool_service_send_txs_batch_works");
+
+            ps.open_pools.lock().await.insert(
+                pool_id,
+                Arc::new(ZMQPool::new(
+                    Pool::new("", pool_id, PoolOpenConfig::default()),
+                    send_cmd_sock,
+                )),
+            );
+
+            let pool_mock = thread::spawn(move || {
+                assert_eq!(
+                    1,
+                    zmq::poll(&mut [recv_cmd_sock.as_poll_item(zmq::POLLIN)], 10_000).unwrap()
+                );
+                let recv = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
+                assert_eq!(recv.len(), 5);
+                assert_eq!(COMMAND_BATCH, String::from_utf8(recv[0].clone()).unwrap());
+                assert_eq!("req_1", String::from_utf8(recv[2].clone()).unwrap());
+                assert_eq!("req_2", String::from_utf8(recv[4].clone()).unwrap());
+                let cmd_id_1 = LittleEndian::read_i32(recv[1].as_slice());
+                let cmd_id_2 = LittleEndian::read_i32(recv[3].as_slice());
+                block_on(PoolService::submit_ack(
+                    cmd_id_2,
+                    Err(err_msg(IndyErrorKind::PoolTimeout, "timeout")),
+                ));
+                block_on(PoolService::submit_ack(cmd_id_1, Ok("reply_1".to_string())));
+            });
+
+            let res = ps
+                .send_txs_batch(pool_id, &["req_1".to_string(), "req_2".to_string()])
+                .await
+                .unwrap();
+            pool_mock.join().unwrap();
+
+            assert_eq!(2, res.len());
+            assert_eq!("reply_1", res[0].as_ref().unwrap());
+            assert_eq!(IndyErrorKind::PoolTimeout, res[1].as_ref().unwrap_err().kind());
+        }
+
+        #[async_std::test]
+        async fn pool_service_get_status_works() {
+            test::cleanup_storage("pool_service_get_status_works");
+
+            let ps = PoolService::new();
+            let pool_id = next_pool_handle();
+            let (send_cmd_sock, recv_cmd_sock) =
+                pool_create_pair_of_sockets("pool_service_get_status_works");
+
+            ps.open_pools.lock().await.insert(
+                pool_id,
+                Arc::new(ZMQPool::new(
+                    Pool::new("", pool_id, PoolOpenConfig::default()),
+                    send_cmd_sock,
+                )),
+            );
+
+            let pool_mock = thread::spawn(move || {
+                assert_eq!(
+                    1,
+                    zmq::poll(&mut [recv_cmd_sock.as_poll_item(zmq::POLLIN)], 10_000).unwrap()
+                );
+                let recv = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
+                assert_eq!(recv.len(), 3);
+                assert_eq!(COMMAND_STATUS, String::from_utf8(recv[0].clone()).unwrap());
+                let cmd_id = LittleEndian::read_i32(recv[1].as_slice());
+                block_on(PoolService::submit_ack(cmd_id, Ok("{}".to_string())));
+            });
+
+            assert_eq!("{}", ps.get_status(pool_id).await.unwrap());
+            pool_mock.join().unwrap();
+        }
+
         #[async_std::test]
         async fn pool_service_delete_works() {
             test::cleanup_storage("pool_service_delete_works");
@@ -759,6 +1068,28 @@ pub mod tests {
             assert_eq!(IndyErrorKind::IOError, res.unwrap_err().kind());
         }
 
+        #[async_std::test]
+        async fn pool_send_txs_batch_works_for_closed_socket() {
+            test::cleanup_storage("pool_send_txs_batch_works_for_closed_socket");
+
+            let zmq_ctx = zmq::Context::new();
+            let send_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR).unwrap();
+
+            let pool_id = next_pool_handle();
+            let pool = Pool::new("test", pool_id, PoolOpenConfig::default());
+            let ps = PoolService::new();
+
+            ps.open_pools
+                .lock()
+                .await
+                .insert(pool_id, Arc::new(ZMQPool::new(pool, send_cmd_sock)));
+
+            let res = ps
+                .send_txs_batch(pool_id, &["req_1".to_string(), "req_2".to_string()])
+                .await;
+            assert_eq!(IndyErrorKind::IOError, res.unwrap_err().kind());
+        }
+
         #[async_std::test]
         async fn pool_send_tx_works_for_invalid_handle() {
             test::cleanup_storage("pool_send_tx_works_for_invalid_handle");
@@ -824,49 +1155,6 @@ pub mod tests {
             let res = block_on(ps.refresh(INVALID_POOL_HANDLE));
             assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
         }
-
-        // FIXME:
-        // #[test]
-        // fn pool_register_sp_parser_works() {
-        //     test::cleanup_storage("pool_register_sp_parser_works");
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
-        //     extern "C" fn test_sp(
-        //         _reply_from_node: *const c_char,
-        //         _parsed_sp: *mut *const c_char,
-        //     ) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     extern "C" fn test_free(_data: *const c_char) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     PoolService::register_sp_parser("test", test_sp, test_free).unwrap();
-        // }
-
-        // FIMXE:
-        // #[test]
-        // fn pool_get_sp_parser_works() {
-        //     test::cleanup_storage("pool_get_sp_parser_works");
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
-        //     extern "C" fn test_sp(
-        //         _reply_from_node: *const c_char,
-        //         _parsed_sp: *mut *const c_char,
-        //     ) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     extern "C" fn test_free(_data: *const c_char) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     PoolService::register_sp_parser("test", test_sp, test_free).unwrap();
-        //     PoolService::get_sp_parser("test").unwrap();
-        // }
-
-        // FIXME:
-        // #[test]
-        // fn pool_get_sp_parser_works_for_invalid_name() {
-        //     test::cleanup_storage("pool_get_sp_parser_works_for_invalid_name");
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
-        //     assert_eq!(None, PoolService::get_sp_parser("test"));
-        // }
     }
 
     #[async_std::test]
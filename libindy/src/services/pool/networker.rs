@@ -22,6 +22,7 @@ pub trait Networker {
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent>;
     fn get_timeout(&self) -> ((String, String), i64);
     fn get_poll_items(&self) -> Vec<PollItem>;
+    fn get_nodes_status(&self) -> Vec<NodeStatus>;
 }
 
 pub struct ZMQNetworker {
@@ -31,6 +32,36 @@ pub struct ZMQNetworker {
     active_timeout: i64,
     conn_limit: usize,
     preordered_nodes: Vec<String>,
+    health: Mutex<HashMap<String, NodeHealth>>,
+}
+
+/// Weight of the previous value in the rolling latency average (out of 8).
+const LATENCY_HISTORY_WEIGHT: i64 = 7;
+
+#[derive(Default)]
+struct NodeHealth {
+    last_reply: Option<Tm>,
+    latency_ms: Option<i64>,
+    timeouts: u64,
+    consecutive_timeouts: u64,
+}
+
+impl NodeHealth {
+    fn on_reply(&mut self, latency_ms: Option<i64>) {
+        self.last_reply = Some(time::now());
+        self.consecutive_timeouts = 0;
+        if let Some(sample) = latency_ms {
+            self.latency_ms = Some(match self.latency_ms {
+                Some(latency) => (latency * LATENCY_HISTORY_WEIGHT + sample) / (LATENCY_HISTORY_WEIGHT + 1),
+                None => sample
+            });
+        }
+    }
+
+    fn on_timeout(&mut self) {
+        self.timeouts += 1;
+        self.consecutive_timeouts += 1;
+    }
 }
 
 impl Networker for ZMQNetworker {
@@ -42,6 +73,7 @@ impl Networker for ZMQNetworker {
             active_timeout,
             conn_limit,
             preordered_nodes,
+            health: Mutex::new(HashMap::new()),
         }
     }
 
@@ -50,7 +82,13 @@ impl Networker for ZMQNetworker {
         self.pool_connections.iter().map(|(_, pc)| {
             let ocnt = cnt;
             cnt += pc.sockets.iter().filter(|s| s.is_some()).count();
-            pc.fetch_events(&poll_items[ocnt..cnt])
+            let events = pc.fetch_events(&poll_items[ocnt..cnt]);
+            events.iter().for_each(|event| {
+                if let PoolEvent::NodeReply(ref msg, ref node_alias) = *event {
+                    self._on_node_reply(pc, msg, node_alias);
+                }
+            });
+            events
         }).flat_map(|v| v.into_iter()).collect()
     }
 
@@ -137,6 +175,18 @@ impl Networker for ZMQNetworker {
 
                 None
             }
+            Some(NetworkerEvent::NodeTimeout(req_id, node_alias)) => {
+                let missed = self.req_id_mappings.get(&req_id)
+                    .and_then(|idx| self.pool_connections.get(idx))
+                    .map(|pc| pc.take_sent(&req_id, &node_alias).is_some())
+                    .unwrap_or(false);
+
+                if missed {
+                    self.health.lock().unwrap().entry(node_alias).or_default().on_timeout();
+                }
+
+                None
+            }
             Some(NetworkerEvent::Timeout) => {
                 let pc_to_delete: Vec<i32> = self.pool_connections.iter()
                     .filter(|(_, v)| v.is_orphaned())
@@ -163,6 +213,41 @@ impl Networker for ZMQNetworker {
         self.pool_connections.iter()
             .flat_map(|(_, pool)| pool.get_poll_items()).collect()
     }
+
+    fn get_nodes_status(&self) -> Vec<NodeStatus> {
+        let health = self.health.lock().unwrap();
+
+        self.nodes.iter().map(|node| {
+            let connected = self.pool_connections.values().any(|pc| pc.is_connected(&node.name));
+            let node_health = health.get(&node.name);
+
+            NodeStatus {
+                alias: node.name.clone(),
+                address: node.zaddr.clone(),
+                blacklisted: node.is_blacklisted,
+                connected,
+                last_reply_time: node_health.and_then(|h| h.last_reply).map(|tm| tm.to_timespec().sec),
+                latency_ms: node_health.and_then(|h| h.latency_ms),
+                timeouts: node_health.map(|h| h.timeouts).unwrap_or(0),
+                consecutive_timeouts: node_health.map(|h| h.consecutive_timeouts).unwrap_or(0),
+            }
+        }).collect()
+    }
+}
+
+impl ZMQNetworker {
+    /// REQACK only confirms that a request was received, so the request stays unanswered until the final reply.
+    fn _on_node_reply(&self, pc: &PoolConnection, msg: &str, node_alias: &str) {
+        let latency = match Message::from_raw_str(msg) {
+            Ok(Message::ReqACK(_)) => return,
+            Ok(Message::Reply(ref reply)) => pc.take_latency(&reply.req_id().to_string(), node_alias),
+            Ok(Message::ReqNACK(ref response)) | Ok(Message::Reject(ref response)) =>
+                pc.take_latency(&response.req_id().to_string(), node_alias),
+            _ => None
+        };
+
+        self.health.lock().unwrap().entry(node_alias.to_string()).or_default().on_reply(latency);
+    }
 }
 
 pub struct PoolConnection {
@@ -172,6 +257,7 @@ pub struct PoolConnection {
     key_pair: zmq::CurveKeyPair,
     resend: Mutex<HashMap<String, (usize, String)>>,
     timeouts: Mutex<HashMap<(String, String), Tm>>,
+    sent: Mutex<HashMap<(String, String), Tm>>,
     time_created: time::Tm,
     req_cnt: usize,
     active_timeout: i64,
@@ -203,6 +289,7 @@ impl PoolConnection {
             resend: Mutex::new(HashMap::new()),
             time_created: time::now(),
             timeouts: Mutex::new(HashMap::new()),
+            sent: Mutex::new(HashMap::new()),
             req_cnt: 0,
             active_timeout,
         }
@@ -299,16 +386,35 @@ impl PoolConnection {
     fn clean_timeout(&self, req_id: &str, node_alias: Option<String>) {
         match node_alias {
             Some(node_alias) => {
-                self.timeouts.lock().unwrap().remove(&(req_id.to_string(), node_alias));
+                let key = (req_id.to_string(), node_alias);
+                self.timeouts.lock().unwrap().remove(&key);
+                self.sent.lock().unwrap().remove(&key);
             }
             None => {
                 let keys_to_remove: Vec<(String, String)> = self.timeouts.lock().unwrap().keys()
                     .cloned().filter(|&(ref req_id_timeout, _)| req_id == req_id_timeout).collect();
                 keys_to_remove.iter().for_each(|key| { self.timeouts.lock().unwrap().remove(key); });
+                self.sent.lock().unwrap().retain(|&(ref req_id_sent, _), _| req_id != req_id_sent);
             }
         }
     }
 
+    /// Forgets that a request was sent to the node. Returns the time of sending if the node has not answered yet.
+    fn take_sent(&self, req_id: &str, node_alias: &str) -> Option<Tm> {
+        self.sent.lock().unwrap().remove(&(req_id.to_string(), node_alias.to_string()))
+    }
+
+    /// Returns the time passed since the request was sent to the node and forgets about the request.
+    fn take_latency(&self, req_id: &str, node_alias: &str) -> Option<i64> {
+        self.take_sent(req_id, node_alias)
+            .map(|sent_time| (time::now() - sent_time).num_milliseconds())
+    }
+
+    fn is_connected(&self, node_alias: &str) -> bool {
+        self.nodes.iter().zip(self.sockets.iter())
+            .any(|(node, socket)| node.name == node_alias && socket.is_some())
+    }
+
     fn has_active_requests(&self) -> bool {
         !self.timeouts.lock().unwrap().is_empty()
     }
@@ -323,7 +429,10 @@ impl PoolConnection {
             let s = self._get_socket(idx)?;
             s.send(&req, zmq::DONTWAIT)?;
         }
-        self.timeouts.lock().unwrap().insert((req_id, self.nodes[idx].name.clone()), time::now() + Duration::seconds(timeout));
+        let now = time::now();
+        let key = (req_id, self.nodes[idx].name.clone());
+        self.sent.lock().unwrap().entry(key.clone()).or_insert(now);
+        self.timeouts.lock().unwrap().insert(key, now + Duration::seconds(timeout));
         trace!("_send_msg_to_one_node <<");
         Ok(())
     }
@@ -356,6 +465,7 @@ impl RemoteNode {
 #[cfg(test)]
 pub struct MockNetworker {
     pub events: Vec<Option<NetworkerEvent>>,
+    pub nodes: Vec<RemoteNode>,
 }
 
 #[cfg(test)]
@@ -363,6 +473,7 @@ impl Networker for MockNetworker {
     fn new(_active_timeout: i64, _conn_limit: usize, _preordered_nodes: Vec<String>) -> Self {
         MockNetworker {
             events: Vec::new(),
+            nodes: Vec::new(),
         }
     }
 
@@ -371,6 +482,9 @@ impl Networker for MockNetworker {
     }
 
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
+        if let Some(NetworkerEvent::NodesStateUpdated(ref nodes)) = pe {
+            self.nodes = nodes.clone();
+        }
         self.events.push(pe);
         None
     }
@@ -382,6 +496,19 @@ impl Networker for MockNetworker {
     fn get_poll_items(&self) -> Vec<PollItem> {
         unimplemented!()
     }
+
+    fn get_nodes_status(&self) -> Vec<NodeStatus> {
+        self.nodes.iter().map(|node| NodeStatus {
+            alias: node.name.clone(),
+            address: node.zaddr.clone(),
+            blacklisted: node.is_blacklisted,
+            connected: false,
+            last_reply_time: None,
+            latency_ms: None,
+            timeouts: 0,
+            consecutive_timeouts: 0,
+        }).collect()
+    }
 }
 
 
@@ -682,6 +809,98 @@ pub mod networker_tests {
             assert_eq!(2, networker.pool_connections.len());
         }
 
+        #[test]
+        fn networker_process_node_timeout_event_works() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+            let alias = txn.txn.data.data.alias;
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+            networker.process_event(Some(NetworkerEvent::NodeTimeout(REQ_ID.to_string(), alias.clone())));
+            // repeated timeout for the same request is counted once
+            networker.process_event(Some(NetworkerEvent::NodeTimeout(REQ_ID.to_string(), alias.clone())));
+
+            let status = networker.get_nodes_status();
+            assert_eq!(1, status.len());
+            assert_eq!(alias, status[0].alias);
+            assert_eq!(1, status[0].timeouts);
+            assert_eq!(1, status[0].consecutive_timeouts);
+            assert!(!status[0].is_reachable());
+        }
+
+        #[test]
+        fn networker_node_timeout_after_reqack_works() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+            let alias = txn.txn.data.data.alias;
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+            let ack = json!({"op": "REQACK", "reqId": 1}).to_string();
+            networker._on_node_reply(networker.pool_connections.values().next().unwrap(), &ack, &alias);
+
+            networker.process_event(Some(NetworkerEvent::NodeTimeout(REQ_ID.to_string(), alias.clone())));
+
+            let status = networker.get_nodes_status();
+            assert_eq!(1, status[0].timeouts);
+            assert_eq!(None, status[0].last_reply_time);
+        }
+
+        #[test]
+        fn networker_node_reply_consumes_only_its_request() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+            let alias = txn.txn.data.data.alias;
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "2".to_string(), POOL_ACK_TIMEOUT)));
+
+            let reply = json!({"op": "REPLY", "result": {"reqId": 2}}).to_string();
+            networker._on_node_reply(networker.pool_connections.values().next().unwrap(), &reply, &alias);
+
+            let pc = networker.pool_connections.values().next().unwrap();
+            assert!(pc.take_sent("2", &alias).is_none());
+            assert!(pc.take_sent(REQ_ID, &alias).is_some());
+
+            let status = networker.get_nodes_status();
+            assert!(status[0].latency_ms.is_some());
+            assert!(status[0].last_reply_time.is_some());
+        }
+
+        #[test]
+        fn networker_get_nodes_status_works() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn.clone()])));
+
+            let status = networker.get_nodes_status();
+            assert_eq!(vec![NodeStatus {
+                alias: rn.name.clone(),
+                address: rn.zaddr.clone(),
+                blacklisted: false,
+                connected: false,
+                last_reply_time: None,
+                latency_ms: None,
+                timeouts: 0,
+                consecutive_timeouts: 0,
+            }], status);
+
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+            let status = networker.get_nodes_status();
+            assert!(status[0].connected);
+            assert!(status[0].is_reachable());
+        }
+
         #[test]
         fn networker_get_timeout_works() {
             let txn = nodes_emulator::node();
@@ -873,6 +1092,37 @@ pub mod networker_tests {
             assert!(!conn.has_active_requests());
         }
 
+        #[test]
+        fn pool_connection_take_latency_works() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+
+            assert_eq!(None, conn.take_latency(REQ_ID, NODE_NAME));
+
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+
+            assert_eq!(None, conn.take_latency("2", &txn.txn.data.data.alias));
+
+            let latency = conn.take_latency(REQ_ID, &txn.txn.data.data.alias).unwrap();
+            assert!(latency >= 0);
+            assert_eq!(None, conn.take_latency(REQ_ID, &txn.txn.data.data.alias));
+        }
+
+        #[test]
+        fn pool_connection_clean_timeout_forgets_sent_requests() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.clean_timeout(REQ_ID, None);
+
+            assert!(conn.take_sent(REQ_ID, &txn.txn.data.data.alias).is_none());
+        }
+
         #[test]
         fn pool_connection_get_socket_works() {
             let txn = nodes_emulator::node();
@@ -17,7 +17,7 @@ use crate::services::pool::{merkle_tree_factory, Nodes, PoolService};
 use crate::services::pool::networker::{Networker, ZMQNetworker};
 use crate::services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
 use rust_base58::{FromBase58, ToBase58};
-use crate::services::pool::types::{LedgerStatus, RemoteNode};
+use crate::services::pool::types::{LedgerStatus, PoolStatus, RemoteNode};
 use indy_utils::crypto::ed25519_sign;
 
 use ursa::bls::VerKey;
@@ -30,6 +30,7 @@ struct PoolSM<T: Networker, R: RequestHandler<T>> {
     timeout: i64,
     extended_timeout: i64,
     number_read_nodes: u8,
+    merkle_tree_info: Option<(usize, String)>, // size and root of the last synced pool ledger
     state: PoolState<T, R>,
 }
 
@@ -87,14 +88,15 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
             timeout,
             extended_timeout,
             number_read_nodes,
+            merkle_tree_info: None,
             state: PoolState::Initialization(InitializationState {
                 networker
             }),
         }
     }
 
-    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, state: PoolState<T, R>) -> Self {
-        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, state }
+    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, merkle_tree_info: Option<(usize, String)>, state: PoolState<T, R>) -> Self {
+        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, merkle_tree_info, state }
     }
 }
 
@@ -263,7 +265,20 @@ impl<T: Networker> From<TerminatedState<T>> for ClosedState {
 
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
     pub fn handle_event(self, pe: PoolEvent) -> Self {
-        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes } = self;
+        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes, mut merkle_tree_info } = self;
+
+        if let PoolEvent::GetStatus(cmd_id) = pe {
+            _send_submit_ack(cmd_id, _get_pool_status(&state, &merkle_tree_info));
+            return PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, merkle_tree_info, state);
+        }
+
+        if let PoolEvent::SendRequestsBatch(requests) = pe {
+            let pool_sm = PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, merkle_tree_info, state);
+            return requests.into_iter().fold(pool_sm, |pool_sm, (cmd_id, request)| {
+                pool_sm.handle_event(PoolEvent::SendRequest(cmd_id, request, None, None))
+            });
+        }
+
         let state = match state {
             PoolState::Initialization(state) => match pe {
                 PoolEvent::CheckCache(cmd_id) => {
@@ -321,6 +336,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         }
                     }
                     PoolEvent::Synced(merkle) => {
+                        merkle_tree_info = Some(_merkle_tree_info(&merkle));
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle) {
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
@@ -429,6 +445,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     }
                     PoolEvent::NodesBlacklisted => PoolState::Terminated(state.into()),
                     PoolEvent::Synced(merkle) => {
+                        merkle_tree_info = Some(_merkle_tree_info(&merkle));
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle).map_err(map_err_err!()) {
                             state.networker.lock().unwrap().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
@@ -441,7 +458,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                 }
             }
         };
-        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, state)
+        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, merkle_tree_info, state)
     }
 
     pub fn is_terminal(&self) -> bool {
@@ -567,7 +584,7 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
 
     fn _poll(&mut self) {
         let events = {
-            let networker = self.networker.lock().unwrap();
+            let mut networker = self.networker.lock().unwrap();
 
             let mut poll_items = networker.get_poll_items();
             //            trace!("prevents: {:?}", poll_items.iter().map(|pi| pi.revents));
@@ -580,6 +597,11 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
                 .map_err(map_err_err!())
                 .map_err(|_| unimplemented!() /* FIXME */).unwrap();
             //            trace!("poll_res: {:?}", poll_res);
+            let node_timeout = if poll_res == 0 && !alias.is_empty() {
+                Some(NetworkerEvent::NodeTimeout(req_id.clone(), alias.clone()))
+            } else {
+                None
+            };
             if poll_res == 0 {
                 self.events.push_back(PoolEvent::Timeout(req_id, alias)); // TODO check duplicate ?
             }
@@ -592,6 +614,10 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
                 events.extend(self.commander.fetch_events());
             }
 
+            if node_timeout.is_some() {
+                networker.process_event(node_timeout);
+            }
+
             events
         };
 
@@ -647,6 +673,12 @@ fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus {
     }
 }
 
+pub(super) fn get_nodes_and_f(merkle: &MerkleTree) -> IndyResult<(Nodes, usize)> {
+    let (nodes, _) = _get_nodes_and_remotes(merkle)?;
+    let f = _get_f(nodes.len());
+    Ok((nodes, f))
+}
+
 fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(Nodes, Vec<RemoteNode>)> {
     let nodes = merkle_tree_factory::build_node_state(merkle)?;
 
@@ -715,6 +747,37 @@ fn _close_pool_ack(cmd_id: CommandHandle) {
     futures::executor::block_on(PoolService::close_ack(cmd_id, Ok(())));
 }
 
+fn _merkle_tree_info(merkle: &MerkleTree) -> (usize, String) {
+    (merkle.count(), merkle.root_hash().as_slice().to_base58())
+}
+
+fn _get_pool_status<T: Networker, R: RequestHandler<T>>(state: &PoolState<T, R>, merkle_tree_info: &Option<(usize, String)>) -> IndyResult<String> {
+    let (state_name, networker) = match state {
+        PoolState::Initialization(state) => ("initialization", Some(&state.networker)),
+        PoolState::GettingCatchupTarget(state) => ("gettingCatchupTarget", Some(&state.networker)),
+        PoolState::Active(state) => ("active", Some(&state.networker)),
+        PoolState::SyncCatchup(state) => ("syncCatchup", Some(&state.networker)),
+        PoolState::Terminated(state) => ("terminated", Some(&state.networker)),
+        PoolState::Closed(_) => ("closed", None),
+    };
+
+    let nodes = networker
+        .map(|networker| networker.lock().unwrap().get_nodes_status())
+        .unwrap_or_default();
+
+    let status = PoolStatus {
+        state: state_name.to_string(),
+        merkle_tree_size: merkle_tree_info.as_ref().map(|&(size, _)| size),
+        merkle_tree_root: merkle_tree_info.as_ref().map(|&(_, ref root)| root.clone()),
+        f: _get_f(nodes.len()),
+        reachable_nodes: nodes.iter().filter(|node| node.is_reachable()).count(),
+        nodes,
+    };
+
+    serde_json::to_string(&status)
+        .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool status")
+}
+
 fn _send_submit_ack(cmd_id: CommandHandle, res: IndyResult<String>) {
     futures::executor::block_on(PoolService::submit_ack(cmd_id, res));
 }
@@ -1159,6 +1222,46 @@ mod tests {
             test::cleanup_storage("pool_wrapper_active_send_request_works");
         }
 
+        #[async_std::test]
+        pub async fn pool_wrapper_active_send_requests_batch_works() {
+            test::cleanup_storage("pool_wrapper_active_send_requests_batch_works");
+
+            ProtocolVersion::set(2);
+            _write_genesis_txns("pool_wrapper_active_send_requests_batch_works");
+
+            let req_1 = json!({
+                "reqId": 1,
+                "operation": {
+                    "type": "105"
+                }
+            }).to_string();
+            let req_2 = json!({
+                "reqId": 2,
+                "operation": {
+                    "type": "105"
+                }
+            }).to_string();
+
+            let (pool_handle, recv) = fake_pool_handle_for_poolsm().await;
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_active_send_requests_batch_works", pool_handle, 0, 0, NUMBER_READ_NODES);
+            let cmd_id: CommandHandle = next_command_handle();
+            let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let _ = block_on(recv).unwrap();
+            let p = p.handle_event(PoolEvent::SendRequestsBatch(vec![(next_command_handle(), req_1), (next_command_handle(), req_2)]));
+            assert_match!(PoolState::Active(_), p.state);
+            match p.state {
+                PoolState::Active(state) => {
+                    assert_eq!(state.request_handlers.len(), 2);
+                    assert!(state.request_handlers.contains_key("1"));
+                    assert!(state.request_handlers.contains_key("2"));
+                }
+                _ => assert!(false)
+            };
+
+            test::cleanup_storage("pool_wrapper_active_send_requests_batch_works");
+        }
+
         #[async_std::test]
         pub async fn pool_wrapper_active_send_request_works_for_no_req_id() {
             test::cleanup_storage("pool_wrapper_active_send_request_works_for_no_req_id");
@@ -1394,6 +1497,58 @@ mod tests {
             test::cleanup_storage("pool_wrapper_active_node_reply_works_for_invalid_reply");
         }
 
+        #[async_std::test]
+        pub async fn pool_wrapper_get_status_works_for_initialization() {
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_get_status_works_for_initialization", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let (cmd_id, recv) = fake_cmd_id().await;
+            let p = p.handle_event(PoolEvent::GetStatus(cmd_id));
+            assert_match!(PoolState::Initialization(_), p.state);
+
+            let status: serde_json::Value = serde_json::from_str(&recv.await.unwrap().unwrap()).unwrap();
+            assert_eq!(json!({
+                "state": "initialization",
+                "merkleTreeSize": null,
+                "merkleTreeRoot": null,
+                "f": 0,
+                "reachableNodes": 0,
+                "nodes": []
+            }), status);
+        }
+
+        #[async_std::test]
+        pub async fn pool_wrapper_active_get_status_works() {
+            test::cleanup_storage("pool_wrapper_active_get_status_works");
+
+            ProtocolVersion::set(2);
+            _write_genesis_txns("pool_wrapper_active_get_status_works");
+
+            let mt = merkle_tree_factory::create("pool_wrapper_active_get_status_works").unwrap();
+            let (mt_size, mt_root) = _merkle_tree_info(&mt);
+
+            let (pool_handle, _recv) = fake_pool_handle_for_poolsm().await;
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_active_get_status_works", pool_handle, 0, 0, NUMBER_READ_NODES);
+            let cmd_id: CommandHandle = next_command_handle();
+            let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
+            let p = p.handle_event(PoolEvent::Synced(mt));
+            let (cmd_id, recv) = fake_cmd_id().await;
+            let p = p.handle_event(PoolEvent::GetStatus(cmd_id));
+            assert_match!(PoolState::Active(_), p.state);
+
+            let status: serde_json::Value = serde_json::from_str(&recv.await.unwrap().unwrap()).unwrap();
+            assert_eq!("active", status["state"]);
+            assert_eq!(mt_size as u64, status["merkleTreeSize"].as_u64().unwrap());
+            assert_eq!(mt_root, status["merkleTreeRoot"]);
+            assert_eq!(1, status["f"]);
+            assert_eq!(4, status["reachableNodes"]);
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(4, nodes.len());
+            assert!(nodes.iter().all(|node| node["address"].as_str().unwrap().starts_with("tcp://")));
+            assert!(nodes.iter().all(|node| node["timeouts"] == 0 && node["lastReplyTime"].is_null()));
+
+            test::cleanup_storage("pool_wrapper_active_get_status_works");
+        }
+
         fn _write_genesis_txns(pool_name: &str) {
             let txns = test::gen_txns().join("\n");
 
@@ -1216,6 +1216,9 @@ pub mod tests {
     mod single {
         use super::*;
 
+        use rust_base58::{FromBase58, ToBase58};
+        use ursa::bls::{Bls, MultiSignature, Signature, SignKey, VerKey};
+
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_consensus_reached() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_consensus_reached", 1, 2);
@@ -1225,8 +1228,6 @@ pub mod tests {
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
-        // TODO: FIXME: Re-check if required
-        #[allow(dead_code)]
         fn correct_state_proof_reply(timestamp: u64) -> String {
             json!({
                 "result": {
@@ -1244,18 +1245,16 @@ pub mod tests {
             }).to_string()
         }
 
-        // FIXME: !!!
-        // #[test]
-        // fn request_handler_process_reply_event_from_single_state_works_for_state_proof() {
-        //     set_freshness_threshold(600);
-        //     add_state_proof_parser();
-        //     let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof", 1, 2);
-        //     request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
-        //     request_handler.process_event(Some(
-        //         RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() - 300), NODE.to_string(), REQ_ID.to_string()))
-        //     );
-        //     assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
-        // }
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_state_proof() {
+            add_state_proof_parser();
+            let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof", 1, 2);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(
+                RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() - 10), NODE.to_string(), REQ_ID.to_string()))
+            );
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
 
         // FIMXE: !!!
         // #[test]
@@ -1270,25 +1269,130 @@ pub mod tests {
         //     assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         // }
 
-        // FIXME: !!!
-        // fn add_state_proof_parser() {
-        //     use crate::services::pool::REGISTERED_SP_PARSERS;
-        //     use indy_api_types::ErrorCode;
-        //     use libc::c_char;
-        //     use std::ffi::CString;
+        struct EmptyStateProofParser;
 
-        //     REGISTERED_SP_PARSERS.lock().unwrap().clear();
+        impl crate::services::pool::StateProofParser for EmptyStateProofParser {
+            fn parse(&self, _reply_from_node: &str) -> IndyResult<String> {
+                Ok("[]".to_string())
+            }
+        }
 
-        //     extern fn test_sp(_reply_from_node: *const c_char, parsed_sp: *mut *const c_char) -> ErrorCode {
-        //         let sp: CString = CString::new("[]").unwrap();
-        //         unsafe { *parsed_sp = sp.into_raw(); }
-        //         ErrorCode::Success
-        //     }
-        //     extern fn test_free(_data: *const c_char) -> ErrorCode {
-        //         ErrorCode::Success
-        //     }
-        //     PoolService::register_sp_parser("test", test_sp, test_free).unwrap();
-        // }
+        fn add_state_proof_parser() {
+            crate::services::pool::register_sp_parser("test", Arc::new(EmptyStateProofParser)).unwrap();
+        }
+
+        /// Takes the state proof from the reply as a plugin for a custom transaction type would do.
+        struct MultiSignedStateProofParser;
+
+        impl crate::services::pool::StateProofParser for MultiSignedStateProofParser {
+            fn parse(&self, reply_from_node: &str) -> IndyResult<String> {
+                let reply: SJsonValue = serde_json::from_str(reply_from_node)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Invalid reply")?;
+
+                let state_proof = &reply["result"]["state_proof"];
+
+                Ok(json!([{
+                    "root_hash": state_proof["root_hash"],
+                    "proof_nodes": state_proof["proof_nodes"],
+                    "multi_signature": state_proof["multi_signature"],
+                    "kvs_to_verify": {
+                        "type": "Simple",
+                        "kvs": [],
+                    },
+                }]).to_string())
+            }
+        }
+
+        const CUSTOM_TXN_TYPE: &str = "request_handler_custom_txn";
+        const ROOT_HASH: &str = "5BU5Rc3sRtTJB6tVprGiTSqiRaa9o6ei11MjH4Vu16ms";
+
+        fn _nodes_with_bls_keys(gen: &Generator) -> (Nodes, HashMap<String, SignKey>) {
+            let mut nodes: Nodes = HashMap::new();
+            let mut sign_keys = HashMap::new();
+
+            for node in &[NODE, NODE_2, NODE_3, NODE_4] {
+                let sign_key = SignKey::new(None).unwrap();
+                nodes.insert(node.to_string(), Some(VerKey::new(gen, &sign_key).unwrap()));
+                sign_keys.insert(node.to_string(), sign_key);
+            }
+
+            (nodes, sign_keys)
+        }
+
+        fn _multi_signed_reply(sign_keys: &HashMap<String, SignKey>, participants: &[&str]) -> String {
+            let value = json!({
+                "state_root_hash": ROOT_HASH,
+                "timestamp": _get_cur_time() - 10,
+            });
+
+            let message = rmp_serde::to_vec_named(&value).unwrap();
+
+            let signatures = participants
+                .iter()
+                .map(|node| Bls::sign(&message, &sign_keys[*node]).unwrap())
+                .collect::<Vec<Signature>>();
+
+            let signature = MultiSignature::new(&signatures.iter().collect::<Vec<&Signature>>()).unwrap();
+
+            json!({
+                "result": {
+                    "type": CUSTOM_TXN_TYPE,
+                    "ver": "1",
+                    "multiSignature": {
+                        "signedState": {
+                            "stateMetadata": {
+                                "timestamp": _get_cur_time() - 10
+                            }
+                        }
+                    },
+                    "state_proof": {
+                        "root_hash": ROOT_HASH,
+                        "proof_nodes": "",
+                        "multi_signature": {
+                            "participants": participants,
+                            "signature": signature.as_bytes().to_base58(),
+                            "value": value,
+                        }
+                    }
+                },
+                "op": "REPLY",
+            }).to_string()
+        }
+
+        fn _request_handler_with_bls_keys(pool_name: &str, nodes: &Nodes) -> RequestHandlerImpl<MockNetworker> {
+            let networker = Arc::new(Mutex::new(MockNetworker::new(0, 0, vec![])));
+            RequestHandlerImpl::new(networker, 1, &vec![], nodes, pool_name, 0, 0, NUMBER_READ_NODES)
+        }
+
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_custom_state_proof() {
+            crate::services::pool::register_sp_parser(CUSTOM_TXN_TYPE, Arc::new(MultiSignedStateProofParser)).unwrap();
+
+            let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+            let (nodes, sign_keys) = _nodes_with_bls_keys(&gen);
+
+            let mut request_handler = _request_handler_with_bls_keys("request_handler_process_reply_event_from_single_state_works_for_custom_state_proof", &nodes);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(
+                RequestEvent::Reply(Reply::default(), _multi_signed_reply(&sign_keys, &[NODE, NODE_2, NODE_3]), NODE.to_string(), REQ_ID.to_string()))
+            );
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_custom_state_proof_signed_by_less_than_n_minus_f() {
+            crate::services::pool::register_sp_parser(CUSTOM_TXN_TYPE, Arc::new(MultiSignedStateProofParser)).unwrap();
+
+            let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+            let (nodes, sign_keys) = _nodes_with_bls_keys(&gen);
+
+            let mut request_handler = _request_handler_with_bls_keys("request_handler_process_reply_event_from_single_state_works_for_custom_state_proof_signed_by_less_than_n_minus_f", &nodes);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(
+                RequestEvent::Reply(Reply::default(), _multi_signed_reply(&sign_keys, &[NODE, NODE_2]), NODE.to_string(), REQ_ID.to_string()))
+            );
+            assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
+        }
 
         // FIXME:
         // #[test]
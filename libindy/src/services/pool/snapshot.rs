@@ -0,0 +1,172 @@
+use std::fs;
+use std::io::Write;
+
+use rust_base58::FromBase58;
+use serde_json;
+use serde_json::Value as SJsonValue;
+use ursa::bls::Generator;
+
+use indy_api_types::errors::prelude::*;
+use crate::domain::ledger::request::Request;
+use crate::domain::ledger::txn::{GetTxnOperation, LedgerType};
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::pool::ledger_replica::parse_audit_proof;
+use crate::services::pool::pool::get_nodes_and_f;
+use crate::services::pool::request_handler::DEFAULT_GENERATOR;
+use crate::services::pool::state_proof;
+
+const SNAPSHOT_VERSION: &str = "1";
+
+/// Portable copy of the pool ledger.
+///
+/// `proof` is a GET_TXN reply for the last transaction of the snapshot. Its audit path and
+/// multi-signed ledger root prove that the snapshot is a prefix of the ledger signed by the pool.
+#[derive(Serialize, Deserialize, Debug)]
+pub(super) struct PoolLedgerSnapshot {
+    pub ver: String,
+    pub txns: Vec<SJsonValue>,
+    pub proof: SJsonValue,
+}
+
+impl PoolLedgerSnapshot {
+    pub(super) fn new(merkle: &MerkleTree, proof: &str) -> IndyResult<PoolLedgerSnapshot> {
+        let txns = merkle
+            .iter()
+            .map(|txn| {
+                rmp_serde::decode::from_slice(txn.as_slice())
+                    .to_indy(IndyErrorKind::InvalidState, "MerkleTree contains invalid item")
+            })
+            .collect::<IndyResult<Vec<SJsonValue>>>()?;
+
+        let proof = serde_json::from_str(proof)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Invalid GET_TXN reply")?;
+
+        Ok(PoolLedgerSnapshot {
+            ver: SNAPSHOT_VERSION.to_string(),
+            txns,
+            proof,
+        })
+    }
+
+    /// Builds GET_TXN request for the last transaction of `merkle` which reply is used as the snapshot proof.
+    pub(super) fn build_proof_request(merkle: &MerkleTree) -> IndyResult<String> {
+        Request::build_request(None, GetTxnOperation::new(merkle.count() as i32, LedgerType::POOL.to_id()))
+            .map_err(|err| err_msg(IndyErrorKind::InvalidState, err))
+    }
+
+    pub(super) fn from_file(path: &str) -> IndyResult<PoolLedgerSnapshot> {
+        let file = fs::File::open(path)
+            .to_indy(IndyErrorKind::IOError, format!("Can't open pool ledger snapshot file {:?}", path))?;
+
+        let snapshot: PoolLedgerSnapshot = serde_json::from_reader(file)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid pool ledger snapshot")?;
+
+        if snapshot.ver != SNAPSHOT_VERSION {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                format!("Unsupported pool ledger snapshot version: {}", snapshot.ver),
+            ));
+        }
+
+        Ok(snapshot)
+    }
+
+    pub(super) fn to_file(&self, path: &str) -> IndyResult<()> {
+        let snapshot = serde_json::to_vec(self)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool ledger snapshot")?;
+
+        let mut file = fs::File::create(path)
+            .to_indy(IndyErrorKind::IOError, format!("Can't create pool ledger snapshot file {:?}", path))?;
+
+        file.write_all(&snapshot)
+            .to_indy(IndyErrorKind::IOError, "Can't write to pool ledger snapshot file")?;
+
+        file.sync_all()
+            .to_indy(IndyErrorKind::IOError, "Can't write to pool ledger snapshot file")
+    }
+
+    /// Verifies the snapshot against the `trusted` pool ledger and returns transactions encoded as pool ledger cache entries.
+    ///
+    /// The snapshot must extend the trusted ledger. Its proof must be multi-signed by the validators
+    /// that NODE transactions of the snapshot define at the proven seqNo, as validators may have
+    /// been rotated since the trusted ledger.
+    pub(super) fn verify(&self, trusted: &MerkleTree) -> IndyResult<Vec<Vec<u8>>> {
+        let txns = self.txns
+            .iter()
+            .map(|txn| {
+                rmp_serde::encode::to_vec_named(txn)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't encode pool ledger transaction as message pack")
+            })
+            .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+        let last_txn = match txns.last() {
+            Some(txn) => txn.clone(),
+            None => return Err(err_msg(IndyErrorKind::InvalidStructure, "Pool ledger snapshot is empty")),
+        };
+
+        if !is_consistent(trusted, &txns)? {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Pool ledger snapshot doesn't extend the pool ledger",
+            ));
+        }
+
+        let merkle = MerkleTree::from_vec(txns.clone())?;
+        let seq_no = merkle.count();
+
+        let result = &self.proof["result"];
+
+        if result["data"]["txnMetadata"]["seqNo"].as_u64() != Some(seq_no as u64) {
+            return Err(err_msg(
+                IndyErrorKind::InvalidStructure,
+                "Pool ledger snapshot proof is given for another transaction",
+            ));
+        }
+
+        // Proof is given for the last transaction, so all snapshot NODE transactions are replayed
+        let (nodes, f) = get_nodes_and_f(&merkle)?;
+        let generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+
+        let sp_key = seq_no.to_string();
+
+        let parsed_sps = state_proof::parse_generic_reply_for_proof_checking(result, &self.proof.to_string(), Some(sp_key.as_bytes()))
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Pool ledger snapshot proof doesn't contain audit proof"))?;
+
+        if !state_proof::verify_parsed_sp(parsed_sps, &nodes, f, &generator) {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Pool ledger snapshot proof isn't signed by the pool validators",
+            ));
+        }
+
+        let (ledger_size, root_hash, audit_path) = parse_audit_proof(result)?;
+
+        if ledger_size < seq_no {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                format!("Ledger size {} is less than pool ledger snapshot size {}", ledger_size, seq_no),
+            ));
+        }
+
+        let proof = MerkleTree::consistency_proof_from_audit_path(&last_txn, seq_no, &audit_path)?;
+
+        if !merkle.consistency_proof(&root_hash, ledger_size, &proof)? {
+            return Err(err_msg(
+                IndyErrorKind::InvalidState,
+                "Pool ledger snapshot is inconsistent with the multi-signed ledger root",
+            ));
+        }
+
+        Ok(txns)
+    }
+}
+
+/// Checks that one of the ledgers is a prefix of the other one.
+fn is_consistent(merkle: &MerkleTree, txns: &[Vec<u8>]) -> IndyResult<bool> {
+    let count = std::cmp::min(merkle.count(), txns.len());
+
+    let prefix = MerkleTree::from_vec(merkle.iter().take(count).cloned().collect())?;
+    let other_prefix = MerkleTree::from_vec(txns[..count].to_vec())?;
+
+    Ok(prefix.root_hash() == other_prefix.root_hash())
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use lazy_static::lazy_static;
+
+use indy_api_types::errors::prelude::*;
+
+use super::events::REQUESTS_FOR_STATE_PROOFS;
+
+lazy_static! {
+    static ref REGISTERED_SP_PARSERS: RwLock<HashMap<String, Arc<dyn StateProofParser>>> = RwLock::new(HashMap::new());
+}
+
+/// Parser of StateProofs of custom transaction types.
+///
+/// Reads of the registered types are sent to a single node and accepted if the reply
+/// contains valid state proof and multi-signature.
+pub trait StateProofParser: Send + Sync {
+    /// Parse Reply from Node to specific StateProof format
+    ///
+    /// # params
+    /// reply_from_node: string representation of node's reply ("as is")
+    ///
+    /// # return
+    /// serialized as string JSON with array of ParsedSP
+    fn parse(&self, reply_from_node: &str) -> IndyResult<String>;
+}
+
+pub(crate) fn register_sp_parser(txn_type: &str, parser: Arc<dyn StateProofParser>) -> IndyResult<()> {
+    if REQUESTS_FOR_STATE_PROOFS.contains(&txn_type) {
+        return Err(err_msg(
+            IndyErrorKind::InvalidStructure,
+            format!(
+                "Try to override StateProof parser for default TXN_TYPE {}",
+                txn_type
+            ),
+        ));
+    }
+
+    REGISTERED_SP_PARSERS
+        .write()
+        .map_err(|_| err_msg(IndyErrorKind::InvalidState, "StateProof parsers lock is poisoned"))?
+        .insert(txn_type.to_owned(), parser);
+
+    Ok(())
+}
+
+pub(crate) fn get_sp_parser(txn_type: &str) -> Option<Arc<dyn StateProofParser>> {
+    REGISTERED_SP_PARSERS
+        .read()
+        .ok()
+        .and_then(|parsers| parsers.get(txn_type).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::ledger::constants;
+
+    use super::*;
+
+    struct TestParser;
+
+    impl StateProofParser for TestParser {
+        fn parse(&self, _reply_from_node: &str) -> IndyResult<String> {
+            Ok("[]".to_string())
+        }
+    }
+
+    #[test]
+    fn register_sp_parser_works() {
+        register_sp_parser("register_sp_parser_works", Arc::new(TestParser)).unwrap();
+    }
+
+    #[test]
+    fn register_sp_parser_works_for_builtin_type() {
+        let res = register_sp_parser(constants::GET_NYM, Arc::new(TestParser));
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn get_sp_parser_works() {
+        register_sp_parser("get_sp_parser_works", Arc::new(TestParser)).unwrap();
+        let parser = get_sp_parser("get_sp_parser_works").unwrap();
+        assert_eq!("[]", parser.parse("{}").unwrap());
+    }
+
+    #[test]
+    fn get_sp_parser_works_for_invalid_name() {
+        assert!(get_sp_parser("get_sp_parser_works_for_invalid_name").is_none());
+    }
+}
@@ -21,11 +21,11 @@ use self::log_derive::logfn;
 use ursa::bls::{Bls, Generator, MultiSignature, VerKey};
 use self::node::{Node, TrieDB};
 use rust_base58::FromBase58;
-use crate::services::pool::Nodes;
+use crate::services::pool::{get_sp_parser, Nodes};
 
 mod node;
 
-pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, _raw_msg: &str, sp_key: Option<&[u8]>) -> Option<Vec<ParsedSP>> {
+pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, raw_msg: &str, sp_key: Option<&[u8]>) -> Option<Vec<ParsedSP>> {
     let type_ = if let Some(type_) = json_msg["type"].as_str() {
         trace!("TransactionHandler::parse_generic_reply_for_proof_checking: type_: {:?}", type_);
         type_
@@ -42,31 +42,18 @@ pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, _raw_msg: &
             warn!("parse_generic_reply_for_proof_checking: can't get key in sp for built-in type");
             None
         }
-    // FIXME: Do we need custom parsers???
-    // } else if let Some((parser, free)) = PoolService::get_sp_parser(type_) {
-    //     trace!("TransactionHandler::parse_generic_reply_for_proof_checking: plugged: parser {:?}, free {:?}",
-    //            parser, free);
-
-    //     let msg = CString::new(raw_msg).ok()?;
-    //     let mut parsed_c_str = ::std::ptr::null();
-    //     let err = parser(msg.as_ptr(), &mut parsed_c_str);
-    //     if err != ErrorCode::Success {
-    //         debug!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< plugin return err {:?}", err);
-    //         return None;
-    //     }
-    //     let c_str = if parsed_c_str.is_null() { None } else { Some(unsafe { CStr::from_ptr(parsed_c_str) }) };
-    //     let parsed_sps = c_str
-    //         .and_then(|c_str| c_str.to_str().map_err(map_err_trace!()).ok())
-    //         .and_then(|c_str|
-    //             serde_json::from_str::<Vec<ParsedSP>>(c_str)
-    //                 .map_err(|err|
-    //                     debug!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< can't parse plugin response {}", err))
-    //                 .ok());
-
-    //     let err = free(parsed_c_str);
-    //     trace!("TransactionHandler::parse_generic_reply_for_proof_checking: plugin free res {:?}", err);
-
-    //     parsed_sps
+    } else if let Some(parser) = get_sp_parser(type_) {
+        trace!("TransactionHandler::parse_generic_reply_for_proof_checking: plugged");
+
+        parser.parse(raw_msg)
+            .map_err(|err|
+                debug!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< plugin return err {:?}", err))
+            .ok()
+            .and_then(|parsed_sps|
+                serde_json::from_str::<Vec<ParsedSP>>(&parsed_sps)
+                    .map_err(|err|
+                        debug!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< can't parse plugin response {}", err))
+                    .ok())
     } else {
         trace!("TransactionHandler::parse_generic_reply_for_proof_checking: <<< type not supported");
         None
@@ -746,6 +733,51 @@ fn _verify_proof_signature(signature: &str,
     Ok(res)
 }
 
+/// Builds the value stored as a leaf of the ledger merkle tree for a transaction returned by GET_TXN.
+/// Returns `None` if there is no transaction in `parsed_data`.
+pub fn parse_txn_audit_value(parsed_data: &SJsonValue) -> Result<Option<SJsonValue>, String> {
+    let mut value = json!({});
+
+    if parsed_data["txn"].is_null() && parsed_data["txnMetadata"].is_null() &&
+        parsed_data["ver"].is_null() && parsed_data["reqSignature"].is_null() {
+        return Ok(None);
+    }
+    if !parsed_data["txn"].is_null() {
+        value["txn"] = parsed_data["txn"].clone();
+    }
+    if !parsed_data["txnMetadata"].is_null() {
+        value["txnMetadata"] = parsed_data["txnMetadata"].clone();
+    }
+    if !parsed_data["ver"].is_null() {
+        value["ver"] = parsed_data["ver"].clone();
+    }
+    if !parsed_data["reqSignature"].is_null() {
+        value["reqSignature"] = parsed_data["reqSignature"].clone();
+    }
+
+    // Adjust attrib transaction to match stored state
+    if value["txn"]["type"].as_str() == Some(constants::ATTRIB) {
+        if let Some(raw) = value["txn"]["data"]["raw"].as_str() {
+            if raw.is_empty() {
+                value["txn"]["data"]["raw"] = SJsonValue::from("");
+            } else {
+
+                value["txn"]["data"]["raw"] =
+                    SJsonValue::from(hex::encode(openssl_hash(raw.as_bytes()).map_err(|err| err.to_string())?));
+            }
+        } else if let Some(enc) = value["txn"]["data"]["enc"].as_str() {
+            if enc.is_empty() {
+                value["txn"]["data"]["enc"] = SJsonValue::from("");
+            } else {
+                value["txn"]["data"]["enc"] =
+                    SJsonValue::from(hex::encode(openssl_hash(enc.as_bytes()).map_err(|err| err.to_string())?));
+            }
+        }
+    }
+
+    Ok(Some(value))
+}
+
 fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parsed_data: &SJsonValue, xtype: &str, sp_key: &[u8]) -> Result<Option<String>, String> {
     if let Some(data) = data {
         let mut value = json!({});
@@ -768,43 +800,10 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
         match xtype {
             //TODO constants::GET_DDO => support DDO
             constants::GET_TXN => {
-                value = json!({});
-                if parsed_data["txn"].is_null() && parsed_data["txnMetadata"].is_null() &&
-                    parsed_data["ver"].is_null() && parsed_data["reqSignature"].is_null() {
-                    return Ok(None);
-                }
-                if !parsed_data["txn"].is_null() {
-                    value["txn"] = parsed_data["txn"].clone();
-                }
-                if !parsed_data["txnMetadata"].is_null() {
-                    value["txnMetadata"] = parsed_data["txnMetadata"].clone();
-                }
-                if !parsed_data["ver"].is_null() {
-                    value["ver"] = parsed_data["ver"].clone();
-                }
-                if !parsed_data["reqSignature"].is_null() {
-                    value["reqSignature"] = parsed_data["reqSignature"].clone();
-                }
-
-                // Adjust attrib transaction to match stored state
-                if value["txn"]["type"].as_str() == Some(constants::ATTRIB) {
-                    if let Some(raw) = value["txn"]["data"]["raw"].as_str() {
-                        if raw.is_empty() {
-                            value["txn"]["data"]["raw"] = SJsonValue::from("");
-                        } else {
-
-                            value["txn"]["data"]["raw"] =
-                                SJsonValue::from(hex::encode(openssl_hash(raw.as_bytes()).map_err(|err| err.to_string())?));
-                        }
-                    } else if let Some(enc) = value["txn"]["data"]["enc"].as_str() {
-                        if enc.is_empty() {
-                            value["txn"]["data"]["enc"] = SJsonValue::from("");
-                        } else {
-                            value["txn"]["data"]["enc"] =
-                                SJsonValue::from(hex::encode(openssl_hash(enc.as_bytes()).map_err(|err| err.to_string())?));
-                        }
-                    }
-                }
+                value = match parse_txn_audit_value(parsed_data)? {
+                    Some(value) => value,
+                    None => return Ok(None),
+                };
             }
             constants::GET_NYM => {
                 value["identifier"] = parsed_data["identifier"].clone();
@@ -892,6 +891,9 @@ mod tests {
     use super::*;
 
     use hex::FromHex;
+    use std::sync::Arc;
+
+    use crate::services::pool::register_sp_parser;
 
     /// For audit proofs tree looks like this
     ///         12345
@@ -1602,136 +1604,123 @@ mod tests {
                    }));
     }
 
-    // #[test]
-    // FIXME: support pluggable parsers
-    // fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_plugged() {
-    //     extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> ErrorCode {
-    //         unsafe { *parsed = msg; }
-    //         ErrorCode::Success
-    //     }
-    //     extern fn free(_data: *const c_char) -> ErrorCode { ErrorCode::Success }
-
-    //     let parsed_sp = json!([{
-    //         "root_hash": "rh",
-    //         "proof_nodes": "pns",
-    //         "multi_signature": "ms",
-    //         "kvs_to_verify": {
-    //             "type": "Simple",
-    //             "kvs": [],
-    //         },
-    //     }]);
-
-    //     PoolService::register_sp_parser("test", parse, free).unwrap();
-    //     let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&json!({"type".to_owned(): "test"}),
-    //                                                                        parsed_sp.to_string().as_str(),
-    //                                                                        None)
-    //         .unwrap();
-
-    //     assert_eq!(parsed_sps.len(), 1);
-    //     let parsed_sp = parsed_sps.remove(0);
-    //     assert_eq!(parsed_sp.root_hash, "rh");
-    //     assert_eq!(parsed_sp.multi_signature, "ms");
-    //     assert_eq!(parsed_sp.proof_nodes, "pns");
-    //     assert_eq!(parsed_sp.kvs_to_verify,
-    //                KeyValuesInSP::Simple(KeyValueSimpleData {
-    //                    kvs: Vec::new(),
-    //                    verification_type: KeyValueSimpleDataVerificationType::Simple,
-    //                }));
-    // }
-
-    // FIXME: !!!
-    // #[test]
-    // fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_plugged_range() {
-    //     extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> ErrorCode {
-    //         unsafe { *parsed = msg; }
-    //         ErrorCode::Success
-    //     }
-    //     extern fn free(_data: *const c_char) -> ErrorCode { ErrorCode::Success }
-
-    //     let parsed_sp = json!([{
-    //         "root_hash": "rh",
-    //         "proof_nodes": "pns",
-    //         "multi_signature": "ms",
-    //         "kvs_to_verify": {
-    //             "type": "Simple",
-    //             "kvs": [],
-    //             "verification_type": {
-    //                 "type": "NumericalSuffixAscendingNoGaps",
-    //                 "from": 1,
-    //                 "next": 2,
-    //                 "prefix": "abc"
-    //             }
-    //         },
-    //     }]);
-
-    //     PoolService::register_sp_parser("test", parse, free).unwrap();
-    //     let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&json!({"type".to_owned(): "test"}),
-    //                                                                        parsed_sp.to_string().as_str(),
-    //                                                                        None)
-    //         .unwrap();
-
-    //     assert_eq!(parsed_sps.len(), 1);
-    //     let parsed_sp = parsed_sps.remove(0);
-    //     assert_eq!(parsed_sp.root_hash, "rh");
-    //     assert_eq!(parsed_sp.multi_signature, "ms");
-    //     assert_eq!(parsed_sp.proof_nodes, "pns");
-    //     assert_eq!(parsed_sp.kvs_to_verify,
-    //                KeyValuesInSP::Simple(KeyValueSimpleData {
-    //                    kvs: Vec::new(),
-    //                    verification_type: KeyValueSimpleDataVerificationType::NumericalSuffixAscendingNoGaps(
-    //                        NumericalSuffixAscendingNoGapsData {
-    //                            from: Some(1),
-    //                            next: Some(2),
-    //                            prefix: "abc".to_string(),
-    //                        }),
-    //                }));
-    // }
-
-    // FIXME: !!! support pluggable parsers
-    // #[test]
-    // fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_plugged_range_nones() {
-    //     extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> ErrorCode {
-    //         unsafe { *parsed = msg; }
-    //         ErrorCode::Success
-    //     }
-    //     extern fn free(_data: *const c_char) -> ErrorCode { ErrorCode::Success }
-
-    //     let parsed_sp = json!([{
-    //         "root_hash": "rh",
-    //         "proof_nodes": "pns",
-    //         "multi_signature": "ms",
-    //         "kvs_to_verify": {
-    //             "type": "Simple",
-    //             "kvs": [],
-    //             "verification_type": {
-    //                 "type": "NumericalSuffixAscendingNoGaps",
-    //                 "from": serde_json::Value::Null,
-    //                 "next": serde_json::Value::Null,
-    //                 "prefix": "abc"
-    //             }
-    //         },
-    //     }]);
-
-    //     PoolService::register_sp_parser("test", parse, free).unwrap();
-    //     let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&json!({"type".to_owned(): "test"}),
-    //                                                                        parsed_sp.to_string().as_str(),
-    //                                                                        None)
-    //         .unwrap();
-
-    //     assert_eq!(parsed_sps.len(), 1);
-    //     let parsed_sp = parsed_sps.remove(0);
-    //     assert_eq!(parsed_sp.root_hash, "rh");
-    //     assert_eq!(parsed_sp.multi_signature, "ms");
-    //     assert_eq!(parsed_sp.proof_nodes, "pns");
-    //     assert_eq!(parsed_sp.kvs_to_verify,
-    //                KeyValuesInSP::Simple(KeyValueSimpleData {
-    //                    kvs: Vec::new(),
-    //                    verification_type: KeyValueSimpleDataVerificationType::NumericalSuffixAscendingNoGaps(
-    //                        NumericalSuffixAscendingNoGapsData {
-    //                            from: None,
-    //                            next: None,
-    //                            prefix: "abc".to_string(),
-    //                        }),
-    //                }));
-    // }
+    struct EchoParser;
+
+    impl crate::services::pool::StateProofParser for EchoParser {
+        fn parse(&self, reply_from_node: &str) -> IndyResult<String> {
+            Ok(reply_from_node.to_string())
+        }
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_plugged() {
+        let parsed_sp = json!([{
+            "root_hash": "rh",
+            "proof_nodes": "pns",
+            "multi_signature": "ms",
+            "kvs_to_verify": {
+                "type": "Simple",
+                "kvs": [],
+            },
+        }]);
+
+        register_sp_parser("test_plugged", Arc::new(EchoParser)).unwrap();
+        let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&json!({"type".to_owned(): "test_plugged"}),
+                                                                           parsed_sp.to_string().as_str(),
+                                                                           None)
+            .unwrap();
+
+        assert_eq!(parsed_sps.len(), 1);
+        let parsed_sp = parsed_sps.remove(0);
+        assert_eq!(parsed_sp.root_hash, "rh");
+        assert_eq!(parsed_sp.multi_signature, "ms");
+        assert_eq!(parsed_sp.proof_nodes, "pns");
+        assert_eq!(parsed_sp.kvs_to_verify,
+                   KeyValuesInSP::Simple(KeyValueSimpleData {
+                       kvs: Vec::new(),
+                       verification_type: KeyValueSimpleDataVerificationType::Simple,
+                   }));
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_plugged_range() {
+        let parsed_sp = json!([{
+            "root_hash": "rh",
+            "proof_nodes": "pns",
+            "multi_signature": "ms",
+            "kvs_to_verify": {
+                "type": "Simple",
+                "kvs": [],
+                "verification_type": {
+                    "type": "NumericalSuffixAscendingNoGaps",
+                    "from": 1,
+                    "next": 2,
+                    "prefix": "abc"
+                }
+            },
+        }]);
+
+        register_sp_parser("test_plugged_range", Arc::new(EchoParser)).unwrap();
+        let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&json!({"type".to_owned(): "test_plugged_range"}),
+                                                                           parsed_sp.to_string().as_str(),
+                                                                           None)
+            .unwrap();
+
+        assert_eq!(parsed_sps.len(), 1);
+        let parsed_sp = parsed_sps.remove(0);
+        assert_eq!(parsed_sp.root_hash, "rh");
+        assert_eq!(parsed_sp.multi_signature, "ms");
+        assert_eq!(parsed_sp.proof_nodes, "pns");
+        assert_eq!(parsed_sp.kvs_to_verify,
+                   KeyValuesInSP::Simple(KeyValueSimpleData {
+                       kvs: Vec::new(),
+                       verification_type: KeyValueSimpleDataVerificationType::NumericalSuffixAscendingNoGaps(
+                           NumericalSuffixAscendingNoGapsData {
+                               from: Some(1),
+                               next: Some(2),
+                               prefix: "abc".to_string(),
+                           }),
+                   }));
+    }
+
+    #[test]
+    fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_plugged_range_nones() {
+        let parsed_sp = json!([{
+            "root_hash": "rh",
+            "proof_nodes": "pns",
+            "multi_signature": "ms",
+            "kvs_to_verify": {
+                "type": "Simple",
+                "kvs": [],
+                "verification_type": {
+                    "type": "NumericalSuffixAscendingNoGaps",
+                    "from": serde_json::Value::Null,
+                    "next": serde_json::Value::Null,
+                    "prefix": "abc"
+                }
+            },
+        }]);
+
+        register_sp_parser("test_plugged_range_nones", Arc::new(EchoParser)).unwrap();
+        let mut parsed_sps = super::parse_generic_reply_for_proof_checking(&json!({"type".to_owned(): "test_plugged_range_nones"}),
+                                                                           parsed_sp.to_string().as_str(),
+                                                                           None)
+            .unwrap();
+
+        assert_eq!(parsed_sps.len(), 1);
+        let parsed_sp = parsed_sps.remove(0);
+        assert_eq!(parsed_sp.root_hash, "rh");
+        assert_eq!(parsed_sp.multi_signature, "ms");
+        assert_eq!(parsed_sp.proof_nodes, "pns");
+        assert_eq!(parsed_sp.kvs_to_verify,
+                   KeyValuesInSP::Simple(KeyValueSimpleData {
+                       kvs: Vec::new(),
+                       verification_type: KeyValueSimpleDataVerificationType::NumericalSuffixAscendingNoGaps(
+                           NumericalSuffixAscendingNoGapsData {
+                               from: None,
+                               next: None,
+                               prefix: "abc".to_string(),
+                           }),
+                   }));
+    }
 }
@@ -484,6 +484,40 @@ pub struct RemoteNode {
     pub is_blacklisted: bool,
 }
 
+/// Health of a single pool node as observed by the networker.
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStatus {
+    pub alias: String,
+    pub address: String,
+    pub blacklisted: bool,
+    pub connected: bool,
+    /// Unix time (in seconds) of the last message received from the node
+    pub last_reply_time: Option<i64>,
+    /// Rolling average of the request round trip (in milliseconds)
+    pub latency_ms: Option<i64>,
+    pub timeouts: u64,
+    pub consecutive_timeouts: u64,
+}
+
+impl NodeStatus {
+    /// Node is considered reachable until it misses a reply without answering anything after that.
+    pub fn is_reachable(&self) -> bool {
+        !self.blacklisted && self.consecutive_timeouts == 0
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolStatus {
+    pub state: String,
+    pub merkle_tree_size: Option<usize>,
+    pub merkle_tree_root: Option<String>,
+    pub f: usize,
+    pub reachable_nodes: usize,
+    pub nodes: Vec<NodeStatus>,
+}
+
 pub trait MinValue {
     fn get_min_index(&self) -> IndyResult<usize>;
 }
@@ -3,18 +3,28 @@ use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyResultExt};
 use cosmrs::rpc;
 use prost::Message;
 
+/// Checks state proofs of the query response and returns the app hash they lead to.
 pub fn check_proofs(
     result: rpc::endpoint::abci_query::Response,
-) -> IndyResult<()> {
+) -> IndyResult<Vec<u8>> {
     // Decode state proofs
 
-    // Decode proof for inner ival tree
-    let proof_op_0 = &result.response.proof.clone().ok_or(
+    let proof = result.response.proof.as_ref().ok_or(
         IndyError::from_msg(
             IndyErrorKind::InvalidStructure,
-            "The proof for inner ival tree is absent but should be placed"
+            "The proof is absent but should be placed"
         ))?;
-    let proof_op_0 = &proof_op_0.ops[0].clone();
+
+    // Proof consists of the inner ival tree proof and the outer `ics23:simple` tendermint tree proof
+    if proof.ops.len() != 2 {
+        return Err(IndyError::from_msg(
+            IndyErrorKind::InvalidLedgerProof,
+            format!("The proof consists of {} operations instead of 2", proof.ops.len())
+        ));
+    }
+
+    // Decode proof for inner ival tree
+    let proof_op_0 = &proof.ops[0];
     let proof_0_data_decoded =
         ics23::CommitmentProof::decode(proof_op_0.data.as_slice()).to_indy(
                 IndyErrorKind::InvalidStructure,
@@ -22,12 +32,7 @@ pub fn check_proofs(
         )?;
 
     // Decode proof for outer `ics23:simple` tendermint tree)
-    let proof_op_1 = &result.response.proof.ok_or(
-        IndyError::from_msg(
-            IndyErrorKind::InvalidStructure,
-            "The proof for outer ics23:simple is absent but should be placed"
-        ))?;
-    let proof_op_1 = &proof_op_1.ops[1].clone();
+    let proof_op_1 = &proof.ops[1];
     let proof_1_data_decoded =
         ics23::CommitmentProof::decode(proof_op_1.data.as_slice()).to_indy(
                 IndyErrorKind::InvalidStructure,
@@ -82,7 +87,7 @@ pub fn check_proofs(
             "Cannot serialize object with proof for inner ival tree"
         )?;
         return Err(IndyError::from_msg(
-            IndyErrorKind::InvalidStructure,
+            IndyErrorKind::InvalidLedgerProof,
             format!(
                 "Commitment proof 0 is incorrect {}",
                 proof_op_0_str
@@ -90,7 +95,6 @@ pub fn check_proofs(
         ));
     }
 
-    // Should be output from light client
     // Calculate a root hash for the outer tree
     let proof_1_root = ics23::calculate_existence_root(&proof_1_existence.clone())
         .map_err(|er | IndyError::from_msg(
@@ -110,7 +114,7 @@ pub fn check_proofs(
             "Cannot serialize object with proof for outer `ics23:simple` tendermint tree"
         )?;
         return Err(IndyError::from_msg(
-            IndyErrorKind::InvalidStructure,
+            IndyErrorKind::InvalidLedgerProof,
             format!(
                 "Commitment proof 1 is incorrect {}",
                 proof_op_1_str
@@ -118,5 +122,87 @@ pub fn check_proofs(
         ));
     }
 
+    Ok(proof_1_root)
+}
+
+/// Checks state proofs of the query response for `key` of the `store` against the app hash verified by light client.
+pub fn check_proofs_with_app_hash(
+    result: rpc::endpoint::abci_query::Response,
+    store: &[u8],
+    key: &[u8],
+    app_hash: &[u8],
+) -> IndyResult<()> {
+    let (store_key, proof_key) = match result.response.proof {
+        Some(ref proof) if proof.ops.len() == 2 => (proof.ops[1].key.clone(), proof.ops[0].key.clone()),
+        Some(_) => {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidLedgerProof,
+                "The proof in the response of a node doesn't consist of inner and outer tree proofs"
+            ))
+        }
+        None => {
+            return Err(IndyError::from_msg(
+                IndyErrorKind::InvalidLedgerProof,
+                "The proof is absent in the response of a node"
+            ))
+        }
+    };
+
+    // Otherwise proof of another key could be returned, e.g. to fake absence of the data
+    if proof_key != key || store_key != store {
+        return Err(IndyError::from_msg(
+            IndyErrorKind::InvalidLedgerProof,
+            "The proof in the response of a node is built for another key"
+        ));
+    }
+
+    let root = check_proofs(result)?;
+
+    if root != app_hash {
+        return Err(IndyError::from_msg(
+            IndyErrorKind::InvalidLedgerProof,
+            format!(
+                "Commitment proofs lead to app hash {} while the verified one is {}",
+                hex::encode_upper(&root),
+                hex::encode_upper(app_hash)
+            ),
+        ));
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _response(ops: &str) -> rpc::endpoint::abci_query::Response {
+        let response = format!(
+            r#"{{"response":{{"code":0,"log":"","info":"","index":"0","key":"AQ==","value":"","proof":{{"ops":[{}]}},"height":"1","codespace":""}}}}"#,
+            ops
+        );
+
+        serde_json::from_str(&response).unwrap()
+    }
+
+    #[test]
+    fn check_proofs_works_for_single_proof_op() {
+        let response = _response(r#"{"field_type":"ics23:iavl","key":"AQ==","data":""}"#);
+
+        let res = check_proofs(response.clone());
+        assert_kind!(IndyErrorKind::InvalidLedgerProof, res);
+
+        let res = check_proofs_with_app_hash(response, b"acc", &[1], &[]);
+        assert_kind!(IndyErrorKind::InvalidLedgerProof, res);
+    }
+
+    #[test]
+    fn check_proofs_with_app_hash_works_for_another_store() {
+        let response = _response(
+            r#"{"field_type":"ics23:iavl","key":"AQ==","data":""},{"field_type":"ics23:simple","key":"YWNj","data":""}"#,
+        );
+
+        let res = check_proofs_with_app_hash(response, b"cheqd", &[1], &[]);
+        assert_kind!(IndyErrorKind::InvalidLedgerProof, res);
+    }
+}
@@ -1,8 +1,41 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
+/// did:indy method identifiers carry the ledger namespace and optional sub-namespace
+/// (`did:indy:sovrin:staging:<id>`), they are handled as a part of the method.
+pub const INDY_METHOD: &str = "indy";
+
+const DELIMITER: &str = ":";
+const MAX_INDY_NAMESPACE_DEPTH: usize = 2;
+// Fully qualified identifiers nest DIDs right after the method: `schema:indy:sovrin:did:indy:sovrin:<id>:2:gvt:1.0`
+const NESTED_DID_PREFIX: &str = "did";
+
 lazy_static! {
     pub static ref REGEX: Regex = Regex::new("^[a-z0-9]+:([a-z0-9]+):(.*)$").unwrap();
+    static ref NAMESPACE_REGEX: Regex = Regex::new("^[a-z0-9_-]+$").unwrap();
+}
+
+/// Splits fully qualified entity `<prefix>:<method>:<id>` into the method and the identifier.
+fn split(entity: &str) -> Option<(&str, &str)> {
+    let method = REGEX.captures(entity)?.get(1)?;
+    let mut method_end = method.end();
+
+    if method.as_str() == INDY_METHOD {
+        for _ in 0..MAX_INDY_NAMESPACE_DEPTH {
+            let rest = &entity[method_end + 1..];
+
+            match rest.find(DELIMITER) {
+                Some(pos)
+                    if NAMESPACE_REGEX.is_match(&rest[..pos]) && &rest[..pos] != NESTED_DID_PREFIX =>
+                {
+                    method_end += pos + 1
+                }
+                _ => break,
+            }
+        }
+    }
+
+    Some((&entity[method.start()..method_end], &entity[method_end + 1..]))
 }
 
 pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
@@ -10,20 +43,35 @@ pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
 }
 
 pub fn to_unqualified(entity: &str) -> String {
-    match REGEX.captures(entity) {
+    match split(entity) {
         None => entity.to_string(),
-        Some(caps) => caps
-            .get(2)
-            .map(|m| m.as_str().to_string())
-            .unwrap_or(entity.to_string()),
+        Some((_, id)) => id.to_string(),
     }
 }
 
-pub fn method(entity: &str) -> Option<String> {
-    match REGEX.captures(entity) {
-        None => None,
-        Some(caps) => caps.get(1).map(|m| m.as_str().to_string()),
+/// Removes qualification by `method` of the entity and of all identifiers with `prefixes` nested into it:
+/// `creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag` becomes `NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag`.
+pub fn to_unqualified_nested(entity: &str, prefixes: &[&str], method: &str) -> String {
+    let method_parts = method.split(DELIMITER).collect::<Vec<&str>>();
+    let parts = entity.split(DELIMITER).collect::<Vec<&str>>();
+
+    let mut unqualified = Vec::with_capacity(parts.len());
+    let mut i = 0;
+
+    while i < parts.len() {
+        if prefixes.contains(&parts[i]) && parts[i + 1..].starts_with(&method_parts) {
+            i += 1 + method_parts.len();
+        } else {
+            unqualified.push(parts[i]);
+            i += 1;
+        }
     }
+
+    unqualified.join(DELIMITER)
+}
+
+pub fn method(entity: &str) -> Option<String> {
+    split(entity).map(|(method, _)| method.to_string())
 }
 
 pub fn is_fully_qualified(entity: &str) -> bool {
@@ -4652,6 +4652,31 @@ mod high_cases {
             );
         }
 
+        #[test]
+        fn to_unqualified_ids_for_did_indy() {
+            Setup::empty();
+
+            let issuer_did = format!("did:{}:{}", INDY_SUB_NAMESPACE_METHOD, ISSUER_DID);
+            assert_eq!(ISSUER_DID, anoncreds::to_unqualified(&issuer_did).unwrap());
+
+            let (schema_id, _) = anoncreds::issuer_create_schema(
+                &issuer_did,
+                GVT_SCHEMA_NAME,
+                SCHEMA_VERSION,
+                GVT_SCHEMA_ATTRIBUTES,
+            )
+            .unwrap();
+
+            assert_eq!(
+                format!("schema:{}:{}:2:{}:{}", INDY_SUB_NAMESPACE_METHOD, issuer_did, GVT_SCHEMA_NAME, SCHEMA_VERSION),
+                schema_id
+            );
+            assert_eq!(
+                anoncreds::gvt_schema_id(),
+                anoncreds::to_unqualified(&schema_id).unwrap()
+            );
+        }
+
         #[test]
         fn to_unqualified_objects() {
             let setup = Setup::wallet();
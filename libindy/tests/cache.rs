@@ -20,9 +20,12 @@ use utils::{
     domain::{
         anoncreds::{
             credential_definition::{CredentialDefinition, CredentialDefinitionV1},
+            revocation_registry_definition::RevocationRegistryDefinitionV1,
+            revocation_registry_delta::RevocationRegistryDeltaV1,
             schema::{SchemaId, SchemaV1},
         },
         crypto::did::DidValue,
+        ledger::nym::NymData,
     },
     Setup,
 };
@@ -472,4 +475,493 @@ mod high_cases {
                 .unwrap();
         }
     }
+
+    mod nym_cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_nym_cache_works() {
+            let setup = Setup::new_identity();
+
+            let options_json = json!({}).to_string();
+            let nym_json1 = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                &options_json,
+            )
+            .unwrap();
+            let nym: NymData = serde_json::from_str(&nym_json1).unwrap();
+            assert_eq!(Some(setup.verkey.clone()), nym.verkey);
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let nym_json2 = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                &options_json,
+            )
+            .unwrap();
+
+            assert_eq!(nym_json1, nym_json2);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_nym_only_cache_no_cached_data() {
+            let setup = Setup::new_identity();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+
+            let res = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                &options_json,
+            );
+
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_purge_nym_cache_works() {
+            let setup = Setup::new_identity();
+
+            get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                "{}",
+            )
+            .unwrap();
+
+            purge_nym_cache(setup.wallet_handle, "{}").unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                &options_json,
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_nym_cache_older_than_1000_seconds() {
+            let setup = Setup::wallet();
+            purge_nym_cache(setup.wallet_handle, &json!({"maxAge": 1000}).to_string()).unwrap();
+        }
+    }
+
+    mod attrib_cache {
+        use super::*;
+
+        fn post_attrib(setup: &Setup) {
+            let attrib_request = utils::ledger::build_attrib_request(
+                &setup.did,
+                &setup.did,
+                None,
+                Some(ATTRIB_RAW_DATA),
+                None,
+            )
+            .unwrap();
+
+            utils::ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &attrib_request,
+            )
+            .unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_attrib_cache_works() {
+            let setup = Setup::new_identity();
+            post_attrib(&setup);
+
+            let options_json = json!({}).to_string();
+            let attrib1 = get_attrib_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                Some("endpoint"),
+                None,
+                None,
+                &options_json,
+            )
+            .unwrap();
+            assert_eq!(ATTRIB_RAW_DATA, attrib1);
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let attrib2 = get_attrib_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                Some("endpoint"),
+                None,
+                None,
+                &options_json,
+            )
+            .unwrap();
+
+            assert_eq!(attrib1, attrib2);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_attrib_no_store_works() {
+            let setup = Setup::new_identity();
+            post_attrib(&setup);
+
+            let options_json = json!({"noStore": true}).to_string();
+            get_attrib_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                Some("endpoint"),
+                None,
+                None,
+                &options_json,
+            )
+            .unwrap();
+
+            // it should not be present inside of cache, because of noStore option in previous request.
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_attrib_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &setup.did,
+                Some("endpoint"),
+                None,
+                None,
+                &options_json,
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_attrib_works_for_no_attribute_name() {
+            let setup = Setup::wallet_and_pool();
+
+            let res = get_attrib_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                DID_MY1,
+                None,
+                None,
+                None,
+                "{}",
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_purge_attrib_cache_no_options() {
+            let setup = Setup::wallet();
+            purge_attrib_cache(setup.wallet_handle, "{}").unwrap();
+        }
+    }
+
+    mod revoc_reg_def_cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_def_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let options_json = json!({}).to_string();
+            let revoc_reg_def_json1 = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                &options_json,
+            )
+            .unwrap();
+            let revoc_reg_def: RevocationRegistryDefinitionV1 =
+                serde_json::from_str(&revoc_reg_def_json1).unwrap();
+            assert_eq!(rev_reg_id, revoc_reg_def.id.0);
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let revoc_reg_def_json2 = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                &options_json,
+            )
+            .unwrap();
+
+            assert_eq!(revoc_reg_def_json1, revoc_reg_def_json2);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_def_min_fresh_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                "{}",
+            )
+            .unwrap();
+
+            sleep(std::time::Duration::from_secs(2));
+
+            let options_json = json!({"noUpdate": true, "minFresh": 1}).to_string();
+            let res = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                &options_json,
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_revoc_reg_def_cache_no_options() {
+            let setup = Setup::wallet();
+            purge_revoc_reg_def_cache(setup.wallet_handle, "{}").unwrap();
+        }
+    }
+
+    mod revoc_reg_delta_cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let to = time::get_time().sec;
+
+            let options_json = json!({}).to_string();
+            let (revoc_reg_delta_json1, timestamp1) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                &options_json,
+            )
+            .unwrap();
+            let _revoc_reg_delta: RevocationRegistryDeltaV1 =
+                serde_json::from_str(&revoc_reg_delta_json1).unwrap();
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let (revoc_reg_delta_json2, timestamp2) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                &options_json,
+            )
+            .unwrap();
+
+            assert_eq!(revoc_reg_delta_json1, revoc_reg_delta_json2);
+            assert_eq!(timestamp1, timestamp2);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_extends_cached_interval() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let to1 = time::get_time().sec;
+
+            get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to1,
+                "{}",
+            )
+            .unwrap();
+
+            sleep(std::time::Duration::from_secs(2));
+            let to2 = time::get_time().sec;
+
+            // cached interval doesn't cover requested one
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to2,
+                &options_json,
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            let (revoc_reg_delta_json, timestamp) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to2,
+                "{}",
+            )
+            .unwrap();
+
+            let options_json = json!({"noCache": true, "noStore": true}).to_string();
+            let (expected_revoc_reg_delta_json, expected_timestamp) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to2,
+                &options_json,
+            )
+            .unwrap();
+
+            let revoc_reg_delta: serde_json::Value =
+                serde_json::from_str(&revoc_reg_delta_json).unwrap();
+            let expected_revoc_reg_delta: serde_json::Value =
+                serde_json::from_str(&expected_revoc_reg_delta_json).unwrap();
+
+            assert_eq!(
+                expected_revoc_reg_delta["value"]["accum"],
+                revoc_reg_delta["value"]["accum"]
+            );
+            assert_eq!(expected_timestamp, timestamp);
+
+            // extended interval is cached now
+            let options_json = json!({"noUpdate": true}).to_string();
+            get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to2,
+                &options_json,
+            )
+            .unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_no_store_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let to = time::get_time().sec;
+
+            let options_json = json!({"noStore": true}).to_string();
+            get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                &options_json,
+            )
+            .unwrap();
+
+            // it should not be present inside of cache, because of noStore option in previous request.
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                &options_json,
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_purge_revoc_reg_delta_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let to = time::get_time().sec;
+
+            get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                "{}",
+            )
+            .unwrap();
+
+            // other caches are kept
+            purge_revoc_reg_def_cache(setup.wallet_handle, "{}").unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                &options_json,
+            )
+            .unwrap();
+
+            purge_revoc_reg_delta_cache(setup.wallet_handle, "{}").unwrap();
+
+            let res = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                None,
+                to,
+                &options_json,
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
 }
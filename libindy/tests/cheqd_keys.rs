@@ -8,9 +8,10 @@ extern crate serde_json;
 use indyrs::ErrorCode;
 
 #[cfg(feature = "cheqd")]
-use utils::{constants::*, types::ResponseType, test, cheqd_keys, cheqd_setup, cheqd_ledger, wallet};
+use utils::{constants::*, types::ResponseType, test, cheqd_keys, cheqd_pool, cheqd_setup, cheqd_ledger, wallet};
 use serde_json::Value;
 
+#[macro_use]
 mod utils;
 
 #[cfg(feature = "cheqd")]
@@ -36,12 +37,67 @@ mod high_cases {
 
         #[test]
         fn test_add_from_mnemonic() {
+            let alias = "some_alias_2";
+            let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+            let setup = cheqd_setup::CheqdSetup::new();
+            let result = cheqd_keys::add_from_mnemonic(setup.wallet_handle, alias, mnemonic, None).unwrap();
+            println!("Mnemonic: {:?}, Data: {:?}", mnemonic, result);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            assert_eq!("cheqd19rl4cm2hmr8afy4kldpxz3fka4jguq0ajzpa5y", result["account_id"]);
+        }
+
+        #[test]
+        fn test_add_from_mnemonic_for_hd_path() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let mnemonic = cheqd_keys::generate_mnemonic(24).unwrap();
+
+            let key_1 = cheqd_keys::add_from_mnemonic(setup.wallet_handle, "some_alias_1", &mnemonic, None).unwrap();
+            let key_1: Value = serde_json::from_str(&key_1).unwrap();
+
+            let options = json!({"hd_path": "m/44'/118'/0'/0/1"}).to_string();
+            let key_2 = cheqd_keys::add_from_mnemonic(setup.wallet_handle, "some_alias_2", &mnemonic, Some(&options)).unwrap();
+            let key_2: Value = serde_json::from_str(&key_2).unwrap();
+
+            assert_ne!(key_1["account_id"], key_2["account_id"]);
+        }
+
+        #[test]
+        fn test_add_from_mnemonic_for_legacy_derivation() {
             let alias = "some_alias_2";
             let mnemonic = "some_mnemonic";
             let setup = cheqd_setup::CheqdSetup::new();
-            let result = cheqd_keys::add_from_mnemonic(setup.wallet_handle, alias, mnemonic).unwrap();
+            let options = json!({"derivation_method": "LEGACY_SHA3"}).to_string();
+            let result = cheqd_keys::add_from_mnemonic(setup.wallet_handle, alias, mnemonic, Some(&options)).unwrap();
             println!("Mnemonic: {:?}, Data: {:?}", mnemonic, result);
         }
+
+        #[test]
+        fn test_add_from_mnemonic_for_invalid_mnemonic() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let res = cheqd_keys::add_from_mnemonic(setup.wallet_handle, "some_alias_2", "some_mnemonic", None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    #[cfg(test)]
+    mod generate_mnemonic {
+        use super::*;
+
+        #[test]
+        fn test_generate_mnemonic() {
+            let mnemonic = cheqd_keys::generate_mnemonic(12).unwrap();
+            assert_eq!(12, mnemonic.split_whitespace().count());
+
+            let mnemonic = cheqd_keys::generate_mnemonic(24).unwrap();
+            assert_eq!(24, mnemonic.split_whitespace().count());
+        }
+
+        #[test]
+        fn test_generate_mnemonic_for_invalid_words_count() {
+            let res = cheqd_keys::generate_mnemonic(13);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod key_info {
@@ -52,10 +108,37 @@ mod high_cases {
             let alias = "some_alias";
             let setup = cheqd_setup::CheqdSetup::new();
             cheqd_keys::add_random(setup.wallet_handle, alias).unwrap();
-            let result = cheqd_keys::get_info(setup.wallet_handle, alias).unwrap();
+            let result = cheqd_keys::get_info(setup.wallet_handle, alias, None).unwrap();
             println!("Data: {:?} ", result);
         }
 
+        #[test]
+        fn test_key_info_for_pool_account_prefix() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let pool_alias = "pool_with_cosmos_prefix";
+            let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+            test::cleanup_storage(pool_alias);
+            cheqd_pool::add(pool_alias, "rpc_address", "chain_id", Some("cosmos"), None, None).unwrap();
+            cheqd_keys::add_from_mnemonic(setup.wallet_handle, "some_alias", mnemonic, None).unwrap();
+
+            let result = cheqd_keys::get_info(setup.wallet_handle, "some_alias", Some(pool_alias)).unwrap();
+            test::cleanup_storage(pool_alias);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            assert_eq!("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4", result["account_id"]);
+        }
+
+        #[test]
+        fn test_key_info_for_unknown_pool() {
+            let alias = "some_alias";
+            let setup = cheqd_setup::CheqdSetup::new();
+            cheqd_keys::add_random(setup.wallet_handle, alias).unwrap();
+
+            let res = cheqd_keys::get_info(setup.wallet_handle, alias, Some("unknown_pool"));
+            assert_code!(ErrorCode::CommonIOError, res);
+        }
+
         #[test]
         fn test_get_list_keys() {
             let alias_1 = "some_alias_1";
@@ -66,7 +149,7 @@ mod high_cases {
             let key_1 = cheqd_keys::add_random(setup.wallet_handle, alias_1).unwrap();
             let key_2 = cheqd_keys::add_random(setup.wallet_handle, alias_2).unwrap();
 
-            let result = cheqd_keys::get_list_keys(setup.wallet_handle).unwrap();
+            let result = cheqd_keys::get_list_keys(setup.wallet_handle, Some(&setup.pool_alias)).unwrap();
             let result: Vec<Value> = serde_json::from_str(&result).unwrap();
 
             let expect_key_1: Value = serde_json::from_str(&key_1).unwrap();
@@ -104,4 +187,42 @@ mod high_cases {
             println!("Data: {:?} ", result);
         }
     }
+
+    mod get_multisig_info {
+        use super::*;
+
+        #[test]
+        fn test_get_multisig_info() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let key_1 = cheqd_keys::add_random(setup.wallet_handle, "multisig_alias_1").unwrap();
+            let key_1: Value = serde_json::from_str(&key_1).unwrap();
+            let key_2 = cheqd_keys::add_random(setup.wallet_handle, "multisig_alias_2").unwrap();
+            let key_2: Value = serde_json::from_str(&key_2).unwrap();
+
+            let members = json!([
+                {"alias": "multisig_alias_1"},
+                {"alias": "multisig_alias_2"},
+                {"pub_key": setup.pub_key},
+            ]).to_string();
+
+            let result = cheqd_keys::get_multisig_info(setup.wallet_handle, 2, &members, Some(&setup.pool_alias)).unwrap();
+            println!("Data: {:?} ", result);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(2, result["threshold"]);
+            assert_eq!(json!([key_1["pub_key"], key_2["pub_key"], setup.pub_key]), result["pub_keys"]);
+            assert!(result["account_id"].as_str().unwrap().starts_with("cheqd1"));
+        }
+
+        #[test]
+        fn test_get_multisig_info_for_invalid_threshold() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let members = json!([{"alias": setup.key_alias}]).to_string();
+
+            let res = cheqd_keys::get_multisig_info(setup.wallet_handle, 2, &members, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 }
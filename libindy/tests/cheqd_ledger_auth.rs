@@ -67,4 +67,103 @@ mod high_cases {
             println!("Parsed query response: {:?}", parsed);
         }
     }
+
+    #[cfg(test)]
+    mod multisig {
+        use super::*;
+
+        fn build_multisig_tx(setup: &cheqd_setup::CheqdSetup) -> (Vec<u8>, Value) {
+            cheqd_keys::add_random(setup.wallet_handle, "multisig_alias_1").unwrap();
+            cheqd_keys::add_random(setup.wallet_handle, "multisig_alias_2").unwrap();
+
+            let members = json!([
+                {"alias": "multisig_alias_1"},
+                {"alias": "multisig_alias_2"},
+                {"alias": setup.key_alias},
+            ]).to_string();
+
+            let multisig_info = cheqd_keys::get_multisig_info(setup.wallet_handle, 2, &members, Some(&setup.pool_alias)).unwrap();
+            let multisig_info: Value = serde_json::from_str(&multisig_info).unwrap();
+
+            let msg = cheqd_ledger::bank::build_msg_send(
+                &setup.pool_alias, multisig_info["account_id"].as_str().unwrap(), &setup.account_id, "100", &setup.denom,
+            ).unwrap();
+
+            let signers = json!([multisig_info["pub_keys"][0], multisig_info["pub_keys"][1]]).to_string();
+
+            let tx = cheqd_ledger::auth::build_multisig_tx(
+                &setup.pool_alias, &multisig_info.to_string(), &signers, &msg, 0, 0, 300000, 0, &setup.denom, 100, "memo",
+            ).unwrap();
+
+            (tx, multisig_info)
+        }
+
+        #[test]
+        fn test_build_and_combine_multisig_tx() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (tx, multisig_info) = build_multisig_tx(&setup);
+
+            let signature_1 = cheqd_keys::sign_multisig_partial(setup.wallet_handle, "multisig_alias_1", &tx).unwrap();
+            let signature_2 = cheqd_keys::sign_multisig_partial(setup.wallet_handle, "multisig_alias_2", &tx).unwrap();
+
+            let signatures = json!({
+                multisig_info["pub_keys"][0].as_str().unwrap(): signature_1,
+                multisig_info["pub_keys"][1].as_str().unwrap(): signature_2,
+            }).to_string();
+
+            let signed = cheqd_ledger::auth::combine_multisig_signatures(&tx, &signatures).unwrap();
+            assert_ne!(signed.len(), 0);
+        }
+
+        #[test]
+        fn test_build_multisig_tx_for_signers_below_threshold() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let members = json!([{"alias": setup.key_alias}, {"alias": setup.key_alias}]).to_string();
+            let multisig_info = cheqd_keys::get_multisig_info(setup.wallet_handle, 2, &members, None).unwrap();
+
+            let msg = cheqd_ledger::bank::build_msg_send(&setup.pool_alias, &setup.account_id, &setup.account_id, "100", &setup.denom).unwrap();
+            let signers = json!([setup.pub_key]).to_string();
+
+            let res = cheqd_ledger::auth::build_multisig_tx(
+                &setup.pool_alias, &multisig_info, &signers, &msg, 0, 0, 300000, 0, &setup.denom, 100, "memo",
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn test_combine_multisig_signatures_for_missed_signature() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (tx, multisig_info) = build_multisig_tx(&setup);
+
+            let signature_1 = cheqd_keys::sign_multisig_partial(setup.wallet_handle, "multisig_alias_1", &tx).unwrap();
+
+            let signatures = json!({
+                multisig_info["pub_keys"][0].as_str().unwrap(): signature_1,
+            }).to_string();
+
+            let res = cheqd_ledger::auth::combine_multisig_signatures(&tx, &signatures);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn test_combine_multisig_signatures_for_invalid_signature() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (tx, multisig_info) = build_multisig_tx(&setup);
+
+            // Member 2 signature is produced by member 1 key
+            let signature_1 = cheqd_keys::sign_multisig_partial(setup.wallet_handle, "multisig_alias_1", &tx).unwrap();
+
+            let signatures = json!({
+                multisig_info["pub_keys"][0].as_str().unwrap(): signature_1,
+                multisig_info["pub_keys"][1].as_str().unwrap(): signature_1,
+            }).to_string();
+
+            let res = cheqd_ledger::auth::combine_multisig_signatures(&tx, &signatures);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 }
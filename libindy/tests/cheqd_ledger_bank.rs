@@ -13,6 +13,7 @@ mod utils;
 #[cfg(feature = "cheqd")]
 use utils::{cheqd_ledger, cheqd_pool, cheqd_keys, cheqd_setup};
 use serde_json::Value;
+use indyrs::ErrorCode;
 
 #[cfg(feature = "cheqd")]
 mod high_cases {
@@ -22,6 +23,32 @@ mod high_cases {
     mod query_balance {
         use super::*;
 
+        #[test]
+        fn test_build_query_balance_for_other_account_prefix() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let res = cheqd_ledger::bank::bank_build_query_balance(
+                &setup.pool_alias,
+                "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4",
+                &setup.denom,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn test_build_msg_send_for_other_account_prefix() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let res = cheqd_ledger::bank::build_msg_send(
+                &setup.pool_alias,
+                &setup.account_id,
+                "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4",
+                "100",
+                &setup.denom,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_cheqd_pool")]
         fn test_query_balance() {
@@ -30,7 +57,7 @@ mod high_cases {
 
             ///// Query get current balance
 
-            let query = cheqd_ledger::bank::bank_build_query_balance(&setup.account_id, &setup.denom).unwrap();
+            let query = cheqd_ledger::bank::bank_build_query_balance(&setup.pool_alias, &setup.account_id, &setup.denom).unwrap();
             let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
             let query_resp = cheqd_ledger::bank::parse_query_balance_resp(&query_resp).unwrap();
             println!("Query response: {:?}", query_resp);
@@ -53,6 +80,7 @@ mod high_cases {
 
             // Msg send amount
             let msg = cheqd_ledger::bank::build_msg_send(
+                &setup.pool_alias,
                 &setup.account_id,
                 second_account,
                 amount_for_transfer,
@@ -69,7 +97,7 @@ mod high_cases {
 
             ///// Query get balance after send
 
-            let query = cheqd_ledger::bank::bank_build_query_balance(&setup.account_id, &setup.denom).unwrap();
+            let query = cheqd_ledger::bank::bank_build_query_balance(&setup.pool_alias, &setup.account_id, &setup.denom).unwrap();
             let query_resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
             let query_resp = cheqd_ledger::bank::parse_query_balance_resp(&query_resp).unwrap();
             println!("Query response: {:?}", query_resp);
@@ -289,4 +289,61 @@ mod high_cases {
             assert!(result_nym.contains(&expected_nym_2));
         }
     }
+
+    #[cfg(test)]
+    mod resolve_did_document {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_resolve_did_document() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            // Msg
+            let msg = cheqd_ledger::cheqd::build_msg_create_nym(
+                "test-did",
+                &setup.account_id,
+                "test-verkey",
+                "test-alias",
+                "test-role",
+            ).unwrap();
+
+            // Build, sign, broadcast tx
+            let resp = setup.build_and_sign_and_broadcast_tx(&msg).unwrap();
+            let resp = cheqd_ledger::cheqd::parse_msg_create_nym_resp(&resp).unwrap();
+            let resp: Value = serde_json::from_str(&resp).unwrap();
+
+            ///// Resolve + Assert
+
+            let did = format!("did:cheqd:testnet:{}", resp["id"].as_u64().unwrap());
+            let result = cheqd_ledger::cheqd::resolve_did_document(&setup.pool_alias, &did).unwrap();
+            println!("Resolution result: {:?}", result);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            let expected_result: Value = json!({
+                "didDocument": {
+                    "@context": ["https://www.w3.org/ns/did/v1", "https://w3id.org/security/suites/ed25519-2018/v1"],
+                    "id": did,
+                    "verificationMethod": [{
+                        "id": format!("{}#verkey", did),
+                        "type": "Ed25519VerificationKey2018",
+                        "controller": did,
+                        "publicKeyBase58": "test-verkey",
+                    }],
+                    "authentication": [format!("{}#verkey", did)],
+                    "assertionMethod": [format!("{}#verkey", did)],
+                },
+                "didResolutionMetadata": {
+                    "contentType": "application/did+ld+json",
+                },
+                "didDocumentMetadata": {
+                    "alias": "test-alias",
+                    "role": "test-role",
+                    "creator": setup.account_id,
+                },
+            });
+
+            assert_eq!(expected_result, result);
+        }
+    }
 }
@@ -0,0 +1,113 @@
+#[macro_use]
+extern crate derivative;
+
+#[macro_use]
+extern crate serde_derive;
+
+#[macro_use]
+extern crate serde_json;
+
+#[macro_use]
+mod utils;
+
+use indyrs::ErrorCode;
+
+#[cfg(feature = "cheqd")]
+use utils::{cheqd_pool, cheqd_setup, cheqd_ledger};
+use serde_json::Value;
+
+#[cfg(feature = "cheqd")]
+mod high_cases {
+    use super::*;
+
+    #[cfg(test)]
+    mod simulate {
+        use super::*;
+
+        #[test]
+        fn test_build_query_simulate() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let msg = cheqd_ledger::bank::build_msg_send(
+                &setup.pool_alias, &setup.account_id, &setup.account_id, "100", &setup.denom,
+            ).unwrap();
+            let tx = cheqd_ledger::auth::build_tx(
+                &setup.pool_alias, &setup.pub_key, &msg, 0, 0, 0, 0, &setup.denom, 100, "memo",
+            ).unwrap();
+
+            let query = cheqd_ledger::tx::build_query_simulate(&tx).unwrap();
+            let query: Value = serde_json::from_str(&query).unwrap();
+
+            assert_eq!("/cosmos.tx.v1beta1.Service/Simulate", query["path"]);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_simulate() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (account_number, account_sequence) = setup.get_base_account_number_and_sequence(&setup.account_id).unwrap();
+
+            let msg = cheqd_ledger::bank::build_msg_send(
+                &setup.pool_alias, &setup.account_id, &setup.account_id, "100", &setup.denom,
+            ).unwrap();
+            let tx = cheqd_ledger::auth::build_tx(
+                &setup.pool_alias, &setup.pub_key, &msg, account_number, account_sequence, 0, 0, &setup.denom, setup.get_timeout_height(), "memo",
+            ).unwrap();
+
+            let query = cheqd_ledger::tx::build_query_simulate(&tx).unwrap();
+            let resp = cheqd_pool::abci_query(&setup.pool_alias, &query).unwrap();
+            let resp = cheqd_ledger::tx::parse_query_simulate_resp(&resp).unwrap();
+            let resp: Value = serde_json::from_str(&resp).unwrap();
+
+            assert!(resp["gas_info"]["gas_used"].as_u64().unwrap() > 0);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_build_tx_with_fee_estimation() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (account_number, account_sequence) = setup.get_base_account_number_and_sequence(&setup.account_id).unwrap();
+
+            let msg = cheqd_ledger::bank::build_msg_send(
+                &setup.pool_alias, &setup.account_id, &setup.account_id, "100", &setup.denom,
+            ).unwrap();
+            let tx = cheqd_ledger::auth::build_tx_with_fee_estimation(
+                &setup.pool_alias, &setup.pub_key, &msg, account_number, account_sequence, &setup.denom, setup.get_timeout_height(), "memo", None,
+            ).unwrap();
+
+            assert_ne!(tx.len(), 0);
+        }
+    }
+
+    #[cfg(test)]
+    mod estimate_fee {
+        use super::*;
+
+        #[test]
+        fn test_estimate_fee() {
+            let fee = cheqd_ledger::tx::estimate_fee(100000, None).unwrap();
+            let fee: Value = serde_json::from_str(&fee).unwrap();
+
+            assert_eq!(json!({"gas_used": 100000, "gas_limit": 130000, "amount": 3250}), fee);
+        }
+
+        #[test]
+        fn test_estimate_fee_for_options() {
+            let options = json!({"gas_price": 0.5, "gas_adjustment": 2.0}).to_string();
+            let fee = cheqd_ledger::tx::estimate_fee(100000, Some(&options)).unwrap();
+            let fee: Value = serde_json::from_str(&fee).unwrap();
+
+            assert_eq!(json!({"gas_used": 100000, "gas_limit": 200000, "amount": 100000}), fee);
+        }
+
+        #[test]
+        fn test_estimate_fee_for_invalid_options() {
+            let options = json!({"gas_adjustment": 0.5}).to_string();
+            let res = cheqd_ledger::tx::estimate_fee(100000, Some(&options));
+
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+    }
+}
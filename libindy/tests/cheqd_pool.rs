@@ -8,9 +8,13 @@ extern crate serde_json;
 mod utils;
 
 #[cfg(feature = "cheqd")]
-use utils::{cheqd_keys, cheqd_pool, cheqd_setup, cheqd_ledger};
+use utils::{cheqd_keys, cheqd_pool, cheqd_setup, cheqd_ledger, environment};
 use utils::test;
 use serde_json::Value;
+use indyrs::ErrorCode;
+
+#[cfg(feature = "cheqd")]
+const TRUSTED_HASH: &str = "6B68DCE8C5E48A1E4A1C1A4DBA4E7C8B2A2D4D25A1AA9D1B3AC2C2C5C2E1A1F0";
 
 #[cfg(feature = "cheqd")]
 mod high_cases {
@@ -23,9 +27,84 @@ mod high_cases {
         #[test]
         fn test_add() {
             let pool_name = "test_pool";
-            let result = cheqd_pool::add(&pool_name, "rpc_address", "chain_id").unwrap();
+            let result = cheqd_pool::add(&pool_name, "rpc_address", "chain_id", None, None, None).unwrap();
             test::cleanup_storage(&pool_name);
             println!("Data: {:?} ", result);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            assert_eq!("cheqd", result["account_prefix"]);
+        }
+
+        #[test]
+        fn test_add_for_account_prefix() {
+            let pool_name = "test_pool_prefix";
+            test::cleanup_storage(&pool_name);
+
+            cheqd_pool::add(&pool_name, "rpc_address", "chain_id", Some("cosmos"), None, None).unwrap();
+            let result = cheqd_pool::get_config(&pool_name).unwrap();
+            test::cleanup_storage(&pool_name);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            assert_eq!("cosmos", result["account_prefix"]);
+        }
+
+        #[test]
+        fn test_add_for_trust_options() {
+            let pool_name = "test_pool_trust_options";
+            test::cleanup_storage(&pool_name);
+
+            let trust_options = json!({"height": 1, "hash": TRUSTED_HASH}).to_string();
+            cheqd_pool::add(&pool_name, "rpc_address", "chain_id", None, Some(&trust_options), None).unwrap();
+            let result = cheqd_pool::get_config(&pool_name).unwrap();
+            test::cleanup_storage(&pool_name);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(1, result["trust_options"]["height"]);
+            assert_eq!(TRUSTED_HASH, result["trust_options"]["hash"]);
+            assert_eq!(json!({"numerator": 1, "denominator": 3}), result["trust_options"]["trust_level"]);
+        }
+
+        #[test]
+        fn test_add_for_invalid_trust_options() {
+            let pool_name = "test_pool_invalid_trust_options";
+            test::cleanup_storage(&pool_name);
+
+            let trust_options = json!({"height": 1, "hash": "hash"}).to_string();
+            let res = cheqd_pool::add(&pool_name, "rpc_address", "chain_id", None, Some(&trust_options), None);
+            test::cleanup_storage(&pool_name);
+
+            assert_code!(ErrorCode::CommonInvalidParam6, res);
+        }
+
+        #[test]
+        fn test_add_for_connection_options() {
+            let pool_name = "test_pool_connection_options";
+            test::cleanup_storage(&pool_name);
+
+            let connection_options = json!({
+                "backup_rpc_addresses": ["http://127.0.0.2:26657"],
+                "timeout": 5,
+            }).to_string();
+            cheqd_pool::add(&pool_name, "http://127.0.0.1:26657", "chain_id", None, None, Some(&connection_options)).unwrap();
+            let result = cheqd_pool::get_config(&pool_name).unwrap();
+            test::cleanup_storage(&pool_name);
+
+            let result: Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(json!(["http://127.0.0.2:26657"]), result["connection_options"]["backup_rpc_addresses"]);
+            assert_eq!(5, result["connection_options"]["timeout"]);
+            assert_eq!(3, result["connection_options"]["max_retries"]);
+        }
+
+        #[test]
+        fn test_add_for_invalid_connection_options() {
+            let pool_name = "test_pool_invalid_connection_options";
+            test::cleanup_storage(&pool_name);
+
+            let connection_options = json!({"timeout": 0}).to_string();
+            let res = cheqd_pool::add(&pool_name, "http://127.0.0.1:26657", "chain_id", None, None, Some(&connection_options));
+            test::cleanup_storage(&pool_name);
+
+            assert_code!(ErrorCode::CommonInvalidParam7, res);
         }
     }
 
@@ -40,7 +119,7 @@ mod high_cases {
             let pool_name = "test_pool";
             test::cleanup_storage(&pool_name);
 
-            cheqd_pool::add(&pool_name, "rpc_address", "chain_id").unwrap();
+            cheqd_pool::add(&pool_name, "rpc_address", "chain_id", None, None, None).unwrap();
             let result = cheqd_pool::get_config(&pool_name).unwrap();
             test::cleanup_storage(&pool_name);
 
@@ -57,8 +136,8 @@ mod high_cases {
             test::cleanup_storage(&pool_name_1);
             test::cleanup_storage(&pool_name_2);
 
-            cheqd_pool::add(&pool_name_1, RPC_ADDRESS, CHAIN_ID).unwrap();
-            cheqd_pool::add(&pool_name_2, RPC_ADDRESS, CHAIN_ID).unwrap();
+            cheqd_pool::add(&pool_name_1, RPC_ADDRESS, CHAIN_ID, None, None, None).unwrap();
+            cheqd_pool::add(&pool_name_2, RPC_ADDRESS, CHAIN_ID, None, None, None).unwrap();
 
             let result = cheqd_pool::get_all_config().unwrap();
             let result: Vec<Value> = serde_json::from_str(&result).unwrap();
@@ -66,12 +145,26 @@ mod high_cases {
             let expect_pool_1 = &json!({
                 "alias": pool_name_1.to_string(),
                 "rpc_address": RPC_ADDRESS.to_string(),
-                "chain_id": CHAIN_ID.to_string()
+                "chain_id": CHAIN_ID.to_string(),
+                "account_prefix": "cheqd",
+                "connection_options": {
+                    "backup_rpc_addresses": [],
+                    "timeout": 20,
+                    "max_retries": 3,
+                    "retry_backoff": 500
+                }
             });
             let expect_pool_2 = &json!({
                 "alias": pool_name_2.to_string(),
                 "rpc_address": RPC_ADDRESS.to_string(),
-                "chain_id": CHAIN_ID.to_string()
+                "chain_id": CHAIN_ID.to_string(),
+                "account_prefix": "cheqd",
+                "connection_options": {
+                    "backup_rpc_addresses": [],
+                    "timeout": 20,
+                    "max_retries": 3,
+                    "retry_backoff": 500
+                }
             });
 
             println!("Data: {:?} ", result);
@@ -119,6 +212,104 @@ mod high_cases {
         }
     }
 
+    #[cfg(test)]
+    mod broadcast_tx_sync {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_broadcast_tx_sync_and_get_tx() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (account_number, account_sequence) = setup.get_base_account_number_and_sequence(&setup.account_id).unwrap();
+
+            // Message
+            let msg = cheqd_ledger::cheqd::build_msg_create_nym(
+                "test-did",
+                &setup.account_id,
+                "test-verkey",
+                "test-alias",
+                "test-role",
+            ).unwrap();
+
+            // Transaction
+            let tx = cheqd_ledger::auth::build_tx(
+                &setup.pool_alias, &setup.pub_key, &msg, account_number, account_sequence, 300000, 0, "cheq", setup.get_timeout_height(), "memo",
+            ).unwrap();
+
+            // Sign
+            let signed = cheqd_keys::sign(setup.wallet_handle, &setup.key_alias, &tx).unwrap();
+
+            // Broadcast
+            let resp = cheqd_pool::broadcast_tx_sync(&setup.pool_alias, &signed).unwrap();
+            let resp: Value = serde_json::from_str(&resp).unwrap();
+            let tx_hash = resp["hash"].as_str().unwrap();
+
+            // Wait for the commit
+            let mut tx_resp = None;
+            for _ in 0..10 {
+                match cheqd_pool::get_tx(&setup.pool_alias, tx_hash) {
+                    Ok(resp) => {
+                        tx_resp = Some(resp);
+                        break;
+                    }
+                    Err(err) => {
+                        assert_eq!(ErrorCode::LedgerNotFound, err.error_code);
+                        std::thread::sleep(std::time::Duration::from_secs(1));
+                    }
+                }
+            }
+
+            // Parse the response
+            let tx_resp_parsed = cheqd_ledger::cheqd::parse_msg_create_nym_resp(&tx_resp.unwrap()).unwrap();
+            let tx_resp: Value = serde_json::from_str(&tx_resp_parsed).unwrap();
+            assert!(tx_resp["id"].as_u64().is_some());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_broadcast_tx_async() {
+            let setup = cheqd_setup::CheqdSetup::new();
+
+            let (account_number, account_sequence) = setup.get_base_account_number_and_sequence(&setup.account_id).unwrap();
+
+            let msg = cheqd_ledger::cheqd::build_msg_create_nym(
+                "test-did",
+                &setup.account_id,
+                "test-verkey",
+                "test-alias",
+                "test-role",
+            ).unwrap();
+
+            let tx = cheqd_ledger::auth::build_tx(
+                &setup.pool_alias, &setup.pub_key, &msg, account_number, account_sequence, 300000, 0, "cheq", setup.get_timeout_height(), "memo",
+            ).unwrap();
+
+            let signed = cheqd_keys::sign(setup.wallet_handle, &setup.key_alias, &tx).unwrap();
+
+            let resp = cheqd_pool::broadcast_tx_async(&setup.pool_alias, &signed).unwrap();
+            let resp: Value = serde_json::from_str(&resp).unwrap();
+            assert!(resp["hash"].is_string());
+        }
+    }
+
+    #[cfg(test)]
+    mod get_tx {
+        use super::*;
+
+        #[test]
+        fn test_get_tx_for_invalid_hash() {
+            let pool_name = "test_pool_get_tx";
+            test::cleanup_storage(&pool_name);
+
+            cheqd_pool::add(&pool_name, "http://127.0.0.1:26657", "chain_id", None, None, None).unwrap();
+            let res = cheqd_pool::get_tx(&pool_name, "invalid_hash");
+            test::cleanup_storage(&pool_name);
+
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     #[cfg(test)]
     mod abci_query {
         use super::*;
@@ -166,6 +357,31 @@ mod high_cases {
 
             assert!(true);
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_cheqd_pool")]
+        fn test_abci_query_for_untrusted_header() {
+            let setup = cheqd_setup::CheqdSetup::new();
+            let pool_alias = "pool_with_untrusted_header";
+            test::cleanup_storage(pool_alias);
+
+            // Header at height 1 doesn't have this hash
+            let trust_options = json!({"height": 1, "hash": TRUSTED_HASH}).to_string();
+            cheqd_pool::add(
+                pool_alias,
+                &environment::cheqd_test_pool_ip(),
+                &environment::cheqd_test_chain_id(),
+                None,
+                Some(&trust_options),
+                None,
+            ).unwrap();
+
+            let query = cheqd_ledger::auth::build_query_account(&setup.account_id).unwrap();
+            let res = cheqd_pool::abci_query(pool_alias, &query);
+            test::cleanup_storage(pool_alias);
+
+            assert_code!(ErrorCode::LedgerInvalidProofError, res);
+        }
     }
 
     #[cfg(test)]
@@ -459,6 +459,22 @@ mod high_cases {
             assert_eq!(my_verkey.from_base58().unwrap().len(), 32);
         }
 
+        #[test]
+        fn indy_create_my_did_works_for_did_indy_namespace() {
+            let setup = Setup::wallet();
+
+            let my_did_json =
+                json!({"method_name": INDY_SUB_NAMESPACE_METHOD, "seed": MY1_SEED}).to_string();
+            let (my_did, my_verkey) =
+                did::create_my_did(setup.wallet_handle, &my_did_json).unwrap();
+
+            assert_eq!(format!("did:{}:{}", INDY_SUB_NAMESPACE_METHOD, DID_MY1), my_did);
+            assert_eq!(
+                my_verkey,
+                did::key_for_local_did(setup.wallet_handle, &my_did).unwrap()
+            );
+        }
+
         #[test]
         fn indy_create_my_did_works_for_several_dids_but_different_methods() {
             let setup = Setup::wallet();
@@ -802,6 +818,27 @@ mod high_cases {
             );
         }
 
+        #[test]
+        fn qualify_did_for_did_indy_namespace() {
+            let setup = Setup::did();
+
+            let full_qualified_did =
+                did::qualify_did(setup.wallet_handle, &setup.did, INDY_SUB_NAMESPACE_METHOD)
+                    .unwrap();
+            assert_eq!(
+                full_qualified_did,
+                format!("did:{}:{}", INDY_SUB_NAMESPACE_METHOD, setup.did)
+            );
+
+            let new_full_qualified_did =
+                did::qualify_did(setup.wallet_handle, &full_qualified_did, DEFAULT_METHOD_NAME)
+                    .unwrap();
+            assert_eq!(
+                new_full_qualified_did,
+                format!("{}{}", DEFAULT_PREFIX, setup.did)
+            );
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn qualify_did_for_keeping_related_entities() {
@@ -902,6 +939,81 @@ mod high_cases {
             }
         }
     }
+
+    #[cfg(feature = "local_nodes_pool")]
+    mod resolve_did_document {
+        use super::*;
+
+        #[test]
+        fn indy_resolve_did_document_works() {
+            let setup = Setup::pool();
+
+            let result = did::resolve_did_document(setup.pool_handle, DID_TRUSTEE).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            let did_document = &result["didDocument"];
+            assert_eq!(format!("did:sov:{}", DID_TRUSTEE), did_document["id"]);
+            assert_eq!(VERKEY_TRUSTEE, did_document["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(did_document["verificationMethod"][0]["id"], did_document["authentication"][0]);
+            assert!(did_document["service"].is_null());
+            assert_eq!("application/did+ld+json", result["didResolutionMetadata"]["contentType"]);
+            assert_eq!("0", result["didDocumentMetadata"]["role"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_document_works_for_indy_method() {
+            let setup = Setup::pool();
+
+            let did = format!("did:indy:sovrin:{}", DID_TRUSTEE);
+
+            let result = did::resolve_did_document(setup.pool_handle, &did).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(did, result["didDocument"]["id"]);
+            assert_eq!(VERKEY_TRUSTEE, result["didDocument"]["verificationMethod"][0]["publicKeyBase58"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_document_works_for_endpoint() {
+            let setup = Setup::new_identity();
+
+            let attrib_data =
+                json!({"endpoint": {"ha": ENDPOINT, "verkey": VERKEY_TRUSTEE}}).to_string();
+            let attrib_request = ledger::build_attrib_request(
+                &setup.did,
+                &setup.did,
+                None,
+                Some(&attrib_data),
+                None,
+            )
+            .unwrap();
+            ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &attrib_request,
+            )
+            .unwrap();
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let did = format!("did:sov:{}", setup.did);
+
+            let result = did::resolve_did_document(setup.pool_handle, &did).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            let did_document = &result["didDocument"];
+            assert_eq!(setup.verkey, did_document["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(
+                json!([{
+                    "id": format!("{}#endpoint", did),
+                    "type": "endpoint",
+                    "serviceEndpoint": ENDPOINT,
+                    "recipientKeys": [VERKEY_TRUSTEE],
+                }]),
+                did_document["service"]
+            );
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -1281,4 +1393,25 @@ mod medium_cases {
             }
         }
     }
+
+    mod resolve_did_document {
+        use super::*;
+
+        #[test]
+        fn indy_resolve_did_document_works_for_unsupported_method() {
+            Setup::empty();
+
+            let res = did::resolve_did_document(-1, &format!("did:peer:{}", DID_TRUSTEE));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_resolve_did_document_works_for_unknown_did() {
+            let setup = Setup::pool();
+
+            let res = did::resolve_did_document(setup.pool_handle, DID_MY2);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
 }
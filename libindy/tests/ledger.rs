@@ -181,6 +181,110 @@ mod high_cases {
         }
     }
 
+    mod submit_requests_batch {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works() {
+            let setup = Setup::trustee();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+            let get_unknown_nym_request = ledger::build_get_nym_request(Some(&setup.did), DID_MY1).unwrap();
+            let requests = format!("[{}, {}]", get_nym_request, get_unknown_nym_request);
+
+            let results = ledger::submit_requests_batch(setup.pool_handle, &requests).unwrap();
+            let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+            assert_eq!(2, results.len());
+
+            let nym_response = results[0]["response"].as_str().unwrap();
+            pool::check_response_type(nym_response, ResponseType::REPLY);
+            let nym_data = ledger::parse_get_nym_response(nym_response).unwrap();
+            let nym_data: NymData = serde_json::from_str(&nym_data).unwrap();
+            assert_eq!(setup.did, nym_data.did.0);
+
+            let unknown_nym_response = results[1]["response"].as_str().unwrap();
+            pool::check_response_type(unknown_nym_response, ResponseType::REPLY);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works_for_empty_batch() {
+            let setup = Setup::pool();
+
+            let results = ledger::submit_requests_batch(setup.pool_handle, "[]").unwrap();
+            assert_eq!("[]", results);
+        }
+    }
+
+    mod replicate_ledger {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_replicate_ledger_works() {
+            let setup = Setup::pool();
+
+            let status = ledger::replicate_ledger(setup.pool_handle, "DOMAIN", Some(r#"{"batchSize": 2, "maxTxns": 3}"#)).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(1, status["fromSeqNo"].as_u64().unwrap());
+            assert_eq!(3, status["seqNo"].as_u64().unwrap());
+            assert!(status["ledgerSize"].as_u64().unwrap() >= 3);
+            assert!(status["rootHash"].is_string());
+
+            let status = ledger::replicate_ledger(setup.pool_handle, "DOMAIN", Some(r#"{"maxTxns": 2}"#)).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(4, status["fromSeqNo"].as_u64().unwrap());
+            assert_eq!(5, status["seqNo"].as_u64().unwrap());
+
+            let txns = ledger::get_ledger_replica_txns(&setup.name, "DOMAIN", Some(r#"{"from": 2, "to": 4}"#)).unwrap();
+            let txns: serde_json::Value = serde_json::from_str(&txns).unwrap();
+            assert_eq!(5, txns["seqNo"].as_u64().unwrap());
+            assert_eq!(status["rootHash"], txns["rootHash"]);
+
+            let txns = txns["txns"].as_array().unwrap();
+            assert_eq!(3, txns.len());
+            assert_eq!(2, txns[0]["txnMetadata"]["seqNo"].as_u64().unwrap());
+            assert_eq!(4, txns[2]["txnMetadata"]["seqNo"].as_u64().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_replicate_ledger_works_for_config_ledger() {
+            let setup = Setup::pool();
+
+            let status = ledger::replicate_ledger(setup.pool_handle, "CONFIG", None).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert!(status["complete"].as_bool().unwrap());
+
+            match status["ledgerSize"].as_u64() {
+                Some(ledger_size) => {
+                    assert_eq!(ledger_size, status["seqNo"].as_u64().unwrap());
+                    assert_eq!(status["signedRootHash"], status["rootHash"]);
+                }
+                None => assert_eq!(0, status["seqNo"].as_u64().unwrap()),
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_ledger_replica_txns_works_for_authors() {
+            let setup = Setup::pool();
+
+            ledger::replicate_ledger(setup.pool_handle, "DOMAIN", Some(r#"{"maxTxns": 5}"#)).unwrap();
+
+            let filter = json!({"authors": [DID_TRUSTEE]}).to_string();
+            let txns = ledger::get_ledger_replica_txns(&setup.name, "DOMAIN", Some(&filter)).unwrap();
+            let txns: serde_json::Value = serde_json::from_str(&txns).unwrap();
+
+            let txns = txns["txns"].as_array().unwrap();
+            assert!(!txns.is_empty());
+            for txn in txns {
+                assert_eq!(DID_TRUSTEE, txn["txn"]["metadata"]["from"].as_str().unwrap());
+            }
+        }
+    }
+
     mod submit_action {
         use super::*;
 
@@ -1080,6 +1184,49 @@ mod high_cases {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        fn indy_build_ledgers_freeze_request() {
+            let expected_result = json!({
+                "type": constants::LEDGERS_FREEZE,
+                "ledgers_ids": [0, 1, 28, 345]
+            });
+
+            let request = ledger::build_ledgers_freeze_request(IDENTIFIER, "[0, 1, 28, 345]").unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works_for_invalid_ledgers_ids() {
+            let res = ledger::build_ledgers_freeze_request(IDENTIFIER, r#"["domain"]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_get_frozen_ledgers_request() {
+            let expected_result = json!({
+                "type": constants::GET_FROZEN_LEDGERS,
+            });
+
+            let request = ledger::build_get_frozen_ledgers_request(IDENTIFIER).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_frozen_ledgers_request_works() {
+            let setup = Setup::trustee();
+
+            let request = ledger::build_get_frozen_ledgers_request(&setup.did).unwrap();
+            let response = ledger::submit_request(setup.pool_handle, &request).unwrap();
+
+            let frozen_ledgers = ledger::parse_get_frozen_ledgers_response(&response).unwrap();
+            let _frozen_ledgers: serde_json::Value = serde_json::from_str(&frozen_ledgers).unwrap();
+        }
+    }
+
     mod get_txn_requests {
         use super::*;
 
@@ -1822,27 +1969,37 @@ mod high_cases {
         }
     }
 
-    // FIXME: [async] re-check if needed
-    //    mod indy_register_transaction_parser_for_sp {
-    //        extern crate libc;
-    //
-    //        use super::*;
-    //
-    //        use self::libc::c_char;
-    //
-    //        #[test]
-    //        fn indy_register_transaction_parser_for_sp_works() {
-    //            Setup::empty();
-    //
-    //            extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> i32 {
-    //                unsafe { *parsed = msg; }
-    //                ErrorCode::Success as i32
-    //            }
-    //            extern fn free(_buf: *const c_char) -> i32 { ErrorCode::Success as i32 }
-    //
-    //            ledger::register_transaction_parser_for_sp("my_txn_type", parse, free).unwrap();
-    //        }
-    //    }
+    mod indy_register_transaction_parser_for_sp {
+        use super::*;
+
+        use libc::c_char;
+
+        extern "C" fn parse(msg: *const c_char, parsed: *mut *const c_char) -> i32 {
+            unsafe {
+                *parsed = msg;
+            }
+            ErrorCode::Success as i32
+        }
+
+        extern "C" fn free(_buf: *const c_char) -> i32 {
+            ErrorCode::Success as i32
+        }
+
+        #[test]
+        fn indy_register_transaction_parser_for_sp_works() {
+            Setup::empty();
+
+            ledger::register_transaction_parser_for_sp("my_txn_type", parse, free).unwrap();
+        }
+
+        #[test]
+        fn indy_register_transaction_parser_for_sp_works_for_builtin_type() {
+            Setup::empty();
+
+            let res = ledger::register_transaction_parser_for_sp(constants::GET_NYM, parse, free);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 
     mod get_response_metadata {
         use super::*;
@@ -4128,6 +4285,228 @@ mod high_cases {
 
             _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
         }
+
+        fn _acceptance_json(text: &str, version: &str, mechanism: &str) -> String {
+            json!({
+                "text": text,
+                "version": version,
+                "mechanism": mechanism,
+                "time": time::get_time().sec as u64,
+            })
+            .to_string()
+        }
+
+        fn _send_nym(setup: &Setup) -> String {
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req =
+                ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+
+            ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &nym_req,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn indy_get_pool_txn_author_agreement_works() {
+            let setup = Setup::trustee();
+
+            let (aml, _, aml_version, aml_context) =
+                _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (taa_text, taa_version, taa_digest, ratification_ts) =
+                _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            pool::refresh(setup.pool_handle).unwrap();
+
+            let taa = ledger::get_pool_txn_author_agreement(setup.pool_handle).unwrap();
+            let taa: serde_json::Value = serde_json::from_str(&taa).unwrap();
+
+            let expected_aml: serde_json::Value = serde_json::from_str(&aml).unwrap();
+
+            assert_eq!(taa_text, taa["text"]);
+            assert_eq!(taa_version, taa["version"]);
+            assert_eq!(taa_digest, taa["digest"]);
+            assert_eq!(ratification_ts, taa["ratificationTs"]);
+            assert_eq!(expected_aml, taa["aml"]);
+            assert_eq!(aml_version, taa["amlVersion"]);
+            assert_eq!(aml_context, taa["amlContext"]);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            pool::refresh(setup.pool_handle).unwrap();
+
+            let taa = ledger::get_pool_txn_author_agreement(setup.pool_handle).unwrap();
+            assert_eq!("null", taa);
+        }
+
+        #[test]
+        fn indy_sign_and_submit_request_works_for_stored_taa_acceptance() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (taa_text, taa_version, _, _) =
+                _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            pool::refresh(setup.pool_handle).unwrap();
+
+            ledger::store_txn_author_agreement_acceptance(
+                setup.wallet_handle,
+                &setup.name,
+                &_acceptance_json(&taa_text, &taa_version, &aml_label),
+            )
+            .unwrap();
+
+            let nym_resp = _send_nym(&setup);
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        fn indy_sign_and_submit_request_works_for_taa_acceptance_from_pool_config() {
+            let mut setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (taa_text, taa_version, _, _) =
+                _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let config = json!({
+                "taa_acceptance": {
+                    "text": taa_text,
+                    "version": taa_version,
+                    "mechanism": aml_label,
+                    "time": time::get_time().sec as u64,
+                }
+            })
+            .to_string();
+
+            pool::close(setup.pool_handle).unwrap();
+            setup.pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            let nym_resp = _send_nym(&setup);
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_sign_and_submit_request_works_for_changed_taa() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (taa_text, taa_version, _, _) =
+                _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            ledger::store_txn_author_agreement_acceptance(
+                setup.wallet_handle,
+                &setup.name,
+                &_acceptance_json(&taa_text, &taa_version, &aml_label),
+            )
+            .unwrap();
+
+            _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            pool::refresh(setup.pool_handle).unwrap();
+
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req =
+                ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+
+            let res = ledger::sign_and_submit_request(
+                setup.pool_handle,
+                setup.wallet_handle,
+                &setup.did,
+                &nym_req,
+            );
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_sign_and_submit_request_works_for_changed_taa_and_warn_policy() {
+            let mut setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (taa_text, taa_version, _, _) =
+                _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let config = json!({
+                "taa_acceptance": {
+                    "text": taa_text,
+                    "version": taa_version,
+                    "mechanism": aml_label,
+                    "time": time::get_time().sec as u64,
+                },
+                "taa_change_policy": "warn",
+            })
+            .to_string();
+
+            pool::close(setup.pool_handle).unwrap();
+            setup.pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            // Not retired TAA is still accepted by the ledger
+            let nym_resp = _send_nym(&setup);
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_store_txn_author_agreement_acceptance_works_for_invalid_digest() {
+            let setup = Setup::wallet();
+
+            let acceptance = json!({
+                "text": TEXT,
+                "version": VERSION,
+                "taa_digest": "0000",
+                "mechanism": ACCEPTANCE_MECH_TYPE,
+                "time": TIME_OF_ACCEPTANCE,
+            })
+            .to_string();
+
+            let res = ledger::store_txn_author_agreement_acceptance(
+                setup.wallet_handle,
+                &setup.name,
+                &acceptance,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_store_txn_author_agreement_acceptance_works_for_missed_mechanism() {
+            let setup = Setup::wallet();
+
+            let acceptance = json!({
+                "taa_digest": HASH,
+                "mechanism": "",
+                "time": TIME_OF_ACCEPTANCE,
+            })
+            .to_string();
+
+            let res = ledger::store_txn_author_agreement_acceptance(
+                setup.wallet_handle,
+                &setup.name,
+                &acceptance,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod append_request_endorser {
@@ -4232,6 +4611,82 @@ mod high_cases {
             pool::check_response_type(&response, ResponseType::REQNACK);
         }
     }
+
+    mod transaction_bundle {
+        use super::*;
+
+        fn _setup_new_identity(wallet_handle: WalletHandle, pool_handle: PoolHandle) -> String {
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym =
+                ledger::build_nym_request(DID_TRUSTEE, &my_did, Some(&my_vk), None, None).unwrap();
+
+            let response =
+                ledger::sign_and_submit_request(pool_handle, wallet_handle, DID_TRUSTEE, &nym)
+                    .unwrap();
+
+            pool::check_response_type(&response, ResponseType::REPLY);
+            my_did
+        }
+
+        #[test]
+        fn indy_transaction_bundle_works_for_endorser() {
+            let setup = Setup::endorser();
+            let my_did = _setup_new_identity(setup.wallet_handle, setup.pool_handle);
+
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let request = ledger::append_request_endorser(&request, &setup.did).unwrap();
+
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, None).unwrap();
+
+            let bundle_value: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            assert_eq!(json!([my_did, setup.did]), bundle_value["requiredSigners"]);
+
+            let status = ledger::get_transaction_bundle_status(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(json!([my_did, setup.did]), status["missingSigners"]);
+            assert_eq!(false, status["ready"].as_bool().unwrap());
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &my_did).unwrap();
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did).unwrap();
+
+            let status = ledger::get_transaction_bundle_status(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(json!([]), status["missingSigners"]);
+            assert_eq!(0, status["missingSignatureCount"].as_u64().unwrap());
+            assert_eq!(true, status["ready"].as_bool().unwrap());
+
+            let response = ledger::submit_transaction_bundle(setup.pool_handle, &bundle).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        fn indy_transaction_bundle_works_for_signed_request() {
+            let setup = Setup::endorser();
+            let my_did = _setup_new_identity(setup.wallet_handle, setup.pool_handle);
+
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let request = ledger::append_request_endorser(&request, &setup.did).unwrap();
+            let request = ledger::sign_request(setup.wallet_handle, &my_did, &request).unwrap();
+
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, None).unwrap();
+
+            let status = ledger::get_transaction_bundle_status(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(json!([setup.did]), status["missingSigners"]);
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did).unwrap();
+
+            let response = ledger::submit_transaction_bundle(setup.pool_handle, &bundle).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -4286,6 +4741,87 @@ mod medium_cases {
         }
     }
 
+    mod submit_requests_batch {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works_for_write_request() {
+            let setup = Setup::pool();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let requests = format!("[{}, {}]", get_nym_request, nym_request);
+
+            let res = ledger::submit_requests_batch(setup.pool_handle, &requests);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works_for_invalid_requests_json() {
+            let setup = Setup::pool();
+
+            let res = ledger::submit_requests_batch(setup.pool_handle, REQUEST);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_batch_works_for_invalid_pool_handle() {
+            let _setup = Setup::empty();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let requests = format!("[{}]", get_nym_request);
+
+            let res = ledger::submit_requests_batch(INVALID_POOL_HANDLE, &requests);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
+    mod replicate_ledger {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_replicate_ledger_works_for_pool_ledger() {
+            let setup = Setup::pool();
+
+            let res = ledger::replicate_ledger(setup.pool_handle, "POOL", None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_replicate_ledger_works_for_invalid_options() {
+            let setup = Setup::pool();
+
+            let res = ledger::replicate_ledger(setup.pool_handle, "DOMAIN", Some(r#"{"maxTxns": "all"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_replicate_ledger_works_for_invalid_pool_handle() {
+            let _setup = Setup::empty();
+
+            let res = ledger::replicate_ledger(INVALID_POOL_HANDLE, "DOMAIN", None);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_ledger_replica_txns_works_for_empty_replica() {
+            let setup = Setup::empty();
+
+            let txns = ledger::get_ledger_replica_txns(&setup.name, "DOMAIN", None).unwrap();
+            let txns: serde_json::Value = serde_json::from_str(&txns).unwrap();
+            assert_eq!(0, txns["seqNo"].as_u64().unwrap());
+            assert!(txns["rootHash"].is_null());
+            assert!(txns["txns"].as_array().unwrap().is_empty());
+        }
+    }
+
     mod submit_action {
         use super::*;
 
@@ -5657,6 +6193,159 @@ mod medium_cases {
         }
     }
 
+    mod transaction_bundle {
+        use super::*;
+
+        #[test]
+        fn indy_submit_transaction_bundle_works_for_missed_signature() {
+            let setup = Setup::endorser();
+
+            let request = ledger::build_schema_request(DID_TRUSTEE, SCHEMA_DATA).unwrap();
+            let request = ledger::append_request_endorser(&request, &setup.did).unwrap();
+
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, None).unwrap();
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did).unwrap();
+
+            let res = ledger::submit_transaction_bundle(setup.pool_handle, &bundle);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        fn indy_transaction_bundle_works_for_expired() {
+            let setup = Setup::endorser();
+
+            let request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+
+            let options = json!({ "expiresAt": 1 }).to_string();
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, Some(&options))
+                    .unwrap();
+
+            let status = ledger::get_transaction_bundle_status(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(true, status["expired"].as_bool().unwrap());
+            assert_eq!(false, status["ready"].as_bool().unwrap());
+
+            let res = ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        fn indy_transaction_bundle_works_for_additional_signers() {
+            let setup = Setup::endorser();
+
+            let request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+
+            let options = json!({ "signers": [DID_TRUSTEE] }).to_string();
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, Some(&options))
+                    .unwrap();
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did).unwrap();
+
+            let status = ledger::get_transaction_bundle_status(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(json!([DID_TRUSTEE]), status["missingSigners"]);
+            assert_eq!(0, status["missingSignatureCount"].as_u64().unwrap());
+            assert_eq!(false, status["ready"].as_bool().unwrap());
+        }
+
+        #[test]
+        fn indy_submit_transaction_bundle_works_for_changed_request() {
+            let setup = Setup::endorser();
+
+            let request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, None).unwrap();
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did).unwrap();
+
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["request"]["reqId"] = json!(1);
+            let bundle = bundle.to_string();
+
+            let res = ledger::submit_transaction_bundle(setup.pool_handle, &bundle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_get_transaction_bundle_status_works_for_changed_request() {
+            let setup = Setup::endorser();
+
+            let request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, None).unwrap();
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did).unwrap();
+
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["request"]["reqId"] = json!(1);
+            let bundle = bundle.to_string();
+
+            let res = ledger::get_transaction_bundle_status(&bundle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_transaction_bundle_works_for_signature_of_another_signer() {
+            let setup = Setup::endorser();
+
+            let request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+
+            let options = json!({ "signers": [DID_TRUSTEE] }).to_string();
+            let bundle =
+                ledger::build_transaction_bundle(setup.pool_handle, &request, Some(&options))
+                    .unwrap();
+
+            let bundle =
+                ledger::sign_transaction_bundle(setup.wallet_handle, &bundle, &setup.did).unwrap();
+
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["signatures"][DID_TRUSTEE] = bundle["signatures"][&setup.did].clone();
+            let bundle = bundle.to_string();
+
+            let status = ledger::get_transaction_bundle_status(&bundle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(true, status["ready"].as_bool().unwrap());
+
+            let res = ledger::submit_transaction_bundle(setup.pool_handle, &bundle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_transaction_bundle_works_for_invalid_options() {
+            let setup = Setup::pool();
+
+            let options = json!({ "authAction": "DELETE" }).to_string();
+            let res = ledger::build_transaction_bundle(setup.pool_handle, REQUEST, Some(&options));
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+        }
+
+        #[test]
+        fn indy_get_transaction_bundle_status_works_for_invalid_bundle() {
+            Setup::empty();
+
+            let bundle = json!({
+                "ver": "1",
+                "request": serde_json::from_str::<serde_json::Value>(REQUEST).unwrap(),
+                "requiredSigners": [],
+                "requiredSignatureCount": 0,
+            })
+            .to_string();
+
+            let res = ledger::get_transaction_bundle_status(&bundle);
+            assert_code!(ErrorCode::CommonInvalidParam2, res);
+        }
+    }
+
     mod get_response_metadata {
         use super::*;
 
@@ -37,8 +37,6 @@ mod high_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_add_wallet_record_works_for_plugged_wallet() {
             let setup = Setup::plugged_wallet();
             add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
@@ -70,8 +68,6 @@ mod high_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_update_record_value_works_for_plugged_wallet() {
             let setup = Setup::plugged_wallet();
 
@@ -245,6 +241,59 @@ mod high_cases {
         }
     }
 
+    mod batch {
+        use super::*;
+
+        #[test]
+        fn indy_wallet_batch_works() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            let tags: serde_json::Value = serde_json::from_str(TAGS).unwrap();
+
+            let operations = json!([
+                {"op": "update", "type": TYPE, "id": ID, "value": VALUE_2},
+                {"op": "add", "type": TYPE, "id": ID_2, "value": VALUE_3},
+                {"op": "addTags", "type": TYPE, "id": ID_2, "tags": tags},
+                {"op": "add", "type": TYPE, "id": ID_3, "value": VALUE_4},
+                {"op": "delete", "type": TYPE, "id": ID_3},
+            ])
+            .to_string();
+
+            wallet_batch(setup.wallet_handle, &operations).unwrap();
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE_2);
+            check_record_field(setup.wallet_handle, TYPE, ID_2, "value", VALUE_3);
+            check_record_field(setup.wallet_handle, TYPE, ID_2, "tags", TAGS);
+
+            let res = get_wallet_record(setup.wallet_handle, TYPE, ID_3, OPTIONS_EMPTY);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_rollback() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            let operations = json!([
+                {"op": "update", "type": TYPE, "id": ID, "value": VALUE_2},
+                {"op": "add", "type": TYPE, "id": ID_2, "value": VALUE_3},
+                {"op": "delete", "type": TYPE, "id": ID_3},
+            ])
+            .to_string();
+
+            let res = wallet_batch(setup.wallet_handle, &operations);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE);
+
+            let res = get_wallet_record(setup.wallet_handle, TYPE, ID_2, OPTIONS_EMPTY);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
     mod get_record {
         use super::*;
 
@@ -271,8 +320,6 @@ mod high_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_get_wallet_record_works_for_plugged_wallet_default_options() {
             let setup = Setup::plugged_wallet();
 
@@ -734,6 +781,43 @@ mod high_cases {
                 tear_down(wallet_handle, search_handle);
                 cleanup_wallet("indy_wallet_search_for_no_records");
             }
+
+            #[test]
+            fn indy_wallet_search_for_empty_query_for_plugged_wallet() {
+                let setup = Setup::plugged_wallet();
+
+                let record_1 = record_1();
+                add_wallet_record(
+                    setup.wallet_handle,
+                    TYPE,
+                    &record_1.id,
+                    &record_1.value.clone().unwrap(),
+                    Some(TAGS),
+                )
+                .unwrap();
+
+                let record_2 = record_2();
+                add_wallet_record(
+                    setup.wallet_handle,
+                    TYPE,
+                    &record_2.id,
+                    &record_2.value.clone().unwrap(),
+                    Some(TAGS_2),
+                )
+                .unwrap();
+
+                let search_handle =
+                    open_wallet_search(setup.wallet_handle, TYPE, QUERY_EMPTY, OPTIONS_FULL)
+                        .unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(setup.wallet_handle, search_handle, 5)
+                        .unwrap();
+
+                check_search_records(&search_records, vec![record_1, record_2]);
+
+                close_wallet_search(search_handle).unwrap();
+            }
         }
 
         mod options {
@@ -1018,6 +1102,253 @@ mod high_cases {
                 tear_down(wallet_handle, search_handle);
                 cleanup_wallet("indy_wallet_search_for_retrieve_records_only");
             }
+            #[test]
+            fn indy_wallet_search_for_sort_by_plain_tag() {
+                const SEARCH_WALLET_CONFIG: &str =
+                    r#"{"id":"indy_wallet_search_for_sort_by_plain_tag"}"#;
+                let wallet_handle = setup(
+                    "indy_wallet_search_for_sort_by_plain_tag",
+                    SEARCH_WALLET_CONFIG,
+                );
+
+                let options = json!({
+                    "sortBy": {"tagName": "~tagName3"}
+                })
+                .to_string();
+
+                let search_handle =
+                    open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options).unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+                // Record without ~tagName3 goes last
+                assert_eq!(
+                    vec![ID_2, ID_4, ID_5, ID, ID_3],
+                    search_record_ids(&search_records)
+                );
+
+                tear_down(wallet_handle, search_handle);
+                cleanup_wallet("indy_wallet_search_for_sort_by_plain_tag");
+            }
+
+            #[test]
+            fn indy_wallet_search_for_sort_by_plain_tag_desc() {
+                const SEARCH_WALLET_CONFIG: &str =
+                    r#"{"id":"indy_wallet_search_for_sort_by_plain_tag_desc"}"#;
+                let wallet_handle = setup(
+                    "indy_wallet_search_for_sort_by_plain_tag_desc",
+                    SEARCH_WALLET_CONFIG,
+                );
+
+                let options = json!({
+                    "sortBy": {"tagName": "~tagName3", "order": "desc"}
+                })
+                .to_string();
+
+                let search_handle =
+                    open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options).unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+                assert_eq!(
+                    vec![ID, ID_5, ID_4, ID_2, ID_3],
+                    search_record_ids(&search_records)
+                );
+
+                tear_down(wallet_handle, search_handle);
+                cleanup_wallet("indy_wallet_search_for_sort_by_plain_tag_desc");
+            }
+
+            #[test]
+            fn indy_wallet_search_for_offset_and_limit() {
+                const SEARCH_WALLET_CONFIG: &str =
+                    r#"{"id":"indy_wallet_search_for_offset_and_limit"}"#;
+                let wallet_handle = setup(
+                    "indy_wallet_search_for_offset_and_limit",
+                    SEARCH_WALLET_CONFIG,
+                );
+
+                let options = json!({
+                    "retrieveTotalCount": true,
+                    "offset": 1,
+                    "limit": 2,
+                    "sortBy": {"tagName": "~tagName3"}
+                })
+                .to_string();
+
+                let search_handle =
+                    open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options).unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+                assert_eq!(vec![ID_4, ID_5], search_record_ids(&search_records));
+
+                let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
+                assert_eq!(5, search_records.total_count.unwrap());
+
+                tear_down(wallet_handle, search_handle);
+                cleanup_wallet("indy_wallet_search_for_offset_and_limit");
+            }
+
+            #[test]
+            fn indy_wallet_search_for_cursor() {
+                const SEARCH_WALLET_CONFIG: &str = r#"{"id":"indy_wallet_search_for_cursor"}"#;
+                let wallet_handle = setup("indy_wallet_search_for_cursor", SEARCH_WALLET_CONFIG);
+
+                let mut options = json!({
+                    "limit": 2,
+                    "sortBy": {"tagName": "~tagName3"}
+                });
+
+                let mut pages: Vec<Vec<String>> = Vec::new();
+
+                loop {
+                    let search_handle = open_wallet_search(
+                        wallet_handle,
+                        TYPE,
+                        QUERY_EMPTY,
+                        &options.to_string(),
+                    )
+                    .unwrap();
+
+                    let search_records =
+                        fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+                    close_wallet_search(search_handle).unwrap();
+
+                    pages.push(search_record_ids(&search_records));
+
+                    let search_records: SearchRecords =
+                        serde_json::from_str(&search_records).unwrap();
+
+                    match search_records.cursor {
+                        Some(cursor) => options["cursor"] = json!(cursor),
+                        None => break,
+                    }
+                }
+
+                assert_eq!(
+                    vec![vec![ID_2, ID_4], vec![ID_5, ID], vec![ID_3]],
+                    pages
+                );
+
+                wallet::close_wallet(wallet_handle).unwrap();
+                cleanup_wallet("indy_wallet_search_for_cursor");
+            }
+
+            #[test]
+            fn indy_wallet_search_for_cursor_after_deleted_records() {
+                const SEARCH_WALLET_CONFIG: &str =
+                    r#"{"id":"indy_wallet_search_for_cursor_after_deleted_records"}"#;
+                let wallet_handle = setup(
+                    "indy_wallet_search_for_cursor_after_deleted_records",
+                    SEARCH_WALLET_CONFIG,
+                );
+
+                let options = json!({
+                    "limit": 2,
+                    "sortBy": {"tagName": "~tagName3"}
+                });
+
+                let search_handle =
+                    open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options.to_string())
+                        .unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+                close_wallet_search(search_handle).unwrap();
+
+                assert_eq!(vec![ID_2, ID_4], search_record_ids(&search_records));
+
+                // Records of the fetched page don't shift the next one
+                delete_wallet_record(wallet_handle, TYPE, ID_2).unwrap();
+                delete_wallet_record(wallet_handle, TYPE, ID_4).unwrap();
+
+                let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
+
+                let mut options = options;
+                options["cursor"] = json!(search_records.cursor.unwrap());
+
+                let search_handle =
+                    open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options.to_string())
+                        .unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+                assert_eq!(vec![ID_5, ID], search_record_ids(&search_records));
+
+                tear_down(wallet_handle, search_handle);
+                cleanup_wallet("indy_wallet_search_for_cursor_after_deleted_records");
+            }
+
+            #[test]
+            fn indy_wallet_search_for_sort_and_limit_for_plugged_wallet() {
+                let setup = Setup::plugged_wallet();
+
+                let record_1 = record_1();
+                add_wallet_record(
+                    setup.wallet_handle,
+                    TYPE,
+                    &record_1.id,
+                    &record_1.value.clone().unwrap(),
+                    Some(TAGS),
+                )
+                .unwrap();
+
+                let record_2 = record_2();
+                add_wallet_record(
+                    setup.wallet_handle,
+                    TYPE,
+                    &record_2.id,
+                    &record_2.value.clone().unwrap(),
+                    Some(TAGS_2),
+                )
+                .unwrap();
+
+                let options = json!({
+                    "retrieveTotalCount": true,
+                    "limit": 1,
+                    "sortBy": {"tagName": "~tagName3"}
+                })
+                .to_string();
+
+                let search_handle =
+                    open_wallet_search(setup.wallet_handle, TYPE, QUERY_EMPTY, &options).unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(setup.wallet_handle, search_handle, 5)
+                        .unwrap();
+
+                close_wallet_search(search_handle).unwrap();
+
+                assert_eq!(vec![ID_2], search_record_ids(&search_records));
+
+                let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
+                assert_eq!(2, search_records.total_count.unwrap());
+
+                let options = json!({
+                    "limit": 1,
+                    "sortBy": {"tagName": "~tagName3"},
+                    "cursor": search_records.cursor.unwrap()
+                })
+                .to_string();
+
+                let search_handle =
+                    open_wallet_search(setup.wallet_handle, TYPE, QUERY_EMPTY, &options).unwrap();
+
+                let search_records =
+                    fetch_wallet_search_next_records(setup.wallet_handle, search_handle, 5)
+                        .unwrap();
+
+                assert_eq!(vec![ID], search_record_ids(&search_records));
+
+                close_wallet_search(search_handle).unwrap();
+            }
         }
 
         mod close {
@@ -1326,6 +1657,64 @@ mod medium_cases {
         }
     }
 
+    mod batch {
+        use super::*;
+
+        #[test]
+        fn indy_wallet_batch_works_for_invalid_handle() {
+            Setup::empty();
+
+            let operations = json!([{"op": "add", "type": TYPE, "id": ID, "value": VALUE}]).to_string();
+
+            let res = wallet_batch(INVALID_WALLET_HANDLE, &operations);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_invalid_operations() {
+            let setup = Setup::wallet();
+
+            let res = wallet_batch(setup.wallet_handle, "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = wallet_batch(setup.wallet_handle, r#"[{"op": "unknown", "type": "TestType", "id": "RecordId"}]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = wallet_batch(setup.wallet_handle, r#"[{"op": "delete", "type": "", "id": "RecordId"}]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_invalid_type() {
+            let setup = Setup::wallet();
+
+            let operations = json!([
+                {"op": "add", "type": TYPE, "id": ID, "value": VALUE},
+                {"op": "add", "type": FORBIDDEN_TYPE, "id": ID, "value": VALUE},
+            ])
+            .to_string();
+
+            let res = wallet_batch(setup.wallet_handle, &operations);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+
+            let res = get_wallet_record(setup.wallet_handle, TYPE, ID, OPTIONS_EMPTY);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_wallet_batch_works_for_plugged_wallet() {
+            let setup = Setup::plugged_wallet();
+
+            let operations = json!([{"op": "add", "type": TYPE, "id": ID, "value": VALUE}]).to_string();
+
+            let res = wallet_batch(setup.wallet_handle, &operations);
+            assert_code!(ErrorCode::WalletStorageError, res);
+
+            let res = get_wallet_record(setup.wallet_handle, TYPE, ID, OPTIONS_EMPTY);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
     mod get_record {
         use super::*;
 
@@ -1452,6 +1841,117 @@ mod medium_cases {
             cleanup_wallet("indy_wallet_search_for_invalid_type");
         }
 
+        #[test]
+        fn indy_wallet_search_for_sort_by_encrypted_tag() {
+            const SEARCH_WALLET_CONFIG: &str =
+                r#"{"id":"indy_wallet_search_for_sort_by_encrypted_tag"}"#;
+            let wallet_handle = setup(
+                "indy_wallet_search_for_sort_by_encrypted_tag",
+                SEARCH_WALLET_CONFIG,
+            );
+
+            let options = json!({
+                "sortBy": {"tagName": "tagName1"}
+            })
+            .to_string();
+
+            let res = open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options);
+            assert_code!(ErrorCode::WalletQueryError, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            cleanup_wallet("indy_wallet_search_for_sort_by_encrypted_tag");
+        }
+
+        #[test]
+        fn indy_wallet_search_for_invalid_cursor() {
+            const SEARCH_WALLET_CONFIG: &str =
+                r#"{"id":"indy_wallet_search_for_invalid_cursor"}"#;
+            let wallet_handle =
+                setup("indy_wallet_search_for_invalid_cursor", SEARCH_WALLET_CONFIG);
+
+            let options = json!({
+                "cursor": "invalid cursor"
+            })
+            .to_string();
+
+            let res = open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            cleanup_wallet("indy_wallet_search_for_invalid_cursor");
+        }
+
+        #[test]
+        fn indy_wallet_search_for_cursor_of_another_search() {
+            const SEARCH_WALLET_CONFIG: &str =
+                r#"{"id":"indy_wallet_search_for_cursor_of_another_search"}"#;
+            let wallet_handle = setup(
+                "indy_wallet_search_for_cursor_of_another_search",
+                SEARCH_WALLET_CONFIG,
+            );
+
+            let search_handle = open_wallet_search(
+                wallet_handle,
+                TYPE,
+                QUERY_EMPTY,
+                &json!({ "limit": 2 }).to_string(),
+            )
+            .unwrap();
+
+            let search_records =
+                fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+            let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
+
+            let options = json!({
+                "limit": 2,
+                "sortBy": {"tagName": "~tagName3"},
+                "cursor": search_records.cursor.unwrap()
+            })
+            .to_string();
+
+            let res = open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            tear_down(wallet_handle, search_handle);
+            cleanup_wallet("indy_wallet_search_for_cursor_of_another_search");
+        }
+
+        #[test]
+        fn indy_wallet_search_for_cursor_and_offset() {
+            const SEARCH_WALLET_CONFIG: &str =
+                r#"{"id":"indy_wallet_search_for_cursor_and_offset"}"#;
+            let wallet_handle = setup(
+                "indy_wallet_search_for_cursor_and_offset",
+                SEARCH_WALLET_CONFIG,
+            );
+
+            let search_handle = open_wallet_search(
+                wallet_handle,
+                TYPE,
+                QUERY_EMPTY,
+                &json!({ "limit": 2 }).to_string(),
+            )
+            .unwrap();
+
+            let search_records =
+                fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+            let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
+
+            let options = json!({
+                "offset": 2,
+                "cursor": search_records.cursor.unwrap()
+            })
+            .to_string();
+
+            let res = open_wallet_search(wallet_handle, TYPE, QUERY_EMPTY, &options);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            tear_down(wallet_handle, search_handle);
+            cleanup_wallet("indy_wallet_search_for_cursor_and_offset");
+        }
+
         #[test]
         fn indy_close_wallet_search_works_for_invalid_handle() {
             const SEARCH_WALLET_CONFIG: &str =
@@ -1513,6 +2013,17 @@ fn check_record_field(
     };
 }
 
+fn search_record_ids(search_records: &str) -> Vec<String> {
+    let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
+
+    search_records
+        .records
+        .unwrap_or_default()
+        .into_iter()
+        .map(|record| record.id)
+        .collect()
+}
+
 fn check_search_records(search_records: &str, expected_records: Vec<WalletRecord>) {
     let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
 
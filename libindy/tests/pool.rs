@@ -136,6 +136,116 @@ mod high_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works() {
+            let setup = Setup::pool();
+
+            let status = pool::get_status(setup.pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!("active", status["state"]);
+            assert!(status["merkleTreeSize"].as_u64().unwrap() >= 4);
+            assert!(status["merkleTreeRoot"].is_string());
+            assert_eq!(1, status["f"]);
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(4, nodes.len());
+            assert_eq!(4, status["reachableNodes"]);
+            assert!(nodes.iter().all(|node| !node["blacklisted"].as_bool().unwrap()));
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works_after_request() {
+            let setup = Setup::pool();
+
+            let request = utils::ledger::build_get_nym_request(None, utils::constants::DID_TRUSTEE).unwrap();
+            utils::ledger::submit_request(setup.pool_handle, &request).unwrap();
+
+            let status = pool::get_status(setup.pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert!(nodes.iter().any(|node| node["connected"].as_bool().unwrap()));
+            assert!(nodes.iter().any(|node| node["lastReplyTime"].is_i64() && node["latencyMs"].is_i64()));
+        }
+    }
+
+    mod snapshot {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_export_pool_ledger_snapshot_works() {
+            let setup = Setup::pool();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+            pool::export_snapshot(setup.pool_handle, snapshot_path.to_str().unwrap()).unwrap();
+
+            let snapshot = std::fs::read_to_string(&snapshot_path).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+
+            assert_eq!("1", snapshot["ver"]);
+            assert!(snapshot["txns"].as_array().unwrap().len() >= 4);
+            assert!(snapshot["proof"]["result"]["data"]["auditPath"].is_array());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_import_pool_ledger_snapshot_works() {
+            let setup = Setup::pool();
+            let mut imported = Setup::empty();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+            pool::export_snapshot(setup.pool_handle, snapshot_path.to_str().unwrap()).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&imported.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+
+            pool::import_snapshot(&imported.name, snapshot_path.to_str().unwrap(), Some(&pool_config)).unwrap();
+
+            imported.pool_handle = pool::open_pool_ledger(&imported.name, None).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_import_pool_ledger_snapshot_works_for_existing_config() {
+            let setup = Setup::pool();
+            let mut imported = Setup::empty();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+            pool::export_snapshot(setup.pool_handle, snapshot_path.to_str().unwrap()).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&imported.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&imported.name, Some(&pool_config)).unwrap();
+
+            pool::import_snapshot(&imported.name, snapshot_path.to_str().unwrap(), None).unwrap();
+
+            imported.pool_handle = pool::open_pool_ledger(&imported.name, None).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_import_pool_ledger_snapshot_works_for_other_pool_genesis() {
+            let setup = Setup::pool();
+            let imported = Setup::empty();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+            pool::export_snapshot(setup.pool_handle, snapshot_path.to_str().unwrap()).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool_with_wrong_alias(&imported.name, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+
+            let res = pool::import_snapshot(&imported.name, snapshot_path.to_str().unwrap(), Some(&pool_config));
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+    }
+
     mod close {
         use super::*;
 
@@ -504,6 +614,95 @@ mod medium_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = pool::get_status(0);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
+    mod snapshot {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_export_pool_ledger_snapshot_works_for_invalid_handle() {
+            let setup = Setup::empty();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+
+            let res = pool::export_snapshot(0, snapshot_path.to_str().unwrap());
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_import_pool_ledger_snapshot_works_for_missed_file() {
+            let setup = Setup::empty();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+
+            let res = pool::import_snapshot(&setup.name, snapshot_path.to_str().unwrap(), None);
+            assert_code!(ErrorCode::CommonIOError, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_import_pool_ledger_snapshot_works_for_opened_pool() {
+            let setup = Setup::pool();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+            pool::export_snapshot(setup.pool_handle, snapshot_path.to_str().unwrap()).unwrap();
+
+            let res = pool::import_snapshot(&setup.name, snapshot_path.to_str().unwrap(), None);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_import_pool_ledger_snapshot_works_for_tampered_txns() {
+            let setup = Setup::pool();
+            let imported = Setup::empty();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+            pool::export_snapshot(setup.pool_handle, snapshot_path.to_str().unwrap()).unwrap();
+
+            let snapshot = std::fs::read_to_string(&snapshot_path).unwrap();
+            let mut snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+            snapshot["txns"][0]["txn"]["data"]["data"]["client_port"] = json!(9999);
+            std::fs::write(&snapshot_path, snapshot.to_string()).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&imported.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+
+            let res = pool::import_snapshot(&imported.name, snapshot_path.to_str().unwrap(), Some(&pool_config));
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            assert!(!environment::pool_path(&imported.name).exists());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_import_pool_ledger_snapshot_works_for_other_pool() {
+            let setup = Setup::pool();
+            let imported = Setup::empty();
+
+            let snapshot_path = pool::snapshot_path(&setup.name);
+            pool::export_snapshot(setup.pool_handle, snapshot_path.to_str().unwrap()).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool_with_wrong_alias(&imported.name, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+
+            let res = pool::import_snapshot(&imported.name, snapshot_path.to_str().unwrap(), Some(&pool_config));
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+    }
+
     mod set_protocol_version {
         use super::*;
 
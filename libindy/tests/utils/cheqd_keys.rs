@@ -4,18 +4,30 @@ pub fn add_random(wallet_handle: WalletHandle, alias: &str) -> Result<String, In
     cheqd_keys::add_random(wallet_handle, alias).wait()
 }
 
-pub fn add_from_mnemonic(wallet_handle: WalletHandle, alias: &str, mnemonic: &str) -> Result<String, IndyError> {
-    cheqd_keys::add_from_mnemonic(wallet_handle, alias, mnemonic).wait()
+pub fn add_from_mnemonic(wallet_handle: WalletHandle, alias: &str, mnemonic: &str, options: Option<&str>) -> Result<String, IndyError> {
+    cheqd_keys::add_from_mnemonic(wallet_handle, alias, mnemonic, options).wait()
 }
 
-pub fn get_info(wallet_handle: WalletHandle, alias: &str) -> Result<String, IndyError> {
-    cheqd_keys::get_info(wallet_handle, alias).wait()
+pub fn generate_mnemonic(words_count: u32) -> Result<String, IndyError> {
+    cheqd_keys::generate_mnemonic(words_count).wait()
 }
 
-pub fn get_list_keys(wallet_handle: WalletHandle) -> Result<String, IndyError> {
-    cheqd_keys::get_list_keys(wallet_handle).wait()
+pub fn get_info(wallet_handle: WalletHandle, alias: &str, pool_alias: Option<&str>) -> Result<String, IndyError> {
+    cheqd_keys::get_info(wallet_handle, alias, pool_alias).wait()
+}
+
+pub fn get_list_keys(wallet_handle: WalletHandle, pool_alias: Option<&str>) -> Result<String, IndyError> {
+    cheqd_keys::get_list_keys(wallet_handle, pool_alias).wait()
 }
 
 pub fn sign(wallet_handle: WalletHandle, alias: &str, tx: &[u8]) -> Result<Vec<u8>, IndyError> {
     cheqd_keys::sign(wallet_handle, alias, tx).wait()
 }
+
+pub fn get_multisig_info(wallet_handle: WalletHandle, threshold: u32, members: &str, pool_alias: Option<&str>) -> Result<String, IndyError> {
+    cheqd_keys::get_multisig_info(wallet_handle, threshold, members, pool_alias).wait()
+}
+
+pub fn sign_multisig_partial(wallet_handle: WalletHandle, alias: &str, tx: &[u8]) -> Result<String, IndyError> {
+    cheqd_keys::sign_multisig_partial(wallet_handle, alias, tx).wait()
+}
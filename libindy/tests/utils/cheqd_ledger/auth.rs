@@ -26,6 +26,38 @@ pub fn build_tx(
     ).wait()
 }
 
+pub fn build_multisig_tx(
+    pool_alias: &str,
+    multisig_info: &str,
+    signers: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::auth::build_multisig_tx(
+        pool_alias,
+        multisig_info,
+        signers,
+        msg,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo,
+    ).wait()
+}
+
+pub fn combine_multisig_signatures(tx: &[u8], signatures: &str) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::auth::combine_multisig_signatures(tx, signatures).wait()
+}
+
 pub fn build_query_account(address: &str) -> Result<String, IndyError> {
     cheqd_ledger::auth::build_query_account(address).wait()
 }
@@ -33,3 +65,27 @@ pub fn build_query_account(address: &str) -> Result<String, IndyError> {
 pub fn parse_query_account_resp(query_resp: &str) -> Result<String, IndyError> {
     cheqd_ledger::auth::parse_query_account_resp(query_resp).wait()
 }
+
+pub fn build_tx_with_fee_estimation(
+    pool_alias: &str,
+    sender_public_key: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+    fee_options: Option<&str>,
+) -> Result<Vec<u8>, IndyError> {
+    cheqd_ledger::auth::build_tx_with_fee_estimation(
+        pool_alias,
+        sender_public_key,
+        msg,
+        account_number,
+        sequence_number,
+        coin_denom,
+        timeout_height,
+        memo,
+        fee_options,
+    ).wait()
+}
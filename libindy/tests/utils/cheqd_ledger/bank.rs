@@ -1,12 +1,13 @@
 use indyrs::{future::Future, cheqd_ledger, IndyError};
 
 pub fn build_msg_send(
+    pool_alias: &str,
     from: &str,
     to: &str,
     amount: &str,
     denom: &str,
 ) -> Result<Vec<u8>, IndyError> {
-    cheqd_ledger::bank::build_msg_send(from, to, amount, denom).wait()
+    cheqd_ledger::bank::build_msg_send(pool_alias, from, to, amount, denom).wait()
 }
 
 pub fn parse_msg_send_resp(commit_resp: &str) -> Result<String, IndyError> {
@@ -14,10 +15,11 @@ pub fn parse_msg_send_resp(commit_resp: &str) -> Result<String, IndyError> {
 }
 
 pub fn bank_build_query_balance(
+    pool_alias: &str,
     address: &str,
     denom: &str,
 ) -> Result<String, IndyError> {
-    cheqd_ledger::bank::build_query_balance(address, denom).wait()
+    cheqd_ledger::bank::build_query_balance(pool_alias, address, denom).wait()
 }
 
 pub fn parse_query_balance_resp(commit_resp: &str) -> Result<String, IndyError> {
@@ -52,3 +52,7 @@ pub fn build_query_all_nym() -> Result<String, IndyError> {
 pub fn parse_query_all_nym_resp(query_resp: &str) -> Result<String, IndyError> {
     cheqd_ledger::cheqd::parse_query_all_nym_resp(query_resp).wait()
 }
+
+pub fn resolve_did_document(pool_alias: &str, did: &str) -> Result<String, IndyError> {
+    cheqd_ledger::cheqd::resolve_did_document(pool_alias, did).wait()
+}
@@ -0,0 +1,13 @@
+use indyrs::{future::Future, IndyError, cheqd_ledger};
+
+pub fn build_query_simulate(tx: &[u8]) -> Result<String, IndyError> {
+    cheqd_ledger::tx::build_query_simulate(tx).wait()
+}
+
+pub fn parse_query_simulate_resp(query_resp: &str) -> Result<String, IndyError> {
+    cheqd_ledger::tx::parse_query_simulate_resp(query_resp).wait()
+}
+
+pub fn estimate_fee(gas_used: u64, fee_options: Option<&str>) -> Result<String, IndyError> {
+    cheqd_ledger::tx::estimate_fee(gas_used, fee_options).wait()
+}
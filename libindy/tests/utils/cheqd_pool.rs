@@ -1,7 +1,7 @@
 use indyrs::{cheqd_pool, future::Future, IndyError};
 
-pub fn add(alias: &str, rpc_address: &str, chain_id: &str) -> Result<String, IndyError> {
-    cheqd_pool::add(alias, rpc_address, chain_id).wait()
+pub fn add(alias: &str, rpc_address: &str, chain_id: &str, account_prefix: Option<&str>, trust_options: Option<&str>, connection_options: Option<&str>) -> Result<String, IndyError> {
+    cheqd_pool::add(alias, rpc_address, chain_id, account_prefix, trust_options, connection_options).wait()
 }
 
 pub fn get_config(alias: &str) -> Result<String, IndyError> {
@@ -16,6 +16,18 @@ pub fn broadcast_tx_commit(pool_alias: &str, signed_tx: &[u8]) -> Result<String,
     cheqd_pool::broadcast_tx_commit(pool_alias, signed_tx).wait()
 }
 
+pub fn broadcast_tx_sync(pool_alias: &str, signed_tx: &[u8]) -> Result<String, IndyError> {
+    cheqd_pool::broadcast_tx_sync(pool_alias, signed_tx).wait()
+}
+
+pub fn broadcast_tx_async(pool_alias: &str, signed_tx: &[u8]) -> Result<String, IndyError> {
+    cheqd_pool::broadcast_tx_async(pool_alias, signed_tx).wait()
+}
+
+pub fn get_tx(pool_alias: &str, tx_hash: &str) -> Result<String, IndyError> {
+    cheqd_pool::get_tx(pool_alias, tx_hash).wait()
+}
+
 pub fn abci_query(pool_alias: &str, req_json: &str) -> Result<String, IndyError> {
     cheqd_pool::abci_query(pool_alias, req_json).wait()
 }
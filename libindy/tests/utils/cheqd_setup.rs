@@ -50,7 +50,7 @@ impl CheqdSetup {
         // Pool
         let cheqd_test_pool_ip = environment::cheqd_test_pool_ip();
         let cheqd_test_chain_id = environment::cheqd_test_chain_id();
-        cheqd_pool::add(&name, &cheqd_test_pool_ip, &cheqd_test_chain_id).unwrap();
+        cheqd_pool::add(&name, &cheqd_test_pool_ip, &cheqd_test_chain_id, None, None, None).unwrap();
 
         // Denom
         let denom = environment::cheqd_denom();
@@ -69,7 +69,9 @@ impl CheqdSetup {
     }
 
     pub fn create_key(wallet_handle: WalletHandle, alias: &str, mnemonic: &str) -> Result<(String, String), IndyError> {
-        let key = cheqd_keys::add_from_mnemonic(wallet_handle, alias, mnemonic).unwrap();
+        // Test pool accounts were generated with the legacy (pre BIP-44) scheme
+        let options = json!({"derivation_method": "LEGACY_SHA3"}).to_string();
+        let key = cheqd_keys::add_from_mnemonic(wallet_handle, alias, mnemonic, Some(&options)).unwrap();
         let key: Value = serde_json::from_str(&key).unwrap();
         println!("Cheqd setup. Create key: {:?}", key);
 
@@ -62,3 +62,4 @@ pub const UNKNOWN_WALLET_CONFIG: &'static str = r#"{"id":"unknown_wallet_1","sto
 pub const AGENT_MESSAGE: &'static str = r#"{ "@id": "123456780","@type":"did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/basicmessage/1.0/message","sent_time": "2019-01-15 18:42:01Z","content": "Your hovercraft is full of eels."}"#;
 pub const DEFAULT_METHOD_NAME: &'static str = "sov";
 pub const DEFAULT_PREFIX: &'static str = "did:sov:";
+pub const INDY_SUB_NAMESPACE_METHOD: &'static str = "indy:sovrin:staging";
@@ -169,3 +169,7 @@ pub fn qualify_did(
 ) -> Result<String, IndyError> {
     did::qualify_did(wallet_handle, did, prefix).wait()
 }
+
+pub fn resolve_did_document(pool_handle: PoolHandle, did: &str) -> Result<String, IndyError> {
+    did::resolve_did_document(pool_handle, did).wait()
+}
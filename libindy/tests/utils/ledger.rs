@@ -1,7 +1,11 @@
 use std::{mem, sync::Once};
 
 use indy_utils::crypto::hash::hash;
-use indyrs::{future::Future, ledger, IndyError, PoolHandle, WalletHandle};
+use indyrs::{
+    future::Future,
+    ledger::{self, CustomFree, CustomTransactionParser},
+    IndyError, PoolHandle, WalletHandle,
+};
 use lazy_static::lazy_static;
 
 use crate::utils::{anoncreds, blob_storage, constants::*, did, pool, timeout, wallet};
@@ -40,6 +44,18 @@ pub fn submit_request(pool_handle: PoolHandle, request_json: &str) -> Result<Str
     ledger::submit_request(pool_handle, request_json).wait()
 }
 
+pub fn submit_requests_batch(pool_handle: PoolHandle, requests_json: &str) -> Result<String, IndyError> {
+    ledger::submit_requests_batch(pool_handle, requests_json).wait()
+}
+
+pub fn replicate_ledger(pool_handle: PoolHandle, ledger_type: &str, options_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::replicate_ledger(pool_handle, ledger_type, options_json).wait()
+}
+
+pub fn get_ledger_replica_txns(pool_name: &str, ledger_type: &str, filter_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::get_ledger_replica_txns(pool_name, ledger_type, filter_json).wait()
+}
+
 pub fn submit_action(
     pool_handle: PoolHandle,
     request_json: &str,
@@ -308,22 +324,13 @@ pub fn parse_get_revoc_reg_delta_response(
     ledger::parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response).wait()
 }
 
-//pub fn register_transaction_parser_for_sp(txn_type: &str, parse: CustomTransactionParser, free: CustomFree) -> Result<(), ErrorCode> {
-//    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
-//
-//    let txn_type = CString::new(txn_type).unwrap();
-//
-//    let err =
-//        unsafe {
-//            indy_register_transaction_parser_for_sp(command_handle,
-//                                                    txn_type.as_ptr(),
-//                                                    Some(parse),
-//                                                    Some(free),
-//                                                    cb)
-//        };
-//
-//    super::results::result_to_empty(err, receiver)
-//}
+pub fn register_transaction_parser_for_sp(
+    txn_type: &str,
+    parser: CustomTransactionParser,
+    free: CustomFree,
+) -> Result<(), IndyError> {
+    ledger::register_transaction_parser_for_sp(txn_type, parser, free).wait()
+}
 
 pub fn get_response_metadata(response: &str) -> Result<String, IndyError> {
     ledger::get_response_metadata(response).wait()
@@ -439,6 +446,18 @@ pub fn append_txn_author_agreement_acceptance_to_request(
     .wait()
 }
 
+pub fn store_txn_author_agreement_acceptance(
+    wallet_handle: WalletHandle,
+    pool_name: &str,
+    acceptance_json: &str,
+) -> Result<(), IndyError> {
+    ledger::store_txn_author_agreement_acceptance(wallet_handle, pool_name, acceptance_json).wait()
+}
+
+pub fn get_pool_txn_author_agreement(pool_handle: PoolHandle) -> Result<String, IndyError> {
+    ledger::get_pool_txn_author_agreement(pool_handle).wait()
+}
+
 pub fn append_request_endorser(
     request_json: &str,
     endorser_did: &str,
@@ -446,6 +465,45 @@ pub fn append_request_endorser(
     ledger::append_request_endorser(request_json, endorser_did).wait()
 }
 
+pub fn build_transaction_bundle(
+    pool_handle: PoolHandle,
+    request_json: &str,
+    options_json: Option<&str>,
+) -> Result<String, IndyError> {
+    ledger::build_transaction_bundle(pool_handle, request_json, options_json).wait()
+}
+
+pub fn sign_transaction_bundle(
+    wallet_handle: WalletHandle,
+    bundle_json: &str,
+    signer_did: &str,
+) -> Result<String, IndyError> {
+    ledger::sign_transaction_bundle(wallet_handle, bundle_json, signer_did).wait()
+}
+
+pub fn get_transaction_bundle_status(bundle_json: &str) -> Result<String, IndyError> {
+    ledger::get_transaction_bundle_status(bundle_json).wait()
+}
+
+pub fn submit_transaction_bundle(
+    pool_handle: PoolHandle,
+    bundle_json: &str,
+) -> Result<String, IndyError> {
+    ledger::submit_transaction_bundle(pool_handle, bundle_json).wait()
+}
+
+pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Result<String, IndyError> {
+    ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+}
+
+pub fn build_get_frozen_ledgers_request(submitter_did: &str) -> Result<String, IndyError> {
+    ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+}
+
+pub fn parse_get_frozen_ledgers_response(get_frozen_ledgers_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_frozen_ledgers_response(get_frozen_ledgers_response).wait()
+}
+
 #[cfg(feature = "local_nodes_pool")]
 pub fn post_entities() -> (&'static str, &'static str, &'static str) {
     lazy_static! {
@@ -18,6 +18,7 @@ pub mod pairwise;
 pub mod pool;
 pub mod results;
 pub mod types;
+pub mod vdr;
 pub mod wallet;
 //pub mod payments;
 pub mod cache;
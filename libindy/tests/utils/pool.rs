@@ -201,6 +201,26 @@ pub fn refresh(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::refresh_pool_ledger(pool_handle).wait()
 }
 
+pub fn get_status(pool_handle: PoolHandle) -> Result<String, IndyError> {
+    pool::get_pool_status(pool_handle).wait()
+}
+
+pub fn snapshot_path(pool_name: &str) -> PathBuf {
+    let mut path = environment::tmp_file_path(pool_name);
+    fs::create_dir_all(path.as_path()).unwrap();
+    path.push(pool_name);
+    path.set_extension("snapshot");
+    path
+}
+
+pub fn export_snapshot(pool_handle: PoolHandle, snapshot_path: &str) -> Result<(), IndyError> {
+    pool::export_pool_ledger_snapshot(pool_handle, snapshot_path).wait()
+}
+
+pub fn import_snapshot(pool_name: &str, snapshot_path: &str, config: Option<&str>) -> Result<(), IndyError> {
+    pool::import_pool_ledger_snapshot(pool_name, snapshot_path, config).wait()
+}
+
 pub fn close(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::close_pool_ledger(pool_handle).wait()
 }
@@ -81,5 +81,6 @@ pub struct WalletRecord {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SearchRecords {
     pub total_count: Option<i32>,
+    pub cursor: Option<String>,
     pub records: Option<Vec<WalletRecord>>,
 }
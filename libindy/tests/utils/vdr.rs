@@ -0,0 +1,53 @@
+use indyrs::{vdr, future::Future, IndyError, VdrHandle};
+
+pub fn vdr_create() -> Result<VdrHandle, IndyError> {
+    vdr::vdr_create().wait()
+}
+
+pub fn vdr_register_indy_ledger(vdr_handle: VdrHandle, namespace_list: &str, genesis_txn_data: &str, taa_config: &str) -> Result<(), IndyError> {
+    vdr::vdr_register_indy_ledger(vdr_handle, namespace_list, genesis_txn_data, taa_config).wait()
+}
+
+pub fn vdr_register_cheqd_ledger(vdr_handle: VdrHandle, namespace_list: &str, chain_id: &str, node_addrs_list: &str) -> Result<(), IndyError> {
+    vdr::vdr_register_cheqd_ledger(vdr_handle, namespace_list, chain_id, node_addrs_list).wait()
+}
+
+pub fn vdr_ping(vdr_handle: VdrHandle, namespace_list: &str) -> Result<String, IndyError> {
+    vdr::vdr_ping(vdr_handle, namespace_list).wait()
+}
+
+pub fn vdr_cleanup(vdr_handle: VdrHandle) -> Result<(), IndyError> {
+    vdr::vdr_cleanup(vdr_handle).wait()
+}
+
+pub fn vdr_resolve_did(vdr_handle: VdrHandle, fqdid: &str, cache_options: &str) -> Result<String, IndyError> {
+    vdr::vdr_resolve_did(vdr_handle, fqdid, cache_options).wait()
+}
+
+pub fn vdr_resolve_schema(vdr_handle: VdrHandle, fqschema: &str, cache_options: &str) -> Result<String, IndyError> {
+    vdr::vdr_resolve_schema(vdr_handle, fqschema, cache_options).wait()
+}
+
+pub fn vdr_resolve_cred_def(vdr_handle: VdrHandle, fqcreddef: &str, cache_options: &str) -> Result<String, IndyError> {
+    vdr::vdr_resolve_cred_def(vdr_handle, fqcreddef, cache_options).wait()
+}
+
+pub fn vdr_prepare_did(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_did: &str, endorser: Option<&str>) -> Result<(String, String, Vec<u8>, Vec<u8>, Option<String>), IndyError> {
+    vdr::vdr_prepare_did(vdr_handle, txn_specific_params, submitter_did, endorser).wait()
+}
+
+pub fn vdr_prepare_schema(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_did: &str, endorser: Option<&str>) -> Result<(String, String, Vec<u8>, Vec<u8>, Option<String>), IndyError> {
+    vdr::vdr_prepare_schema(vdr_handle, txn_specific_params, submitter_did, endorser).wait()
+}
+
+pub fn vdr_prepare_cred_def(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_did: &str, endorser: Option<&str>) -> Result<(String, String, Vec<u8>, Vec<u8>, Option<String>), IndyError> {
+    vdr::vdr_prepare_cred_def(vdr_handle, txn_specific_params, submitter_did, endorser).wait()
+}
+
+pub fn vdr_submit_txn(vdr_handle: VdrHandle, namespace: &str, signature_spec: &str, txn_bytes: &[u8], signature: &[u8], endorsement: Option<&str>) -> Result<String, IndyError> {
+    vdr::vdr_submit_txn(vdr_handle, namespace, signature_spec, txn_bytes, signature, endorsement).wait()
+}
+
+pub fn vdr_submit_query(vdr_handle: VdrHandle, namespace: &str, query: &str) -> Result<String, IndyError> {
+    vdr::vdr_submit_query(vdr_handle, namespace, query).wait()
+}
@@ -128,6 +128,42 @@ pub fn create_and_open_mysql_wallet(
     Ok((wallet_handle, wallet_config, wallet_credentials))
 }
 
+pub fn create_and_open_postgres_wallet(
+    wallet_name: &str,
+) -> Result<(WalletHandle, String, String), IndyError> {
+    let storage_config = json!({
+        "read_host": "127.0.0.1",
+        "write_host": "127.0.0.1",
+        "port": 5432,
+        "db_name": "indy",
+        "wallet_scheme": "MultiWalletSingleTable"
+    });
+
+    let storage_creds = json!({
+        "user": "postgres",
+        "pass": "pass@word1"
+    });
+
+    let wallet_config = json!({
+        "id": format!("default-wallet_id-{}-{}", wallet_name, sequence::get_next_id()),
+        "storage_type": "postgres",
+        "storage_config": storage_config
+    })
+    .to_string();
+
+    let wallet_credentials = json!({
+        "key": "8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY",
+        "key_derivation_method": "RAW",
+        "storage_credentials": storage_creds
+    })
+    .to_string();
+
+    create_wallet(&wallet_config, &wallet_credentials)?;
+    let wallet_handle = open_wallet(&wallet_config, &wallet_credentials).unwrap();
+
+    Ok((wallet_handle, wallet_config, wallet_credentials))
+}
+
 pub fn create_and_open_plugged_wallet() -> Result<(WalletHandle, String), IndyError> {
     let config = json!({
         "id": format!("default-wallet_id-{}", sequence::get_next_id()),
@@ -189,6 +225,20 @@ pub fn generate_wallet_key(config: Option<&str>) -> Result<String, IndyError> {
     wallet::generate_wallet_key(config).wait()
 }
 
+pub fn rotate_wallet_data_keys(
+    wallet_handle: WalletHandle,
+    credentials: &str,
+    config: Option<&str>,
+) -> Result<String, IndyError> {
+    wallet::rotate_wallet_data_keys(wallet_handle, credentials, config).wait()
+}
+
+pub fn get_wallet_data_keys_rotation_progress(
+    wallet_handle: WalletHandle,
+) -> Result<String, IndyError> {
+    wallet::get_wallet_data_keys_rotation_progress(wallet_handle).wait()
+}
+
 extern "C" {
     pub fn indy_register_wallet_storage(
         command_handle: CommandHandle,
@@ -0,0 +1,332 @@
+#[macro_use]
+extern crate derivative;
+
+#[macro_use]
+extern crate serde_derive;
+
+#[macro_use]
+extern crate serde_json;
+
+#[macro_use]
+mod utils;
+
+use indyrs::ErrorCode;
+
+use utils::{vdr, Setup};
+
+#[cfg(feature = "local_nodes_pool")]
+use indyrs::VdrHandle;
+#[cfg(feature = "local_nodes_pool")]
+use utils::{constants::*, crypto, test};
+
+#[cfg(feature = "local_nodes_pool")]
+const INDY_NAMESPACE_LIST: &str = r#"["sov"]"#;
+
+#[cfg(feature = "local_nodes_pool")]
+fn genesis_txn_data() -> String {
+    test::gen_txns().join("\n")
+}
+
+#[cfg(feature = "local_nodes_pool")]
+fn vdr_with_indy_ledger() -> VdrHandle {
+    let vdr_handle = vdr::vdr_create().unwrap();
+    vdr::vdr_register_indy_ledger(vdr_handle, INDY_NAMESPACE_LIST, &genesis_txn_data(), "{}").unwrap();
+    vdr_handle
+}
+
+mod high_cases {
+    use super::*;
+
+    mod vdr_create {
+        use super::*;
+
+        #[test]
+        fn vdr_create_works() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+
+        #[test]
+        fn vdr_create_works_for_twice() {
+            Setup::empty();
+
+            let vdr_handle_1 = vdr::vdr_create().unwrap();
+            let vdr_handle_2 = vdr::vdr_create().unwrap();
+            assert_ne!(vdr_handle_1, vdr_handle_2);
+
+            vdr::vdr_cleanup(vdr_handle_1).unwrap();
+            vdr::vdr_cleanup(vdr_handle_2).unwrap();
+        }
+    }
+
+    #[cfg(feature = "local_nodes_pool")]
+    mod vdr_register_indy_ledger {
+        use super::*;
+
+        #[test]
+        fn vdr_register_indy_ledger_works() {
+            Setup::empty();
+
+            let vdr_handle = vdr_with_indy_ledger();
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+
+        #[test]
+        fn vdr_register_indy_ledger_works_for_several_namespaces() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+            vdr::vdr_register_indy_ledger(vdr_handle, r#"["sov", "indy:sovrin"]"#, &genesis_txn_data(), "{}").unwrap();
+
+            let status = vdr::vdr_ping(vdr_handle, r#"["sov", "indy:sovrin"]"#).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!("SUCCESS", status["sov"]["code"]);
+            assert_eq!("SUCCESS", status["indy:sovrin"]["code"]);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "local_nodes_pool")]
+    mod vdr_ping {
+        use super::*;
+
+        #[test]
+        fn vdr_ping_works() {
+            Setup::empty();
+
+            let vdr_handle = vdr_with_indy_ledger();
+
+            let status = vdr::vdr_ping(vdr_handle, INDY_NAMESPACE_LIST).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!("SUCCESS", status["sov"]["code"]);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "local_nodes_pool")]
+    mod vdr_resolve_did {
+        use super::*;
+
+        #[test]
+        fn vdr_resolve_did_works() {
+            Setup::empty();
+
+            let vdr_handle = vdr_with_indy_ledger();
+
+            let nym = vdr::vdr_resolve_did(vdr_handle, &format!("did:sov:{}", DID_TRUSTEE), "{}").unwrap();
+            let nym: serde_json::Value = serde_json::from_str(&nym).unwrap();
+            assert_eq!(DID_TRUSTEE, nym["did"]);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+
+        #[test]
+        fn vdr_resolve_did_works_for_cache() {
+            Setup::empty();
+
+            let vdr_handle = vdr_with_indy_ledger();
+            let did = format!("did:sov:{}", DID_TRUSTEE);
+
+            let res = vdr::vdr_resolve_did(vdr_handle, &did, r#"{"noUpdate": true}"#);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            let nym = vdr::vdr_resolve_did(vdr_handle, &did, "{}").unwrap();
+
+            let cached_nym = vdr::vdr_resolve_did(vdr_handle, &did, r#"{"noUpdate": true}"#).unwrap();
+            assert_eq!(nym, cached_nym);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+
+        #[test]
+        fn vdr_resolve_did_works_for_did_indy_sub_namespace() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+            vdr::vdr_register_indy_ledger(vdr_handle, r#"["indy:sovrin:staging"]"#, &genesis_txn_data(), "{}").unwrap();
+
+            let did = format!("did:{}:{}", INDY_SUB_NAMESPACE_METHOD, DID_TRUSTEE);
+            let nym = vdr::vdr_resolve_did(vdr_handle, &did, "{}").unwrap();
+            let nym: serde_json::Value = serde_json::from_str(&nym).unwrap();
+            assert_eq!(DID_TRUSTEE, nym["did"]);
+
+            let res = vdr::vdr_resolve_did(vdr_handle, &format!("did:indy:sovrin:{}", DID_TRUSTEE), "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "local_nodes_pool")]
+    mod vdr_prepare_and_submit_did {
+        use super::*;
+
+        #[test]
+        fn vdr_prepare_and_submit_did_works() {
+            let setup = Setup::trustee();
+
+            let vdr_handle = vdr_with_indy_ledger();
+
+            let (did, verkey) = utils::did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+            let params = json!({
+                "dest": format!("did:sov:{}", did),
+                "verkey": verkey,
+            }).to_string();
+
+            let (namespace, signature_spec, txn_bytes, bytes_to_sign, endorsement_spec) =
+                vdr::vdr_prepare_did(vdr_handle, &params, &format!("did:sov:{}", setup.did), None).unwrap();
+            assert_eq!("sov", namespace);
+            assert_eq!("ed25519", signature_spec);
+            assert_eq!(None, endorsement_spec);
+
+            let signature = crypto::sign(setup.wallet_handle, &setup.verkey, &bytes_to_sign).unwrap();
+
+            let response = vdr::vdr_submit_txn(vdr_handle, &namespace, &signature_spec, &txn_bytes, &signature, None).unwrap();
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            assert_eq!("REPLY", response["op"]);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+}
+
+mod medium_cases {
+    use super::*;
+
+    mod vdr_register_indy_ledger {
+        use super::*;
+
+        #[test]
+        fn vdr_register_indy_ledger_works_for_invalid_namespace_list() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+
+            let res = vdr::vdr_register_indy_ledger(vdr_handle, r#"["Sov::"]"#, "", "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = vdr::vdr_register_indy_ledger(vdr_handle, "[]", "", "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+
+        #[test]
+        fn vdr_register_indy_ledger_works_for_unknown_handle() {
+            Setup::empty();
+
+            let res = vdr::vdr_register_indy_ledger(-1, r#"["sov"]"#, "", "{}");
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[cfg(feature = "local_nodes_pool")]
+        #[test]
+        fn vdr_register_indy_ledger_works_for_registered_namespace() {
+            Setup::empty();
+
+            let vdr_handle = vdr_with_indy_ledger();
+
+            let res = vdr::vdr_register_indy_ledger(vdr_handle, INDY_NAMESPACE_LIST, &genesis_txn_data(), "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+
+    mod vdr_ping {
+        use super::*;
+
+        #[test]
+        fn vdr_ping_works_for_unknown_namespace() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+
+            let res = vdr::vdr_ping(vdr_handle, r#"["sov"]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+
+    mod vdr_resolve_did {
+        use super::*;
+
+        #[test]
+        fn vdr_resolve_did_works_for_unqualified_did() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+
+            let res = vdr::vdr_resolve_did(vdr_handle, "V4SGRU86Z58d6TV7PBUe6f", "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+
+        #[test]
+        fn vdr_resolve_did_works_for_no_update_and_empty_cache() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+
+            let res = vdr::vdr_resolve_did(vdr_handle, "did:sov:V4SGRU86Z58d6TV7PBUe6f", r#"{"noUpdate": true}"#);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+
+        #[test]
+        fn vdr_resolve_did_works_for_invalid_cache_options() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+
+            let res = vdr::vdr_resolve_did(vdr_handle, "did:sov:V4SGRU86Z58d6TV7PBUe6f", "not a json");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "cheqd")]
+    mod vdr_register_cheqd_ledger {
+        use super::*;
+
+        #[test]
+        fn vdr_register_cheqd_ledger_works_for_empty_node_addresses() {
+            Setup::empty();
+
+            let vdr_handle = vdr::vdr_create().unwrap();
+
+            let res = vdr::vdr_register_cheqd_ledger(vdr_handle, r#"["cheqd:testnet"]"#, "cheqd", "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = vdr::vdr_register_cheqd_ledger(
+                vdr_handle,
+                r#"["cheqd:testnet"]"#,
+                "cheqd",
+                r#"["http://127.0.0.1:26657", ""]"#,
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            vdr::vdr_cleanup(vdr_handle).unwrap();
+        }
+    }
+
+    mod vdr_cleanup {
+        use super::*;
+
+        #[test]
+        fn vdr_cleanup_works_for_unknown_handle() {
+            Setup::empty();
+
+            let res = vdr::vdr_cleanup(-1);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+    }
+}
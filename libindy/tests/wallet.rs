@@ -72,8 +72,6 @@ mod high_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_create_wallet_works_for_plugged() {
             Setup::empty();
             InmemWallet::cleanup();
@@ -84,6 +82,31 @@ mod high_cases {
             InmemWallet::cleanup();
         }
 
+        #[test]
+        #[cfg(feature = "postgres_storage")]
+        fn indy_create_wallet_works_for_postgres_multiple_wallets() {
+            Setup::empty();
+
+            let (wallet_handle_1, wallet_config_1, wallet_credentials_1) =
+                wallet::create_and_open_postgres_wallet("postgres_multiple_wallets_1").unwrap();
+
+            let (wallet_handle_2, wallet_config_2, wallet_credentials_2) =
+                wallet::create_and_open_postgres_wallet("postgres_multiple_wallets_2").unwrap();
+
+            let (did, verkey) = did::create_and_store_my_did(wallet_handle_1, None).unwrap();
+
+            assert_eq!(verkey, did::key_for_local_did(wallet_handle_1, &did).unwrap());
+
+            let res = did::key_for_local_did(wallet_handle_2, &did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle_1).unwrap();
+            wallet::delete_wallet(&wallet_config_1, &wallet_credentials_1).unwrap();
+
+            wallet::close_wallet(wallet_handle_2).unwrap();
+            wallet::delete_wallet(&wallet_config_2, &wallet_credentials_2).unwrap();
+        }
+
         #[test]
         fn indy_create_wallet_works_for_unknown_type() {
             Setup::empty();
@@ -139,8 +162,6 @@ mod high_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_delete_wallet_works_for_plugged() {
             Setup::empty();
             InmemWallet::cleanup();
@@ -182,8 +203,6 @@ mod high_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_open_wallet_works_for_plugged() {
             Setup::empty();
             InmemWallet::cleanup();
@@ -218,8 +237,6 @@ mod high_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_close_wallet_works_for_plugged() {
             Setup::empty();
             InmemWallet::cleanup();
@@ -342,6 +359,64 @@ mod high_cases {
             wallet::delete_wallet(&wallet_config, &credentials).unwrap();
         }
     }
+
+    mod rotate_wallet_data_keys {
+        use super::*;
+
+        #[test]
+        fn indy_rotate_wallet_data_keys_works() {
+            let setup = Setup::empty();
+            let config = config(&setup.name);
+
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            let (did, _) = did::create_my_did(wallet_handle, "{}").unwrap();
+            did::set_did_metadata(wallet_handle, &did, METADATA).unwrap();
+            did::create_my_did(wallet_handle, "{}").unwrap();
+
+            let did_with_meta = did::get_my_did_with_metadata(wallet_handle, &did).unwrap();
+
+            let progress = wallet::get_wallet_data_keys_rotation_progress(wallet_handle).unwrap();
+            assert_eq!("null", progress);
+
+            let progress = wallet::rotate_wallet_data_keys(
+                wallet_handle,
+                WALLET_CREDENTIALS,
+                Some(r#"{"batch_size": 1}"#),
+            )
+            .unwrap();
+
+            let progress: serde_json::Value = serde_json::from_str(&progress).unwrap();
+            assert_eq!(progress["processed"], progress["total"]);
+            assert_eq!(true, progress["completed"]);
+
+            let did_with_meta_after_rotation =
+                did::get_my_did_with_metadata(wallet_handle, &did).unwrap();
+            assert_eq!(did_with_meta, did_with_meta_after_rotation);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            let did_with_meta_after_reopen =
+                did::get_my_did_with_metadata(wallet_handle, &did).unwrap();
+            assert_eq!(did_with_meta, did_with_meta_after_reopen);
+
+            wallet::close_and_delete_wallet(wallet_handle, &config).unwrap();
+        }
+
+        #[test]
+        fn indy_rotate_wallet_data_keys_works_for_default_config() {
+            let setup = Setup::wallet();
+
+            let (did, _) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+
+            wallet::rotate_wallet_data_keys(setup.wallet_handle, WALLET_CREDENTIALS, None).unwrap();
+
+            did::get_my_did_with_metadata(setup.wallet_handle, &did).unwrap();
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -352,8 +427,6 @@ mod medium_cases {
         use super::*;
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_register_wallet_storage_does_not_work_twice_with_same_name() {
             Setup::empty();
             InmemWallet::cleanup();
@@ -366,8 +439,6 @@ mod medium_cases {
         }
 
         #[test]
-        #[ignore]
-        // plugged wallet not supported
         fn indy_register_wallet_storage_does_not_work_with_null_params() {
             Setup::empty();
             InmemWallet::cleanup();
@@ -773,6 +844,64 @@ mod medium_cases {
             cleanup_file(&path);
         }
     }
+
+    mod rotate_wallet_data_keys {
+        use super::*;
+
+        #[test]
+        fn indy_rotate_wallet_data_keys_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res =
+                wallet::rotate_wallet_data_keys(INVALID_WALLET_HANDLE, WALLET_CREDENTIALS, None);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+
+            let res = wallet::get_wallet_data_keys_rotation_progress(INVALID_WALLET_HANDLE);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_rotate_wallet_data_keys_works_for_zero_batch_size() {
+            let setup = Setup::wallet();
+
+            let res = wallet::rotate_wallet_data_keys(
+                setup.wallet_handle,
+                WALLET_CREDENTIALS,
+                Some(r#"{"batch_size": 0}"#),
+            );
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+        }
+
+        #[test]
+        fn indy_rotate_wallet_data_keys_works_for_wrong_credentials() {
+            let setup = Setup::empty();
+            let config = config(&setup.name);
+
+            wallet::create_wallet(&config, r#"{"key":"key"}"#).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, r#"{"key":"key"}"#).unwrap();
+
+            let res = wallet::rotate_wallet_data_keys(wallet_handle, r#"{"key":"other_key"}"#, None);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+
+            let progress = wallet::get_wallet_data_keys_rotation_progress(wallet_handle).unwrap();
+            assert_eq!("null", progress);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_rotate_wallet_data_keys_works_for_plugged_wallet() {
+            let setup = Setup::plugged_wallet();
+
+            let res =
+                wallet::rotate_wallet_data_keys(setup.wallet_handle, WALLET_CREDENTIALS, None);
+            assert_code!(ErrorCode::WalletStorageError, res);
+
+            let progress =
+                wallet::get_wallet_data_keys_rotation_progress(setup.wallet_handle).unwrap();
+            assert_eq!("null", progress);
+        }
+    }
 }
 
 fn _custom_path(name: &str) -> String {
@@ -15,6 +15,13 @@ extern "C" {
         wallet_handle: WalletHandle,
         alias: CString,
         mnemonic: CString,
+        options: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_keys_generate_mnemonic(
+        command_handle: CommandHandle,
+        words_count: u32,
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
@@ -22,12 +29,14 @@ extern "C" {
         command_handle: CommandHandle,
         wallet_handle: WalletHandle,
         alias: CString,
+        pool_alias: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
     pub fn indy_cheqd_keys_get_list_keys(
         command_handle: CommandHandle,
         wallet_handle: WalletHandle,
+        pool_alias: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
@@ -39,4 +48,22 @@ extern "C" {
         tx_len: u32,
         cb: Option<ResponseSliceCB>,
     ) -> Error;
+
+    pub fn indy_cheqd_keys_get_multisig_info(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        threshold: u32,
+        members: CString,
+        pool_alias: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_keys_sign_multisig_partial(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        alias: CString,
+        tx_raw: BString,
+        tx_len: u32,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
 }
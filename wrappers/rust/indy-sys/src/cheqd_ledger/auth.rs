@@ -19,6 +19,46 @@ extern "C" {
         cb: Option<ResponseSliceCB>,
     ) -> Error;
 
+    pub fn indy_cheqd_ledger_auth_build_tx_with_fee_estimation(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        sender_public_key: CString,
+        msg_raw: BString,
+        msg_len: u32,
+        account_number: u64,
+        sequence_number: u64,
+        coin_denom: CString,
+        timeout_height: u64,
+        memo: CString,
+        fee_options: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_auth_build_multisig_tx(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        multisig_info: CString,
+        signers: CString,
+        msg_raw: BString,
+        msg_len: u32,
+        account_number: u64,
+        sequence_number: u64,
+        max_gas: u64,
+        max_coin_amount: u64,
+        max_coin_denom: CString,
+        timeout_height: u64,
+        memo: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_auth_combine_multisig_signatures(
+        command_handle: CommandHandle,
+        tx_raw: BString,
+        tx_len: u32,
+        signatures: CString,
+        cb: Option<ResponseSliceCB>,
+    ) -> Error;
+
     pub fn indy_cheqd_ledger_auth_build_query_account(
         command_handle: CommandHandle,
         address: CString,
@@ -4,6 +4,7 @@ use crate::{ResponseSliceCB, ResponseStringCB};
 extern "C" {
     pub fn indy_cheqd_ledger_bank_build_msg_send(
         command_handle: CommandHandle,
+        pool_alias: CString,
         from: CString,
         to: CString,
         amount: CString,
@@ -19,6 +20,7 @@ extern "C" {
 
     pub fn indy_cheqd_ledger_bank_build_query_balance(
         command_handle: CommandHandle,
+        pool_alias: CString,
         address: CString,
         denom: CString,
         cb: Option<ResponseStringCB>,
@@ -70,4 +70,11 @@ extern "C" {
         query_resp: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
+
+    pub fn indy_cheqd_ledger_cheqd_resolve_did_document(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        did: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
 }
@@ -0,0 +1,25 @@
+use crate::ResponseStringCB;
+
+use crate::{BString, CommandHandle, CString, Error};
+
+extern "C" {
+    pub fn indy_cheqd_ledger_tx_build_query_simulate(
+        command_handle: CommandHandle,
+        tx_raw: BString,
+        tx_len: u32,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_tx_parse_query_simulate_resp(
+        command_handle: CommandHandle,
+        query_resp: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_ledger_tx_estimate_fee(
+        command_handle: CommandHandle,
+        gas_used: u64,
+        fee_options: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+}
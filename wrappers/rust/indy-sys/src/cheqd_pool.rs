@@ -8,6 +8,9 @@ extern "C" {
         alias: CString,
         rpc_address: CString,
         chain_id: CString,
+        account_prefix: CString,
+        trust_options: CString,
+        connection_options: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
@@ -30,6 +33,29 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_cheqd_pool_broadcast_tx_sync(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        signed_tx_raw: BString,
+        signed_tx_len: u32,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_pool_broadcast_tx_async(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        signed_tx_raw: BString,
+        signed_tx_len: u32,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_cheqd_pool_get_tx(
+        command_handle: CommandHandle,
+        pool_alias: CString,
+        tx_hash: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_cheqd_pool_abci_query(
         command_handle: CommandHandle,
         pool_alias: CString,
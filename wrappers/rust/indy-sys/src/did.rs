@@ -106,4 +106,11 @@ extern "C" {
         prefix: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
+
+    pub fn indy_resolve_did_document(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        did: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
 }
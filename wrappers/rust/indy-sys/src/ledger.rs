@@ -19,6 +19,29 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_submit_requests_batch(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        requests_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_replicate_ledger(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        ledger_type: CString,
+        options_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_get_ledger_replica_txns(
+        command_handle: CommandHandle,
+        pool_name: CString,
+        ledger_type: CString,
+        filter_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_submit_action(
         command_handle: CommandHandle,
         pool_handle: PoolHandle,
@@ -246,11 +269,13 @@ extern "C" {
         cb: Option<ResponseStringStringU64CB>,
     ) -> Error;
 
-    //    pub fn indy_register_transaction_parser_for_sp(command_handle: CommandHandle,
-//                                                   txn_type: CString,
-//                                                   parser: Option<CustomTransactionParser>,
-//                                                   free: Option<CustomFree>,
-//                                                   cb: Option<ResponseEmptyCB>) -> Error;
+    pub fn indy_register_transaction_parser_for_sp(
+        command_handle: CommandHandle,
+        txn_type: CString,
+        parser: Option<CustomTransactionParser>,
+        free: Option<CustomFree>,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
 
     pub fn indy_get_response_metadata(
         command_handle: CommandHandle,
@@ -339,12 +364,74 @@ extern "C" {
         cb: Option<ResponseStringCB>,
     ) -> Error;
 
+    pub fn indy_store_txn_author_agreement_acceptance(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        pool_name: CString,
+        acceptance_json: CString,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
+
+    pub fn indy_get_pool_txn_author_agreement(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
     pub fn indy_append_request_endorser(
         command_handle: CommandHandle,
         request_json: CString,
         endorser_did: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
+
+    pub fn indy_build_transaction_bundle(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        request_json: CString,
+        options_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_sign_transaction_bundle(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        bundle_json: CString,
+        signer_did: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_get_transaction_bundle_status(
+        command_handle: CommandHandle,
+        bundle_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_submit_transaction_bundle(
+        command_handle: CommandHandle,
+        pool_handle: PoolHandle,
+        bundle_json: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_build_ledgers_freeze_request(
+        command_handle: CommandHandle,
+        submitter_did: CString,
+        ledgers_ids: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_build_get_frozen_ledgers_request(
+        command_handle: CommandHandle,
+        submitter_did: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_parse_get_frozen_ledgers_response(
+        command_handle: CommandHandle,
+        get_frozen_ledgers_response: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
 }
 
 pub type CustomTransactionParser =
@@ -58,6 +58,13 @@ extern "C" {
         cb: Option<ResponseEmptyCB>,
     ) -> Error;
 
+    pub fn indy_wallet_batch(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        operations_json: CString,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
+
     pub fn indy_get_wallet_record(
         command_handle: CommandHandle,
         wallet_handle: WalletHandle,
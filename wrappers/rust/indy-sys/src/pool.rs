@@ -24,6 +24,27 @@ extern "C" {
         cb: Option<ResponseEmptyCB>,
     ) -> Error;
 
+    pub fn indy_get_pool_status(
+        command_handle: CommandHandle,
+        handle: PoolHandle,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_export_pool_ledger_snapshot(
+        command_handle: CommandHandle,
+        handle: PoolHandle,
+        snapshot_path: CString,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
+
+    pub fn indy_import_pool_ledger_snapshot(
+        command_handle: CommandHandle,
+        config_name: CString,
+        snapshot_path: CString,
+        config: CString,
+        cb: Option<ResponseEmptyCB>,
+    ) -> Error;
+
     pub fn indy_list_pools(command_handle: CommandHandle, cb: Option<ResponseStringCB>) -> Error;
 
     pub fn indy_close_pool_ledger(
@@ -82,6 +82,20 @@ extern "C" {
         config: CString,
         cb: Option<ResponseStringCB>,
     ) -> Error;
+
+    pub fn indy_rotate_wallet_data_keys(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        credentials: CString,
+        config: CString,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
+
+    pub fn indy_get_wallet_data_keys_rotation_progress(
+        command_handle: CommandHandle,
+        wallet_handle: WalletHandle,
+        cb: Option<ResponseStringCB>,
+    ) -> Error;
 }
 
 pub type WalletCreate = extern "C" fn(
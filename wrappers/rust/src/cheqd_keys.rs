@@ -1,6 +1,7 @@
 use crate::{ErrorCode, IndyError};
 
 use std::ffi::CString;
+use std::ptr::null;
 
 use futures::Future;
 
@@ -31,14 +32,23 @@ fn _add_random(
     })
 }
 
+/// Creates keys for a new account from mnemonic.
+///
+/// `options` is an optional json:
+/// {
+///     "derivation_method": "BIP44" (default) or "LEGACY_SHA3",
+///     "hd_path": string, (optional) "m/44'/118'/0'/0/0" by default
+///     "passphrase": string, (optional) BIP-39 passphrase
+/// }
 pub fn add_from_mnemonic(
     wallet_handle: WalletHandle,
     alias: &str,
     mnemonic: &str,
+    options: Option<&str>,
 ) -> Box<dyn Future<Item = String, Error = IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _add_from_mnemonic(command_handle, wallet_handle, alias, mnemonic, cb);
+    let err = _add_from_mnemonic(command_handle, wallet_handle, alias, mnemonic, options, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
@@ -48,10 +58,12 @@ fn _add_from_mnemonic(
     wallet_handle: WalletHandle,
     alias: &str,
     mnemonic: &str,
+    options: Option<&str>,
     cb: Option<ResponseStringCB>,
 ) -> ErrorCode {
     let alias = c_str!(alias);
     let mnemonic = c_str!(mnemonic);
+    let options_str = opt_c_str!(options);
 
     ErrorCode::from(unsafe {
         cheqd_keys::indy_cheqd_keys_add_from_mnemonic(
@@ -59,15 +71,42 @@ fn _add_from_mnemonic(
             wallet_handle,
             alias.as_ptr(),
             mnemonic.as_ptr(),
+            opt_c_ptr!(options, options_str),
             cb,
         )
     })
 }
 
-pub fn get_info(wallet_handle: WalletHandle, alias: &str) -> Box<dyn Future<Item = String, Error = IndyError>> {
+/// Generates new BIP-39 mnemonic phrase with `words_count` words (12, 15, 18, 21 or 24).
+pub fn generate_mnemonic(words_count: u32) -> Box<dyn Future<Item = String, Error = IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _get_info(command_handle, wallet_handle, alias, cb);
+    let err = _generate_mnemonic(command_handle, words_count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _generate_mnemonic(
+    command_handle: CommandHandle,
+    words_count: u32,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        cheqd_keys::indy_cheqd_keys_generate_mnemonic(command_handle, words_count, cb)
+    })
+}
+
+/// Gets information about the key.
+///
+/// Account address is rendered with the bech32 prefix of `pool_alias` pool ("cheqd" if pool is not set).
+pub fn get_info(
+    wallet_handle: WalletHandle,
+    alias: &str,
+    pool_alias: Option<&str>,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_info(command_handle, wallet_handle, alias, pool_alias, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
@@ -76,19 +115,33 @@ fn _get_info(
     command_handle: CommandHandle,
     wallet_handle: WalletHandle,
     alias: &str,
+    pool_alias: Option<&str>,
     cb: Option<ResponseStringCB>,
 ) -> ErrorCode {
     let alias = c_str!(alias);
+    let pool_alias_str = opt_c_str!(pool_alias);
 
     ErrorCode::from(unsafe {
-        cheqd_keys::indy_cheqd_keys_get_info(command_handle, wallet_handle, alias.as_ptr(), cb)
+        cheqd_keys::indy_cheqd_keys_get_info(
+            command_handle,
+            wallet_handle,
+            alias.as_ptr(),
+            opt_c_ptr!(pool_alias, pool_alias_str),
+            cb,
+        )
     })
 }
 
-pub fn get_list_keys(wallet_handle: WalletHandle) -> Box<dyn Future<Item = String, Error = IndyError>> {
+/// Gets information about all keys of the wallet.
+///
+/// Account addresses are rendered with the bech32 prefix of `pool_alias` pool ("cheqd" if pool is not set).
+pub fn get_list_keys(
+    wallet_handle: WalletHandle,
+    pool_alias: Option<&str>,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _get_list_keys(command_handle, wallet_handle, cb);
+    let err = _get_list_keys(command_handle, wallet_handle, pool_alias, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
@@ -96,11 +149,18 @@ pub fn get_list_keys(wallet_handle: WalletHandle) -> Box<dyn Future<Item = Strin
 fn _get_list_keys(
     command_handle: CommandHandle,
     wallet_handle: WalletHandle,
+    pool_alias: Option<&str>,
     cb: Option<ResponseStringCB>,
 ) -> ErrorCode {
+    let pool_alias_str = opt_c_str!(pool_alias);
 
     ErrorCode::from(unsafe {
-        cheqd_keys::indy_cheqd_keys_get_list_keys(command_handle, wallet_handle, cb)
+        cheqd_keys::indy_cheqd_keys_get_list_keys(
+            command_handle,
+            wallet_handle,
+            opt_c_ptr!(pool_alias, pool_alias_str),
+            cb,
+        )
     })
 }
 
@@ -132,3 +192,77 @@ fn _sign(
         )
     })
 }
+
+/// Gets information about multisig threshold account of several members.
+///
+/// `members` is a json array of members in the order of the multisig key:
+/// [{"alias": string} - key stored in the wallet | {"pub_key": string} - base58-encoded public key]
+pub fn get_multisig_info(
+    wallet_handle: WalletHandle,
+    threshold: u32,
+    members: &str,
+    pool_alias: Option<&str>,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_multisig_info(command_handle, wallet_handle, threshold, members, pool_alias, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_multisig_info(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    threshold: u32,
+    members: &str,
+    pool_alias: Option<&str>,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let members = c_str!(members);
+    let pool_alias_str = opt_c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_keys::indy_cheqd_keys_get_multisig_info(
+            command_handle,
+            wallet_handle,
+            threshold,
+            members.as_ptr(),
+            opt_c_ptr!(pool_alias, pool_alias_str),
+            cb,
+        )
+    })
+}
+
+/// Signs multisig transaction by one of its members. Returns base58-encoded signature.
+pub fn sign_multisig_partial(
+    wallet_handle: WalletHandle,
+    alias: &str,
+    tx: &[u8],
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_multisig_partial(command_handle, wallet_handle, alias, tx, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_multisig_partial(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    alias: &str,
+    tx: &[u8],
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let alias = c_str!(alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_keys::indy_cheqd_keys_sign_multisig_partial(
+            command_handle,
+            wallet_handle,
+            alias.as_ptr(),
+            tx.as_ptr() as *const u8,
+            tx.len() as u32,
+            cb,
+        )
+    })
+}
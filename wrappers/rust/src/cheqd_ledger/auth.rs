@@ -2,6 +2,7 @@ use crate::{ErrorCode, IndyError};
 
 use futures::Future;
 use std::ffi::CString;
+use std::ptr::null;
 use crate::ffi::cheqd_ledger::auth;
 use crate::ffi::{ResponseSliceCB, ResponseStringCB};
 use crate::utils::callbacks::{ClosureHandler, ResultHandler};
@@ -77,6 +78,192 @@ fn _build_tx(
     })
 }
 
+/// Builds transaction of multisig account.
+///
+/// `multisig_info` is the json returned by `cheqd_keys::get_multisig_info`,
+/// `signers` is a json array with base58-encoded public keys of members which are going to sign.
+pub fn build_multisig_tx(
+    pool_alias: &str,
+    multisig_info: &str,
+    signers: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_multisig_tx(
+        command_handle,
+        pool_alias,
+        multisig_info,
+        signers,
+        msg,
+        account_number,
+        sequence_number,
+        max_gas,
+        max_coin_amount,
+        max_coin_denom,
+        timeout_height,
+        memo,
+        cb,
+    );
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_multisig_tx(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    multisig_info: &str,
+    signers: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    max_gas: u64,
+    max_coin_amount: u64,
+    max_coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+    let multisig_info = c_str!(multisig_info);
+    let signers = c_str!(signers);
+    let max_coin_denom = c_str!(max_coin_denom);
+    let memo = c_str!(memo);
+
+    ErrorCode::from(unsafe {
+        auth::indy_cheqd_ledger_auth_build_multisig_tx(
+            command_handle,
+            pool_alias.as_ptr(),
+            multisig_info.as_ptr(),
+            signers.as_ptr(),
+            msg.as_ptr() as *const u8,
+            msg.len() as u32,
+            account_number,
+            sequence_number,
+            max_gas,
+            max_coin_amount,
+            max_coin_denom.as_ptr(),
+            timeout_height,
+            memo.as_ptr(),
+            cb,
+        )
+    })
+}
+
+/// Combines signatures of multisig members into transaction ready for broadcasting.
+///
+/// `signatures` is a json object: {<base58 public key of member>: <base58 signature>}
+pub fn combine_multisig_signatures(
+    tx: &[u8],
+    signatures: &str,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _combine_multisig_signatures(command_handle, tx, signatures, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _combine_multisig_signatures(
+    command_handle: CommandHandle,
+    tx: &[u8],
+    signatures: &str,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let signatures = c_str!(signatures);
+
+    ErrorCode::from(unsafe {
+        auth::indy_cheqd_ledger_auth_combine_multisig_signatures(
+            command_handle,
+            tx.as_ptr() as *const u8,
+            tx.len() as u32,
+            signatures.as_ptr(),
+            cb,
+        )
+    })
+}
+
+/// Builds transaction which gas limit and fee are estimated by simulating it on the ledger.
+///
+/// `fee_options` is an optional json:
+/// {
+///     "gas_price": float, (optional) 0.025 by default
+///     "gas_adjustment": float, (optional) 1.3 by default
+/// }
+pub fn build_tx_with_fee_estimation(
+    pool_alias: &str,
+    sender_public_key: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+    fee_options: Option<&str>,
+) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _build_tx_with_fee_estimation(
+        command_handle,
+        pool_alias,
+        sender_public_key,
+        msg,
+        account_number,
+        sequence_number,
+        coin_denom,
+        timeout_height,
+        memo,
+        fee_options,
+        cb,
+    );
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _build_tx_with_fee_estimation(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    sender_public_key: &str,
+    msg: &[u8],
+    account_number: u64,
+    sequence_number: u64,
+    coin_denom: &str,
+    timeout_height: u64,
+    memo: &str,
+    fee_options: Option<&str>,
+    cb: Option<ResponseSliceCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+    let sender_public_key = c_str!(sender_public_key);
+    let coin_denom = c_str!(coin_denom);
+    let memo = c_str!(memo);
+    let fee_options_str = opt_c_str!(fee_options);
+
+    ErrorCode::from(unsafe {
+        auth::indy_cheqd_ledger_auth_build_tx_with_fee_estimation(
+            command_handle,
+            pool_alias.as_ptr(),
+            sender_public_key.as_ptr(),
+            msg.as_ptr() as *const u8,
+            msg.len() as u32,
+            account_number,
+            sequence_number,
+            coin_denom.as_ptr(),
+            timeout_height,
+            memo.as_ptr(),
+            opt_c_ptr!(fee_options, fee_options_str),
+            cb,
+        )
+    })
+}
+
 pub fn build_query_account(address: &str) -> Box<dyn Future<Item = String, Error = IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
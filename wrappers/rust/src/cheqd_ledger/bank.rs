@@ -11,6 +11,7 @@ use crate::utils::callbacks::{ClosureHandler, ResultHandler};
 use crate::CommandHandle;
 
 pub fn build_msg_send(
+    pool_alias: &str,
     from: &str,
     to: &str,
     amount: &str,
@@ -18,19 +19,21 @@ pub fn build_msg_send(
 ) -> Box<dyn Future<Item = Vec<u8>, Error = IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
 
-    let err = _build_msg_send(command_handle, from, to, amount, denom, cb);
+    let err = _build_msg_send(command_handle, pool_alias, from, to, amount, denom, cb);
 
     ResultHandler::slice(command_handle, err, receiver)
 }
 
 fn _build_msg_send(
     command_handle: CommandHandle,
+    pool_alias: &str,
     from: &str,
     to: &str,
     amount: &str,
     denom: &str,
     cb: Option<ResponseSliceCB>,
 ) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
     let from = c_str!(from);
     let to = c_str!(to);
     let amount = c_str!(amount);
@@ -39,6 +42,7 @@ fn _build_msg_send(
     ErrorCode::from(unsafe {
         cheqd_ledger::bank::indy_cheqd_ledger_bank_build_msg_send(
             command_handle,
+            pool_alias.as_ptr(),
             from.as_ptr(),
             to.as_ptr(),
             amount.as_ptr(),
@@ -75,28 +79,32 @@ fn _parse_msg_send_resp(
 }
 
 pub fn build_query_balance(
+    pool_alias: &str,
     address: &str,
     denom: &str,
 ) -> Box<dyn Future<Item = String, Error = IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _build_query_balance(command_handle, address, denom, cb);
+    let err = _build_query_balance(command_handle, pool_alias, address, denom, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
 
 fn _build_query_balance(
     command_handle: CommandHandle,
+    pool_alias: &str,
     address: &str,
     denom: &str,
     cb: Option<ResponseStringCB>,
 ) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
     let address = c_str!(address);
     let denom = c_str!(denom);
 
     ErrorCode::from(unsafe {
         cheqd_ledger::bank::indy_cheqd_ledger_bank_build_query_balance(
             command_handle,
+            pool_alias.as_ptr(),
             address.as_ptr(),
             denom.as_ptr(),
             cb,
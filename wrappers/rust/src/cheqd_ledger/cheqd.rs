@@ -291,4 +291,32 @@ fn _parse_query_all_nym_resp(
     })
 }
 
+pub fn resolve_did_document(
+    pool_alias: &str,
+    did: &str,
+) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did_document(command_handle, pool_alias, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did_document(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    did: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+    let did = c_str!(did);
 
+    ErrorCode::from(unsafe {
+        cheqd_ledger::cheqd::indy_cheqd_ledger_cheqd_resolve_did_document(
+            command_handle,
+            pool_alias.as_ptr(),
+            did.as_ptr(),
+            cb,
+        )
+    })
+}
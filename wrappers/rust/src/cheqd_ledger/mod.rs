@@ -4,3 +4,5 @@ pub mod auth;
 pub mod cheqd;
 #[cfg(feature = "cheqd")]
 pub mod bank;
+#[cfg(feature = "cheqd")]
+pub mod tx;
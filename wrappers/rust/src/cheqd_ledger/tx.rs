@@ -0,0 +1,86 @@
+use crate::{ErrorCode, IndyError};
+
+use futures::Future;
+use std::ffi::CString;
+use std::ptr::null;
+use crate::ffi::cheqd_ledger::tx;
+use crate::ffi::ResponseStringCB;
+use crate::utils::callbacks::{ClosureHandler, ResultHandler};
+use crate::CommandHandle;
+
+/// Builds query simulating execution of the (not signed) transaction.
+pub fn build_query_simulate(tx: &[u8]) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_query_simulate(command_handle, tx, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_query_simulate(
+    command_handle: CommandHandle,
+    tx: &[u8],
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        tx::indy_cheqd_ledger_tx_build_query_simulate(
+            command_handle,
+            tx.as_ptr() as *const u8,
+            tx.len() as u32,
+            cb,
+        )
+    })
+}
+
+pub fn parse_query_simulate_resp(query_resp: &str) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_query_simulate_resp(command_handle, query_resp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_query_simulate_resp(
+    command_handle: CommandHandle,
+    query_resp: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let query_resp = c_str!(query_resp);
+
+    ErrorCode::from(unsafe {
+        tx::indy_cheqd_ledger_tx_parse_query_simulate_resp(command_handle, query_resp.as_ptr(), cb)
+    })
+}
+
+/// Suggests gas limit and fee for the transaction which used `gas_used` in simulation.
+///
+/// `fee_options` is an optional json:
+/// {
+///     "gas_price": float, (optional) 0.025 by default
+///     "gas_adjustment": float, (optional) 1.3 by default
+/// }
+pub fn estimate_fee(gas_used: u64, fee_options: Option<&str>) -> Box<dyn Future<Item = String, Error = IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _estimate_fee(command_handle, gas_used, fee_options, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _estimate_fee(
+    command_handle: CommandHandle,
+    gas_used: u64,
+    fee_options: Option<&str>,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let fee_options_str = opt_c_str!(fee_options);
+
+    ErrorCode::from(unsafe {
+        tx::indy_cheqd_ledger_tx_estimate_fee(
+            command_handle,
+            gas_used,
+            opt_c_ptr!(fee_options, fee_options_str),
+            cb,
+        )
+    })
+}
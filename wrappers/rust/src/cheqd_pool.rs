@@ -1,4 +1,5 @@
 use std::ffi::CString;
+use std::ptr::null;
 
 use crate::CommandHandle;
 use crate::ffi::ResponseStringCB;
@@ -8,14 +9,22 @@ use crate::utils::callbacks::{ClosureHandler, ResultHandler};
 
 use crate::{ErrorCode, IndyError};
 
+/// Adds information about cheqd pool.
+///
+/// `account_prefix` is an optional bech32 human-readable prefix of network accounts ("cheqd" by default).
+/// `trust_options` is an optional json with the header trusted by the light client verifying query proofs.
+/// `connection_options` is an optional json with backup RPC addresses, request timeout and retry policy.
 pub fn add(
     alias: &str,
     rpc_address: &str,
     chain_id: &str,
+    account_prefix: Option<&str>,
+    trust_options: Option<&str>,
+    connection_options: Option<&str>,
 ) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _add(command_handle, alias, rpc_address, chain_id, cb);
+    let err = _add(command_handle, alias, rpc_address, chain_id, account_prefix, trust_options, connection_options, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
@@ -25,11 +34,17 @@ fn _add(
     alias: &str,
     rpc_address: &str,
     chain_id: &str,
+    account_prefix: Option<&str>,
+    trust_options: Option<&str>,
+    connection_options: Option<&str>,
     cb: Option<ResponseStringCB>,
 ) -> ErrorCode {
     let alias = c_str!(alias);
     let rpc_address = c_str!(rpc_address);
     let chain_id = c_str!(chain_id);
+    let account_prefix_str = opt_c_str!(account_prefix);
+    let trust_options_str = opt_c_str!(trust_options);
+    let connection_options_str = opt_c_str!(connection_options);
 
     ErrorCode::from(unsafe {
         cheqd_pool::indy_cheqd_pool_add(
@@ -37,6 +52,9 @@ fn _add(
             alias.as_ptr(),
             rpc_address.as_ptr(),
             chain_id.as_ptr(),
+            opt_c_ptr!(account_prefix, account_prefix_str),
+            opt_c_ptr!(trust_options, trust_options_str),
+            opt_c_ptr!(connection_options, connection_options_str),
             cb,
         )
     })
@@ -109,6 +127,102 @@ fn _broadcast_tx_commit(
     })
 }
 
+/// Broadcasts transaction and waits only for its check by a node.
+/// Returns json with `hash` of transaction.
+pub fn broadcast_tx_sync(
+    pool_alias: &str,
+    signed_tx: &[u8],
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _broadcast_tx_sync(command_handle, pool_alias, signed_tx, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _broadcast_tx_sync(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    signed_tx: &[u8],
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_broadcast_tx_sync(
+            command_handle,
+            pool_alias.as_ptr(),
+            signed_tx.as_ptr() as *const u8,
+            signed_tx.len() as u32,
+            cb,
+        )
+    })
+}
+
+/// Broadcasts transaction without waiting for its check.
+/// Returns json with `hash` of transaction.
+pub fn broadcast_tx_async(
+    pool_alias: &str,
+    signed_tx: &[u8],
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _broadcast_tx_async(command_handle, pool_alias, signed_tx, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _broadcast_tx_async(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    signed_tx: &[u8],
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_broadcast_tx_async(
+            command_handle,
+            pool_alias.as_ptr(),
+            signed_tx.as_ptr() as *const u8,
+            signed_tx.len() as u32,
+            cb,
+        )
+    })
+}
+
+/// Gets committed transaction by hash.
+/// The result can be parsed like the response of `broadcast_tx_commit`.
+pub fn get_tx(
+    pool_alias: &str,
+    tx_hash: &str,
+) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_tx(command_handle, pool_alias, tx_hash, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_tx(
+    command_handle: CommandHandle,
+    pool_alias: &str,
+    tx_hash: &str,
+    cb: Option<ResponseStringCB>,
+) -> ErrorCode {
+    let pool_alias = c_str!(pool_alias);
+    let tx_hash = c_str!(tx_hash);
+
+    ErrorCode::from(unsafe {
+        cheqd_pool::indy_cheqd_pool_get_tx(
+            command_handle,
+            pool_alias.as_ptr(),
+            tx_hash.as_ptr(),
+            cb,
+        )
+    })
+}
+
 pub fn abci_query(
     pool_alias: &str,
     req_json: &str,
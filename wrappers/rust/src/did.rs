@@ -35,6 +35,7 @@ use crate::{CommandHandle, WalletHandle, PoolHandle};
 ///               currently only 'ed25519' value is supported for this field)
 ///     "cid": bool, (optional; if not set then false is used;)
 ///     "method_name": string, (optional) method name to create fully qualified did.
+///                    did:indy method must include ledger namespace with optional sub-namespace: `indy:sovrin:staging`.
 /// }
 ///
 /// # Returns
@@ -372,6 +373,7 @@ fn _abbreviate_verkey(command_handle: CommandHandle, tgt_did: &str, verkey: &str
 /// * `wallet_handle` - wallet handle (created by Wallet::open)
 /// * `did` - target DID stored in the wallet.
 /// * `method` - method to apply to the DID.
+///              did:indy method must include ledger namespace with optional sub-namespace: `indy:sovrin:staging`.
 ///
 /// # Returns
 /// fully qualified did
@@ -389,3 +391,25 @@ fn _qualify_did(command_handle: CommandHandle, wallet_handle: WalletHandle, did:
 
     ErrorCode::from(unsafe { did::indy_qualify_did(command_handle, wallet_handle, did.as_ptr(), method.as_ptr(), cb) })
 }
+
+/// Resolves W3C DID Document for the DID written on Indy ledger.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `did` - DID to resolve (`did:sov`, `did:indy` or unqualified).
+///
+/// # Returns
+/// DID resolution result json containing `didDocument`, `didResolutionMetadata` and `didDocumentMetadata`
+pub fn resolve_did_document(pool_handle: PoolHandle, did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did_document(command_handle, pool_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did_document(command_handle: CommandHandle, pool_handle: PoolHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_resolve_did_document(command_handle, pool_handle, did.as_ptr(), cb) })
+}
@@ -6,7 +6,9 @@ use std::ptr::null;
 use futures::Future;
 
 use crate::ffi::ledger;
-use crate::ffi::{ResponseStringCB,
+pub use crate::ffi::ledger::{CustomFree, CustomTransactionParser};
+use crate::ffi::{ResponseEmptyCB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
@@ -73,6 +75,83 @@ fn _submit_request(command_handle: CommandHandle, pool_handle: PoolHandle, reque
     ErrorCode::from(unsafe { ledger::indy_submit_request(command_handle, pool_handle, request_json.as_ptr(), cb) })
 }
 
+/// Publishes a batch of read requests to validator pool with a single pool command.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `requests_json` - array of read requests data json.
+///
+/// # Returns
+/// Array of `{"response": ...}` or `{"error": {"code": ..., "message": ...}}` in the same order as requests.
+pub fn submit_requests_batch(pool_handle: PoolHandle, requests_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_requests_batch(command_handle, pool_handle, requests_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_requests_batch(command_handle: CommandHandle, pool_handle: PoolHandle, requests_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let requests_json = c_str!(requests_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_requests_batch(command_handle, pool_handle, requests_json.as_ptr(), cb) })
+}
+
+/// Replicates DOMAIN or CONFIG ledger into a local append-only store verified against multi-signed ledger roots.
+///
+/// Replication resumes right after the last stored transaction.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `ledger_type` - "DOMAIN" or "CONFIG".
+/// * `options_json` - (Optional) `{"batchSize": <int>, "maxTxns": <int>}`.
+///
+/// # Returns
+/// Replication status json.
+pub fn replicate_ledger(pool_handle: PoolHandle, ledger_type: &str, options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _replicate_ledger(command_handle, pool_handle, ledger_type, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _replicate_ledger(command_handle: CommandHandle, pool_handle: PoolHandle, ledger_type: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let ledger_type = c_str!(ledger_type);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_replicate_ledger(command_handle, pool_handle, ledger_type.as_ptr(), opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+/// Reads transactions from the local ledger replica made by `replicate_ledger`.
+///
+/// # Arguments
+/// * `pool_name` - name of the pool ledger configuration.
+/// * `ledger_type` - "DOMAIN" or "CONFIG".
+/// * `filter_json` - (Optional) `{"from": <int>, "to": <int>, "authors": [<did>]}`.
+///
+/// # Returns
+/// `{"seqNo": <int>, "rootHash": string, "txns": [...]}`
+pub fn get_ledger_replica_txns(pool_name: &str, ledger_type: &str, filter_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_ledger_replica_txns(command_handle, pool_name, ledger_type, filter_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_ledger_replica_txns(command_handle: CommandHandle, pool_name: &str, ledger_type: &str, filter_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+    let ledger_type = c_str!(ledger_type);
+    let filter_json_str = opt_c_str!(filter_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_get_ledger_replica_txns(command_handle, pool_name.as_ptr(), ledger_type.as_ptr(), opt_c_ptr!(filter_json, filter_json_str), cb)
+    })
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, wait_timeout: Option<i32>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -994,6 +1073,32 @@ fn _parse_get_revoc_reg_delta_response(command_handle: CommandHandle, get_revoc_
     ErrorCode::from(unsafe { ledger::indy_parse_get_revoc_reg_delta_response(command_handle, get_revoc_reg_delta_response.as_ptr(), cb) })
 }
 
+/// Register callbacks for parsing state proofs of plugged transaction type.
+///
+/// Reads of transactions of the registered type are sent to a single node
+/// and accepted if the reply contains valid state proof and multi-signature.
+///
+/// # Arguments
+/// * `txn_type` - type of transaction to apply `parser` callback.
+/// * `parser` - callback to parse reply for state proof.
+///     Returns serialized as string JSON with array of ParsedSP
+/// * `free` - callback to deallocate memory allocated by `parser`.
+pub fn register_transaction_parser_for_sp(txn_type: &str, parser: CustomTransactionParser, free: CustomFree) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_transaction_parser_for_sp(command_handle, txn_type, parser, free, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_transaction_parser_for_sp(command_handle: CommandHandle, txn_type: &str, parser: CustomTransactionParser, free: CustomFree, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let txn_type = c_str!(txn_type);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_register_transaction_parser_for_sp(command_handle, txn_type.as_ptr(), Some(parser), Some(free), cb)
+    })
+}
+
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
 ///
@@ -1472,6 +1577,69 @@ fn _append_txn_author_agreement_acceptance_to_request(command_handle: CommandHan
     })
 }
 
+/// Stores transaction author agreement acceptance for the pool in the wallet.
+///
+/// `sign_and_submit_request` appends the stored acceptance to DOMAIN write requests
+/// sent to the pool with this name if the ledger requires the transaction author agreement
+/// and `taa_acceptance` isn't set in the pool open config.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `pool_name`: name of the pool ledger configuration.
+/// * `acceptance_json`: transaction author agreement acceptance json
+///     {
+///         "text": string (optional), "version": string (optional) - accepted TAA (passed together),
+///         "taa_digest": string (optional) - digest of the accepted TAA (required if text and version are omitted),
+///         "mechanism": string - acceptance mechanism from the active AML,
+///         "time": int - UTC timestamp of the acceptance.
+///     }
+pub fn store_txn_author_agreement_acceptance(wallet_handle: WalletHandle,
+                                             pool_name: &str,
+                                             acceptance_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _store_txn_author_agreement_acceptance(command_handle, wallet_handle, pool_name, acceptance_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _store_txn_author_agreement_acceptance(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          pool_name: &str,
+                                          acceptance_json: &str,
+                                          cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+    let acceptance_json = c_str!(acceptance_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_store_txn_author_agreement_acceptance(command_handle,
+                                                           wallet_handle,
+                                                           pool_name.as_ptr(),
+                                                           acceptance_json.as_ptr(),
+                                                           cb)
+    })
+}
+
+/// Returns transaction author agreement and acceptance mechanisms active on the ledger.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+///
+/// # Returns
+/// Active transaction author agreement json or null if the ledger doesn't require it
+/// (see `indy_get_pool_txn_author_agreement` for the format).
+pub fn get_pool_txn_author_agreement(pool_handle: PoolHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_txn_author_agreement(command_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_txn_author_agreement(command_handle: CommandHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { ledger::indy_get_pool_txn_author_agreement(command_handle, pool_handle, cb) })
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1510,4 +1678,190 @@ fn _append_request_endorser(command_handle: CommandHandle,
                                              endorser_did.as_ptr(),
                                              cb)
     })
-}
\ No newline at end of file
+}
+
+/// Builds a transaction bundle to collect signatures of the transaction author, the Endorser
+/// and other signers required by the ledger auth rules before submission.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `request_json` - request to collect signatures for.
+/// * `options_json` - (optional) bundle options json:
+///     {
+///         "signers": [string] - (optional) DIDs which must sign the request
+///                               in addition to the transaction author and the Endorser,
+///         "expiresAt": int - (optional) timestamp after which the bundle can't be signed and submitted,
+///         "authAction": string - (optional) auth rule action of the request: "ADD" (default) or "EDIT",
+///     }
+///
+/// # Returns
+/// Transaction bundle json.
+pub fn build_transaction_bundle(pool_handle: PoolHandle, request_json: &str, options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_transaction_bundle(command_handle, pool_handle, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_transaction_bundle(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_transaction_bundle(command_handle, pool_handle, request_json.as_ptr(), opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+/// Adds the signature of the signer to the transaction bundle.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `bundle_json` - transaction bundle (see build_transaction_bundle).
+/// * `signer_did` - DID of the signer stored in the wallet.
+///
+/// # Returns
+/// Transaction bundle json with the signature added.
+pub fn sign_transaction_bundle(wallet_handle: WalletHandle, bundle_json: &str, signer_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_transaction_bundle(command_handle, wallet_handle, bundle_json, signer_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_transaction_bundle(command_handle: CommandHandle, wallet_handle: WalletHandle, bundle_json: &str, signer_did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let bundle_json = c_str!(bundle_json);
+    let signer_did = c_str!(signer_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_sign_transaction_bundle(command_handle, wallet_handle, bundle_json.as_ptr(), signer_did.as_ptr(), cb)
+    })
+}
+
+/// Returns which signatures are still missing in the transaction bundle.
+///
+/// Signatures of the bundle are verified against its request first. Whether signature keys
+/// belong to the signers on the ledger is checked only on submit (see submit_transaction_bundle).
+///
+/// # Arguments
+/// * `bundle_json` - transaction bundle (see build_transaction_bundle).
+///
+/// # Returns
+/// Transaction bundle status json:
+///     {
+///         "missingSigners": [string] - required signers which haven't signed the request yet,
+///         "missingSignatureCount": int - number of signatures still required by the ledger,
+///         "expired": bool - whether the bundle has expired,
+///         "ready": bool - whether the bundle can be submitted,
+///     }
+pub fn get_transaction_bundle_status(bundle_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_transaction_bundle_status(command_handle, bundle_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_transaction_bundle_status(command_handle: CommandHandle, bundle_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let bundle_json = c_str!(bundle_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_get_transaction_bundle_status(command_handle, bundle_json.as_ptr(), cb)
+    })
+}
+
+/// Submits the request of the transaction bundle with all collected signatures to validator pool.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `bundle_json` - transaction bundle (see build_transaction_bundle).
+///
+/// # Returns
+/// Request result as json.
+pub fn submit_transaction_bundle(pool_handle: PoolHandle, bundle_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_transaction_bundle(command_handle, pool_handle, bundle_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_transaction_bundle(command_handle: CommandHandle, pool_handle: PoolHandle, bundle_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let bundle_json = c_str!(bundle_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_submit_transaction_bundle(command_handle, pool_handle, bundle_json.as_ptr(), cb)
+    })
+}
+/// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
+/// * `ledgers_ids` - list of ledgers IDs for freezing ledgers (json format).
+///
+/// # Returns
+/// Request result as json.
+pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_ledgers_freeze_request(command_handle, submitter_did, ledgers_ids, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_ledgers_freeze_request(command_handle: CommandHandle, submitter_did: &str, ledgers_ids: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let ledgers_ids = c_str!(ledgers_ids);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_ledgers_freeze_request(command_handle, submitter_did.as_ptr(), ledgers_ids.as_ptr(), cb)
+    })
+}
+
+/// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+///
+/// # Arguments
+/// * `submitter_did` - DID of the read request sender.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_frozen_ledgers_request(submitter_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_frozen_ledgers_request(command_handle, submitter_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_frozen_ledgers_request(command_handle: CommandHandle, submitter_did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_frozen_ledgers_request(command_handle, submitter_did.as_ptr(), cb)
+    })
+}
+
+/// Parse a GET_FROZEN_LEDGERS response to get list of frozen ledgers.
+///
+/// # Arguments
+/// * `get_frozen_ledgers_response` - response on GET_FROZEN_LEDGERS request.
+///
+/// # Returns
+/// Frozen ledgers json: {<ledger_id>: {"ledger": string, "state": string, "seq_no": u64}}
+pub fn parse_get_frozen_ledgers_response(get_frozen_ledgers_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_frozen_ledgers_response(command_handle, get_frozen_ledgers_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_frozen_ledgers_response(command_handle: CommandHandle, get_frozen_ledgers_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_frozen_ledgers_response = c_str!(get_frozen_ledgers_response);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_parse_get_frozen_ledgers_response(command_handle, get_frozen_ledgers_response.as_ptr(), cb)
+    })
+}
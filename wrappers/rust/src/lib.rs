@@ -271,6 +271,10 @@ pub enum ErrorCode
     #[fail(display = "LedgerNotFound")]
     LedgerNotFound = 309,
 
+    // Ledger response proof doesn't match the state verified by the light client.
+    #[fail(display = "LedgerInvalidProofError")]
+    LedgerInvalidProofError = 310,
+
     // Revocation registry is full and creation of new registry is necessary
     #[fail(display = "AnoncredsRevocationRegistryFullError")]
     AnoncredsRevocationRegistryFullError = 400,
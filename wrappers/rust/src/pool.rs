@@ -58,6 +58,17 @@ fn _create_pool_ledger_config(command_handle: CommandHandle, pool_name: &str, po
 ///     "number_read_nodes": int (optional) - the number of nodes to send read requests (2 by default)
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
+///     "taa_acceptance": object (optional) - transaction author agreement acceptance appended to write requests
+///         sent with indy_sign_and_submit_request if the ledger requires it. If omitted, the acceptance
+///         stored in the wallet with indy_store_txn_author_agreement_acceptance is used:
+///         {
+///             "text": string (optional), "version": string (optional) - accepted TAA (passed together),
+///             "taa_digest": string (optional) - digest of the accepted TAA (required if text and version are omitted),
+///             "mechanism": string - acceptance mechanism from the active AML,
+///             "time": int - UTC timestamp of the acceptance (rounded to the day)
+///         }
+///     "taa_change_policy": string (optional) - "fail" (default) or "warn". Whether indy_sign_and_submit_request fails
+///         or only logs a warning if the accepted TAA isn't the one active on the ledger.
 /// }
 ///
 /// # Returns
@@ -93,6 +104,67 @@ fn _refresh_pool_ledger(command_handle: CommandHandle, pool_handle: PoolHandle,
     ErrorCode::from(unsafe { pool::indy_refresh_pool_ledger(command_handle, pool_handle, cb) })
 }
 
+/// Returns health of the opened pool: state, last synced merkle tree and per-node statistics.
+///
+/// # Arguments
+/// * `handle` - pool handle returned by `open_pool_ledger`
+///
+/// # Returns
+/// Pool status json (see `indy_get_pool_status` for the format)
+pub fn get_pool_status(pool_handle: PoolHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_status(command_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_status(command_handle: CommandHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { pool::indy_get_pool_status(command_handle, pool_handle, cb) })
+}
+
+/// Exports the pool ledger cache of the opened pool into a portable snapshot file.
+///
+/// # Arguments
+/// * `handle` - pool handle returned by `open_pool_ledger`
+/// * `snapshot_path` - path of the snapshot file to create
+pub fn export_pool_ledger_snapshot(pool_handle: PoolHandle, snapshot_path: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _export_pool_ledger_snapshot(command_handle, pool_handle, snapshot_path, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _export_pool_ledger_snapshot(command_handle: CommandHandle, pool_handle: PoolHandle, snapshot_path: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let snapshot_path = c_str!(snapshot_path);
+
+    ErrorCode::from(unsafe { pool::indy_export_pool_ledger_snapshot(command_handle, pool_handle, snapshot_path.as_ptr(), cb) })
+}
+
+/// Imports pool ledger snapshot created by `export_pool_ledger_snapshot`.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+/// * `snapshot_path` - path of the snapshot file
+/// * `config` (optional) - Pool configuration json used if the configuration doesn't exist.
+///                         See `create_pool_ledger_config`.
+pub fn import_pool_ledger_snapshot(pool_name: &str, snapshot_path: &str, pool_config: Option<&str>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _import_pool_ledger_snapshot(command_handle, pool_name, snapshot_path, pool_config, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _import_pool_ledger_snapshot(command_handle: CommandHandle, pool_name: &str, snapshot_path: &str, pool_config: Option<&str>, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+    let snapshot_path = c_str!(snapshot_path);
+    let pool_config_str = opt_c_str!(pool_config);
+
+    ErrorCode::from(unsafe { pool::indy_import_pool_ledger_snapshot(command_handle, pool_name.as_ptr(), snapshot_path.as_ptr(), opt_c_ptr!(pool_config, pool_config_str), cb) })
+}
+
 /// Lists names of created pool ledgers
 pub fn list_pools() -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
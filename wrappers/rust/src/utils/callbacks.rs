@@ -18,13 +18,14 @@ lazy_static! {
     static ref CALLBACKS_HANDLE: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<CommandHandle, IndyError>>>> = Default::default();
     static ref CALLBACKS_WALLETHANDLE: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<WalletHandle, IndyError>>>> = Default::default();
     static ref CALLBACKS_VDRHANDLE: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<VdrHandle, IndyError>>>> = Default::default();
-    static ref CALLBACKS_PREPAREDTXNHANDLE: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String, Vec<u8>, Vec<u8>, String), IndyError>>>> = Default::default();
+    static ref CALLBACKS_PREPAREDTXNHANDLE: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String, Vec<u8>, Vec<u8>, Option<String>), IndyError>>>> = Default::default();
     static ref CALLBACKS_BOOL: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<bool, IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_SLICE: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, Vec<u8>), IndyError>>>> = Default::default();
     static ref CALLBACKS_HANDLE_USIZE: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(CommandHandle, usize), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_STR_U64: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String, u64), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<String, IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_I64: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, i64), IndyError>>>> = Default::default();
+    static ref CALLBACKS_STR_U64: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, u64), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_STR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_OPTSTR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, Option<String>), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_STR_STR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String, String), IndyError>>>> = Default::default();
@@ -74,9 +75,9 @@ impl ClosureHandler {
 
     cb_ec!(cb_ec_vdrhandle(handle:VdrHandle)->VdrHandle, CALLBACKS_VDRHANDLE, handle);
 
-    cb_ec!(cb_ec_preparedtxnhandle(namespace: *const c_char, signature_spec: *const c_char, txn_bytes_raw: *const u8, txn_bytes_len: u32, bytes_to_sign_raw: *const u8, bytes_to_sign_len: u32, endorsement_spec: *const c_char)->(String, String, Vec<u8>, Vec<u8>, String),
+    cb_ec!(cb_ec_preparedtxnhandle(namespace: *const c_char, signature_spec: *const c_char, txn_bytes_raw: *const u8, txn_bytes_len: u32, bytes_to_sign_raw: *const u8, bytes_to_sign_len: u32, endorsement_spec: *const c_char)->(String, String, Vec<u8>, Vec<u8>, Option<String>),
         CALLBACKS_PREPAREDTXNHANDLE,
-        (rust_str!(namespace), rust_str!(signature_spec), rust_slice!(txn_bytes_raw, txn_bytes_len).to_owned(), rust_slice!(bytes_to_sign_raw, bytes_to_sign_len).to_owned(), rust_str!(endorsement_spec)));
+        (rust_str!(namespace), rust_str!(signature_spec), rust_slice!(txn_bytes_raw, txn_bytes_len).to_owned(), rust_slice!(bytes_to_sign_raw, bytes_to_sign_len).to_owned(), opt_rust_str!(endorsement_spec)));
 
     cb_ec!(cb_ec_handle_usize(handle:CommandHandle, u: usize)->(CommandHandle, usize), CALLBACKS_HANDLE_USIZE, (handle, u));
 
@@ -88,6 +89,10 @@ impl ClosureHandler {
            CALLBACKS_STR_I64,
            (rust_str!(str1), num));
 
+    cb_ec!(cb_ec_string_u64(str1:*const c_char, u: u64)->(String, u64),
+           CALLBACKS_STR_U64,
+           (rust_str!(str1), u));
+
     cb_ec!(cb_ec_string_string(str1:*const c_char, str2:*const c_char)->(String, String),
            CALLBACKS_STR_STR,
            (rust_str!(str1), rust_str!(str2)));
@@ -142,11 +147,12 @@ impl ResultHandler {
     result_handler!(handle(CommandHandle), CALLBACKS_HANDLE);
     result_handler!(wallethandle(WalletHandle), CALLBACKS_WALLETHANDLE);
     result_handler!(vdrhandle(VdrHandle), CALLBACKS_VDRHANDLE);
-    result_handler!(preparedtxnhandle((String, String, Vec<u8>, Vec<u8>, String)), CALLBACKS_PREPAREDTXNHANDLE);
+    result_handler!(preparedtxnhandle((String, String, Vec<u8>, Vec<u8>, Option<String>)), CALLBACKS_PREPAREDTXNHANDLE);
     result_handler!(slice(Vec<u8>), CALLBACKS_SLICE);
     result_handler!(bool(bool), CALLBACKS_BOOL);
     result_handler!(str(String), CALLBACKS_STR);
     result_handler!(str_i64((String, i64)), CALLBACKS_STR_I64);
+    result_handler!(str_u64((String, u64)), CALLBACKS_STR_U64);
     result_handler!(handle_usize((CommandHandle, usize)), CALLBACKS_HANDLE_USIZE);
     result_handler!(str_slice((String, Vec<u8>)), CALLBACKS_STR_SLICE);
     result_handler!(str_str((String, String)), CALLBACKS_STR_STR);
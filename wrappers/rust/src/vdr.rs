@@ -53,7 +53,7 @@ fn _vdr_register_cheqd_ledger(command_handle: CommandHandle, vdr_handle: VdrHand
     let node_addrs_list = c_str!(node_addrs_list);
 
     ErrorCode::from(unsafe {
-        vdr::vdr_register_indy_ledger(command_handle, vdr_handle, namespace_list.as_ptr(), chain_id.as_ptr(), node_addrs_list.as_ptr(), cb)
+        vdr::vdr_register_cheqd_ledger(command_handle, vdr_handle, namespace_list.as_ptr(), chain_id.as_ptr(), node_addrs_list.as_ptr(), cb)
     })
 }
 
@@ -138,7 +138,7 @@ fn _vdr_resolve_cred_def(command_handle: CommandHandle, vdr_handle: VdrHandle, f
     })
 }
 
-pub fn vdr_prepare_did(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_did: &str, endorser: &str) -> Box<dyn Future<Item=(String, String, Vec<u8>, Vec<u8>, String), Error=IndyError>> {
+pub fn vdr_prepare_did(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_did: &str, endorser: Option<&str>) -> Box<dyn Future<Item=(String, String, Vec<u8>, Vec<u8>, Option<String>), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_preparedtxnhandle();
 
     let err = _vdr_prepare_did(command_handle, vdr_handle, txn_specific_params, submitter_did, endorser, cb);
@@ -146,17 +146,17 @@ pub fn vdr_prepare_did(vdr_handle: VdrHandle, txn_specific_params: &str, submitt
     ResultHandler::preparedtxnhandle(command_handle, err, receiver)
 }
 
-fn _vdr_prepare_did(command_handle: CommandHandle, vdr_handle: VdrHandle, txn_specific_params: &str, submitter_did: &str, endorser: &str, cb: Option<ResponsePreparedTxnCB>) -> ErrorCode {
+fn _vdr_prepare_did(command_handle: CommandHandle, vdr_handle: VdrHandle, txn_specific_params: &str, submitter_did: &str, endorser: Option<&str>, cb: Option<ResponsePreparedTxnCB>) -> ErrorCode {
     let txn_specific_params = c_str!(txn_specific_params);
     let submitter_did = c_str!(submitter_did);
-    let endorser = c_str!(endorser);
+    let endorser_str = opt_c_str!(endorser);
 
     ErrorCode::from(unsafe {
-        vdr::vdr_prepare_did(command_handle, vdr_handle, txn_specific_params.as_ptr(), submitter_did.as_ptr(), endorser.as_ptr(), cb)
+        vdr::vdr_prepare_did(command_handle, vdr_handle, txn_specific_params.as_ptr(), submitter_did.as_ptr(), opt_c_ptr!(endorser, endorser_str), cb)
     })
 }
 
-pub fn vdr_prepare_schema(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_schema: &str, endorser: &str) -> Box<dyn Future<Item=(String, String, Vec<u8>, Vec<u8>, String), Error=IndyError>> {
+pub fn vdr_prepare_schema(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_schema: &str, endorser: Option<&str>) -> Box<dyn Future<Item=(String, String, Vec<u8>, Vec<u8>, Option<String>), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_preparedtxnhandle();
 
     let err = _vdr_prepare_schema(command_handle, vdr_handle, txn_specific_params, submitter_schema, endorser, cb);
@@ -164,17 +164,17 @@ pub fn vdr_prepare_schema(vdr_handle: VdrHandle, txn_specific_params: &str, subm
     ResultHandler::preparedtxnhandle(command_handle, err, receiver)
 }
 
-fn _vdr_prepare_schema(command_handle: CommandHandle, vdr_handle: VdrHandle, txn_specific_params: &str, submitter_schema: &str, endorser: &str, cb: Option<ResponsePreparedTxnCB>) -> ErrorCode {
+fn _vdr_prepare_schema(command_handle: CommandHandle, vdr_handle: VdrHandle, txn_specific_params: &str, submitter_schema: &str, endorser: Option<&str>, cb: Option<ResponsePreparedTxnCB>) -> ErrorCode {
     let txn_specific_params = c_str!(txn_specific_params);
     let submitter_schema = c_str!(submitter_schema);
-    let endorser = c_str!(endorser);
+    let endorser_str = opt_c_str!(endorser);
 
     ErrorCode::from(unsafe {
-        vdr::vdr_prepare_schema(command_handle, vdr_handle, txn_specific_params.as_ptr(), submitter_schema.as_ptr(), endorser.as_ptr(), cb)
+        vdr::vdr_prepare_schema(command_handle, vdr_handle, txn_specific_params.as_ptr(), submitter_schema.as_ptr(), opt_c_ptr!(endorser, endorser_str), cb)
     })
 }
 
-pub fn vdr_prepare_cred_def(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_cred_def: &str, endorser: &str) -> Box<dyn Future<Item=(String, String, Vec<u8>, Vec<u8>, String), Error=IndyError>> {
+pub fn vdr_prepare_cred_def(vdr_handle: VdrHandle, txn_specific_params: &str, submitter_cred_def: &str, endorser: Option<&str>) -> Box<dyn Future<Item=(String, String, Vec<u8>, Vec<u8>, Option<String>), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_preparedtxnhandle();
 
     let err = _vdr_prepare_cred_def(command_handle, vdr_handle, txn_specific_params, submitter_cred_def, endorser, cb);
@@ -182,49 +182,66 @@ pub fn vdr_prepare_cred_def(vdr_handle: VdrHandle, txn_specific_params: &str, su
     ResultHandler::preparedtxnhandle(command_handle, err, receiver)
 }
 
-fn _vdr_prepare_cred_def(command_handle: CommandHandle, vdr_handle: VdrHandle, txn_specific_params: &str, submitter_cred_def: &str, endorser: &str, cb: Option<ResponsePreparedTxnCB>) -> ErrorCode {
+fn _vdr_prepare_cred_def(command_handle: CommandHandle, vdr_handle: VdrHandle, txn_specific_params: &str, submitter_cred_def: &str, endorser: Option<&str>, cb: Option<ResponsePreparedTxnCB>) -> ErrorCode {
     let txn_specific_params = c_str!(txn_specific_params);
     let submitter_cred_def = c_str!(submitter_cred_def);
-    let endorser = c_str!(endorser);
+    let endorser_str = opt_c_str!(endorser);
 
     ErrorCode::from(unsafe {
-        vdr::vdr_prepare_cred_def(command_handle, vdr_handle, txn_specific_params.as_ptr(), submitter_cred_def.as_ptr(), endorser.as_ptr(), cb)
+        vdr::vdr_prepare_cred_def(command_handle, vdr_handle, txn_specific_params.as_ptr(), submitter_cred_def.as_ptr(), opt_c_ptr!(endorser, endorser_str), cb)
     })
 }
 
 pub fn vdr_submit_txn(
-    vdr_hanlde: VdrHandle,
+    vdr_handle: VdrHandle,
     namespace: &str,
     signature_spec: &str,
     txn_bytes: &[u8],
     signature: &[u8],
-    endorsement_spec: &str,
+    endorsement: Option<&str>,
 ) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _vdr_submit_txn(command_handle, vdr_hanlde, namespace, signature_spec, txn_bytes, signature, endorsement_spec, cb);
+    let err = _vdr_submit_txn(command_handle, vdr_handle, namespace, signature_spec, txn_bytes, signature, endorsement, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
 
 fn _vdr_submit_txn(
     command_handle: CommandHandle,
-    vdr_hanlde: VdrHandle,
+    vdr_handle: VdrHandle,
     namespace: &str,
     signature_spec: &str,
     txn_bytes: &[u8],
     signature: &[u8],
-    endorsement_spec: &str,
+    endorsement: Option<&str>,
     cb: Option<ResponseStringCB>,
 ) -> ErrorCode {
     let namespace = c_str!(namespace);
     let signature_spec = c_str!(signature_spec);
-    let endorsement_spec = c_str!(endorsement_spec);
+    let endorsement_str = opt_c_str!(endorsement);
 
     ErrorCode::from(unsafe {
-        vdr::vdr_submit_txn(command_handle, vdr_hanlde, namespace.as_ptr(), signature_spec.as_ptr(),
+        vdr::vdr_submit_txn(command_handle, vdr_handle, namespace.as_ptr(), signature_spec.as_ptr(),
                             txn_bytes.as_ptr() as *const u8, txn_bytes.len() as u32,
                             signature.as_ptr() as *const u8, signature.len() as u32,
-                            endorsement_spec.as_ptr(), cb)
+                            opt_c_ptr!(endorsement, endorsement_str), cb)
+    })
+}
+
+pub fn vdr_submit_query(vdr_handle: VdrHandle, namespace: &str, query: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _vdr_submit_query(command_handle, vdr_handle, namespace, query, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _vdr_submit_query(command_handle: CommandHandle, vdr_handle: VdrHandle, namespace: &str, query: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let namespace = c_str!(namespace);
+    let query = c_str!(query);
+
+    ErrorCode::from(unsafe {
+        vdr::vdr_submit_query(command_handle, vdr_handle, namespace.as_ptr(), query.as_ptr(), cb)
     })
 }
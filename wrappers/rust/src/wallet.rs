@@ -499,6 +499,38 @@ fn _delete_wallet_record(command_handle: CommandHandle, wallet_handle: WalletHan
     })
 }
 
+/// Apply several wallet record changes atomically
+///
+/// Either all operations are stored or, if any of them fails, none of them is.
+/// Plugged wallet storages don't support atomic batches.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `operations_json` - list of operations as json:
+///   [
+///     {"op": "add", "type": <str>, "id": <str>, "value": <str>, "tags": (optional) {<tag_name>: <str>, ...}},
+///     {"op": "update", "type": <str>, "id": <str>, "value": <str>},
+///     {"op": "addTags", "type": <str>, "id": <str>, "tags": {<tag_name>: <str>, ...}},
+///     {"op": "updateTags", "type": <str>, "id": <str>, "tags": {<tag_name>: <str>, ...}},
+///     {"op": "deleteTags", "type": <str>, "id": <str>, "tagNames": [<str>, ...]},
+///     {"op": "delete", "type": <str>, "id": <str>},
+///   ]
+pub fn batch_wallet_records(wallet_handle: WalletHandle, operations_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _batch_wallet_records(command_handle, wallet_handle, operations_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _batch_wallet_records(command_handle: CommandHandle, wallet_handle: WalletHandle, operations_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let operations_json = c_str!(operations_json);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_wallet_batch(command_handle, wallet_handle, operations_json.as_ptr(), cb)
+    })
+}
+
 /// Get an wallet record by id
 ///
 /// # Arguments
@@ -561,6 +593,17 @@ fn _get_wallet_record(command_handle: CommandHandle, wallet_handle: WalletHandle
 ///    retrieveType: (optional, false by default) Retrieve record type,
 ///    retrieveValue: (optional, true by default) Retrieve record value,
 ///    retrieveTags: (optional, false by default) Retrieve record tags,
+///    offset: (optional) Count of matched records to skip,
+///    limit: (optional) Max count of records to return,
+///    sortBy: (optional) Sort records by the value of plaintext tag:
+///      {
+///        tagName: "~tagName", // name of plaintext tag
+///        order: (optional, "asc" by default) "asc" or "desc",
+///      }
+///      Records without the tag go after all others. Order without sortBy is storage specific but stable.
+///    cursor: (optional) Cursor returned by indy_fetch_wallet_search_next_records
+///      to continue search after the last fetched record. Records added or deleted between pages
+///      don't shift the next page. Must be used with the same type, query and sortBy. Can't be combined with offset.
 ///  }
 /// # Returns
 /// * `search_handle` - Wallet search handle that can be used later
@@ -596,6 +639,7 @@ fn _open_wallet_search(command_handle: CommandHandle, wallet_handle: WalletHandl
 /// * `wallet records json` -
 /// {
 ///   totalCount: <str>, // present only if retrieveTotalCount set to true
+///   cursor: <str>, // cursor to continue search from the next record, absent if all records are fetched
 ///   records: [{ // present only if retrieveRecords set to true
 ///       id: "Some id",
 ///       type: "Some type", // present only if retrieveType set to true
@@ -668,3 +712,61 @@ fn _generate_wallet_key(command_handle: CommandHandle, config: Option<&str>, cb:
 
     ErrorCode::from(unsafe { wallet::indy_generate_wallet_key(command_handle, config.as_ptr(), cb) })
 }
+
+/// Rotate wallet data keys and re-encrypt all wallet records with the new ones.
+/// Interrupted rotation is resumed by the next call or on the next wallet opening.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `credentials` - wallet credentials json. Opened wallet doesn't keep the master key,
+///                   so it is derived again to protect the new data keys.
+/// {
+///   "key": string, Key or passphrase the wallet was opened with.
+///   "key_derivation_method": optional<string> Algorithm used for wallet key derivation:
+///                             ARGON2I_MOD (used by default), ARGON2I_INT or RAW.
+/// }
+/// * `config` - (optional) rotation configuration json.
+/// {
+///   "batch_size": optional<int>, Count of records re-encrypted in one atomic batch. Defaults to 100.
+/// }
+///
+/// # Returns
+/// final rotation progress json
+/// {
+///   "total": int, Count of wallet records
+///   "processed": int, Count of processed records
+///   "completed": bool, Whether rotation is completed
+/// }
+pub fn rotate_wallet_data_keys(wallet_handle: WalletHandle, credentials: &str, config: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _rotate_wallet_data_keys(command_handle, wallet_handle, credentials, config, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _rotate_wallet_data_keys(command_handle: CommandHandle, wallet_handle: WalletHandle, credentials: &str, config: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let credentials = c_str!(credentials);
+    let config = opt_c_str_json!(config);
+
+    ErrorCode::from(unsafe { wallet::indy_rotate_wallet_data_keys(command_handle, wallet_handle, credentials.as_ptr(), config.as_ptr(), cb) })
+}
+
+/// Get progress of the wallet data keys rotation
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+///
+/// # Returns
+/// rotation progress json or "null" if rotation wasn't started for the opened wallet
+pub fn get_wallet_data_keys_rotation_progress(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_wallet_data_keys_rotation_progress(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_wallet_data_keys_rotation_progress(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { wallet::indy_get_wallet_data_keys_rotation_progress(command_handle, wallet_handle, cb) })
+}